@@ -0,0 +1,592 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use avro_rs::{rabin::Rabin, types::Value as AvroValue, Schema};
+use bytes::{Buf, Bytes};
+use chrono::{TimeZone, Utc};
+use lookup::lookup_v2::parse_value_path;
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use value::{
+    kind::{Collection, Field},
+    Kind, Value,
+};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::Event,
+    schema,
+};
+
+use super::Deserializer;
+
+/// The magic byte that starts every message in the Confluent Schema Registry
+/// wire format.
+const CONFLUENT_MAGIC_BYTE: u8 = 0;
+
+/// The length of the Confluent wire format prefix: the magic byte followed by a
+/// big-endian, 4-byte schema ID.
+const CONFLUENT_PREFIX_LEN: usize = 5;
+
+/// The marker that starts every message in the Avro single-object encoding.
+const SINGLE_OBJECT_MARKER: [u8; 2] = [0xc3, 0x01];
+
+/// Config used to build a `AvroDeserializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AvroDeserializerConfig {
+    /// Options for the Avro deserializer.
+    pub avro: AvroDeserializerOptions,
+}
+
+impl AvroDeserializerConfig {
+    /// Creates a new `AvroDeserializerConfig`.
+    pub const fn new(avro: AvroDeserializerOptions) -> Self {
+        Self { avro }
+    }
+
+    /// Build the `AvroDeserializer` from this configuration.
+    pub fn build(&self) -> vector_common::Result<AvroDeserializer> {
+        let schema = self.avro.schema()?;
+        let header = match (
+            self.avro.strip_schema_id_prefix,
+            self.avro.single_object_encoding,
+        ) {
+            (false, false) => AvroHeader::None,
+            (true, false) => AvroHeader::Confluent,
+            (false, true) => AvroHeader::SingleObject(schema.fingerprint::<Rabin>().bytes),
+            (true, true) => {
+                return Err(
+                    "Only one of `strip_schema_id_prefix` or `single_object_encoding` can be set for Avro"
+                        .into(),
+                )
+            }
+        };
+        Ok(AvroDeserializer::new(schema, header))
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    ///
+    /// If the Avro schema can't be loaded, a permissive definition is returned
+    /// instead. The error itself is reported when the deserializer is built.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let kind = self
+            .avro
+            .schema()
+            .map(|schema| avro_kind(&schema))
+            .unwrap_or_else(|_| Kind::any_object());
+
+        match log_namespace {
+            LogNamespace::Legacy => {
+                schema::Definition::new_with_default_metadata(kind, [log_namespace]).try_with_field(
+                    &parse_value_path(log_schema().timestamp_key()).expect("valid timestamp key"),
+                    // The Avro decoder will insert a new `timestamp`-type value into the
+                    // "timestamp_key" field, but only if that field doesn't already exist.
+                    Kind::timestamp(),
+                    Some("timestamp"),
+                )
+            }
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(kind, [log_namespace])
+            }
+        }
+    }
+}
+
+/// Apache Avro deserializer options.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+pub struct AvroDeserializerOptions {
+    /// The Avro schema, as a JSON string.
+    ///
+    /// Exactly one of `schema` or `schema_file` must be set.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub schema: Option<String>,
+
+    /// Path to a file containing the Avro schema, as JSON.
+    ///
+    /// Exactly one of `schema` or `schema_file` must be set.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub schema_file: Option<PathBuf>,
+
+    /// Whether each message is prefixed with the Confluent Schema Registry wire format header.
+    ///
+    /// When enabled, the leading magic byte and 4-byte schema ID are validated and stripped
+    /// before the remaining bytes are decoded with the configured schema. The schema ID itself
+    /// isn't used to look up a schema.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub strip_schema_id_prefix: bool,
+
+    /// Whether each message uses the Avro single-object encoding.
+    ///
+    /// When enabled, the leading `C3 01` marker and 8-byte schema fingerprint are validated and
+    /// stripped before the remaining bytes are decoded with the configured schema. The fingerprint
+    /// must be the CRC-64-AVRO fingerprint of the configured schema.
+    ///
+    /// Only one of `strip_schema_id_prefix` or `single_object_encoding` can be set.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub single_object_encoding: bool,
+}
+
+impl AvroDeserializerOptions {
+    fn schema(&self) -> vector_common::Result<Schema> {
+        let schema = match (&self.schema, &self.schema_file) {
+            (Some(schema), None) => schema.clone(),
+            (None, Some(path)) => std::fs::read_to_string(path).map_err(|error| {
+                format!(
+                    "Failed reading Avro schema file {:?}: {}",
+                    path.display(),
+                    error
+                )
+            })?,
+            (Some(_), Some(_)) => {
+                return Err("Only one of `schema` or `schema_file` can be set for Avro".into())
+            }
+            (None, None) => {
+                return Err("One of `schema` or `schema_file` must be set for Avro".into())
+            }
+        };
+
+        let schema = Schema::parse_str(&schema)
+            .map_err(|error| format!("Failed parsing Avro schema: {}", error))?;
+
+        // Each datum becomes the root of a log event, so it has to be an object.
+        match schema {
+            Schema::Record { .. } => Ok(schema),
+            _ => Err("The top-level Avro schema must be a record".into()),
+        }
+    }
+}
+
+/// The header that precedes the Avro datum in each message.
+#[derive(Debug, Clone)]
+pub enum AvroHeader {
+    /// The message only contains the datum.
+    None,
+    /// The Confluent Schema Registry wire format prefix.
+    Confluent,
+    /// The Avro single-object encoding marker, followed by the given schema fingerprint.
+    SingleObject(Vec<u8>),
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a single
+/// Avro datum.
+#[derive(Debug, Clone)]
+pub struct AvroDeserializer {
+    schema: Schema,
+    header: AvroHeader,
+}
+
+impl AvroDeserializer {
+    /// Creates a new `AvroDeserializer`.
+    pub const fn new(schema: Schema, header: AvroHeader) -> Self {
+        Self { schema, header }
+    }
+}
+
+impl Deserializer for AvroDeserializer {
+    fn parse(
+        &self,
+        mut bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        match &self.header {
+            AvroHeader::None => {}
+            AvroHeader::Confluent => {
+                if bytes.len() < CONFLUENT_PREFIX_LEN || bytes[0] != CONFLUENT_MAGIC_BYTE {
+                    return Err("Avro message is missing the Confluent wire format prefix".into());
+                }
+                bytes.advance(CONFLUENT_PREFIX_LEN);
+            }
+            AvroHeader::SingleObject(fingerprint) => {
+                if !bytes.starts_with(&SINGLE_OBJECT_MARKER) {
+                    return Err("Avro message is missing the single-object encoding marker".into());
+                }
+                bytes.advance(SINGLE_OBJECT_MARKER.len());
+                if !bytes.starts_with(fingerprint) {
+                    return Err(
+                        "Avro message fingerprint doesn't match the configured schema".into(),
+                    );
+                }
+                bytes.advance(fingerprint.len());
+            }
+        }
+
+        let mut reader = bytes.reader();
+        let value = avro_rs::from_avro_datum(&self.schema, &mut reader, None)
+            .map_err(|error| format!("Error parsing Avro: {}", error))?;
+        let trailing = reader.get_ref().remaining();
+        if trailing > 0 {
+            return Err(format!("Avro message has {} trailing bytes", trailing).into());
+        }
+
+        let mut log = log_namespace.new_log_from_data(avro_to_value(value)?);
+
+        if let LogNamespace::Legacy = log_namespace {
+            let timestamp_key = log_schema().timestamp_key();
+            if !log.contains(timestamp_key) {
+                log.insert(timestamp_key, Utc::now());
+            }
+        }
+
+        Ok(smallvec![Event::Log(log)])
+    }
+}
+
+fn avro_to_value(value: AvroValue) -> vector_common::Result<Value> {
+    Ok(match value {
+        AvroValue::Null => Value::Null,
+        AvroValue::Boolean(value) => Value::Boolean(value),
+        AvroValue::Int(value) | AvroValue::TimeMillis(value) => Value::Integer(i64::from(value)),
+        AvroValue::Long(value) | AvroValue::TimeMicros(value) => Value::Integer(value),
+        AvroValue::Float(value) => float_to_value(f64::from(value))?,
+        AvroValue::Double(value) => float_to_value(value)?,
+        AvroValue::Bytes(value) | AvroValue::Fixed(_, value) => Value::Bytes(Bytes::from(value)),
+        AvroValue::String(value) | AvroValue::Enum(_, value) => Value::Bytes(Bytes::from(value)),
+        AvroValue::Uuid(value) => Value::Bytes(Bytes::from(value.to_string())),
+        // Decimals are kept as their big-endian, two's complement unscaled
+        // value, which is how Avro encodes them.
+        AvroValue::Decimal(value) => Value::Bytes(Bytes::from(Vec::<u8>::try_from(&value)?)),
+        AvroValue::Date(days) => timestamp_to_value(i64::from(days) * 86_400, 0)?,
+        AvroValue::TimestampMillis(millis) => timestamp_to_value(
+            millis.div_euclid(1_000),
+            millis.rem_euclid(1_000) * 1_000_000,
+        )?,
+        AvroValue::TimestampMicros(micros) => timestamp_to_value(
+            micros.div_euclid(1_000_000),
+            micros.rem_euclid(1_000_000) * 1_000,
+        )?,
+        AvroValue::Duration(duration) => Value::Object(BTreeMap::from([
+            (
+                "months".to_string(),
+                Value::Integer(i64::from(u32::from(duration.months()))),
+            ),
+            (
+                "days".to_string(),
+                Value::Integer(i64::from(u32::from(duration.days()))),
+            ),
+            (
+                "milliseconds".to_string(),
+                Value::Integer(i64::from(u32::from(duration.millis()))),
+            ),
+        ])),
+        AvroValue::Union(value) => avro_to_value(*value)?,
+        AvroValue::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(avro_to_value)
+                .collect::<vector_common::Result<Vec<_>>>()?,
+        ),
+        AvroValue::Map(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| Ok((key, avro_to_value(value)?)))
+                .collect::<vector_common::Result<BTreeMap<_, _>>>()?,
+        ),
+        AvroValue::Record(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| {
+                    let value = avro_to_value(value)
+                        .map_err(|error| format!("Field {:?}: {}", name, error))?;
+                    Ok((name, value))
+                })
+                .collect::<vector_common::Result<BTreeMap<_, _>>>()?,
+        ),
+    })
+}
+
+fn float_to_value(value: f64) -> vector_common::Result<Value> {
+    NotNan::new(value)
+        .map(Value::Float)
+        .map_err(|_| "NaN is not a supported float value".into())
+}
+
+fn timestamp_to_value(seconds: i64, nanos: i64) -> vector_common::Result<Value> {
+    u32::try_from(nanos)
+        .ok()
+        .and_then(|nanos| Utc.timestamp_opt(seconds, nanos).single())
+        .map(Value::Timestamp)
+        .ok_or_else(|| format!("Invalid timestamp {}s {}ns", seconds, nanos).into())
+}
+
+/// Builds the `Kind` of the value an Avro schema is decoded into.
+fn avro_kind(schema: &Schema) -> Kind {
+    match schema {
+        Schema::Null => Kind::null(),
+        Schema::Boolean => Kind::boolean(),
+        Schema::Int | Schema::Long | Schema::TimeMillis | Schema::TimeMicros => Kind::integer(),
+        Schema::Float | Schema::Double => Kind::float(),
+        Schema::Bytes
+        | Schema::String
+        | Schema::Fixed { .. }
+        | Schema::Enum { .. }
+        | Schema::Decimal { .. }
+        | Schema::Uuid => Kind::bytes(),
+        Schema::Date | Schema::TimestampMillis | Schema::TimestampMicros => Kind::timestamp(),
+        Schema::Duration => Kind::object(BTreeMap::from([
+            (Field::from("months"), Kind::integer()),
+            (Field::from("days"), Kind::integer()),
+            (Field::from("milliseconds"), Kind::integer()),
+        ])),
+        Schema::Array(items) => Kind::array(Collection::from_unknown(avro_kind(items))),
+        Schema::Map(values) => Kind::object(Collection::from_unknown(avro_kind(values))),
+        Schema::Union(union) => union
+            .variants()
+            .iter()
+            .fold(Kind::never(), |kind, variant| {
+                kind.union(avro_kind(variant))
+            }),
+        Schema::Record { fields, .. } => Kind::object(
+            fields
+                .iter()
+                .map(|field| (Field::from(field.name.as_str()), avro_kind(&field.schema)))
+                .collect::<BTreeMap<_, _>>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use avro_rs::types::Record;
+
+    use super::*;
+
+    const SCHEMA: &str = r#"
+        {
+            "type": "record",
+            "name": "Log",
+            "fields": [
+                {"name": "message", "type": "string"},
+                {"name": "count", "type": "long"},
+                {"name": "level", "type": {"type": "enum", "name": "Level", "symbols": ["INFO", "WARN"]}},
+                {"name": "host", "type": ["null", "string"]},
+                {"name": "tags", "type": {"type": "array", "items": "string"}},
+                {"name": "attributes", "type": {"type": "map", "values": "int"}},
+                {"name": "created", "type": {"type": "long", "logicalType": "timestamp-millis"}}
+            ]
+        }
+    "#;
+
+    fn test_data_dir() -> PathBuf {
+        PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("tests/data/avro")
+    }
+
+    fn options() -> AvroDeserializerOptions {
+        AvroDeserializerOptions {
+            schema: Some(SCHEMA.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn encode_record(schema: &Schema) -> Vec<u8> {
+        let mut record = Record::new(schema).unwrap();
+        record.put("message", "hello");
+        record.put("count", 42i64);
+        record.put("level", AvroValue::Enum(1, "WARN".to_string()));
+        record.put(
+            "host",
+            AvroValue::Union(Box::new(AvroValue::String("localhost".to_string()))),
+        );
+        record.put(
+            "tags",
+            AvroValue::Array(vec![AvroValue::String("a".to_string())]),
+        );
+        record.put(
+            "attributes",
+            AvroValue::Map(
+                [("code".to_string(), AvroValue::Int(7))]
+                    .into_iter()
+                    .collect(),
+            ),
+        );
+        record.put("created", AvroValue::TimestampMillis(1_660_000_000_123));
+        avro_rs::to_avro_datum(schema, record).unwrap()
+    }
+
+    fn assert_event(event: &Event) {
+        let log = event.as_log();
+        assert_eq!(log["message"], "hello".into());
+        assert_eq!(log["count"], 42.into());
+        assert_eq!(log["level"], "WARN".into());
+        assert_eq!(log["host"], "localhost".into());
+        assert_eq!(log["tags"], Value::Array(vec!["a".into()]));
+        assert_eq!(log["attributes.code"], 7.into());
+        assert_eq!(
+            log["created"],
+            Value::Timestamp(Utc.timestamp_opt(1_660_000_000, 123_000_000).unwrap())
+        );
+    }
+
+    #[test]
+    fn deserialize_avro() {
+        let deserializer = AvroDeserializerConfig::new(options()).build().unwrap();
+        let input = Bytes::from(encode_record(&deserializer.schema));
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+            assert_event(&events[0]);
+
+            let log = events[0].as_log();
+            match namespace {
+                LogNamespace::Legacy => assert!(log.get(log_schema().timestamp_key()).is_some()),
+                LogNamespace::Vector => assert!(log.get(log_schema().timestamp_key()).is_none()),
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_avro_schema_file() {
+        let deserializer = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema_file: Some(test_data_dir().join("test_avro.avsc")),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+        let input = Bytes::from(encode_record(&deserializer.schema));
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert_event(&events[0]);
+    }
+
+    #[test]
+    fn deserialize_avro_confluent_prefix() {
+        let deserializer = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            strip_schema_id_prefix: true,
+            ..options()
+        })
+        .build()
+        .unwrap();
+
+        let mut input = vec![CONFLUENT_MAGIC_BYTE, 0, 0, 0, 42];
+        input.extend(encode_record(&deserializer.schema));
+        let events = deserializer
+            .parse(Bytes::from(input), LogNamespace::Vector)
+            .unwrap();
+        assert_event(&events[0]);
+
+        let unprefixed = Bytes::from(encode_record(&deserializer.schema));
+        assert!(deserializer
+            .parse(unprefixed, LogNamespace::Vector)
+            .is_err());
+    }
+
+    #[test]
+    fn deserialize_avro_single_object_encoding() {
+        let deserializer = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            single_object_encoding: true,
+            ..options()
+        })
+        .build()
+        .unwrap();
+
+        let mut input = SINGLE_OBJECT_MARKER.to_vec();
+        input.extend(deserializer.schema.fingerprint::<Rabin>().bytes);
+        input.extend(encode_record(&deserializer.schema));
+        let events = deserializer
+            .parse(Bytes::from(input.clone()), LogNamespace::Vector)
+            .unwrap();
+        assert_event(&events[0]);
+
+        // The fingerprint must be the one of the configured schema.
+        input[2] ^= 0xff;
+        assert!(deserializer
+            .parse(Bytes::from(input), LogNamespace::Vector)
+            .is_err());
+
+        let unprefixed = Bytes::from(encode_record(&deserializer.schema));
+        assert!(deserializer
+            .parse(unprefixed, LogNamespace::Vector)
+            .is_err());
+    }
+
+    #[test]
+    fn avro_schema_definition() {
+        let definition =
+            AvroDeserializerConfig::new(options()).schema_definition(LogNamespace::Vector);
+        let fields = definition.event_kind().as_object().unwrap().known();
+
+        assert_eq!(fields[&Field::from("message")], Kind::bytes());
+        assert_eq!(fields[&Field::from("host")], Kind::null().or_bytes());
+        assert_eq!(fields[&Field::from("created")], Kind::timestamp());
+        assert_eq!(
+            fields[&Field::from("tags")],
+            Kind::array(Collection::from_unknown(Kind::bytes()))
+        );
+    }
+
+    #[test]
+    fn deserialize_avro_error() {
+        let deserializer = AvroDeserializerConfig::new(options()).build().unwrap();
+        assert!(deserializer
+            .parse(Bytes::from_static(b"\x02"), LogNamespace::Vector)
+            .is_err());
+
+        let mut input = encode_record(&deserializer.schema);
+        input.push(0);
+        let error = deserializer
+            .parse(Bytes::from(input), LogNamespace::Vector)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Avro message has 1 trailing bytes");
+    }
+
+    #[test]
+    fn build_avro_non_record_schema() {
+        let error = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema: Some(r#""string""#.to_string()),
+            ..Default::default()
+        })
+        .build()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The top-level Avro schema must be a record"
+        );
+    }
+
+    #[test]
+    fn build_avro_header_conflict() {
+        let error = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            strip_schema_id_prefix: true,
+            single_object_encoding: true,
+            ..options()
+        })
+        .build()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Only one of `strip_schema_id_prefix` or `single_object_encoding` can be set for Avro"
+        );
+    }
+
+    #[test]
+    fn build_avro_schema_conflict() {
+        let error = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema_file: Some(test_data_dir().join("test_avro.avsc")),
+            ..options()
+        })
+        .build()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Only one of `schema` or `schema_file` can be set for Avro"
+        );
+    }
+}
@@ -0,0 +1,437 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use chrono::{TimeZone, Utc};
+use lookup::lookup_v2::parse_value_path;
+use ordered_float::NotNan;
+use prost_reflect::{DynamicMessage, MapKey, MessageDescriptor};
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use value::{
+    kind::{Collection, Field},
+    Kind, Value,
+};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::Event,
+    schema,
+};
+
+use super::Deserializer;
+use crate::protobuf::{get_message_descriptor, TIMESTAMP_MESSAGE_TYPE};
+
+/// Config used to build a `ProtobufDeserializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProtobufDeserializerConfig {
+    /// Options for the Protobuf deserializer.
+    pub protobuf: ProtobufDeserializerOptions,
+}
+
+impl ProtobufDeserializerConfig {
+    /// Creates a new `ProtobufDeserializerConfig`.
+    pub const fn new(desc_file: PathBuf, message_type: String) -> Self {
+        Self {
+            protobuf: ProtobufDeserializerOptions {
+                desc_file,
+                message_type,
+            },
+        }
+    }
+
+    /// Build the `ProtobufDeserializer` from this configuration.
+    pub fn build(&self) -> vector_common::Result<ProtobufDeserializer> {
+        let message_descriptor = self.protobuf.message_descriptor()?;
+        Ok(ProtobufDeserializer::new(message_descriptor))
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    ///
+    /// If the descriptor set can't be loaded, a permissive definition is
+    /// returned instead. The error itself is reported when the deserializer is
+    /// built.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let kind = self
+            .protobuf
+            .message_descriptor()
+            .map(|descriptor| message_kind(&descriptor, &mut HashSet::new()))
+            .unwrap_or_else(|_| Kind::any_object());
+
+        match log_namespace {
+            LogNamespace::Legacy => {
+                schema::Definition::new_with_default_metadata(kind, [log_namespace]).try_with_field(
+                    &parse_value_path(log_schema().timestamp_key()).expect("valid timestamp key"),
+                    // The Protobuf decoder will insert a new `timestamp`-type value into the
+                    // "timestamp_key" field, but only if that field doesn't already exist.
+                    Kind::timestamp(),
+                    Some("timestamp"),
+                )
+            }
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(kind, [log_namespace])
+            }
+        }
+    }
+}
+
+/// Protobuf-specific decoding options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ProtobufDeserializerOptions {
+    /// Path to a compiled `FileDescriptorSet` containing the message type.
+    ///
+    /// The file can be generated with `protoc --include_imports --descriptor_set_out=<file>`.
+    pub desc_file: PathBuf,
+
+    /// The fully qualified name of the message type to decode each frame as, e.g. `package.Message`.
+    pub message_type: String,
+}
+
+impl ProtobufDeserializerOptions {
+    fn message_descriptor(&self) -> vector_common::Result<MessageDescriptor> {
+        get_message_descriptor(&self.desc_file, &self.message_type)
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a Protobuf
+/// message of a user-supplied type.
+#[derive(Debug, Clone)]
+pub struct ProtobufDeserializer {
+    message_descriptor: MessageDescriptor,
+}
+
+impl ProtobufDeserializer {
+    /// Creates a new `ProtobufDeserializer`.
+    pub const fn new(message_descriptor: MessageDescriptor) -> Self {
+        Self { message_descriptor }
+    }
+}
+
+impl Deserializer for ProtobufDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let message = DynamicMessage::decode(self.message_descriptor.clone(), bytes)
+            .map_err(|error| format!("Error parsing Protobuf: {:?}", error))?;
+
+        let mut log = log_namespace.new_log_from_data(message_to_value(&message)?);
+
+        if let LogNamespace::Legacy = log_namespace {
+            let timestamp_key = log_schema().timestamp_key();
+            if !log.contains(timestamp_key) {
+                log.insert(timestamp_key, Utc::now());
+            }
+        }
+
+        Ok(smallvec![Event::Log(log)])
+    }
+}
+
+/// Converts a decoded message into an object, skipping fields that aren't set.
+fn message_to_value(message: &DynamicMessage) -> vector_common::Result<Value> {
+    let mut object = BTreeMap::new();
+    for (field, value) in message.fields() {
+        let value = proto_to_value(value, &field.kind())
+            .map_err(|error| format!("Field {:?}: {}", field.name(), error))?;
+        object.insert(field.name().to_string(), value);
+    }
+    Ok(Value::Object(object))
+}
+
+fn proto_to_value(
+    value: &prost_reflect::Value,
+    kind: &prost_reflect::Kind,
+) -> vector_common::Result<Value> {
+    Ok(match value {
+        prost_reflect::Value::Bool(value) => Value::Boolean(*value),
+        prost_reflect::Value::I32(value) => Value::Integer(i64::from(*value)),
+        prost_reflect::Value::I64(value) => Value::Integer(*value),
+        prost_reflect::Value::U32(value) => Value::Integer(i64::from(*value)),
+        prost_reflect::Value::U64(value) => Value::Integer(
+            i64::try_from(*value).map_err(|_| format!("Value {} is out of range", value))?,
+        ),
+        prost_reflect::Value::F32(value) => float_to_value(f64::from(*value))?,
+        prost_reflect::Value::F64(value) => float_to_value(*value)?,
+        prost_reflect::Value::String(value) => Value::Bytes(Bytes::from(value.clone())),
+        prost_reflect::Value::Bytes(value) => Value::Bytes(value.clone()),
+        prost_reflect::Value::EnumNumber(number) => match kind {
+            prost_reflect::Kind::Enum(descriptor) => descriptor
+                .get_value(*number)
+                .map_or(Value::Integer(i64::from(*number)), |value| {
+                    Value::from(value.name())
+                }),
+            _ => Value::Integer(i64::from(*number)),
+        },
+        prost_reflect::Value::Message(message) => match kind {
+            prost_reflect::Kind::Message(descriptor)
+                if descriptor.full_name() == TIMESTAMP_MESSAGE_TYPE =>
+            {
+                timestamp_to_value(message)?
+            }
+            _ => message_to_value(message)?,
+        },
+        prost_reflect::Value::List(values) => Value::Array(
+            values
+                .iter()
+                .map(|value| proto_to_value(value, kind))
+                .collect::<vector_common::Result<Vec<_>>>()?,
+        ),
+        prost_reflect::Value::Map(map) => {
+            let value_kind = match kind {
+                prost_reflect::Kind::Message(descriptor) if descriptor.is_map_entry() => {
+                    descriptor.map_entry_value_field().kind()
+                }
+                _ => return Err("Map field is missing its entry descriptor".into()),
+            };
+            Value::Object(
+                map.iter()
+                    .map(|(key, value)| {
+                        Ok((map_key_to_string(key), proto_to_value(value, &value_kind)?))
+                    })
+                    .collect::<vector_common::Result<BTreeMap<_, _>>>()?,
+            )
+        }
+    })
+}
+
+fn float_to_value(value: f64) -> vector_common::Result<Value> {
+    NotNan::new(value)
+        .map(Value::Float)
+        .map_err(|_| "NaN is not a supported float value".into())
+}
+
+fn timestamp_to_value(message: &DynamicMessage) -> vector_common::Result<Value> {
+    let seconds = message
+        .get_field_by_name("seconds")
+        .and_then(|value| value.as_i64())
+        .unwrap_or_default();
+    let nanos = message
+        .get_field_by_name("nanos")
+        .and_then(|value| value.as_i32())
+        .unwrap_or_default();
+    let nanos = u32::try_from(nanos).map_err(|_| format!("Invalid timestamp nanos {}", nanos))?;
+
+    Utc.timestamp_opt(seconds, nanos)
+        .single()
+        .map(Value::Timestamp)
+        .ok_or_else(|| format!("Invalid timestamp {}s {}ns", seconds, nanos).into())
+}
+
+fn map_key_to_string(key: &MapKey) -> String {
+    match key {
+        MapKey::Bool(value) => value.to_string(),
+        MapKey::I32(value) => value.to_string(),
+        MapKey::I64(value) => value.to_string(),
+        MapKey::U32(value) => value.to_string(),
+        MapKey::U64(value) => value.to_string(),
+        MapKey::String(value) => value.clone(),
+    }
+}
+
+/// Builds the `Kind` of the object a message is decoded into.
+///
+/// Recursive message types are described as arbitrary objects once they are
+/// encountered again, since their depth isn't known up front.
+fn message_kind(descriptor: &MessageDescriptor, seen: &mut HashSet<String>) -> Kind {
+    if !seen.insert(descriptor.full_name().to_string()) {
+        return Kind::any_object();
+    }
+
+    let fields = descriptor
+        .fields()
+        .map(|field| {
+            let kind = if field.is_map() {
+                let value_kind = field.kind().as_message().map_or_else(Kind::any, |entry| {
+                    proto_kind(&entry.map_entry_value_field().kind(), seen)
+                });
+                Kind::object(Collection::from_unknown(value_kind))
+            } else if field.is_list() {
+                Kind::array(Collection::from_unknown(proto_kind(&field.kind(), seen)))
+            } else {
+                proto_kind(&field.kind(), seen)
+            };
+
+            // Fields that aren't set in a message are omitted from the event.
+            (Field::from(field.name()), kind.or_undefined())
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    seen.remove(descriptor.full_name());
+    Kind::object(fields)
+}
+
+fn proto_kind(kind: &prost_reflect::Kind, seen: &mut HashSet<String>) -> Kind {
+    match kind {
+        prost_reflect::Kind::Double | prost_reflect::Kind::Float => Kind::float(),
+        prost_reflect::Kind::Int32
+        | prost_reflect::Kind::Int64
+        | prost_reflect::Kind::Uint32
+        | prost_reflect::Kind::Uint64
+        | prost_reflect::Kind::Sint32
+        | prost_reflect::Kind::Sint64
+        | prost_reflect::Kind::Fixed32
+        | prost_reflect::Kind::Fixed64
+        | prost_reflect::Kind::Sfixed32
+        | prost_reflect::Kind::Sfixed64 => Kind::integer(),
+        prost_reflect::Kind::Bool => Kind::boolean(),
+        prost_reflect::Kind::String | prost_reflect::Kind::Bytes => Kind::bytes(),
+        // Enum values are decoded by name, falling back to the number for
+        // values that aren't part of the descriptor.
+        prost_reflect::Kind::Enum(_) => Kind::bytes().or_integer(),
+        prost_reflect::Kind::Message(descriptor)
+            if descriptor.full_name() == TIMESTAMP_MESSAGE_TYPE =>
+        {
+            Kind::timestamp()
+        }
+        prost_reflect::Kind::Message(descriptor) => message_kind(descriptor, seen),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+
+    fn test_data_dir() -> PathBuf {
+        PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("tests/data/protobuf")
+    }
+
+    fn build_config() -> ProtobufDeserializerConfig {
+        ProtobufDeserializerConfig::new(
+            test_data_dir().join("test_protobuf.desc"),
+            "test_protobuf.Person".to_string(),
+        )
+    }
+
+    fn person_message(descriptor: &MessageDescriptor) -> DynamicMessage {
+        let phone_descriptor = descriptor
+            .parent_pool()
+            .get_message_by_name("test_protobuf.Person.PhoneNumber")
+            .unwrap();
+        let mut phone = DynamicMessage::new(phone_descriptor);
+        phone.set_field_by_name("number", prost_reflect::Value::String("123-456".into()));
+        phone.set_field_by_name("type", prost_reflect::Value::EnumNumber(1));
+
+        let timestamp_descriptor = descriptor
+            .parent_pool()
+            .get_message_by_name("google.protobuf.Timestamp")
+            .unwrap();
+        let mut timestamp = DynamicMessage::new(timestamp_descriptor);
+        timestamp.set_field_by_name("seconds", prost_reflect::Value::I64(1_660_000_000));
+        timestamp.set_field_by_name("nanos", prost_reflect::Value::I32(500));
+
+        let mut person = DynamicMessage::new(descriptor.clone());
+        person.set_field_by_name("name", prost_reflect::Value::String("someone".into()));
+        person.set_field_by_name("id", prost_reflect::Value::I32(42));
+        person.set_field_by_name(
+            "phones",
+            prost_reflect::Value::List(vec![prost_reflect::Value::Message(phone)]),
+        );
+        person.set_field_by_name(
+            "data",
+            prost_reflect::Value::Map(
+                [(
+                    MapKey::String("team".into()),
+                    prost_reflect::Value::String("observability".into()),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+        );
+        person.set_field_by_name("score", prost_reflect::Value::F64(9.5));
+        person.set_field_by_name("active", prost_reflect::Value::Bool(true));
+        person.set_field_by_name("last_updated", prost_reflect::Value::Message(timestamp));
+        person
+    }
+
+    #[test]
+    fn deserialize_protobuf() {
+        let config = build_config();
+        let deserializer = config.build().unwrap();
+        let input = Bytes::from(person_message(&deserializer.message_descriptor).encode_to_vec());
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["name"], "someone".into());
+            assert_eq!(log["id"], 42.into());
+            assert_eq!(log["phones[0].number"], "123-456".into());
+            assert_eq!(log["phones[0].type"], "HOME".into());
+            assert_eq!(log["data.team"], "observability".into());
+            assert_eq!(log["score"], 9.5.into());
+            assert_eq!(log["active"], true.into());
+            assert_eq!(
+                log["last_updated"],
+                Value::Timestamp(Utc.timestamp_opt(1_660_000_000, 500).unwrap())
+            );
+            // Fields that aren't set aren't emitted.
+            assert!(!log.contains("email"));
+
+            match namespace {
+                LogNamespace::Legacy => assert!(log.contains(log_schema().timestamp_key())),
+                LogNamespace::Vector => assert!(!log.contains(log_schema().timestamp_key())),
+            }
+        }
+    }
+
+    #[test]
+    fn protobuf_schema_definition() {
+        let definition = build_config().schema_definition(LogNamespace::Vector);
+        let fields = definition.event_kind().as_object().unwrap().known();
+
+        assert_eq!(fields[&Field::from("name")], Kind::bytes().or_undefined());
+        assert_eq!(fields[&Field::from("id")], Kind::integer().or_undefined());
+        assert_eq!(
+            fields[&Field::from("last_updated")],
+            Kind::timestamp().or_undefined()
+        );
+        assert_eq!(
+            fields[&Field::from("tags")],
+            Kind::array(Collection::from_unknown(Kind::bytes())).or_undefined()
+        );
+        assert_eq!(
+            fields[&Field::from("data")],
+            Kind::object(Collection::from_unknown(Kind::bytes())).or_undefined()
+        );
+    }
+
+    #[test]
+    fn deserialize_protobuf_error() {
+        let deserializer = build_config().build().unwrap();
+
+        assert!(deserializer
+            .parse(Bytes::from_static(b"\x0a\xff"), LogNamespace::Vector)
+            .is_err());
+    }
+
+    #[test]
+    fn build_protobuf_unknown_message_type() {
+        let config = ProtobufDeserializerConfig::new(
+            test_data_dir().join("test_protobuf.desc"),
+            "test_protobuf.Unknown".to_string(),
+        );
+
+        assert!(config.build().is_err());
+    }
+
+    #[test]
+    fn build_protobuf_missing_desc_file() {
+        let config = ProtobufDeserializerConfig::new(
+            test_data_dir().join("missing.desc"),
+            "test_protobuf.Person".to_string(),
+        );
+
+        assert!(config.build().is_err());
+    }
+}
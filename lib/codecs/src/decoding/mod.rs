@@ -8,9 +8,11 @@ pub mod framing;
 use bytes::{Bytes, BytesMut};
 pub use error::StreamDecodingError;
 pub use format::{
-    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
-    GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, NativeDeserializer,
-    NativeDeserializerConfig, NativeJsonDeserializer, NativeJsonDeserializerConfig,
+    AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions, BoxedDeserializer,
+    BytesDeserializer, BytesDeserializerConfig, GelfDeserializer, GelfDeserializerConfig,
+    JsonDeserializer, JsonDeserializerConfig, NativeDeserializer, NativeDeserializerConfig,
+    NativeJsonDeserializer, NativeJsonDeserializerConfig, ProtobufDeserializer,
+    ProtobufDeserializerConfig, ProtobufDeserializerOptions,
 };
 #[cfg(feature = "syslog")]
 pub use format::{SyslogDeserializer, SyslogDeserializerConfig};
@@ -237,6 +239,16 @@ pub enum DeserializerConfig {
     NativeJson,
     /// Configures the `GelfDeserializer`.
     Gelf,
+    /// Configures the `ProtobufDeserializer`.
+    Protobuf {
+        /// Options for the Protobuf deserializer.
+        protobuf: ProtobufDeserializerOptions,
+    },
+    /// Configures the `AvroDeserializer`.
+    Avro {
+        /// Options for the Avro deserializer.
+        avro: AvroDeserializerOptions,
+    },
 }
 
 impl From<BytesDeserializerConfig> for DeserializerConfig {
@@ -264,26 +276,55 @@ impl From<GelfDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<ProtobufDeserializerConfig> for DeserializerConfig {
+    fn from(config: ProtobufDeserializerConfig) -> Self {
+        Self::Protobuf {
+            protobuf: config.protobuf,
+        }
+    }
+}
+
+impl From<AvroDeserializerConfig> for DeserializerConfig {
+    fn from(config: AvroDeserializerConfig) -> Self {
+        Self::Avro { avro: config.avro }
+    }
+}
+
 impl DeserializerConfig {
     /// Build the `Deserializer` from this configuration.
-    pub fn build(&self) -> Deserializer {
+    pub fn build(&self) -> vector_common::Result<Deserializer> {
         match self {
-            DeserializerConfig::Bytes => Deserializer::Bytes(BytesDeserializerConfig.build()),
-            DeserializerConfig::Json => Deserializer::Json(JsonDeserializerConfig.build()),
+            DeserializerConfig::Bytes => Ok(Deserializer::Bytes(BytesDeserializerConfig.build())),
+            DeserializerConfig::Json => Ok(Deserializer::Json(JsonDeserializerConfig.build())),
             #[cfg(feature = "syslog")]
-            DeserializerConfig::Syslog => Deserializer::Syslog(SyslogDeserializerConfig.build()),
-            DeserializerConfig::Native => Deserializer::Native(NativeDeserializerConfig.build()),
-            DeserializerConfig::NativeJson => {
-                Deserializer::NativeJson(NativeJsonDeserializerConfig.build())
+            DeserializerConfig::Syslog => {
+                Ok(Deserializer::Syslog(SyslogDeserializerConfig.build()))
+            }
+            DeserializerConfig::Native => {
+                Ok(Deserializer::Native(NativeDeserializerConfig.build()))
             }
-            DeserializerConfig::Gelf => Deserializer::Gelf(GelfDeserializerConfig.build()),
+            DeserializerConfig::NativeJson => Ok(Deserializer::NativeJson(
+                NativeJsonDeserializerConfig.build(),
+            )),
+            DeserializerConfig::Gelf => Ok(Deserializer::Gelf(GelfDeserializerConfig.build())),
+            DeserializerConfig::Protobuf { protobuf } => Ok(Deserializer::Protobuf(
+                ProtobufDeserializerConfig {
+                    protobuf: protobuf.clone(),
+                }
+                .build()?,
+            )),
+            DeserializerConfig::Avro { avro } => Ok(Deserializer::Avro(
+                AvroDeserializerConfig { avro: avro.clone() }.build()?,
+            )),
         }
     }
 
     /// Return an appropriate default framer for the given deserializer
     pub fn default_stream_framing(&self) -> FramingConfig {
         match self {
-            DeserializerConfig::Native => FramingConfig::LengthDelimited,
+            DeserializerConfig::Native
+            | DeserializerConfig::Protobuf { .. }
+            | DeserializerConfig::Avro { .. } => FramingConfig::LengthDelimited,
             DeserializerConfig::Bytes
             | DeserializerConfig::Json
             | DeserializerConfig::Gelf
@@ -307,6 +348,13 @@ impl DeserializerConfig {
             DeserializerConfig::Native => NativeDeserializerConfig.output_type(),
             DeserializerConfig::NativeJson => NativeJsonDeserializerConfig.output_type(),
             DeserializerConfig::Gelf => GelfDeserializerConfig.output_type(),
+            DeserializerConfig::Protobuf { protobuf } => ProtobufDeserializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .output_type(),
+            DeserializerConfig::Avro { avro } => {
+                AvroDeserializerConfig { avro: avro.clone() }.output_type()
+            }
         }
     }
 
@@ -322,6 +370,13 @@ impl DeserializerConfig {
                 NativeJsonDeserializerConfig.schema_definition(log_namespace)
             }
             DeserializerConfig::Gelf => GelfDeserializerConfig.schema_definition(log_namespace),
+            DeserializerConfig::Protobuf { protobuf } => ProtobufDeserializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .schema_definition(log_namespace),
+            DeserializerConfig::Avro { avro } => {
+                AvroDeserializerConfig { avro: avro.clone() }.schema_definition(log_namespace)
+            }
         }
     }
 
@@ -344,7 +399,12 @@ impl DeserializerConfig {
                         },
                 },
             ) => "application/json",
-            (DeserializerConfig::Native, _) => "application/octet-stream",
+            (
+                DeserializerConfig::Native
+                | DeserializerConfig::Protobuf { .. }
+                | DeserializerConfig::Avro { .. },
+                _,
+            ) => "application/octet-stream",
             (
                 DeserializerConfig::Json
                 | DeserializerConfig::NativeJson
@@ -376,6 +436,10 @@ pub enum Deserializer {
     Boxed(BoxedDeserializer),
     /// Uses a `GelfDeserializer` for deserialization.
     Gelf(GelfDeserializer),
+    /// Uses a `ProtobufDeserializer` for deserialization.
+    Protobuf(ProtobufDeserializer),
+    /// Uses an `AvroDeserializer` for deserialization.
+    Avro(AvroDeserializer),
 }
 
 impl format::Deserializer for Deserializer {
@@ -393,6 +457,8 @@ impl format::Deserializer for Deserializer {
             Deserializer::NativeJson(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Boxed(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Gelf(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Protobuf(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Avro(deserializer) => deserializer.parse(bytes, log_namespace),
         }
     }
 }
@@ -0,0 +1,313 @@
+use std::{borrow::Cow, collections::BTreeMap};
+
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::Value;
+use vector_config::configurable_component;
+use vector_core::{
+    config::DataType,
+    event::{Event, LogEvent},
+    schema,
+};
+
+use crate::encoding::BuildError;
+
+/// Config used to build a `CefSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CefSerializerConfig {
+    /// Options for the CEF serializer.
+    pub cef: CefSerializerOptions,
+}
+
+impl CefSerializerConfig {
+    /// Creates a new `CefSerializerConfig`.
+    pub const fn new(cef: CefSerializerOptions) -> Self {
+        Self { cef }
+    }
+
+    /// Build the `CefSerializer` from this configuration.
+    pub fn build(&self) -> Result<CefSerializer, BuildError> {
+        if let Some(key) = self
+            .cef
+            .extensions
+            .keys()
+            .find(|key| key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return Err(format!(
+                "Invalid CEF extension key {:?}: keys must be alphanumeric.",
+                key
+            )
+            .into());
+        }
+
+        Ok(CefSerializer::new(self.cef.clone()))
+    }
+
+    /// The data type of events that are accepted by `CefSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // The header fields are only checked when encoding, since we don't want to enforce them
+        // on the schema definition yet.
+        schema::Requirement::empty()
+    }
+}
+
+/// CEF serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct CefSerializerOptions {
+    /// The vendor of the device that sends the events, written as the `Device Vendor` header.
+    pub device_vendor: String,
+
+    /// The product that sends the events, written as the `Device Product` header.
+    pub device_product: String,
+
+    /// The version of the product that sends the events, written as the `Device Version` header.
+    pub device_version: String,
+
+    /// The field holding the `Signature ID` header, which identifies the type of the event.
+    #[serde(default = "default_signature_id_field")]
+    pub signature_id_field: String,
+
+    /// The field holding the `Name` header, a human-readable description of the event.
+    #[serde(default = "default_name_field")]
+    pub name_field: String,
+
+    /// The field holding the `Severity` header.
+    ///
+    /// Integer severities must be between 0 and 10. Strings, such as `High`, are written as is.
+    #[serde(default = "default_severity_field")]
+    pub severity_field: String,
+
+    /// The extensions to encode, as a map of extension keys to the fields holding their values.
+    ///
+    /// Keys must be alphanumeric, such as `src` or `cs1Label`. Fields that are missing or `null`
+    /// in an event are left out, timestamps are encoded as milliseconds since the Unix epoch, and
+    /// objects and arrays are encoded as JSON.
+    #[serde(default)]
+    pub extensions: BTreeMap<String, String>,
+}
+
+fn default_signature_id_field() -> String {
+    "signature_id".to_owned()
+}
+
+fn default_name_field() -> String {
+    "name".to_owned()
+}
+
+fn default_severity_field() -> String {
+    "severity".to_owned()
+}
+
+/// Serializer that converts an `Event` to bytes using the [CEF][cef] format.
+///
+/// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors/pdfdoc/common-event-format-v25/common-event-format-v25.pdf
+#[derive(Debug, Clone)]
+pub struct CefSerializer {
+    options: CefSerializerOptions,
+}
+
+impl CefSerializer {
+    /// Creates a new `CefSerializer`.
+    pub const fn new(options: CefSerializerOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Encoder<Event> for CefSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let options = &self.options;
+
+        let signature_id = header_field(&log, &options.signature_id_field)?;
+        let name = header_field(&log, &options.name_field)?;
+        let severity = match log.get(options.severity_field.as_str()) {
+            Some(Value::Integer(severity)) if !(0..=10).contains(severity) => {
+                return Err(
+                    format!("CEF severity must be between 0 and 10, got {}.", severity).into(),
+                )
+            }
+            _ => header_field(&log, &options.severity_field)?,
+        };
+
+        let headers: [&str; 6] = [
+            &options.device_vendor,
+            &options.device_product,
+            &options.device_version,
+            &signature_id,
+            &name,
+            &severity,
+        ];
+        let mut line = String::from("CEF:0");
+        for header in headers {
+            line.push('|');
+            escape_header(header, &mut line);
+        }
+        line.push('|');
+
+        let mut first = true;
+        for (key, field) in &options.extensions {
+            let value = match log.get(field.as_str()) {
+                None | Some(Value::Null) => continue,
+                Some(value) => value_to_string(value),
+            };
+            if !first {
+                line.push(' ');
+            }
+            first = false;
+            line.push_str(key);
+            line.push('=');
+            escape_extension(&value, &mut line);
+        }
+
+        buffer.put_slice(line.as_bytes());
+        Ok(())
+    }
+}
+
+/// Renders a value the way SIEMs expect it, with timestamps as milliseconds since the Unix epoch.
+pub(super) fn value_to_string(value: &Value) -> Cow<'_, str> {
+    match value {
+        Value::Timestamp(timestamp) => timestamp.timestamp_millis().to_string().into(),
+        value => value.to_string_lossy(),
+    }
+}
+
+/// Escapes a header field, in which pipes and backslashes are escaped and line breaks aren't
+/// allowed. This is shared with the LEEF header, which follows the same rules.
+pub(super) fn escape_header(value: &str, line: &mut String) {
+    for c in value.chars() {
+        match c {
+            '\\' => line.push_str(r"\\"),
+            '|' => line.push_str(r"\|"),
+            '\n' | '\r' => line.push(' '),
+            c => line.push(c),
+        }
+    }
+}
+
+fn escape_extension(value: &str, line: &mut String) {
+    for c in value.chars() {
+        match c {
+            '\\' => line.push_str(r"\\"),
+            '=' => line.push_str(r"\="),
+            '\n' => line.push_str(r"\n"),
+            '\r' => line.push_str(r"\r"),
+            c => line.push(c),
+        }
+    }
+}
+
+fn header_field<'a>(log: &'a LogEvent, field: &str) -> Result<Cow<'a, str>, vector_common::Error> {
+    match log.get(field) {
+        None | Some(Value::Null) => {
+            Err(format!("Missing field {:?} for the CEF header.", field).into())
+        }
+        Some(value) => Ok(value_to_string(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use chrono::{TimeZone, Utc};
+    use vector_common::btreemap;
+
+    use super::*;
+
+    fn options(extensions: &[(&str, &str)]) -> CefSerializerOptions {
+        CefSerializerOptions {
+            device_vendor: "Vector".to_owned(),
+            device_product: "vector".to_owned(),
+            device_version: "0.26".to_owned(),
+            signature_id_field: default_signature_id_field(),
+            name_field: default_name_field(),
+            severity_field: default_severity_field(),
+            extensions: extensions
+                .iter()
+                .map(|(key, field)| (key.to_string(), field.to_string()))
+                .collect(),
+        }
+    }
+
+    fn encode(options: CefSerializerOptions, event: Event) -> Result<String, vector_common::Error> {
+        let mut serializer = CefSerializerConfig::new(options).build().unwrap();
+        let mut bytes = BytesMut::new();
+        serializer.encode(event, &mut bytes)?;
+        Ok(String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn serialize_header_and_extensions() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "signature_id" => Value::from("100"),
+            "name" => Value::from("Login failed"),
+            "severity" => Value::from(7),
+            "source" => btreemap! { "ip" => Value::from("10.0.0.1") },
+            "user" => Value::from("root"),
+            "timestamp" => Value::Timestamp(Utc.ymd(2022, 1, 2).and_hms(3, 4, 5)),
+        }));
+
+        assert_eq!(
+            encode(
+                options(&[
+                    ("src", "source.ip"),
+                    ("suser", "user"),
+                    ("rt", "timestamp"),
+                    ("msg", "missing"),
+                ]),
+                event
+            )
+            .unwrap(),
+            "CEF:0|Vector|vector|0.26|100|Login failed|7|rt=1641092645000 src=10.0.0.1 suser=root"
+        );
+    }
+
+    #[test]
+    fn serialize_escaping() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "signature_id" => Value::from("a|b"),
+            "name" => Value::from("C:\\temp\nfile"),
+            "severity" => Value::from("High"),
+            "message" => Value::from("a=b\\c\r\nd|e"),
+        }));
+
+        assert_eq!(
+            encode(options(&[("msg", "message")]), event).unwrap(),
+            r"CEF:0|Vector|vector|0.26|a\|b|C:\\temp file|High|msg=a\=b\\c\r\nd|e"
+        );
+    }
+
+    #[test]
+    fn serialize_invalid_header() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "signature_id" => Value::from("100"),
+            "severity" => Value::from(5),
+        }));
+        assert!(encode(options(&[]), event).is_err());
+
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "signature_id" => Value::from("100"),
+            "name" => Value::from("name"),
+            "severity" => Value::from(11),
+        }));
+        assert!(encode(options(&[]), event).is_err());
+    }
+
+    #[test]
+    fn invalid_extension_key() {
+        assert!(
+            CefSerializerConfig::new(options(&[("source ip", "source.ip")]))
+                .build()
+                .is_err()
+        );
+    }
+}
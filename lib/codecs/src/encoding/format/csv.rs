@@ -0,0 +1,304 @@
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::Value;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+/// Config used to build a `CsvSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CsvSerializerConfig {
+    /// Options for the CSV serializer.
+    pub csv: CsvSerializerOptions,
+}
+
+impl CsvSerializerConfig {
+    /// Creates a new `CsvSerializerConfig`.
+    pub const fn new(csv: CsvSerializerOptions) -> Self {
+        Self { csv }
+    }
+
+    /// Build the `CsvSerializer` from this configuration.
+    pub fn build(&self) -> CsvSerializer {
+        CsvSerializer::new(self.csv.clone())
+    }
+
+    /// The data type of events that are accepted by `CsvSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // While technically we support `Value` variants that can't be losslessly serialized to
+        // CSV, we don't want to enforce that limitation to users yet.
+        schema::Requirement::empty()
+    }
+}
+
+/// CSV serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct CsvSerializerOptions {
+    /// The fields to encode, in column order.
+    ///
+    /// Fields that are missing or `null` in an event are encoded as empty columns. Objects and
+    /// arrays are encoded as JSON.
+    pub fields: Vec<String>,
+
+    /// The ASCII (7-bit) character that separates the columns of a row.
+    #[serde(default = "default_delimiter", with = "vector_core::serde::ascii_char")]
+    pub delimiter: u8,
+
+    /// The quoting style to use when writing columns.
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub quote_style: QuoteStyle,
+
+    /// Whether to write a header row with the field names.
+    ///
+    /// The header is written at the start of each batch of events, which for object store sinks
+    /// means once per object, and by the `file` sink whenever it starts writing to an empty file.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub include_header: bool,
+}
+
+const fn default_delimiter() -> u8 {
+    b','
+}
+
+/// The quoting style of CSV columns.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteStyle {
+    /// Always quote columns.
+    Always,
+
+    /// Only quote columns when necessary, such as when they contain the delimiter, a quote or a
+    /// line break.
+    Necessary,
+
+    /// Quote every column that isn't numeric.
+    NonNumeric,
+
+    /// Never quote columns, even if that produces invalid CSV.
+    Never,
+}
+
+impl Default for QuoteStyle {
+    fn default() -> Self {
+        Self::Necessary
+    }
+}
+
+impl From<QuoteStyle> for csv::QuoteStyle {
+    fn from(style: QuoteStyle) -> Self {
+        match style {
+            QuoteStyle::Always => csv::QuoteStyle::Always,
+            QuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+            QuoteStyle::NonNumeric => csv::QuoteStyle::NonNumeric,
+            QuoteStyle::Never => csv::QuoteStyle::Never,
+        }
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the CSV format.
+#[derive(Debug, Clone)]
+pub struct CsvSerializer {
+    options: CsvSerializerOptions,
+}
+
+impl CsvSerializer {
+    /// Creates a new `CsvSerializer`.
+    pub const fn new(options: CsvSerializerOptions) -> Self {
+        Self { options }
+    }
+
+    /// Encode the header row containing the field names, if a header is configured.
+    pub fn header(&self) -> Option<BytesMut> {
+        self.options.include_header.then(|| {
+            let mut buffer = BytesMut::new();
+            self.write_row(
+                self.options.fields.iter().map(String::as_bytes),
+                &mut buffer,
+            )
+            .expect("writing CSV to memory can't fail");
+            buffer
+        })
+    }
+
+    /// Write a single row, without the record terminator, since the framer
+    /// takes care of delimiting rows.
+    fn write_row<I, T>(&self, row: I, buffer: &mut BytesMut) -> Result<(), vector_common::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let start = buffer.len();
+        {
+            let mut writer = csv::WriterBuilder::new()
+                .delimiter(self.options.delimiter)
+                .quote_style(self.options.quote_style.into())
+                .terminator(csv::Terminator::Any(b'\n'))
+                .has_headers(false)
+                .from_writer(buffer.writer());
+            writer.write_record(row)?;
+            writer.flush()?;
+        }
+
+        if buffer.len() > start {
+            buffer.truncate(buffer.len() - 1);
+        }
+
+        Ok(())
+    }
+}
+
+impl Encoder<Event> for CsvSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let row = self
+            .options
+            .fields
+            .iter()
+            .map(|field| match log.get(field.as_str()) {
+                None | Some(Value::Null) => Vec::new(),
+                Some(Value::Bytes(bytes)) => bytes.to_vec(),
+                Some(value) => value.to_string_lossy().into_owned().into_bytes(),
+            })
+            .collect::<Vec<_>>();
+
+        self.write_row(row, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use chrono::{TimeZone, Utc};
+    use vector_common::btreemap;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+
+    fn options(fields: &[&str]) -> CsvSerializerOptions {
+        CsvSerializerOptions {
+            fields: fields.iter().map(ToString::to_string).collect(),
+            delimiter: b',',
+            quote_style: QuoteStyle::Necessary,
+            include_header: false,
+        }
+    }
+
+    fn encode(options: CsvSerializerOptions, event: Event) -> String {
+        let mut serializer = CsvSerializerConfig::new(options).build();
+        let mut bytes = BytesMut::new();
+        serializer.encode(event, &mut bytes).unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn serialize_fields_in_order() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "foo" => Value::from("bar"),
+            "int" => Value::from(123),
+            "bool" => Value::from(true),
+            "float" => Value::from(1.5),
+            "null" => Value::Null,
+            "nested" => btreemap! { "key" => Value::from("value") },
+            "timestamp" => Value::Timestamp(Utc.ymd(2022, 1, 2).and_hms(3, 4, 5)),
+        }));
+
+        assert_eq!(
+            encode(
+                options(&[
+                    "timestamp",
+                    "int",
+                    "foo",
+                    "missing",
+                    "null",
+                    "float",
+                    "bool",
+                    "nested.key"
+                ]),
+                event
+            ),
+            "2022-01-02T03:04:05Z,123,bar,,,1.5,true,value"
+        );
+    }
+
+    #[test]
+    fn serialize_quoting() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "message" => Value::from("hello, \"world\""),
+            "count" => Value::from(1),
+        }));
+
+        assert_eq!(
+            encode(options(&["message", "count"]), event.clone()),
+            r#""hello, ""world""",1"#
+        );
+        assert_eq!(
+            encode(
+                CsvSerializerOptions {
+                    quote_style: QuoteStyle::NonNumeric,
+                    ..options(&["message", "count"])
+                },
+                event.clone()
+            ),
+            r#""hello, ""world""",1"#
+        );
+        assert_eq!(
+            encode(
+                CsvSerializerOptions {
+                    quote_style: QuoteStyle::Always,
+                    ..options(&["count"])
+                },
+                event
+            ),
+            r#""1""#
+        );
+    }
+
+    #[test]
+    fn serialize_custom_delimiter() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "a" => Value::from("x,y"),
+            "b" => Value::from("z"),
+        }));
+
+        assert_eq!(
+            encode(
+                CsvSerializerOptions {
+                    delimiter: b'\t',
+                    ..options(&["a", "b"])
+                },
+                event
+            ),
+            "x,y\tz"
+        );
+    }
+
+    #[test]
+    fn header_row() {
+        let serializer = CsvSerializerConfig::new(options(&["a", "b c"])).build();
+        assert!(serializer.header().is_none());
+
+        let serializer = CsvSerializerConfig::new(CsvSerializerOptions {
+            include_header: true,
+            delimiter: b';',
+            ..options(&["a", "b;c"])
+        })
+        .build();
+        assert_eq!(serializer.header().unwrap().as_ref(), b"a;\"b;c\"");
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::BTreeMap;
+
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::Value;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+use super::cef::{escape_header, value_to_string};
+use crate::encoding::BuildError;
+
+/// Config used to build a `LeefSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeefSerializerConfig {
+    /// Options for the LEEF serializer.
+    pub leef: LeefSerializerOptions,
+}
+
+impl LeefSerializerConfig {
+    /// Creates a new `LeefSerializerConfig`.
+    pub const fn new(leef: LeefSerializerOptions) -> Self {
+        Self { leef }
+    }
+
+    /// Build the `LeefSerializer` from this configuration.
+    pub fn build(&self) -> Result<LeefSerializer, BuildError> {
+        let delimiter = self.leef.delimiter;
+        if self.leef.version == LeefVersion::V1 && delimiter != b'\t' {
+            return Err("LEEF 1.0 only supports tab as the attribute delimiter.".into());
+        }
+        if delimiter == b'=' || delimiter == b'\\' || delimiter == b'|' {
+            return Err(format!(
+                "Invalid LEEF attribute delimiter {:?}.",
+                char::from(delimiter)
+            )
+            .into());
+        }
+
+        if let Some(key) = self.leef.attributes.keys().find(|key| {
+            key.is_empty()
+                || key
+                    .bytes()
+                    .any(|b| !b.is_ascii_graphic() || b == b'=' || b == delimiter)
+        }) {
+            return Err(format!(
+                "Invalid LEEF attribute key {:?}: keys can't contain spaces, `=` or the delimiter.",
+                key
+            )
+            .into());
+        }
+
+        Ok(LeefSerializer::new(self.leef.clone()))
+    }
+
+    /// The data type of events that are accepted by `LeefSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // The event ID field is only checked when encoding, since we don't want to enforce it on
+        // the schema definition yet.
+        schema::Requirement::empty()
+    }
+}
+
+/// LEEF serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct LeefSerializerOptions {
+    /// The version of the LEEF format.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub version: LeefVersion,
+
+    /// The vendor of the device that sends the events, written as the `Vendor` header.
+    pub vendor: String,
+
+    /// The product that sends the events, written as the `Product Name` header.
+    pub product_name: String,
+
+    /// The version of the product that sends the events, written as the `Product Version` header.
+    pub product_version: String,
+
+    /// The field holding the `EventID` header, which identifies the type of the event.
+    #[serde(default = "default_event_id_field")]
+    pub event_id_field: String,
+
+    /// The ASCII (7-bit) character that separates the attributes.
+    ///
+    /// LEEF 1.0 only supports tab, while LEEF 2.0 writes the delimiter to its header.
+    #[serde(default = "default_delimiter", with = "vector_core::serde::ascii_char")]
+    pub delimiter: u8,
+
+    /// The attributes to encode, as a map of attribute keys to the fields holding their values.
+    ///
+    /// Fields that are missing or `null` in an event are left out, timestamps are encoded as
+    /// milliseconds since the Unix epoch, and objects and arrays are encoded as JSON.
+    #[serde(default)]
+    pub attributes: BTreeMap<String, String>,
+}
+
+fn default_event_id_field() -> String {
+    "event_id".to_owned()
+}
+
+const fn default_delimiter() -> u8 {
+    b'\t'
+}
+
+/// The version of the LEEF format.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LeefVersion {
+    /// LEEF 1.0, with tab-delimited attributes.
+    #[serde(rename = "1.0")]
+    V1,
+
+    /// LEEF 2.0, with a configurable attribute delimiter.
+    #[serde(rename = "2.0")]
+    V2,
+}
+
+impl Default for LeefVersion {
+    fn default() -> Self {
+        Self::V2
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the [LEEF][leef] format.
+///
+/// [leef]: https://www.ibm.com/docs/en/dsm?topic=leef-overview
+#[derive(Debug, Clone)]
+pub struct LeefSerializer {
+    options: LeefSerializerOptions,
+}
+
+impl LeefSerializer {
+    /// Creates a new `LeefSerializer`.
+    pub const fn new(options: LeefSerializerOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl Encoder<Event> for LeefSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let options = &self.options;
+
+        let event_id = match log.get(options.event_id_field.as_str()) {
+            None | Some(Value::Null) => {
+                return Err(format!(
+                    "Missing field {:?} for the LEEF header.",
+                    options.event_id_field
+                )
+                .into())
+            }
+            Some(value) => value_to_string(value),
+        };
+
+        let mut line = String::from(match options.version {
+            LeefVersion::V1 => "LEEF:1.0",
+            LeefVersion::V2 => "LEEF:2.0",
+        });
+        let headers: [&str; 4] = [
+            &options.vendor,
+            &options.product_name,
+            &options.product_version,
+            &event_id,
+        ];
+        for header in headers {
+            line.push('|');
+            escape_header(header, &mut line);
+        }
+        line.push('|');
+        if options.version == LeefVersion::V2 {
+            line.push_str(&format!("x{:02X}|", options.delimiter));
+        }
+
+        let delimiter = char::from(options.delimiter);
+        let mut first = true;
+        for (key, field) in &options.attributes {
+            let value = match log.get(field.as_str()) {
+                None | Some(Value::Null) => continue,
+                Some(value) => value_to_string(value),
+            };
+            if !first {
+                line.push(delimiter);
+            }
+            first = false;
+            line.push_str(key);
+            line.push('=');
+            escape_attribute(&value, delimiter, &mut line);
+        }
+
+        buffer.put_slice(line.as_bytes());
+        Ok(())
+    }
+}
+
+fn escape_attribute(value: &str, delimiter: char, line: &mut String) {
+    for c in value.chars() {
+        match c {
+            '\\' => line.push_str(r"\\"),
+            '\n' => line.push_str(r"\n"),
+            '\r' => line.push_str(r"\r"),
+            '\t' => line.push_str(r"\t"),
+            c if c == delimiter => {
+                line.push('\\');
+                line.push(c);
+            }
+            c => line.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use chrono::{TimeZone, Utc};
+    use vector_common::btreemap;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+
+    fn options(attributes: &[(&str, &str)]) -> LeefSerializerOptions {
+        LeefSerializerOptions {
+            version: LeefVersion::V2,
+            vendor: "Vector".to_owned(),
+            product_name: "vector".to_owned(),
+            product_version: "0.26".to_owned(),
+            event_id_field: default_event_id_field(),
+            delimiter: b'\t',
+            attributes: attributes
+                .iter()
+                .map(|(key, field)| (key.to_string(), field.to_string()))
+                .collect(),
+        }
+    }
+
+    fn encode(
+        options: LeefSerializerOptions,
+        event: Event,
+    ) -> Result<String, vector_common::Error> {
+        let mut serializer = LeefSerializerConfig::new(options).build().unwrap();
+        let mut bytes = BytesMut::new();
+        serializer.encode(event, &mut bytes)?;
+        Ok(String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    fn event() -> Event {
+        Event::Log(LogEvent::from(btreemap! {
+            "event_id" => Value::from("login|failed"),
+            "source" => btreemap! { "ip" => Value::from("10.0.0.1") },
+            "user" => Value::from("ad\\min\tx^y"),
+            "timestamp" => Value::Timestamp(Utc.ymd(2022, 1, 2).and_hms(3, 4, 5)),
+        }))
+    }
+
+    #[test]
+    fn serialize_v2() {
+        assert_eq!(
+            encode(
+                options(&[
+                    ("src", "source.ip"),
+                    ("usrName", "user"),
+                    ("devTime", "timestamp"),
+                    ("msg", "missing"),
+                ]),
+                event()
+            )
+            .unwrap(),
+            "LEEF:2.0|Vector|vector|0.26|login\\|failed|x09|devTime=1641092645000\tsrc=10.0.0.1\tusrName=ad\\\\min\\tx^y"
+        );
+
+        assert_eq!(
+            encode(
+                LeefSerializerOptions {
+                    delimiter: b'^',
+                    ..options(&[("src", "source.ip"), ("usrName", "user")])
+                },
+                event()
+            )
+            .unwrap(),
+            "LEEF:2.0|Vector|vector|0.26|login\\|failed|x5E|src=10.0.0.1^usrName=ad\\\\min\\tx\\^y"
+        );
+    }
+
+    #[test]
+    fn serialize_v1() {
+        assert_eq!(
+            encode(
+                LeefSerializerOptions {
+                    version: LeefVersion::V1,
+                    ..options(&[("src", "source.ip")])
+                },
+                event()
+            )
+            .unwrap(),
+            "LEEF:1.0|Vector|vector|0.26|login\\|failed|src=10.0.0.1"
+        );
+    }
+
+    #[test]
+    fn serialize_missing_event_id() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "message" => Value::from("foo"),
+        }));
+        assert!(encode(options(&[]), event).is_err());
+    }
+
+    #[test]
+    fn invalid_config() {
+        assert!(LeefSerializerConfig::new(LeefSerializerOptions {
+            version: LeefVersion::V1,
+            delimiter: b'^',
+            ..options(&[])
+        })
+        .build()
+        .is_err());
+        assert!(
+            LeefSerializerConfig::new(options(&[("src ip", "source.ip")]))
+                .build()
+                .is_err()
+        );
+    }
+}
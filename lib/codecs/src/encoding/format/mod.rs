@@ -4,27 +4,44 @@
 #![deny(missing_docs)]
 
 mod avro;
+mod cef;
+mod csv;
 mod gelf;
 mod json;
+mod leef;
 mod logfmt;
 mod native;
 mod native_json;
+#[cfg(feature = "parquet")]
+mod parquet;
+mod protobuf;
 mod raw_message;
+mod syslog;
 mod text;
 
 use std::fmt::Debug;
 
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
+pub use cef::{CefSerializer, CefSerializerConfig, CefSerializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfSerializer, GelfSerializerConfig};
 pub use json::{JsonSerializer, JsonSerializerConfig};
+pub use leef::{LeefSerializer, LeefSerializerConfig, LeefSerializerOptions, LeefVersion};
 pub use logfmt::{LogfmtSerializer, LogfmtSerializerConfig};
 pub use native::{NativeSerializer, NativeSerializerConfig};
 pub use native_json::{NativeJsonSerializer, NativeJsonSerializerConfig};
+pub use protobuf::{ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions};
 pub use raw_message::{RawMessageSerializer, RawMessageSerializerConfig};
+pub use syslog::{SyslogRfc, SyslogSerializer, SyslogSerializerConfig, SyslogSerializerOptions};
 pub use text::{TextSerializer, TextSerializerConfig};
 use vector_core::event::Event;
 
+pub use self::csv::{CsvSerializer, CsvSerializerConfig, CsvSerializerOptions, QuoteStyle};
+#[cfg(feature = "parquet")]
+pub use self::parquet::{
+    ParquetCompression, ParquetSerializer, ParquetSerializerConfig, ParquetSerializerOptions,
+};
+
 /// Serialize a structured event into a byte frame.
 pub trait Serializer:
     tokio_util::codec::Encoder<Event, Error = vector_common::Error> + DynClone + Debug + Send + Sync
@@ -0,0 +1,563 @@
+use std::{num::NonZeroUsize, sync::Arc};
+
+use bytes::{BufMut, BytesMut};
+use parquet::{
+    basic::{Compression, ConvertedType, Repetition, Type as PhysicalType},
+    data_type::{
+        BoolType, ByteArray, ByteArrayType, DataType as ParquetDataType, DoubleType, FloatType,
+        Int32Type, Int64Type,
+    },
+    file::{
+        properties::WriterProperties,
+        writer::{SerializedColumnWriter, SerializedFileWriter},
+    },
+    schema::{parser::parse_message_type, types::Type},
+};
+use serde::{Deserialize, Serialize};
+use value::{Kind, Value};
+use vector_config::configurable_component;
+use vector_core::{
+    config::DataType,
+    event::{Event, LogEvent},
+    schema,
+};
+
+use crate::encoding::BuildError;
+
+/// Config used to build a `ParquetSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ParquetSerializerConfig {
+    /// Options for the Parquet serializer.
+    pub parquet: ParquetSerializerOptions,
+}
+
+impl ParquetSerializerConfig {
+    /// Creates a new `ParquetSerializerConfig`.
+    pub const fn new(parquet: ParquetSerializerOptions) -> Self {
+        Self { parquet }
+    }
+
+    /// Build the `ParquetSerializer` from this configuration.
+    pub fn build(&self) -> Result<ParquetSerializer, BuildError> {
+        let schema = self
+            .parquet
+            .schema
+            .as_deref()
+            .map(|schema| {
+                let schema = parse_message_type(schema)
+                    .map_err(|error| format!("Failed parsing Parquet schema: {}", error))?;
+                Columns::from_schema(schema)
+            })
+            .transpose()?;
+
+        Ok(ParquetSerializer {
+            schema,
+            row_group_size: self.parquet.row_group_size.get(),
+            properties: Arc::new(
+                WriterProperties::builder()
+                    .set_compression(self.parquet.compression.into())
+                    .build(),
+            ),
+        })
+    }
+
+    /// The data type of events that are accepted by `ParquetSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // Values that don't match the type of their column are written as `null`, or as strings in
+        // `binary` columns, so there is nothing to enforce up front.
+        schema::Requirement::empty()
+    }
+}
+
+/// Parquet serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ParquetSerializerOptions {
+    /// The Parquet schema of the encoded objects, in the Parquet message type syntax.
+    ///
+    /// Only flat schemas are supported: each field of the message must be a primitive, `optional`
+    /// or `required` column, and is filled from the top-level event field of the same name. Values
+    /// that can't be represented by the type of their column are written as `null`, except in
+    /// `binary` columns, which hold the string form of any other value, with objects and arrays
+    /// encoded as JSON. An event without a value for a `required` column fails the whole batch.
+    ///
+    /// When not set, the schema is derived from the schema definition of the events: each known
+    /// top-level field becomes an `optional` column typed after the field, with objects, arrays
+    /// and fields of mixed types encoded as JSON strings.
+    #[configurable(metadata(
+        docs::examples = "message logs { required binary message (UTF8); optional int64 timestamp (TIMESTAMP_MICROS); }"
+    ))]
+    pub schema: Option<String>,
+
+    /// The maximum number of rows in each row group.
+    ///
+    /// Larger row groups compress better and are cheaper to scan, at the cost of more memory when
+    /// reading and writing them.
+    #[serde(default = "default_row_group_size")]
+    pub row_group_size: NonZeroUsize,
+
+    /// The compression applied to the column data.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub compression: ParquetCompression,
+}
+
+fn default_row_group_size() -> NonZeroUsize {
+    NonZeroUsize::new(100_000).expect("default row group size is not zero")
+}
+
+impl Default for ParquetSerializerOptions {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            row_group_size: default_row_group_size(),
+            compression: ParquetCompression::default(),
+        }
+    }
+}
+
+/// The compression applied to the column data of Parquet objects.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParquetCompression {
+    /// No compression.
+    None,
+
+    /// [Snappy][snappy] compression.
+    ///
+    /// [snappy]: https://github.com/google/snappy
+    Snappy,
+
+    /// [Gzip][gzip] compression.
+    ///
+    /// [gzip]: https://www.gzip.org/
+    Gzip,
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+}
+
+impl Default for ParquetCompression {
+    fn default() -> Self {
+        Self::Snappy
+    }
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::None => Compression::UNCOMPRESSED,
+            ParquetCompression::Snappy => Compression::SNAPPY,
+            ParquetCompression::Gzip => Compression::GZIP,
+            ParquetCompression::Zstd => Compression::ZSTD,
+        }
+    }
+}
+
+/// A column of a flat Parquet schema.
+#[derive(Debug, Clone)]
+struct Column {
+    name: String,
+    physical_type: PhysicalType,
+    converted_type: ConvertedType,
+    optional: bool,
+}
+
+/// The Parquet schema of an object, along with its columns.
+#[derive(Debug, Clone)]
+struct Columns {
+    schema: Arc<Type>,
+    columns: Vec<Column>,
+}
+
+impl Columns {
+    fn from_schema(schema: Type) -> Result<Self, BuildError> {
+        let columns = schema
+            .get_fields()
+            .iter()
+            .map(|field| {
+                let info = field.get_basic_info();
+                if !field.is_primitive() {
+                    return Err(format!(
+                        "Parquet column `{}` is a group, only flat schemas are supported.",
+                        info.name()
+                    ));
+                }
+                if info.repetition() == Repetition::REPEATED {
+                    return Err(format!(
+                        "Parquet column `{}` is repeated, only flat schemas are supported.",
+                        info.name()
+                    ));
+                }
+                match field.get_physical_type() {
+                    PhysicalType::INT96 | PhysicalType::FIXED_LEN_BYTE_ARRAY => Err(format!(
+                        "Parquet column `{}` has unsupported type `{}`.",
+                        info.name(),
+                        field.get_physical_type()
+                    )),
+                    physical_type => Ok(Column {
+                        name: info.name().to_owned(),
+                        physical_type,
+                        converted_type: info.converted_type(),
+                        optional: info.repetition() == Repetition::OPTIONAL,
+                    }),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            columns,
+        })
+    }
+
+    /// Derives the columns from the known top-level fields of an event `Kind`.
+    fn from_kind(kind: &Kind) -> Result<Self, vector_common::Error> {
+        let fields = kind
+            .as_object()
+            .map(|object| object.known())
+            .filter(|fields| !fields.is_empty())
+            .ok_or("Can't derive a Parquet schema from an event schema without known fields, configure `schema` instead.")?;
+
+        let mut columns = fields
+            .iter()
+            .map(|(field, kind)| {
+                let (physical_type, converted_type) = column_type(kind);
+                Type::primitive_type_builder(field.as_str(), physical_type)
+                    .with_repetition(Repetition::OPTIONAL)
+                    .with_converted_type(converted_type)
+                    .build()
+                    .map(Arc::new)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let schema = Type::group_type_builder("vector")
+            .with_fields(&mut columns)
+            .build()?;
+
+        Self::from_schema(schema)
+    }
+}
+
+/// The Parquet type of a column derived from the `Kind` of its field.
+fn column_type(kind: &Kind) -> (PhysicalType, ConvertedType) {
+    let mut kind = kind.clone();
+    kind.remove_null();
+    kind.remove_undefined();
+
+    if kind.is_integer() {
+        (PhysicalType::INT64, ConvertedType::NONE)
+    } else if kind.is_float() {
+        (PhysicalType::DOUBLE, ConvertedType::NONE)
+    } else if kind.is_boolean() {
+        (PhysicalType::BOOLEAN, ConvertedType::NONE)
+    } else if kind.is_timestamp() {
+        (PhysicalType::INT64, ConvertedType::TIMESTAMP_MICROS)
+    } else {
+        (PhysicalType::BYTE_ARRAY, ConvertedType::UTF8)
+    }
+}
+
+/// Serializer that converts a batch of `Event`s to a Parquet object.
+#[derive(Debug, Clone)]
+pub struct ParquetSerializer {
+    schema: Option<Columns>,
+    row_group_size: usize,
+    properties: Arc<WriterProperties>,
+}
+
+impl ParquetSerializer {
+    /// Encode a batch of events as a single Parquet object.
+    pub fn encode(
+        &self,
+        events: Vec<Event>,
+        buffer: &mut BytesMut,
+    ) -> Result<(), vector_common::Error> {
+        let logs = events.into_iter().map(Event::into_log).collect::<Vec<_>>();
+
+        let derived;
+        let columns = match &self.schema {
+            Some(columns) => columns,
+            None => {
+                let kind = logs
+                    .first()
+                    .map(|log| log.metadata().schema_definition().event_kind().clone())
+                    .unwrap_or_else(Kind::never);
+                derived = Columns::from_kind(&kind)?;
+                &derived
+            }
+        };
+
+        let mut writer = SerializedFileWriter::new(
+            buffer.writer(),
+            Arc::clone(&columns.schema),
+            Arc::clone(&self.properties),
+        )?;
+        for rows in logs.chunks(self.row_group_size) {
+            let mut row_group = writer.next_row_group()?;
+            for column in &columns.columns {
+                let mut column_writer = row_group
+                    .next_column()?
+                    .ok_or("Parquet schema has fewer leaf columns than fields.")?;
+                write_column(column, &mut column_writer, rows)?;
+                column_writer.close()?;
+            }
+            row_group.close()?;
+        }
+        writer.close()?;
+
+        Ok(())
+    }
+}
+
+fn write_column(
+    column: &Column,
+    writer: &mut SerializedColumnWriter<'_>,
+    rows: &[LogEvent],
+) -> Result<(), vector_common::Error> {
+    match column.physical_type {
+        PhysicalType::BOOLEAN => {
+            write_values::<BoolType, _>(column, writer, rows, |value| value.as_boolean())
+        }
+        PhysicalType::INT32 => write_values::<Int32Type, _>(column, writer, rows, |value| {
+            value.as_integer().and_then(|int| i32::try_from(int).ok())
+        }),
+        PhysicalType::INT64 => {
+            let millis = column.converted_type == ConvertedType::TIMESTAMP_MILLIS;
+            write_values::<Int64Type, _>(column, writer, rows, |value| match value {
+                Value::Integer(int) => Some(*int),
+                Value::Timestamp(ts) if millis => Some(ts.timestamp_millis()),
+                Value::Timestamp(ts) => Some(ts.timestamp_micros()),
+                _ => None,
+            })
+        }
+        PhysicalType::FLOAT => {
+            write_values::<FloatType, _>(column, writer, rows, |value| match value {
+                Value::Float(float) => Some(float.into_inner() as f32),
+                Value::Integer(int) => Some(*int as f32),
+                _ => None,
+            })
+        }
+        PhysicalType::DOUBLE => {
+            write_values::<DoubleType, _>(column, writer, rows, |value| match value {
+                Value::Float(float) => Some(float.into_inner()),
+                Value::Integer(int) => Some(*int as f64),
+                _ => None,
+            })
+        }
+        PhysicalType::BYTE_ARRAY => {
+            write_values::<ByteArrayType, _>(column, writer, rows, |value| match value {
+                Value::Bytes(bytes) => Some(ByteArray::from(bytes.to_vec())),
+                Value::Null => None,
+                value => Some(ByteArray::from(
+                    value.to_string_lossy().into_owned().into_bytes(),
+                )),
+            })
+        }
+        PhysicalType::INT96 | PhysicalType::FIXED_LEN_BYTE_ARRAY => {
+            unreachable!("rejected when building the schema")
+        }
+    }
+}
+
+fn write_values<T, F>(
+    column: &Column,
+    writer: &mut SerializedColumnWriter<'_>,
+    rows: &[LogEvent],
+    convert: F,
+) -> Result<(), vector_common::Error>
+where
+    T: ParquetDataType,
+    F: Fn(&Value) -> Option<T::T>,
+{
+    let mut values = Vec::with_capacity(rows.len());
+    let mut definition_levels = Vec::with_capacity(rows.len());
+    for log in rows {
+        match log.get(column.name.as_str()).and_then(&convert) {
+            Some(value) => {
+                values.push(value);
+                definition_levels.push(1);
+            }
+            None if column.optional => definition_levels.push(0),
+            None => {
+                return Err(format!(
+                    "Missing or invalid value for required Parquet column `{}`.",
+                    column.name
+                )
+                .into())
+            }
+        }
+    }
+
+    let definition_levels = column.optional.then(|| definition_levels.as_slice());
+    writer
+        .typed::<T>()
+        .write_batch(&values, definition_levels, None)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use lookup::owned_value_path;
+    use parquet::{
+        file::reader::{FileReader, SerializedFileReader},
+        record::RowAccessor,
+    };
+    use vector_common::btreemap;
+    use vector_core::schema::Definition;
+
+    use super::*;
+
+    fn encode(
+        options: ParquetSerializerOptions,
+        events: Vec<Event>,
+    ) -> SerializedFileReader<Bytes> {
+        let serializer = ParquetSerializerConfig::new(options).build().unwrap();
+        let mut buffer = BytesMut::new();
+        serializer.encode(events, &mut buffer).unwrap();
+        SerializedFileReader::new(buffer.freeze()).unwrap()
+    }
+
+    fn event(message: &str, count: i64) -> Event {
+        Event::Log(LogEvent::from(btreemap! {
+            "message" => Value::from(message),
+            "count" => Value::from(count),
+            "timestamp" => Value::Timestamp(Utc.ymd(2022, 1, 2).and_hms(3, 4, 5)),
+        }))
+    }
+
+    #[test]
+    fn serialize_explicit_schema() {
+        let options = ParquetSerializerOptions {
+            schema: Some(
+                "message logs {
+                    required binary message (UTF8);
+                    optional int32 count;
+                    optional int64 timestamp (TIMESTAMP_MILLIS);
+                    optional boolean missing;
+                }"
+                .to_owned(),
+            ),
+            row_group_size: NonZeroUsize::new(2).unwrap(),
+            compression: ParquetCompression::Zstd,
+        };
+        let reader = encode(
+            options,
+            vec![event("foo", 1), event("bar", 2), event("baz", i64::MAX)],
+        );
+
+        assert_eq!(reader.metadata().num_row_groups(), 2);
+        let rows = reader.get_row_iter(None).unwrap().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].get_string(0).unwrap(), "foo");
+        assert_eq!(rows[0].get_int(1).unwrap(), 1);
+        assert_eq!(rows[0].get_long(2).unwrap(), 1_641_092_645_000);
+        assert!(rows[0].get_bool(3).is_err());
+        assert_eq!(rows[2].get_string(0).unwrap(), "baz");
+        // Out of range integers are written as `null`.
+        assert!(rows[2].get_int(1).is_err());
+    }
+
+    #[test]
+    fn binary_columns_stringify_other_values() {
+        let mut log = event("foo", 1).into_log();
+        log.insert("object", Value::from(btreemap! { "a" => Value::from(1) }));
+        let options = ParquetSerializerOptions {
+            schema: Some(
+                "message logs {
+                    optional binary count (UTF8);
+                    optional binary object (UTF8);
+                }"
+                .to_owned(),
+            ),
+            ..Default::default()
+        };
+        let reader = encode(options, vec![Event::Log(log)]);
+
+        let rows = reader.get_row_iter(None).unwrap().collect::<Vec<_>>();
+        assert_eq!(rows[0].get_string(0).unwrap(), "1");
+        assert_eq!(rows[0].get_string(1).unwrap(), r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn serialize_required_column_missing() {
+        let serializer = ParquetSerializerConfig::new(ParquetSerializerOptions {
+            schema: Some("message logs { required binary host (UTF8); }".to_owned()),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+        let mut buffer = BytesMut::new();
+        assert!(serializer
+            .encode(vec![event("foo", 1)], &mut buffer)
+            .is_err());
+    }
+
+    #[test]
+    fn serialize_derived_schema() {
+        let definition = Arc::new(
+            Definition::empty_legacy_namespace()
+                .with_event_field(&owned_value_path!("message"), Kind::bytes(), None)
+                .with_event_field(&owned_value_path!("count"), Kind::integer().or_null(), None)
+                .with_event_field(&owned_value_path!("timestamp"), Kind::timestamp(), None),
+        );
+        let events = vec![event("foo", 1), event("bar", 2)]
+            .into_iter()
+            .map(|event| {
+                let mut log = event.into_log();
+                log.metadata_mut().set_schema_definition(&definition);
+                Event::Log(log)
+            })
+            .collect();
+
+        let reader = encode(ParquetSerializerOptions::default(), events);
+
+        let schema = reader.metadata().file_metadata().schema_descr();
+        assert_eq!(schema.num_columns(), 3);
+        assert_eq!(schema.column(0).name(), "count");
+        assert_eq!(schema.column(0).physical_type(), PhysicalType::INT64);
+        assert_eq!(schema.column(1).name(), "message");
+        assert_eq!(schema.column(1).physical_type(), PhysicalType::BYTE_ARRAY);
+        assert_eq!(
+            schema.column(2).converted_type(),
+            ConvertedType::TIMESTAMP_MICROS
+        );
+
+        let rows = reader.get_row_iter(None).unwrap().collect::<Vec<_>>();
+        assert_eq!(rows[1].get_long(0).unwrap(), 2);
+        assert_eq!(rows[1].get_string(1).unwrap(), "bar");
+        assert_eq!(rows[1].get_long(2).unwrap(), 1_641_092_645_000_000);
+    }
+
+    #[test]
+    fn derived_schema_requires_known_fields() {
+        let serializer = ParquetSerializerConfig::new(ParquetSerializerOptions::default())
+            .build()
+            .unwrap();
+
+        let mut buffer = BytesMut::new();
+        assert!(serializer
+            .encode(vec![event("foo", 1)], &mut buffer)
+            .is_err());
+    }
+
+    #[test]
+    fn nested_schema_is_rejected() {
+        let config = ParquetSerializerConfig::new(ParquetSerializerOptions {
+            schema: Some("message logs { optional group nested { optional int64 a; } }".to_owned()),
+            ..Default::default()
+        });
+        assert!(config.build().is_err());
+    }
+}
@@ -0,0 +1,430 @@
+use std::path::PathBuf;
+
+use bytes::BytesMut;
+use prost::Message;
+use prost_reflect::{DynamicMessage, FieldDescriptor, MapKey, MessageDescriptor};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::Value;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+use crate::encoding::BuildError;
+use crate::protobuf::{get_message_descriptor, TIMESTAMP_MESSAGE_TYPE};
+
+/// Config used to build a `ProtobufSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProtobufSerializerConfig {
+    /// Options for the Protobuf serializer.
+    pub protobuf: ProtobufSerializerOptions,
+}
+
+impl ProtobufSerializerConfig {
+    /// Creates a new `ProtobufSerializerConfig`.
+    pub const fn new(desc_file: PathBuf, message_type: String) -> Self {
+        Self {
+            protobuf: ProtobufSerializerOptions {
+                desc_file,
+                message_type,
+            },
+        }
+    }
+
+    /// Build the `ProtobufSerializer` from this configuration.
+    pub fn build(&self) -> Result<ProtobufSerializer, BuildError> {
+        let message_descriptor =
+            get_message_descriptor(&self.protobuf.desc_file, &self.protobuf.message_type)
+                .map_err(|error| format!("Failed building Protobuf serializer: {}", error))?;
+        Ok(ProtobufSerializer::new(message_descriptor))
+    }
+
+    /// The data type of events that are accepted by `ProtobufSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // Any schema is accepted: event fields are only checked against the message descriptor
+        // when encoding, since we don't want to enforce it on the schema definition yet.
+        schema::Requirement::empty()
+    }
+}
+
+/// Protobuf serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ProtobufSerializerOptions {
+    /// Path to a compiled `FileDescriptorSet` containing the message type.
+    ///
+    /// The file can be generated with `protoc --include_imports --descriptor_set_out=<file>`.
+    pub desc_file: PathBuf,
+
+    /// The fully qualified name of the message type to encode events as, e.g. `package.Message`.
+    ///
+    /// Top-level event fields are mapped onto message fields of the same name. Event fields
+    /// without a matching message field are ignored.
+    pub message_type: String,
+}
+
+/// Serializer that converts an `Event` to bytes using a user-supplied Protobuf
+/// message type.
+#[derive(Debug, Clone)]
+pub struct ProtobufSerializer {
+    message_descriptor: MessageDescriptor,
+}
+
+impl ProtobufSerializer {
+    /// Creates a new `ProtobufSerializer`.
+    pub const fn new(message_descriptor: MessageDescriptor) -> Self {
+        Self { message_descriptor }
+    }
+}
+
+impl Encoder<Event> for ProtobufSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let message = value_to_message(log.value(), &self.message_descriptor, "")?;
+        message.encode(buffer)?;
+        Ok(())
+    }
+}
+
+/// Builds a message of the given type from an object, field by field.
+///
+/// `path` is the location of the object within the event and is only used for
+/// error messages.
+fn value_to_message(
+    value: &Value,
+    descriptor: &MessageDescriptor,
+    path: &str,
+) -> vector_common::Result<DynamicMessage> {
+    let object = match value {
+        Value::Object(object) => object,
+        value => return Err(type_mismatch(path, descriptor.full_name(), value)),
+    };
+
+    let mut message = DynamicMessage::new(descriptor.clone());
+    for field in descriptor.fields() {
+        let value = match object.get(field.name()) {
+            Some(Value::Null) | None => continue,
+            Some(value) => value,
+        };
+        let path = field_path(path, field.name());
+        let value = if field.is_map() {
+            map_to_proto(value, &field, &path)?
+        } else if field.is_list() {
+            match value {
+                Value::Array(values) => prost_reflect::Value::List(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(index, value)| {
+                            value_to_proto(value, &field.kind(), &format!("{}[{}]", path, index))
+                        })
+                        .collect::<vector_common::Result<_>>()?,
+                ),
+                value => return Err(type_mismatch(&path, "array", value)),
+            }
+        } else {
+            value_to_proto(value, &field.kind(), &path)?
+        };
+        message.set_field(&field, value);
+    }
+    Ok(message)
+}
+
+fn map_to_proto(
+    value: &Value,
+    field: &FieldDescriptor,
+    path: &str,
+) -> vector_common::Result<prost_reflect::Value> {
+    let object = match value {
+        Value::Object(object) => object,
+        value => return Err(type_mismatch(path, "object", value)),
+    };
+    let entry = match field.kind() {
+        prost_reflect::Kind::Message(entry) => entry,
+        _ => return Err(format!("Field {:?} is missing its map entry descriptor", path).into()),
+    };
+    let key_kind = entry.map_entry_key_field().kind();
+    let value_kind = entry.map_entry_value_field().kind();
+
+    object
+        .iter()
+        .map(|(key, value)| -> vector_common::Result<_> {
+            let path = field_path(path, key);
+            Ok((
+                string_to_map_key(key, &key_kind, &path)?,
+                value_to_proto(value, &value_kind, &path)?,
+            ))
+        })
+        .collect::<vector_common::Result<_>>()
+        .map(prost_reflect::Value::Map)
+}
+
+fn value_to_proto(
+    value: &Value,
+    kind: &prost_reflect::Kind,
+    path: &str,
+) -> vector_common::Result<prost_reflect::Value> {
+    use prost_reflect::Kind as K;
+
+    Ok(match (kind, value) {
+        (K::Double, Value::Float(value)) => prost_reflect::Value::F64(value.into_inner()),
+        (K::Double, Value::Integer(value)) => prost_reflect::Value::F64(*value as f64),
+        (K::Float, Value::Float(value)) => prost_reflect::Value::F32(value.into_inner() as f32),
+        (K::Float, Value::Integer(value)) => prost_reflect::Value::F32(*value as f32),
+        (K::Int32 | K::Sint32 | K::Sfixed32, Value::Integer(value)) => {
+            prost_reflect::Value::I32(integer_in_range(*value, path)?)
+        }
+        (K::Int64 | K::Sint64 | K::Sfixed64, Value::Integer(value)) => {
+            prost_reflect::Value::I64(*value)
+        }
+        (K::Uint32 | K::Fixed32, Value::Integer(value)) => {
+            prost_reflect::Value::U32(integer_in_range(*value, path)?)
+        }
+        (K::Uint64 | K::Fixed64, Value::Integer(value)) => {
+            prost_reflect::Value::U64(integer_in_range(*value, path)?)
+        }
+        (K::Bool, Value::Boolean(value)) => prost_reflect::Value::Bool(*value),
+        (K::String, Value::Bytes(value)) => prost_reflect::Value::String(
+            String::from_utf8(value.to_vec())
+                .map_err(|_| format!("Field {:?} is not valid UTF-8", path))?,
+        ),
+        (K::Bytes, Value::Bytes(value)) => prost_reflect::Value::Bytes(value.clone()),
+        (K::Enum(descriptor), Value::Bytes(name)) => {
+            let name = String::from_utf8_lossy(name);
+            let value = descriptor.get_value_by_name(&name).ok_or_else(|| {
+                format!(
+                    "Field {:?} has value {:?}, which is not a variant of enum {}",
+                    path,
+                    name,
+                    descriptor.full_name()
+                )
+            })?;
+            prost_reflect::Value::EnumNumber(value.number())
+        }
+        (K::Enum(_), Value::Integer(number)) => {
+            prost_reflect::Value::EnumNumber(integer_in_range(*number, path)?)
+        }
+        (K::Message(descriptor), Value::Timestamp(timestamp))
+            if descriptor.full_name() == TIMESTAMP_MESSAGE_TYPE =>
+        {
+            let mut message = DynamicMessage::new(descriptor.clone());
+            message.set_field_by_name("seconds", prost_reflect::Value::I64(timestamp.timestamp()));
+            message.set_field_by_name(
+                "nanos",
+                prost_reflect::Value::I32(timestamp.timestamp_subsec_nanos() as i32),
+            );
+            prost_reflect::Value::Message(message)
+        }
+        (K::Message(descriptor), value) => {
+            prost_reflect::Value::Message(value_to_message(value, descriptor, path)?)
+        }
+        (kind, value) => return Err(type_mismatch(path, kind_name(kind), value)),
+    })
+}
+
+fn string_to_map_key(
+    key: &str,
+    kind: &prost_reflect::Kind,
+    path: &str,
+) -> vector_common::Result<MapKey> {
+    use prost_reflect::Kind as K;
+
+    let invalid = || format!("Map key {:?} is not a valid {}", path, kind_name(kind));
+    Ok(match kind {
+        K::String => MapKey::String(key.to_owned()),
+        K::Bool => MapKey::Bool(key.parse().map_err(|_| invalid())?),
+        K::Int32 | K::Sint32 | K::Sfixed32 => MapKey::I32(key.parse().map_err(|_| invalid())?),
+        K::Int64 | K::Sint64 | K::Sfixed64 => MapKey::I64(key.parse().map_err(|_| invalid())?),
+        K::Uint32 | K::Fixed32 => MapKey::U32(key.parse().map_err(|_| invalid())?),
+        K::Uint64 | K::Fixed64 => MapKey::U64(key.parse().map_err(|_| invalid())?),
+        _ => return Err(invalid().into()),
+    })
+}
+
+fn integer_in_range<T: TryFrom<i64>>(value: i64, path: &str) -> vector_common::Result<T> {
+    T::try_from(value).map_err(|_| {
+        format!(
+            "Field {:?} has value {}, which is out of range for its Protobuf type",
+            path, value
+        )
+        .into()
+    })
+}
+
+fn field_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", parent, name)
+    }
+}
+
+fn type_mismatch(path: &str, expected: &str, value: &Value) -> vector_common::Error {
+    if path.is_empty() {
+        format!(
+            "Expected the event to be {}, got {}",
+            expected,
+            value.kind_str()
+        )
+        .into()
+    } else {
+        format!(
+            "Field {:?} expected to be {}, got {}",
+            path,
+            expected,
+            value.kind_str()
+        )
+        .into()
+    }
+}
+
+const fn kind_name(kind: &prost_reflect::Kind) -> &'static str {
+    use prost_reflect::Kind as K;
+
+    match kind {
+        K::Double => "double",
+        K::Float => "float",
+        K::Int32 => "int32",
+        K::Int64 => "int64",
+        K::Uint32 => "uint32",
+        K::Uint64 => "uint64",
+        K::Sint32 => "sint32",
+        K::Sint64 => "sint64",
+        K::Fixed32 => "fixed32",
+        K::Fixed64 => "fixed64",
+        K::Sfixed32 => "sfixed32",
+        K::Sfixed64 => "sfixed64",
+        K::Bool => "bool",
+        K::String => "string",
+        K::Bytes => "bytes",
+        K::Message(_) => "message",
+        K::Enum(_) => "enum",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use vector_common::btreemap;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+
+    fn build_serializer() -> ProtobufSerializer {
+        let desc_file = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap())
+            .join("tests/data/protobuf/test_protobuf.desc");
+        ProtobufSerializerConfig::new(desc_file, "test_protobuf.Person".to_string())
+            .build()
+            .unwrap()
+    }
+
+    fn encode(serializer: &mut ProtobufSerializer, log: LogEvent) -> vector_common::Result<Bytes> {
+        let mut bytes = BytesMut::new();
+        serializer.encode(Event::Log(log), &mut bytes)?;
+        Ok(bytes.freeze())
+    }
+
+    #[test]
+    fn serialize_protobuf() {
+        let mut serializer = build_serializer();
+        let log = LogEvent::from(btreemap! {
+            "name" => "someone",
+            "id" => 42,
+            "phones" => vec![Value::from(btreemap! {
+                "number" => "123-456",
+                "type" => "WORK",
+            })],
+            "data" => btreemap! { "team" => "observability" },
+            "score" => 9.5,
+            "active" => true,
+            "last_updated" => Utc.timestamp_opt(1_660_000_000, 500).unwrap(),
+            "tags" => vec!["a", "b"],
+            "unmapped" => "ignored",
+        });
+
+        let bytes = encode(&mut serializer, log).unwrap();
+        let message = DynamicMessage::decode(serializer.message_descriptor.clone(), bytes).unwrap();
+
+        assert_eq!(
+            message.get_field_by_name("name").unwrap().as_str(),
+            Some("someone")
+        );
+        assert_eq!(message.get_field_by_name("id").unwrap().as_i32(), Some(42));
+        assert_eq!(
+            message.get_field_by_name("score").unwrap().as_f64(),
+            Some(9.5)
+        );
+        assert_eq!(
+            message.get_field_by_name("active").unwrap().as_bool(),
+            Some(true)
+        );
+
+        let phones = message.get_field_by_name("phones").unwrap();
+        let phone = phones.as_list().unwrap()[0].as_message().unwrap();
+        assert_eq!(
+            phone.get_field_by_name("type").unwrap().as_enum_number(),
+            Some(2)
+        );
+
+        let data = message.get_field_by_name("data").unwrap();
+        assert_eq!(
+            data.as_map().unwrap()[&MapKey::String("team".into())].as_str(),
+            Some("observability")
+        );
+
+        let last_updated = message.get_field_by_name("last_updated").unwrap();
+        let last_updated = last_updated.as_message().unwrap();
+        assert_eq!(
+            last_updated.get_field_by_name("seconds").unwrap().as_i64(),
+            Some(1_660_000_000)
+        );
+        assert_eq!(
+            last_updated.get_field_by_name("nanos").unwrap().as_i32(),
+            Some(500)
+        );
+    }
+
+    #[test]
+    fn serialize_protobuf_type_mismatch() {
+        let mut serializer = build_serializer();
+
+        let error = encode(
+            &mut serializer,
+            LogEvent::from(btreemap! { "id" => "not a number" }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"Field "id" expected to be int32, got string"#
+        );
+
+        let error = encode(
+            &mut serializer,
+            LogEvent::from(btreemap! {
+                "phones" => vec![Value::from(btreemap! { "type" => "PAGER" })],
+            }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"Field "phones[0].type" has value "PAGER", which is not a variant of enum test_protobuf.Person.PhoneType"#
+        );
+
+        let error = encode(
+            &mut serializer,
+            LogEvent::from(btreemap! { "visits" => -1 }),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"Field "visits" has value -1, which is out of range for its Protobuf type"#
+        );
+    }
+}
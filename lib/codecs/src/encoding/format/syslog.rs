@@ -0,0 +1,477 @@
+use std::fmt::Write;
+
+use bytes::{BufMut, BytesMut};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::{Kind, Value};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType},
+    event::{Event, LogEvent},
+    schema,
+};
+
+/// The facility used when an event has no valid facility, `user`.
+const DEFAULT_FACILITY: u8 = 1;
+/// The severity used when an event has no valid severity, `info`.
+const DEFAULT_SEVERITY: u8 = 6;
+
+/// The facility names, in the order of their codes, as produced by the syslog decoder.
+const FACILITIES: [&str; 24] = [
+    "kern", "user", "mail", "daemon", "auth", "syslog", "lpr", "news", "uucp", "cron", "authpriv",
+    "ftp", "ntp", "audit", "alert", "clockd", "local0", "local1", "local2", "local3", "local4",
+    "local5", "local6", "local7",
+];
+
+/// The severity names, in the order of their codes, as produced by the syslog decoder.
+const SEVERITIES: [&str; 8] = [
+    "emerg", "alert", "crit", "err", "warning", "notice", "info", "debug",
+];
+
+/// Config used to build a `SyslogSerializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SyslogSerializerConfig {
+    /// Options for the syslog serializer.
+    #[serde(default)]
+    pub syslog: SyslogSerializerOptions,
+}
+
+impl SyslogSerializerConfig {
+    /// Creates a new `SyslogSerializerConfig`.
+    pub const fn new(syslog: SyslogSerializerOptions) -> Self {
+        Self { syslog }
+    }
+
+    /// Build the `SyslogSerializer` from this configuration.
+    pub fn build(&self) -> SyslogSerializer {
+        SyslogSerializer::new(self.syslog.clone())
+    }
+
+    /// The data type of events that are accepted by `SyslogSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty().required_meaning(log_schema().message_key(), Kind::any())
+    }
+}
+
+/// Syslog serializer options.
+///
+/// The message and timestamp are taken from the fields with the `message` and `timestamp`
+/// meanings, and the other fields default to the ones produced by the `syslog` decoder.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct SyslogSerializerOptions {
+    /// The syslog protocol to encode messages with.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub rfc: SyslogRfc,
+
+    /// The field holding the facility, either as a code or as a name such as `local0`.
+    ///
+    /// Events without a valid facility are sent with the `user` facility.
+    #[serde(default = "default_facility_field")]
+    pub facility_field: String,
+
+    /// The field holding the severity, either as a code or as a name such as `warning`.
+    ///
+    /// Events without a valid severity are sent with the `info` severity.
+    #[serde(default = "default_severity_field")]
+    pub severity_field: String,
+
+    /// The field holding the hostname.
+    #[serde(default = "default_hostname_field")]
+    pub hostname_field: String,
+
+    /// The field holding the application name, which is used as the tag for RFC 3164.
+    #[serde(default = "default_appname_field")]
+    pub appname_field: String,
+
+    /// The field holding the process ID.
+    #[serde(default = "default_procid_field")]
+    pub procid_field: String,
+
+    /// The field holding the message ID, which is only encoded for RFC 5424.
+    #[serde(default = "default_msgid_field")]
+    pub msgid_field: String,
+
+    /// The field holding the structured data, which is only encoded for RFC 5424.
+    ///
+    /// The field must be an object mapping each SD-ID, such as `origin` or `exampleSDID@32473`, to
+    /// an object of its parameters.
+    #[serde(default = "default_structured_data_field")]
+    pub structured_data_field: String,
+}
+
+impl Default for SyslogSerializerOptions {
+    fn default() -> Self {
+        Self {
+            rfc: SyslogRfc::default(),
+            facility_field: default_facility_field(),
+            severity_field: default_severity_field(),
+            hostname_field: default_hostname_field(),
+            appname_field: default_appname_field(),
+            procid_field: default_procid_field(),
+            msgid_field: default_msgid_field(),
+            structured_data_field: default_structured_data_field(),
+        }
+    }
+}
+
+fn default_facility_field() -> String {
+    "facility".to_owned()
+}
+
+fn default_severity_field() -> String {
+    "severity".to_owned()
+}
+
+fn default_hostname_field() -> String {
+    "hostname".to_owned()
+}
+
+fn default_appname_field() -> String {
+    "appname".to_owned()
+}
+
+fn default_procid_field() -> String {
+    "procid".to_owned()
+}
+
+fn default_msgid_field() -> String {
+    "msgid".to_owned()
+}
+
+fn default_structured_data_field() -> String {
+    "structured_data".to_owned()
+}
+
+/// The syslog protocol to encode messages with.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogRfc {
+    /// The BSD syslog protocol, as described in [RFC 3164][rfc3164].
+    ///
+    /// [rfc3164]: https://datatracker.ietf.org/doc/html/rfc3164
+    Rfc3164,
+
+    /// The syslog protocol, as described in [RFC 5424][rfc5424].
+    ///
+    /// [rfc5424]: https://datatracker.ietf.org/doc/html/rfc5424
+    Rfc5424,
+}
+
+impl Default for SyslogRfc {
+    fn default() -> Self {
+        Self::Rfc5424
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the syslog format.
+#[derive(Debug, Clone)]
+pub struct SyslogSerializer {
+    options: SyslogSerializerOptions,
+}
+
+impl SyslogSerializer {
+    /// Creates a new `SyslogSerializer`.
+    pub const fn new(options: SyslogSerializerOptions) -> Self {
+        Self { options }
+    }
+
+    fn encode_rfc5424(&self, log: &LogEvent, timestamp: DateTime<Utc>, line: &mut String) {
+        let options = &self.options;
+        let _ = write!(
+            line,
+            "1 {} {} {} {} {} ",
+            timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            header_field(log, &options.hostname_field, 255),
+            header_field(log, &options.appname_field, 48),
+            header_field(log, &options.procid_field, 128),
+            header_field(log, &options.msgid_field, 32),
+        );
+
+        let start = line.len();
+        if let Some(Value::Object(elements)) = log.get(options.structured_data_field.as_str()) {
+            // Elements and parameters whose name is left empty once sanitized are skipped, as
+            // RFC 5424 requires names of at least one character.
+            let elements = elements
+                .iter()
+                .filter_map(|(id, params)| sd_name(id).map(|id| (id, params)));
+            for (id, params) in elements {
+                line.push('[');
+                line.push_str(&id);
+                if let Value::Object(params) = params {
+                    let params = params
+                        .iter()
+                        .filter_map(|(name, value)| sd_name(name).map(|name| (name, value)));
+                    for (name, value) in params {
+                        line.push(' ');
+                        line.push_str(&name);
+                        line.push_str("=\"");
+                        for c in value.to_string_lossy().chars() {
+                            if matches!(c, '"' | '\\' | ']') {
+                                line.push('\\');
+                            }
+                            line.push(c);
+                        }
+                        line.push('"');
+                    }
+                }
+                line.push(']');
+            }
+        }
+        if line.len() == start {
+            line.push('-');
+        }
+    }
+
+    fn encode_rfc3164(&self, log: &LogEvent, timestamp: DateTime<Utc>, line: &mut String) {
+        let options = &self.options;
+        let _ = write!(
+            line,
+            "{} {}",
+            timestamp.format("%b %e %H:%M:%S"),
+            header_field(log, &options.hostname_field, 255),
+        );
+
+        let tag = header_field(log, &options.appname_field, 32);
+        if tag != "-" {
+            line.push(' ');
+            line.push_str(&tag);
+            let procid = header_field(log, &options.procid_field, 128);
+            if procid != "-" {
+                let _ = write!(line, "[{}]", procid);
+            }
+            line.push(':');
+        }
+    }
+}
+
+impl Encoder<Event> for SyslogSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let options = &self.options;
+
+        let facility =
+            code(log.get(options.facility_field.as_str()), &FACILITIES).unwrap_or(DEFAULT_FACILITY);
+        let severity =
+            code(log.get(options.severity_field.as_str()), &SEVERITIES).unwrap_or(DEFAULT_SEVERITY);
+        let timestamp = match log
+            .get_by_meaning("timestamp")
+            .or_else(|| log.get(log_schema().timestamp_key()))
+        {
+            Some(Value::Timestamp(timestamp)) => *timestamp,
+            _ => Utc::now(),
+        };
+
+        let mut line = format!("<{}>", u16::from(facility) * 8 + u16::from(severity));
+        match options.rfc {
+            SyslogRfc::Rfc5424 => self.encode_rfc5424(&log, timestamp, &mut line),
+            SyslogRfc::Rfc3164 => self.encode_rfc3164(&log, timestamp, &mut line),
+        }
+        buffer.put_slice(line.as_bytes());
+
+        let message_key = log_schema().message_key();
+        if let Some(message) = log
+            .get_by_meaning(message_key)
+            .or_else(|| log.get(message_key))
+            .map(|value| value.coerce_to_bytes())
+        {
+            buffer.put_u8(b' ');
+            buffer.put(message);
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolves a facility or severity given either as a code or as one of `names`.
+fn code(value: Option<&Value>, names: &[&str]) -> Option<u8> {
+    match value? {
+        Value::Integer(code) => u8::try_from(*code)
+            .ok()
+            .filter(|code| usize::from(*code) < names.len()),
+        Value::Bytes(name) => {
+            let name = std::str::from_utf8(name).ok()?.to_ascii_lowercase();
+            let name = match name.as_str() {
+                "emergency" | "panic" => "emerg",
+                "critical" => "crit",
+                "error" => "err",
+                "warn" => "warning",
+                "informational" => "info",
+                name => name,
+            };
+            names
+                .iter()
+                .position(|candidate| *candidate == name)
+                .and_then(|code| u8::try_from(code).ok())
+        }
+        _ => None,
+    }
+}
+
+/// Renders a header field as printable ASCII truncated to `max_len`, or as the `-` nil value if it
+/// is missing or empty.
+fn header_field(log: &LogEvent, field: &str, max_len: usize) -> String {
+    let value = match log.get(field) {
+        None | Some(Value::Null) => return "-".to_owned(),
+        Some(value) => value.to_string_lossy(),
+    };
+    let value = value
+        .chars()
+        .map(|c| if c.is_ascii_graphic() { c } else { '_' })
+        .take(max_len)
+        .collect::<String>();
+    if value.is_empty() {
+        "-".to_owned()
+    } else {
+        value
+    }
+}
+
+/// Renders an SD-ID or a parameter name, dropping the characters it can't contain, or returns
+/// `None` if none are left.
+fn sd_name(name: &str) -> Option<String> {
+    let name = name
+        .chars()
+        .filter(|c| c.is_ascii_graphic() && !matches!(c, '=' | ']' | '"'))
+        .take(32)
+        .collect::<String>();
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use bytes::BytesMut;
+    use chrono::{TimeZone, Utc};
+    use vector_common::btreemap;
+
+    use super::*;
+
+    fn encode(rfc: SyslogRfc, event: Event) -> String {
+        let mut serializer = SyslogSerializerConfig::new(SyslogSerializerOptions {
+            rfc,
+            ..Default::default()
+        })
+        .build();
+        let mut bytes = BytesMut::new();
+        serializer.encode(event, &mut bytes).unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    fn event() -> Event {
+        Event::Log(LogEvent::from(btreemap! {
+            "message" => Value::from("hello world"),
+            "timestamp" => Value::Timestamp(Utc.ymd(2022, 1, 2).and_hms_milli(3, 4, 5, 678)),
+            "facility" => Value::from("local4"),
+            "severity" => Value::from("warning"),
+            "hostname" => Value::from("web 1"),
+            "appname" => Value::from("nginx"),
+            "procid" => Value::from(1234),
+            "msgid" => Value::from("ID47"),
+        }))
+    }
+
+    #[test]
+    fn serialize_rfc5424() {
+        assert_eq!(
+            encode(SyslogRfc::Rfc5424, event()),
+            "<164>1 2022-01-02T03:04:05.678Z web_1 nginx 1234 ID47 - hello world"
+        );
+    }
+
+    #[test]
+    fn serialize_rfc5424_structured_data() {
+        let mut event = event();
+        event.as_mut_log().insert(
+            "structured_data",
+            btreemap! {
+                "exampleSDID@32473" => btreemap! {
+                    "iut" => Value::from(3),
+                    "eventSource" => Value::from("App\"lication]\\"),
+                },
+                "origin" => Value::from(BTreeMap::<String, Value>::new()),
+            },
+        );
+
+        assert_eq!(
+            encode(SyslogRfc::Rfc5424, event),
+            r#"<164>1 2022-01-02T03:04:05.678Z web_1 nginx 1234 ID47 [exampleSDID@32473 eventSource="App\"lication\]\\" iut="3"][origin] hello world"#
+        );
+    }
+
+    #[test]
+    fn serialize_rfc5424_skips_empty_sd_names() {
+        let mut partial = event();
+        partial.as_mut_log().insert(
+            "structured_data",
+            btreemap! {
+                " = " => btreemap! { "iut" => Value::from(3) },
+                "origin" => btreemap! {
+                    "  " => Value::from(1),
+                    "ip" => Value::from("192.0.2.1"),
+                },
+            },
+        );
+        assert_eq!(
+            encode(SyslogRfc::Rfc5424, partial),
+            r#"<164>1 2022-01-02T03:04:05.678Z web_1 nginx 1234 ID47 [origin ip="192.0.2.1"] hello world"#
+        );
+
+        let mut empty = event();
+        empty.as_mut_log().insert(
+            "structured_data",
+            btreemap! { "===" => Value::from(BTreeMap::<String, Value>::new()) },
+        );
+        assert_eq!(
+            encode(SyslogRfc::Rfc5424, empty),
+            "<164>1 2022-01-02T03:04:05.678Z web_1 nginx 1234 ID47 - hello world"
+        );
+    }
+
+    #[test]
+    fn serialize_rfc3164() {
+        assert_eq!(
+            encode(SyslogRfc::Rfc3164, event()),
+            "<164>Jan  2 03:04:05 web_1 nginx[1234]: hello world"
+        );
+    }
+
+    #[test]
+    fn serialize_defaults() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "message" => Value::from("hello"),
+            "timestamp" => Value::Timestamp(Utc.ymd(2022, 11, 12).and_hms(13, 14, 15)),
+            "facility" => Value::from(30),
+            "severity" => Value::from("unknown"),
+        }));
+
+        assert_eq!(
+            encode(SyslogRfc::Rfc5424, event.clone()),
+            "<14>1 2022-11-12T13:14:15Z - - - - - hello"
+        );
+        assert_eq!(
+            encode(SyslogRfc::Rfc3164, event),
+            "<14>Nov 12 13:14:15 - hello"
+        );
+    }
+
+    #[test]
+    fn serialize_numeric_codes() {
+        let mut event = event();
+        event.as_mut_log().insert("facility", 0);
+        event.as_mut_log().insert("severity", 2);
+
+        assert!(encode(SyslogRfc::Rfc5424, event).starts_with("<2>1 "));
+    }
+}
@@ -8,16 +8,25 @@ use std::fmt::Debug;
 
 use bytes::BytesMut;
 pub use format::{
-    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, GelfSerializer,
-    GelfSerializerConfig, JsonSerializer, JsonSerializerConfig, LogfmtSerializer,
-    LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
-    NativeSerializerConfig, RawMessageSerializer, RawMessageSerializerConfig, TextSerializer,
+    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CefSerializer,
+    CefSerializerConfig, CefSerializerOptions, CsvSerializer, CsvSerializerConfig,
+    CsvSerializerOptions, GelfSerializer, GelfSerializerConfig, JsonSerializer,
+    JsonSerializerConfig, LeefSerializer, LeefSerializerConfig, LeefSerializerOptions, LeefVersion,
+    LogfmtSerializer, LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig,
+    NativeSerializer, NativeSerializerConfig, ProtobufSerializer, ProtobufSerializerConfig,
+    ProtobufSerializerOptions, QuoteStyle, RawMessageSerializer, RawMessageSerializerConfig,
+    SyslogRfc, SyslogSerializer, SyslogSerializerConfig, SyslogSerializerOptions, TextSerializer,
     TextSerializerConfig,
 };
+#[cfg(feature = "parquet")]
+pub use format::{
+    ParquetCompression, ParquetSerializer, ParquetSerializerConfig, ParquetSerializerOptions,
+};
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesEncoder, BytesEncoderConfig, CharacterDelimitedEncoder,
     CharacterDelimitedEncoderConfig, CharacterDelimitedEncoderOptions, LengthDelimitedEncoder,
     LengthDelimitedEncoderConfig, NewlineDelimitedEncoder, NewlineDelimitedEncoderConfig,
+    OctetCountingEncoder, OctetCountingEncoderConfig,
 };
 use vector_config::configurable_component;
 use vector_core::{config::DataType, event::Event, schema};
@@ -72,6 +81,15 @@ pub enum FramingConfig {
 
     /// Event data is delimited by a newline (LF) character.
     NewlineDelimited,
+
+    /// Event data is prefixed with its length in bytes, as a decimal number followed by a space.
+    ///
+    /// This is the octet counting framing described in [RFC 6587][rfc6587], which syslog
+    /// collectors such as rsyslog and syslog-ng accept over TCP, and which keeps multi-line
+    /// messages intact.
+    ///
+    /// [rfc6587]: https://datatracker.ietf.org/doc/html/rfc6587#section-3.4.1
+    OctetCounting,
 }
 
 impl From<BytesEncoderConfig> for FramingConfig {
@@ -100,6 +118,12 @@ impl From<NewlineDelimitedEncoderConfig> for FramingConfig {
     }
 }
 
+impl From<OctetCountingEncoderConfig> for FramingConfig {
+    fn from(_: OctetCountingEncoderConfig) -> Self {
+        Self::OctetCounting
+    }
+}
+
 impl FramingConfig {
     /// Build the `Framer` from this configuration.
     pub fn build(&self) -> Framer {
@@ -119,6 +143,9 @@ impl FramingConfig {
             FramingConfig::NewlineDelimited => {
                 Framer::NewlineDelimited(NewlineDelimitedEncoderConfig.build())
             }
+            FramingConfig::OctetCounting => {
+                Framer::OctetCounting(OctetCountingEncoderConfig.build())
+            }
         }
     }
 }
@@ -134,6 +161,8 @@ pub enum Framer {
     LengthDelimited(LengthDelimitedEncoder),
     /// Uses a `NewlineDelimitedEncoder` for framing.
     NewlineDelimited(NewlineDelimitedEncoder),
+    /// Uses an `OctetCountingEncoder` for framing.
+    OctetCounting(OctetCountingEncoder),
     /// Uses an opaque `Encoder` implementation for framing.
     Boxed(BoxedFramer),
 }
@@ -162,6 +191,12 @@ impl From<NewlineDelimitedEncoder> for Framer {
     }
 }
 
+impl From<OctetCountingEncoder> for Framer {
+    fn from(encoder: OctetCountingEncoder) -> Self {
+        Self::OctetCounting(encoder)
+    }
+}
+
 impl From<BoxedFramer> for Framer {
     fn from(encoder: BoxedFramer) -> Self {
         Self::Boxed(encoder)
@@ -177,6 +212,7 @@ impl tokio_util::codec::Encoder<()> for Framer {
             Framer::CharacterDelimited(framer) => framer.encode((), buffer),
             Framer::LengthDelimited(framer) => framer.encode((), buffer),
             Framer::NewlineDelimited(framer) => framer.encode((), buffer),
+            Framer::OctetCounting(framer) => framer.encode((), buffer),
             Framer::Boxed(framer) => framer.encode((), buffer),
         }
     }
@@ -193,12 +229,42 @@ pub enum SerializerConfig {
         avro: AvroSerializerOptions,
     },
 
+    /// [Common Event Format][cef] (CEF) serialization.
+    ///
+    /// Each event is encoded as a single CEF message, with the configured fields as its header and
+    /// extensions.
+    ///
+    /// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors/pdfdoc/common-event-format-v25/common-event-format-v25.pdf
+    Cef {
+        /// CEF serializer options.
+        cef: CefSerializerOptions,
+    },
+
+    /// CSV serialization.
+    ///
+    /// Each event is encoded as a single row containing the configured fields.
+    Csv {
+        /// CSV serializer options.
+        csv: CsvSerializerOptions,
+    },
+
     /// GELF serialization.
     Gelf,
 
     /// JSON serialization.
     Json,
 
+    /// [Log Event Extended Format][leef] (LEEF) serialization.
+    ///
+    /// Each event is encoded as a single LEEF message, with the configured fields as its header and
+    /// attributes.
+    ///
+    /// [leef]: https://www.ibm.com/docs/en/dsm?topic=leef-overview
+    Leef {
+        /// LEEF serializer options.
+        leef: LeefSerializerOptions,
+    },
+
     /// Logfmt serialization.
     Logfmt,
 
@@ -208,6 +274,12 @@ pub enum SerializerConfig {
     /// Native Vector serialization based on JSON.
     NativeJson,
 
+    /// Protocol Buffers serialization, using a user-supplied message type.
+    Protobuf {
+        /// Protobuf serializer options.
+        protobuf: ProtobufSerializerOptions,
+    },
+
     /// No serialization.
     ///
     /// This encoding, specifically, will only encode the `message` field of a log event. Users should take care if
@@ -216,6 +288,16 @@ pub enum SerializerConfig {
     /// event.
     RawMessage,
 
+    /// Syslog serialization.
+    ///
+    /// Each event is encoded as a single syslog message, following either RFC 5424 or RFC 3164.
+    /// Multi-line messages can be sent over TCP with the `octet_counting` framing.
+    Syslog {
+        /// Syslog serializer options.
+        #[serde(default)]
+        syslog: SyslogSerializerOptions,
+    },
+
     /// Plaintext serialization.
     ///
     /// This encoding, specifically, will only encode the `message` field of a log event. Users should take care if
@@ -231,6 +313,18 @@ impl From<AvroSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<CefSerializerConfig> for SerializerConfig {
+    fn from(config: CefSerializerConfig) -> Self {
+        Self::Cef { cef: config.cef }
+    }
+}
+
+impl From<CsvSerializerConfig> for SerializerConfig {
+    fn from(config: CsvSerializerConfig) -> Self {
+        Self::Csv { csv: config.csv }
+    }
+}
+
 impl From<GelfSerializerConfig> for SerializerConfig {
     fn from(_: GelfSerializerConfig) -> Self {
         Self::Gelf
@@ -243,6 +337,12 @@ impl From<JsonSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<LeefSerializerConfig> for SerializerConfig {
+    fn from(config: LeefSerializerConfig) -> Self {
+        Self::Leef { leef: config.leef }
+    }
+}
+
 impl From<LogfmtSerializerConfig> for SerializerConfig {
     fn from(_: LogfmtSerializerConfig) -> Self {
         Self::Logfmt
@@ -261,12 +361,28 @@ impl From<NativeJsonSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<ProtobufSerializerConfig> for SerializerConfig {
+    fn from(config: ProtobufSerializerConfig) -> Self {
+        Self::Protobuf {
+            protobuf: config.protobuf,
+        }
+    }
+}
+
 impl From<RawMessageSerializerConfig> for SerializerConfig {
     fn from(_: RawMessageSerializerConfig) -> Self {
         Self::RawMessage
     }
 }
 
+impl From<SyslogSerializerConfig> for SerializerConfig {
+    fn from(config: SyslogSerializerConfig) -> Self {
+        Self::Syslog {
+            syslog: config.syslog,
+        }
+    }
+}
+
 impl From<TextSerializerConfig> for SerializerConfig {
     fn from(_: TextSerializerConfig) -> Self {
         Self::Text
@@ -280,16 +396,34 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => Ok(Serializer::Avro(
                 AvroSerializerConfig::new(avro.schema.clone()).build()?,
             )),
+            SerializerConfig::Cef { cef } => Ok(Serializer::Cef(
+                CefSerializerConfig::new(cef.clone()).build()?,
+            )),
+            SerializerConfig::Csv { csv } => Ok(Serializer::Csv(
+                CsvSerializerConfig::new(csv.clone()).build(),
+            )),
             SerializerConfig::Gelf => Ok(Serializer::Gelf(GelfSerializerConfig::new().build())),
             SerializerConfig::Json => Ok(Serializer::Json(JsonSerializerConfig.build())),
+            SerializerConfig::Leef { leef } => Ok(Serializer::Leef(
+                LeefSerializerConfig::new(leef.clone()).build()?,
+            )),
             SerializerConfig::Logfmt => Ok(Serializer::Logfmt(LogfmtSerializerConfig.build())),
             SerializerConfig::Native => Ok(Serializer::Native(NativeSerializerConfig.build())),
             SerializerConfig::NativeJson => {
                 Ok(Serializer::NativeJson(NativeJsonSerializerConfig.build()))
             }
+            SerializerConfig::Protobuf { protobuf } => Ok(Serializer::Protobuf(
+                ProtobufSerializerConfig {
+                    protobuf: protobuf.clone(),
+                }
+                .build()?,
+            )),
             SerializerConfig::RawMessage => {
                 Ok(Serializer::RawMessage(RawMessageSerializerConfig.build()))
             }
+            SerializerConfig::Syslog { syslog } => Ok(Serializer::Syslog(
+                SyslogSerializerConfig::new(syslog.clone()).build(),
+            )),
             SerializerConfig::Text => Ok(Serializer::Text(TextSerializerConfig.build())),
         }
     }
@@ -300,12 +434,22 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => {
                 AvroSerializerConfig::new(avro.schema.clone()).input_type()
             }
+            SerializerConfig::Cef { cef } => CefSerializerConfig::new(cef.clone()).input_type(),
+            SerializerConfig::Csv { csv } => CsvSerializerConfig::new(csv.clone()).input_type(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::input_type(),
             SerializerConfig::Json => JsonSerializerConfig.input_type(),
+            SerializerConfig::Leef { leef } => LeefSerializerConfig::new(leef.clone()).input_type(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.input_type(),
             SerializerConfig::Native => NativeSerializerConfig.input_type(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.input_type(),
+            SerializerConfig::Protobuf { protobuf } => ProtobufSerializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .input_type(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.input_type(),
+            SerializerConfig::Syslog { syslog } => {
+                SyslogSerializerConfig::new(syslog.clone()).input_type()
+            }
             SerializerConfig::Text => TextSerializerConfig.input_type(),
         }
     }
@@ -316,12 +460,28 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => {
                 AvroSerializerConfig::new(avro.schema.clone()).schema_requirement()
             }
+            SerializerConfig::Cef { cef } => {
+                CefSerializerConfig::new(cef.clone()).schema_requirement()
+            }
+            SerializerConfig::Csv { csv } => {
+                CsvSerializerConfig::new(csv.clone()).schema_requirement()
+            }
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::schema_requirement(),
             SerializerConfig::Json => JsonSerializerConfig.schema_requirement(),
+            SerializerConfig::Leef { leef } => {
+                LeefSerializerConfig::new(leef.clone()).schema_requirement()
+            }
             SerializerConfig::Logfmt => LogfmtSerializerConfig.schema_requirement(),
             SerializerConfig::Native => NativeSerializerConfig.schema_requirement(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.schema_requirement(),
+            SerializerConfig::Protobuf { protobuf } => ProtobufSerializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .schema_requirement(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.schema_requirement(),
+            SerializerConfig::Syslog { syslog } => {
+                SyslogSerializerConfig::new(syslog.clone()).schema_requirement()
+            }
             SerializerConfig::Text => TextSerializerConfig.schema_requirement(),
         }
     }
@@ -332,18 +492,28 @@ impl SerializerConfig {
 pub enum Serializer {
     /// Uses an `AvroSerializer` for serialization.
     Avro(AvroSerializer),
+    /// Uses a `CefSerializer` for serialization.
+    Cef(CefSerializer),
+    /// Uses a `CsvSerializer` for serialization.
+    Csv(CsvSerializer),
     /// Uses a `GelfSerializer` for serialization.
     Gelf(GelfSerializer),
     /// Uses a `JsonSerializer` for serialization.
     Json(JsonSerializer),
+    /// Uses a `LeefSerializer` for serialization.
+    Leef(LeefSerializer),
     /// Uses a `LogfmtSerializer` for serialization.
     Logfmt(LogfmtSerializer),
     /// Uses a `NativeSerializer` for serialization.
     Native(NativeSerializer),
     /// Uses a `NativeJsonSerializer` for serialization.
     NativeJson(NativeJsonSerializer),
+    /// Uses a `ProtobufSerializer` for serialization.
+    Protobuf(ProtobufSerializer),
     /// Uses a `RawMessageSerializer` for serialization.
     RawMessage(RawMessageSerializer),
+    /// Uses a `SyslogSerializer` for serialization.
+    Syslog(SyslogSerializer),
     /// Uses a `TextSerializer` for serialization.
     Text(TextSerializer),
 }
@@ -354,10 +524,15 @@ impl Serializer {
         match self {
             Serializer::Json(_) | Serializer::NativeJson(_) | Serializer::Gelf(_) => true,
             Serializer::Avro(_)
+            | Serializer::Cef(_)
+            | Serializer::Csv(_)
+            | Serializer::Leef(_)
             | Serializer::Logfmt(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
-            | Serializer::RawMessage(_) => false,
+            | Serializer::Protobuf(_)
+            | Serializer::RawMessage(_)
+            | Serializer::Syslog(_) => false,
         }
     }
 
@@ -373,10 +548,15 @@ impl Serializer {
             Serializer::Json(serializer) => serializer.to_json_value(event),
             Serializer::NativeJson(serializer) => serializer.to_json_value(event),
             Serializer::Avro(_)
+            | Serializer::Cef(_)
+            | Serializer::Csv(_)
+            | Serializer::Leef(_)
             | Serializer::Logfmt(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
-            | Serializer::RawMessage(_) => {
+            | Serializer::Protobuf(_)
+            | Serializer::RawMessage(_)
+            | Serializer::Syslog(_) => {
                 panic!("Serializer does not support JSON")
             }
         }
@@ -389,6 +569,18 @@ impl From<AvroSerializer> for Serializer {
     }
 }
 
+impl From<CefSerializer> for Serializer {
+    fn from(serializer: CefSerializer) -> Self {
+        Self::Cef(serializer)
+    }
+}
+
+impl From<CsvSerializer> for Serializer {
+    fn from(serializer: CsvSerializer) -> Self {
+        Self::Csv(serializer)
+    }
+}
+
 impl From<GelfSerializer> for Serializer {
     fn from(serializer: GelfSerializer) -> Self {
         Self::Gelf(serializer)
@@ -401,6 +593,12 @@ impl From<JsonSerializer> for Serializer {
     }
 }
 
+impl From<LeefSerializer> for Serializer {
+    fn from(serializer: LeefSerializer) -> Self {
+        Self::Leef(serializer)
+    }
+}
+
 impl From<LogfmtSerializer> for Serializer {
     fn from(serializer: LogfmtSerializer) -> Self {
         Self::Logfmt(serializer)
@@ -419,12 +617,24 @@ impl From<NativeJsonSerializer> for Serializer {
     }
 }
 
+impl From<ProtobufSerializer> for Serializer {
+    fn from(serializer: ProtobufSerializer) -> Self {
+        Self::Protobuf(serializer)
+    }
+}
+
 impl From<RawMessageSerializer> for Serializer {
     fn from(serializer: RawMessageSerializer) -> Self {
         Self::RawMessage(serializer)
     }
 }
 
+impl From<SyslogSerializer> for Serializer {
+    fn from(serializer: SyslogSerializer) -> Self {
+        Self::Syslog(serializer)
+    }
+}
+
 impl From<TextSerializer> for Serializer {
     fn from(serializer: TextSerializer) -> Self {
         Self::Text(serializer)
@@ -437,13 +647,117 @@ impl tokio_util::codec::Encoder<Event> for Serializer {
     fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
         match self {
             Serializer::Avro(serializer) => serializer.encode(event, buffer),
+            Serializer::Cef(serializer) => serializer.encode(event, buffer),
+            Serializer::Csv(serializer) => serializer.encode(event, buffer),
             Serializer::Gelf(serializer) => serializer.encode(event, buffer),
             Serializer::Json(serializer) => serializer.encode(event, buffer),
+            Serializer::Leef(serializer) => serializer.encode(event, buffer),
             Serializer::Logfmt(serializer) => serializer.encode(event, buffer),
             Serializer::Native(serializer) => serializer.encode(event, buffer),
             Serializer::NativeJson(serializer) => serializer.encode(event, buffer),
+            Serializer::Protobuf(serializer) => serializer.encode(event, buffer),
             Serializer::RawMessage(serializer) => serializer.encode(event, buffer),
+            Serializer::Syslog(serializer) => serializer.encode(event, buffer),
             Serializer::Text(serializer) => serializer.encode(event, buffer),
         }
     }
 }
+
+/// Configuration for building a `BatchSerializer`.
+#[cfg(feature = "parquet")]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "codec", rename_all = "snake_case")]
+pub enum BatchSerializerConfig {
+    /// [Apache Parquet][parquet] serialization.
+    ///
+    /// Each batch of events is encoded as a single Parquet file.
+    ///
+    /// [parquet]: https://parquet.apache.org/
+    Parquet {
+        /// Parquet serializer options.
+        parquet: ParquetSerializerOptions,
+    },
+}
+
+#[cfg(feature = "parquet")]
+impl From<ParquetSerializerConfig> for BatchSerializerConfig {
+    fn from(config: ParquetSerializerConfig) -> Self {
+        Self::Parquet {
+            parquet: config.parquet,
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl BatchSerializerConfig {
+    /// Build the `BatchSerializer` from this configuration.
+    pub fn build(&self) -> Result<BatchSerializer, BuildError> {
+        match self {
+            BatchSerializerConfig::Parquet { parquet } => Ok(BatchSerializer::Parquet(
+                ParquetSerializerConfig::new(parquet.clone()).build()?,
+            )),
+        }
+    }
+
+    /// The data type of events that are accepted by this `BatchSerializer`.
+    pub fn input_type(&self) -> DataType {
+        match self {
+            BatchSerializerConfig::Parquet { parquet } => {
+                ParquetSerializerConfig::new(parquet.clone()).input_type()
+            }
+        }
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        match self {
+            BatchSerializerConfig::Parquet { parquet } => {
+                ParquetSerializerConfig::new(parquet.clone()).schema_requirement()
+            }
+        }
+    }
+}
+
+/// Serialize whole batches of structured events as bytes.
+#[cfg(feature = "parquet")]
+#[derive(Debug, Clone)]
+pub enum BatchSerializer {
+    /// Uses a `ParquetSerializer` for serialization.
+    Parquet(ParquetSerializer),
+}
+
+#[cfg(feature = "parquet")]
+impl BatchSerializer {
+    /// Encode a batch of events.
+    pub fn encode(
+        &self,
+        events: Vec<Event>,
+        buffer: &mut BytesMut,
+    ) -> Result<(), vector_common::Error> {
+        match self {
+            BatchSerializer::Parquet(serializer) => serializer.encode(events, buffer),
+        }
+    }
+
+    /// Get the HTTP content type.
+    pub const fn content_type(&self) -> &'static str {
+        match self {
+            BatchSerializer::Parquet(_) => "application/vnd.apache.parquet",
+        }
+    }
+
+    /// Get the default extension of files encoded by this serializer.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            BatchSerializer::Parquet(_) => "parquet",
+        }
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<ParquetSerializer> for BatchSerializer {
+    fn from(serializer: ParquetSerializer) -> Self {
+        Self::Parquet(serializer)
+    }
+}
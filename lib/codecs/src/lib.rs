@@ -7,24 +7,31 @@
 pub mod decoding;
 pub mod encoding;
 pub mod gelf;
+mod protobuf;
 
 pub use decoding::{
-    BytesDecoder, BytesDecoderConfig, BytesDeserializer, BytesDeserializerConfig,
-    CharacterDelimitedDecoder, CharacterDelimitedDecoderConfig, GelfDeserializer,
-    GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, LengthDelimitedDecoder,
-    LengthDelimitedDecoderConfig, NativeDeserializer, NativeDeserializerConfig,
-    NativeJsonDeserializer, NativeJsonDeserializerConfig, NewlineDelimitedDecoder,
-    NewlineDelimitedDecoderConfig, OctetCountingDecoder, OctetCountingDecoderConfig,
+    AvroDeserializer, AvroDeserializerConfig, BytesDecoder, BytesDecoderConfig, BytesDeserializer,
+    BytesDeserializerConfig, CharacterDelimitedDecoder, CharacterDelimitedDecoderConfig,
+    GelfDeserializer, GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig,
+    LengthDelimitedDecoder, LengthDelimitedDecoderConfig, NativeDeserializer,
+    NativeDeserializerConfig, NativeJsonDeserializer, NativeJsonDeserializerConfig,
+    NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig, OctetCountingDecoder,
+    OctetCountingDecoderConfig, ProtobufDeserializer, ProtobufDeserializerConfig,
     StreamDecodingError,
 };
 #[cfg(feature = "syslog")]
 pub use decoding::{SyslogDeserializer, SyslogDeserializerConfig};
 pub use encoding::{
-    BytesEncoder, BytesEncoderConfig, CharacterDelimitedEncoder, CharacterDelimitedEncoderConfig,
-    GelfSerializer, GelfSerializerConfig, JsonSerializer, JsonSerializerConfig,
-    LengthDelimitedEncoder, LengthDelimitedEncoderConfig, LogfmtSerializer, LogfmtSerializerConfig,
-    NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig,
-    NewlineDelimitedEncoder, NewlineDelimitedEncoderConfig, RawMessageSerializer,
-    RawMessageSerializerConfig, TextSerializer, TextSerializerConfig,
+    BytesEncoder, BytesEncoderConfig, CefSerializer, CefSerializerConfig,
+    CharacterDelimitedEncoder, CharacterDelimitedEncoderConfig, CsvSerializer, CsvSerializerConfig,
+    GelfSerializer, GelfSerializerConfig, JsonSerializer, JsonSerializerConfig, LeefSerializer,
+    LeefSerializerConfig, LengthDelimitedEncoder, LengthDelimitedEncoderConfig, LogfmtSerializer,
+    LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
+    NativeSerializerConfig, NewlineDelimitedEncoder, NewlineDelimitedEncoderConfig,
+    OctetCountingEncoder, OctetCountingEncoderConfig, ProtobufSerializer, ProtobufSerializerConfig,
+    RawMessageSerializer, RawMessageSerializerConfig, SyslogSerializer, SyslogSerializerConfig,
+    TextSerializer, TextSerializerConfig,
 };
+#[cfg(feature = "parquet")]
+pub use encoding::{ParquetSerializer, ParquetSerializerConfig};
 pub use gelf::{gelf_fields, VALID_FIELD_REGEX};
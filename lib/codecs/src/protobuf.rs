@@ -0,0 +1,36 @@
+//! Contains common definitions for Protobuf codec support
+
+use std::path::Path;
+
+use prost_reflect::{DescriptorPool, MessageDescriptor};
+
+/// The fully qualified name of the well-known `Timestamp` message type, which
+/// maps onto native timestamp values rather than objects.
+pub(crate) const TIMESTAMP_MESSAGE_TYPE: &str = "google.protobuf.Timestamp";
+
+/// Loads a compiled `FileDescriptorSet` from disk and looks up the given
+/// message type in it.
+pub(crate) fn get_message_descriptor(
+    desc_file: &Path,
+    message_type: &str,
+) -> vector_common::Result<MessageDescriptor> {
+    let bytes = std::fs::read(desc_file).map_err(|error| {
+        format!(
+            "Failed to read Protobuf descriptor set file {:?}: {}",
+            desc_file, error
+        )
+    })?;
+    let pool = DescriptorPool::decode(bytes.as_slice()).map_err(|error| {
+        format!(
+            "Failed to parse Protobuf descriptor set file {:?}: {}",
+            desc_file, error
+        )
+    })?;
+    pool.get_message_by_name(message_type).ok_or_else(|| {
+        format!(
+            "Message type {:?} not found in Protobuf descriptor set file {:?}",
+            message_type, desc_file
+        )
+        .into()
+    })
+}
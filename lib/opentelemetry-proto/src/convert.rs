@@ -1,27 +1,55 @@
 use super::proto::{
-    common::v1::{any_value::Value as PBValue, KeyValue},
+    common::v1::{any_value::Value as PBValue, InstrumentationScope, KeyValue},
     logs::v1::{LogRecord, ResourceLogs, SeverityNumber},
+    metrics::v1::{
+        metric::Data, number_data_point::Value as NumberValue, AggregationTemporality,
+        DataPointFlags, ExponentialHistogramDataPoint, Metric as PBMetric, NumberDataPoint,
+        ResourceMetrics,
+    },
     resource::v1::Resource,
+    trace::v1::{
+        span::{Event as SpanEvent, Link},
+        ResourceSpans, Span, Status,
+    },
 };
 use bytes::Bytes;
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use ordered_float::NotNan;
 use std::collections::BTreeMap;
 use value::Value;
 use vector_core::{
     config::log_schema,
-    event::{Event, LogEvent},
+    event::{
+        metric::{Bucket, Metric, MetricKind, MetricTags, MetricValue, Quantile},
+        Event, LogEvent, TraceEvent,
+    },
 };
 
-const RESOURCE_KEY: &str = "resources";
-const ATTRIBUTES_KEY: &str = "attributes";
-const TRACE_ID_KEY: &str = "trace_id";
-const SPAN_ID_KEY: &str = "span_id";
-const SEVERITY_TEXT_KEY: &str = "severity_text";
-const SEVERITY_NUMBER_KEY: &str = "severity_number";
-const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
-const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
-const FLAGS_KEY: &str = "flags";
+pub(crate) const RESOURCE_KEY: &str = "resources";
+pub(crate) const ATTRIBUTES_KEY: &str = "attributes";
+pub(crate) const TRACE_ID_KEY: &str = "trace_id";
+pub(crate) const SPAN_ID_KEY: &str = "span_id";
+pub(crate) const SEVERITY_TEXT_KEY: &str = "severity_text";
+pub(crate) const SEVERITY_NUMBER_KEY: &str = "severity_number";
+pub(crate) const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
+pub(crate) const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
+pub(crate) const FLAGS_KEY: &str = "flags";
+pub(crate) const PARENT_SPAN_ID_KEY: &str = "parent_span_id";
+pub(crate) const TRACE_STATE_KEY: &str = "trace_state";
+pub(crate) const NAME_KEY: &str = "name";
+pub(crate) const KIND_KEY: &str = "kind";
+pub(crate) const START_TIME_KEY: &str = "start_time";
+pub(crate) const END_TIME_KEY: &str = "end_time";
+pub(crate) const EVENTS_KEY: &str = "events";
+pub(crate) const DROPPED_EVENTS_COUNT_KEY: &str = "dropped_events_count";
+pub(crate) const LINKS_KEY: &str = "links";
+pub(crate) const DROPPED_LINKS_COUNT_KEY: &str = "dropped_links_count";
+pub(crate) const STATUS_KEY: &str = "status";
+pub(crate) const MESSAGE_KEY: &str = "message";
+pub(crate) const CODE_KEY: &str = "code";
+pub(crate) const RESOURCE_TAG_PREFIX: &str = "resource.";
+pub(crate) const SCOPE_KEY: &str = "scope";
+pub(crate) const VERSION_KEY: &str = "version";
 
 impl IntoIterator for ResourceLogs {
     type Item = Event;
@@ -30,10 +58,17 @@ impl IntoIterator for ResourceLogs {
         let resource = self.resource;
         self.scope_logs
             .into_iter()
-            .flat_map(|scope_log| scope_log.log_records)
-            .map(|log_record| {
+            .flat_map(|scope_log| {
+                let scope = scope_log.scope;
+                scope_log
+                    .log_records
+                    .into_iter()
+                    .map(move |log_record| (scope.clone(), log_record))
+            })
+            .map(|(scope, log_record)| {
                 ResourceLog {
                     resource: resource.clone(),
+                    scope,
                     log_record,
                 }
                 .into()
@@ -62,8 +97,28 @@ impl From<PBValue> for Value {
     }
 }
 
+/// Converts the instrumentation scope into an object with its name, version and attributes, unless
+/// it's empty, which is equivalent to an unknown scope.
+fn scope_into_value(scope: InstrumentationScope) -> Option<Value> {
+    let mut fields = BTreeMap::new();
+    if !scope.name.is_empty() {
+        fields.insert(NAME_KEY.to_owned(), scope.name.into());
+    }
+    if !scope.version.is_empty() {
+        fields.insert(VERSION_KEY.to_owned(), scope.version.into());
+    }
+    if !scope.attributes.is_empty() {
+        fields.insert(
+            ATTRIBUTES_KEY.to_owned(),
+            kv_list_into_value(scope.attributes),
+        );
+    }
+    (!fields.is_empty()).then(|| Value::Object(fields))
+}
+
 struct ResourceLog {
     resource: Option<Resource>,
+    scope: Option<InstrumentationScope>,
     log_record: LogRecord,
 }
 
@@ -88,6 +143,9 @@ impl From<ResourceLog> for Event {
                 le.insert(RESOURCE_KEY, kv_list_into_value(resource.attributes));
             }
         }
+        if let Some(scope) = rl.scope.and_then(scope_into_value) {
+            le.insert(SCOPE_KEY, scope);
+        }
         if !rl.log_record.attributes.is_empty() {
             le.insert(ATTRIBUTES_KEY, kv_list_into_value(rl.log_record.attributes));
         }
@@ -144,3 +202,376 @@ impl From<ResourceLog> for Event {
         le.into()
     }
 }
+
+impl IntoIterator for ResourceSpans {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let resource = self.resource;
+        self.scope_spans
+            .into_iter()
+            .flat_map(|scope_span| {
+                let scope = scope_span.scope;
+                scope_span
+                    .spans
+                    .into_iter()
+                    .map(move |span| (scope.clone(), span))
+            })
+            .map(|(scope, span)| {
+                ResourceSpan {
+                    resource: resource.clone(),
+                    scope,
+                    span,
+                }
+                .into()
+            })
+            .collect::<Vec<Self::Item>>()
+            .into_iter()
+    }
+}
+
+struct ResourceSpan {
+    resource: Option<Resource>,
+    scope: Option<InstrumentationScope>,
+    span: Span,
+}
+
+impl From<ResourceSpan> for Event {
+    fn from(rs: ResourceSpan) -> Self {
+        let span = rs.span;
+        let mut trace = TraceEvent::default();
+
+        trace.insert(
+            TRACE_ID_KEY,
+            Value::Bytes(Bytes::from(hex::encode(span.trace_id))),
+        );
+        trace.insert(
+            SPAN_ID_KEY,
+            Value::Bytes(Bytes::from(hex::encode(span.span_id))),
+        );
+        trace.insert(NAME_KEY, span.name);
+        trace.insert(KIND_KEY, span.kind);
+        trace.insert(START_TIME_KEY, timestamp_value(span.start_time_unix_nano));
+        trace.insert(END_TIME_KEY, timestamp_value(span.end_time_unix_nano));
+
+        // optional fields
+        if !span.parent_span_id.is_empty() {
+            trace.insert(
+                PARENT_SPAN_ID_KEY,
+                Value::Bytes(Bytes::from(hex::encode(span.parent_span_id))),
+            );
+        }
+        if !span.trace_state.is_empty() {
+            trace.insert(TRACE_STATE_KEY, span.trace_state);
+        }
+        if let Some(resource) = rs.resource {
+            if !resource.attributes.is_empty() {
+                trace.insert(RESOURCE_KEY, kv_list_into_value(resource.attributes));
+            }
+        }
+        if let Some(scope) = rs.scope.and_then(scope_into_value) {
+            trace.insert(SCOPE_KEY, scope);
+        }
+        if !span.attributes.is_empty() {
+            trace.insert(ATTRIBUTES_KEY, kv_list_into_value(span.attributes));
+        }
+        if !span.events.is_empty() {
+            trace.insert(
+                EVENTS_KEY,
+                Value::Array(span.events.into_iter().map(Into::into).collect()),
+            );
+        }
+        if !span.links.is_empty() {
+            trace.insert(
+                LINKS_KEY,
+                Value::Array(span.links.into_iter().map(Into::into).collect()),
+            );
+        }
+        if let Some(status) = span.status {
+            trace.insert(STATUS_KEY, status);
+        }
+
+        trace.insert(DROPPED_ATTRIBUTES_COUNT_KEY, span.dropped_attributes_count);
+        trace.insert(DROPPED_EVENTS_COUNT_KEY, span.dropped_events_count);
+        trace.insert(DROPPED_LINKS_COUNT_KEY, span.dropped_links_count);
+
+        trace.insert(log_schema().source_type_key(), Bytes::from("opentelemetry"));
+
+        trace.into()
+    }
+}
+
+impl From<SpanEvent> for Value {
+    fn from(event: SpanEvent) -> Self {
+        Value::Object(BTreeMap::from([
+            (NAME_KEY.to_owned(), event.name.into()),
+            (
+                log_schema().timestamp_key().to_owned(),
+                timestamp_value(event.time_unix_nano),
+            ),
+            (
+                ATTRIBUTES_KEY.to_owned(),
+                kv_list_into_value(event.attributes),
+            ),
+            (
+                DROPPED_ATTRIBUTES_COUNT_KEY.to_owned(),
+                event.dropped_attributes_count.into(),
+            ),
+        ]))
+    }
+}
+
+impl From<Link> for Value {
+    fn from(link: Link) -> Self {
+        Value::Object(BTreeMap::from([
+            (
+                TRACE_ID_KEY.to_owned(),
+                Value::Bytes(Bytes::from(hex::encode(link.trace_id))),
+            ),
+            (
+                SPAN_ID_KEY.to_owned(),
+                Value::Bytes(Bytes::from(hex::encode(link.span_id))),
+            ),
+            (TRACE_STATE_KEY.to_owned(), link.trace_state.into()),
+            (
+                ATTRIBUTES_KEY.to_owned(),
+                kv_list_into_value(link.attributes),
+            ),
+            (
+                DROPPED_ATTRIBUTES_COUNT_KEY.to_owned(),
+                link.dropped_attributes_count.into(),
+            ),
+        ]))
+    }
+}
+
+impl From<Status> for Value {
+    fn from(status: Status) -> Self {
+        Value::Object(BTreeMap::from([
+            (MESSAGE_KEY.to_owned(), status.message.into()),
+            (CODE_KEY.to_owned(), status.code.into()),
+        ]))
+    }
+}
+
+fn timestamp_value(time_unix_nano: u64) -> Value {
+    Utc.timestamp_nanos(time_unix_nano as i64).into()
+}
+
+impl IntoIterator for ResourceMetrics {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        // Resource attributes are added to the tags of every metric, prefixed to tell them apart
+        // from the data point attributes.
+        let resource_tags = self
+            .resource
+            .map(|resource| {
+                attributes_into_tags(resource.attributes)
+                    .map(|(key, value)| (format!("{}{}", RESOURCE_TAG_PREFIX, key), value))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        self.scope_metrics
+            .into_iter()
+            .flat_map(|scope_metric| scope_metric.metrics)
+            .flat_map(|metric| metric_into_events(metric, &resource_tags))
+            .collect::<Vec<Self::Item>>()
+            .into_iter()
+    }
+}
+
+fn metric_into_events(metric: PBMetric, resource_tags: &[(String, String)]) -> Vec<Event> {
+    let name = metric.name;
+    let new_metric = |kind, value, time_unix_nano, attributes: Vec<KeyValue>| -> Event {
+        let tags = resource_tags
+            .iter()
+            .cloned()
+            .chain(attributes_into_tags(attributes))
+            .collect::<MetricTags>();
+        Metric::new(name.clone(), kind, value)
+            .with_timestamp(timestamp(time_unix_nano))
+            .with_tags(tags.as_option())
+            .into()
+    };
+
+    match metric.data {
+        None => Vec::new(),
+        Some(Data::Gauge(gauge)) => gauge
+            .data_points
+            .into_iter()
+            .filter_map(|point| {
+                let value = number_value(&point)?;
+                Some(new_metric(
+                    MetricKind::Absolute,
+                    MetricValue::Gauge { value },
+                    point.time_unix_nano,
+                    point.attributes,
+                ))
+            })
+            .collect(),
+        Some(Data::Sum(sum)) => {
+            let kind = temporality_into_kind(sum.aggregation_temporality);
+            let is_monotonic = sum.is_monotonic;
+            sum.data_points
+                .into_iter()
+                .filter_map(|point| {
+                    let value = number_value(&point)?;
+                    // Only monotonic sums are counters, since counters can't go down.
+                    let value = if is_monotonic {
+                        MetricValue::Counter { value }
+                    } else {
+                        MetricValue::Gauge { value }
+                    };
+                    Some(new_metric(
+                        kind,
+                        value,
+                        point.time_unix_nano,
+                        point.attributes,
+                    ))
+                })
+                .collect()
+        }
+        Some(Data::Histogram(histogram)) => {
+            let kind = temporality_into_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .filter(|point| !has_no_recorded_value(point.flags))
+                .map(|point| {
+                    // The last bucket count is for the values above the last bound, which is
+                    // implied by the total count, like the `+Inf` bucket of Prometheus histograms.
+                    let buckets = point
+                        .explicit_bounds
+                        .iter()
+                        .zip(point.bucket_counts.iter())
+                        .map(|(upper_limit, count)| Bucket {
+                            upper_limit: *upper_limit,
+                            count: *count,
+                        })
+                        .collect();
+                    new_metric(
+                        kind,
+                        MetricValue::AggregatedHistogram {
+                            buckets,
+                            count: point.count,
+                            sum: point.sum.unwrap_or_default(),
+                        },
+                        point.time_unix_nano,
+                        point.attributes,
+                    )
+                })
+                .collect()
+        }
+        Some(Data::ExponentialHistogram(histogram)) => {
+            let kind = temporality_into_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .filter(|point| !has_no_recorded_value(point.flags))
+                .map(|point| {
+                    new_metric(
+                        kind,
+                        MetricValue::AggregatedHistogram {
+                            buckets: exponential_buckets(&point),
+                            count: point.count,
+                            sum: point.sum.unwrap_or_default(),
+                        },
+                        point.time_unix_nano,
+                        point.attributes,
+                    )
+                })
+                .collect()
+        }
+        Some(Data::Summary(summary)) => summary
+            .data_points
+            .into_iter()
+            .filter(|point| !has_no_recorded_value(point.flags))
+            .map(|point| {
+                new_metric(
+                    MetricKind::Absolute,
+                    MetricValue::AggregatedSummary {
+                        quantiles: point
+                            .quantile_values
+                            .iter()
+                            .map(|q| Quantile {
+                                quantile: q.quantile,
+                                value: q.value,
+                            })
+                            .collect(),
+                        count: point.count,
+                        sum: point.sum,
+                    },
+                    point.time_unix_nano,
+                    point.attributes,
+                )
+            })
+            .collect(),
+    }
+}
+
+fn number_value(point: &NumberDataPoint) -> Option<f64> {
+    if has_no_recorded_value(point.flags) {
+        return None;
+    }
+    match point.value? {
+        NumberValue::AsDouble(value) => Some(value),
+        NumberValue::AsInt(value) => Some(value as f64),
+    }
+}
+
+const fn has_no_recorded_value(flags: u32) -> bool {
+    flags & DataPointFlags::FlagNoRecordedValue as u32 != 0
+}
+
+fn temporality_into_kind(temporality: i32) -> MetricKind {
+    if temporality == AggregationTemporality::Delta as i32 {
+        MetricKind::Incremental
+    } else {
+        MetricKind::Absolute
+    }
+}
+
+/// Converts the exponential buckets into buckets with explicit upper limits, where the bucket at
+/// index `i` has the bounds `(base^i, base^(i+1)]` and `base = 2^(2^-scale)`.
+fn exponential_buckets(point: &ExponentialHistogramDataPoint) -> Vec<Bucket> {
+    let base = 2f64.powf(2f64.powi(-point.scale));
+    let mut buckets = Vec::new();
+
+    // Negative buckets mirror the positive ones, so the highest index has the lowest upper limit.
+    if let Some(negative) = &point.negative {
+        for (i, count) in negative.bucket_counts.iter().enumerate().rev() {
+            buckets.push(Bucket {
+                upper_limit: -base.powi(negative.offset + i as i32),
+                count: *count,
+            });
+        }
+    }
+    buckets.push(Bucket {
+        upper_limit: 0.0,
+        count: point.zero_count,
+    });
+    if let Some(positive) = &point.positive {
+        for (i, count) in positive.bucket_counts.iter().enumerate() {
+            buckets.push(Bucket {
+                upper_limit: base.powi(positive.offset + i as i32 + 1),
+                count: *count,
+            });
+        }
+    }
+
+    buckets
+}
+
+fn attributes_into_tags(attributes: Vec<KeyValue>) -> impl Iterator<Item = (String, String)> {
+    attributes.into_iter().filter_map(|kv| {
+        let value: Value = kv.value?.value?.into();
+        Some((kv.key, value.to_string_lossy().into_owned()))
+    })
+}
+
+// 0 represents a missing or unknown timestamp
+fn timestamp(time_unix_nano: u64) -> Option<DateTime<Utc>> {
+    (time_unix_nano > 0).then(|| Utc.timestamp_nanos(time_unix_nano as i64))
+}
@@ -0,0 +1,836 @@
+//! Conversions from Vector events back into OTLP export requests, the reverse of the conversions
+//! in `convert`.
+
+use std::collections::BTreeMap;
+
+use bytes::Bytes;
+use value::Value;
+use vector_core::{
+    config::log_schema,
+    event::{
+        metric::{Bucket, Metric, MetricKind, MetricSketch, MetricValue, Quantile},
+        LogEvent, TraceEvent,
+    },
+    metrics::AgentDDSketch,
+};
+
+use super::{
+    convert::{
+        ATTRIBUTES_KEY, CODE_KEY, DROPPED_ATTRIBUTES_COUNT_KEY, DROPPED_EVENTS_COUNT_KEY,
+        DROPPED_LINKS_COUNT_KEY, END_TIME_KEY, EVENTS_KEY, FLAGS_KEY, KIND_KEY, LINKS_KEY,
+        MESSAGE_KEY, NAME_KEY, OBSERVED_TIMESTAMP_KEY, PARENT_SPAN_ID_KEY, RESOURCE_KEY,
+        RESOURCE_TAG_PREFIX, SCOPE_KEY, SEVERITY_NUMBER_KEY, SEVERITY_TEXT_KEY, SPAN_ID_KEY,
+        START_TIME_KEY, STATUS_KEY, TRACE_ID_KEY, TRACE_STATE_KEY, VERSION_KEY,
+    },
+    proto::{
+        collector::{
+            logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+            trace::v1::ExportTraceServiceRequest,
+        },
+        common::v1::{
+            any_value::Value as PBValue, AnyValue, ArrayValue, InstrumentationScope, KeyValue,
+            KeyValueList,
+        },
+        logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
+        metrics::v1::{
+            metric::Data, number_data_point::Value as NumberValue,
+            summary_data_point::ValueAtQuantile, AggregationTemporality, Gauge, Histogram,
+            HistogramDataPoint, Metric as PBMetric, NumberDataPoint, ResourceMetrics, ScopeMetrics,
+            Sum, Summary, SummaryDataPoint,
+        },
+        resource::v1::Resource,
+        trace::v1::{
+            span::{Event as SpanEvent, Link},
+            ResourceSpans, ScopeSpans, Span, Status,
+        },
+    },
+};
+
+/// Items grouped by the resource and the instrumentation scope they come from.
+struct Group<T> {
+    resource: Option<Resource>,
+    scope: Option<InstrumentationScope>,
+    items: Vec<T>,
+}
+
+fn push_grouped<T>(
+    groups: &mut Vec<Group<T>>,
+    resource: Option<Resource>,
+    scope: Option<InstrumentationScope>,
+    item: T,
+) {
+    // Batches rarely hold more than a few distinct resources, so a linear search is enough.
+    match groups
+        .iter_mut()
+        .find(|group| group.resource == resource && group.scope == scope)
+    {
+        Some(group) => group.items.push(item),
+        None => groups.push(Group {
+            resource,
+            scope,
+            items: vec![item],
+        }),
+    }
+}
+
+/// Converts the logs into a request, where the `resources` and `scope` fields are mapped back to
+/// the resource and the instrumentation scope of the log records.
+///
+/// Fields that aren't part of the OTLP log data model, other than the source type, are added to
+/// the attributes of the log record.
+impl FromIterator<LogEvent> for ExportLogsServiceRequest {
+    fn from_iter<I: IntoIterator<Item = LogEvent>>(iter: I) -> Self {
+        let mut groups = Vec::new();
+        for log in iter {
+            let (value, _metadata) = log.into_parts();
+            let mut fields = match value {
+                Value::Object(fields) => fields,
+                body => {
+                    let record = LogRecord {
+                        body: Some(value_into_any_value(body)),
+                        ..Default::default()
+                    };
+                    push_grouped(&mut groups, None, None, record);
+                    continue;
+                }
+            };
+
+            let resource = take_resource(&mut fields);
+            let scope = take_scope(&mut fields);
+            let record = LogRecord {
+                time_unix_nano: take_timestamp(&mut fields, log_schema().timestamp_key()),
+                observed_time_unix_nano: take_timestamp(&mut fields, OBSERVED_TIMESTAMP_KEY),
+                severity_number: take_integer(&mut fields, SEVERITY_NUMBER_KEY) as i32,
+                severity_text: take_string(&mut fields, SEVERITY_TEXT_KEY),
+                body: fields
+                    .remove(log_schema().message_key())
+                    .map(value_into_any_value),
+                dropped_attributes_count: take_integer(&mut fields, DROPPED_ATTRIBUTES_COUNT_KEY)
+                    as u32,
+                flags: take_integer(&mut fields, FLAGS_KEY) as u32,
+                trace_id: take_id(&mut fields, TRACE_ID_KEY),
+                span_id: take_id(&mut fields, SPAN_ID_KEY),
+                attributes: take_attributes(fields),
+            };
+            push_grouped(&mut groups, resource, scope, record);
+        }
+
+        Self {
+            resource_logs: groups
+                .into_iter()
+                .map(|group| ResourceLogs {
+                    resource: group.resource,
+                    scope_logs: vec![ScopeLogs {
+                        scope: group.scope,
+                        log_records: group.items,
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Converts the traces into a request, where each trace event is a span with the same fields as
+/// the ones produced by the `opentelemetry` source.
+///
+/// Fields that aren't part of the OTLP span data model, other than the source type, are added to
+/// the attributes of the span.
+impl FromIterator<TraceEvent> for ExportTraceServiceRequest {
+    fn from_iter<I: IntoIterator<Item = TraceEvent>>(iter: I) -> Self {
+        let mut groups = Vec::new();
+        for trace in iter {
+            let (mut fields, _metadata) = trace.into_parts();
+
+            let resource = take_resource(&mut fields);
+            let scope = take_scope(&mut fields);
+            let span = Span {
+                trace_id: take_id(&mut fields, TRACE_ID_KEY),
+                span_id: take_id(&mut fields, SPAN_ID_KEY),
+                trace_state: take_string(&mut fields, TRACE_STATE_KEY),
+                parent_span_id: take_id(&mut fields, PARENT_SPAN_ID_KEY),
+                name: take_string(&mut fields, NAME_KEY),
+                kind: take_integer(&mut fields, KIND_KEY) as i32,
+                start_time_unix_nano: take_timestamp(&mut fields, START_TIME_KEY),
+                end_time_unix_nano: take_timestamp(&mut fields, END_TIME_KEY),
+                dropped_attributes_count: take_integer(&mut fields, DROPPED_ATTRIBUTES_COUNT_KEY)
+                    as u32,
+                events: take_array(&mut fields, EVENTS_KEY)
+                    .filter_map(Value::into_object)
+                    .map(span_event_from_fields)
+                    .collect(),
+                dropped_events_count: take_integer(&mut fields, DROPPED_EVENTS_COUNT_KEY) as u32,
+                links: take_array(&mut fields, LINKS_KEY)
+                    .filter_map(Value::into_object)
+                    .map(link_from_fields)
+                    .collect(),
+                dropped_links_count: take_integer(&mut fields, DROPPED_LINKS_COUNT_KEY) as u32,
+                status: fields
+                    .remove(STATUS_KEY)
+                    .and_then(Value::into_object)
+                    .map(|mut status| Status {
+                        message: take_string(&mut status, MESSAGE_KEY),
+                        code: take_integer(&mut status, CODE_KEY) as i32,
+                    }),
+                attributes: take_attributes(fields),
+            };
+            push_grouped(&mut groups, resource, scope, span);
+        }
+
+        Self {
+            resource_spans: groups
+                .into_iter()
+                .map(|group| ResourceSpans {
+                    resource: group.resource,
+                    scope_spans: vec![ScopeSpans {
+                        scope: group.scope,
+                        spans: group.items,
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn span_event_from_fields(mut fields: BTreeMap<String, Value>) -> SpanEvent {
+    SpanEvent {
+        time_unix_nano: take_timestamp(&mut fields, log_schema().timestamp_key()),
+        name: take_string(&mut fields, NAME_KEY),
+        dropped_attributes_count: take_integer(&mut fields, DROPPED_ATTRIBUTES_COUNT_KEY) as u32,
+        attributes: take_object(&mut fields, ATTRIBUTES_KEY),
+    }
+}
+
+fn link_from_fields(mut fields: BTreeMap<String, Value>) -> Link {
+    Link {
+        trace_id: take_id(&mut fields, TRACE_ID_KEY),
+        span_id: take_id(&mut fields, SPAN_ID_KEY),
+        trace_state: take_string(&mut fields, TRACE_STATE_KEY),
+        dropped_attributes_count: take_integer(&mut fields, DROPPED_ATTRIBUTES_COUNT_KEY) as u32,
+        attributes: take_object(&mut fields, ATTRIBUTES_KEY),
+    }
+}
+
+/// Converts the metrics into a request, where tags prefixed with `resource.` are mapped back to
+/// the resource attributes and the other tags are the attributes of the data points.
+///
+/// Sets are sent as gauges of the number of values, while distributions and sketches are sent as
+/// histograms with a bucket per bin of their sketch.
+impl FromIterator<Metric> for ExportMetricsServiceRequest {
+    fn from_iter<I: IntoIterator<Item = Metric>>(iter: I) -> Self {
+        let mut groups = Vec::new();
+        for metric in iter {
+            let mut resource_attributes = Vec::new();
+            let mut attributes = Vec::new();
+            for (key, value) in metric
+                .tags()
+                .into_iter()
+                .flat_map(|tags| tags.iter_single())
+            {
+                let (key, target) = match key.strip_prefix(RESOURCE_TAG_PREFIX) {
+                    Some(key) => (key, &mut resource_attributes),
+                    None => (key, &mut attributes),
+                };
+                target.push(KeyValue {
+                    key: key.to_owned(),
+                    value: Some(AnyValue {
+                        value: Some(PBValue::StringValue(value.to_owned())),
+                    }),
+                });
+            }
+            let resource = (!resource_attributes.is_empty()).then(|| Resource {
+                attributes: resource_attributes,
+                dropped_attributes_count: 0,
+            });
+
+            push_grouped(
+                &mut groups,
+                resource,
+                None,
+                metric_into_proto(&metric, attributes),
+            );
+        }
+
+        Self {
+            resource_metrics: groups
+                .into_iter()
+                .map(|group| ResourceMetrics {
+                    resource: group.resource,
+                    scope_metrics: vec![ScopeMetrics {
+                        scope: group.scope,
+                        metrics: group.items,
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn metric_into_proto(metric: &Metric, attributes: Vec<KeyValue>) -> PBMetric {
+    let time_unix_nano = metric
+        .timestamp()
+        .map_or(0, |timestamp| timestamp.timestamp_nanos() as u64);
+    let temporality = match metric.kind() {
+        MetricKind::Incremental => AggregationTemporality::Delta,
+        MetricKind::Absolute => AggregationTemporality::Cumulative,
+    } as i32;
+    let number_point = |value| NumberDataPoint {
+        attributes: attributes.clone(),
+        start_time_unix_nano: 0,
+        time_unix_nano,
+        exemplars: Vec::new(),
+        flags: 0,
+        value: Some(NumberValue::AsDouble(value)),
+    };
+
+    let data = match metric.value() {
+        MetricValue::Counter { value } => Data::Sum(Sum {
+            data_points: vec![number_point(*value)],
+            aggregation_temporality: temporality,
+            is_monotonic: true,
+        }),
+        // Incremental gauges are changes to the value, which are non-monotonic delta sums in OTLP.
+        MetricValue::Gauge { value } if metric.kind() == MetricKind::Incremental => {
+            Data::Sum(Sum {
+                data_points: vec![number_point(*value)],
+                aggregation_temporality: temporality,
+                is_monotonic: false,
+            })
+        }
+        MetricValue::Gauge { value } => Data::Gauge(Gauge {
+            data_points: vec![number_point(*value)],
+        }),
+        MetricValue::Set { values } => Data::Gauge(Gauge {
+            data_points: vec![number_point(values.len() as f64)],
+        }),
+        MetricValue::AggregatedHistogram {
+            buckets,
+            count,
+            sum,
+        } => Data::Histogram(Histogram {
+            data_points: vec![histogram_point(
+                buckets,
+                *count,
+                *sum,
+                attributes.clone(),
+                time_unix_nano,
+            )],
+            aggregation_temporality: temporality,
+        }),
+        MetricValue::AggregatedSummary {
+            quantiles,
+            count,
+            sum,
+        } => Data::Summary(Summary {
+            data_points: vec![SummaryDataPoint {
+                attributes: attributes.clone(),
+                start_time_unix_nano: 0,
+                time_unix_nano,
+                count: *count,
+                sum: *sum,
+                quantile_values: quantiles
+                    .iter()
+                    .map(|Quantile { quantile, value }| ValueAtQuantile {
+                        quantile: *quantile,
+                        value: *value,
+                    })
+                    .collect(),
+                flags: 0,
+            }],
+        }),
+        // Distributions are sketched, and both are sent as histograms with a bucket per bin of the
+        // sketch.
+        MetricValue::Distribution { .. } => {
+            let sketch = match metric.value().distribution_to_sketch() {
+                Some(MetricValue::Sketch {
+                    sketch: MetricSketch::AgentDDSketch(sketch),
+                }) => sketch,
+                _ => unreachable!("distributions are converted to sketches"),
+            };
+            Data::Histogram(Histogram {
+                data_points: vec![sketch_point(&sketch, attributes, time_unix_nano)],
+                aggregation_temporality: temporality,
+            })
+        }
+        MetricValue::Sketch {
+            sketch: MetricSketch::AgentDDSketch(sketch),
+        } => Data::Histogram(Histogram {
+            data_points: vec![sketch_point(sketch, attributes, time_unix_nano)],
+            aggregation_temporality: temporality,
+        }),
+    };
+
+    PBMetric {
+        name: match metric.namespace() {
+            Some(namespace) => format!("{}.{}", namespace, metric.name()),
+            None => metric.name().to_owned(),
+        },
+        description: String::new(),
+        unit: String::new(),
+        data: Some(data),
+    }
+}
+
+fn histogram_point(
+    buckets: &[Bucket],
+    count: u64,
+    sum: f64,
+    attributes: Vec<KeyValue>,
+    time_unix_nano: u64,
+) -> HistogramDataPoint {
+    // OTLP has one more bucket count than bounds, for the values above the last bound. Vector
+    // leaves that bucket out unless its upper limit is explicitly `+Inf`.
+    let mut explicit_bounds = Vec::with_capacity(buckets.len());
+    let mut bucket_counts = Vec::with_capacity(buckets.len() + 1);
+    for bucket in buckets {
+        if bucket.upper_limit == f64::INFINITY {
+            break;
+        }
+        explicit_bounds.push(bucket.upper_limit);
+        bucket_counts.push(bucket.count);
+    }
+    let bounded_count: u64 = bucket_counts.iter().sum();
+    bucket_counts.push(count.saturating_sub(bounded_count));
+
+    HistogramDataPoint {
+        attributes,
+        start_time_unix_nano: 0,
+        time_unix_nano,
+        count,
+        sum: Some(sum),
+        bucket_counts,
+        explicit_bounds,
+        exemplars: Vec::new(),
+        flags: 0,
+        min: None,
+        max: None,
+    }
+}
+
+fn sketch_point(
+    sketch: &AgentDDSketch,
+    attributes: Vec<KeyValue>,
+    time_unix_nano: u64,
+) -> HistogramDataPoint {
+    let config = sketch.config();
+    let bins = sketch.bin_map();
+    let mut buckets: Vec<Bucket> = Vec::with_capacity(bins.keys.len());
+    for (&k, &n) in bins.keys.iter().zip(bins.counts.iter()) {
+        // Positive keys are the bins of the values between their lower bound and the lower bound of
+        // the next key, negative keys mirror them, and the zero key holds the values too small to
+        // have a bin of their own.
+        let upper_limit = match k {
+            k if k > 0 => config.bin_lower_bound(k) * sketch.gamma(),
+            0 => config.bin_lower_bound(1),
+            k => config.bin_lower_bound(k),
+        };
+        // Bins holding more observations than fit in a single bin are split across several bins
+        // with the same key.
+        match buckets.last_mut() {
+            Some(last) if last.upper_limit == upper_limit => last.count += u64::from(n),
+            _ => buckets.push(Bucket {
+                upper_limit,
+                count: n.into(),
+            }),
+        }
+    }
+
+    let mut point = histogram_point(
+        &buckets,
+        sketch.count().into(),
+        sketch.sum().unwrap_or(0.0),
+        attributes,
+        time_unix_nano,
+    );
+    point.min = sketch.min();
+    point.max = sketch.max();
+    point
+}
+
+fn value_into_any_value(value: Value) -> AnyValue {
+    let value = match value {
+        Value::Bytes(bytes) => Some(PBValue::StringValue(bytes_into_string(bytes))),
+        Value::Regex(regex) => Some(PBValue::StringValue(regex.as_str().to_owned())),
+        Value::Integer(int) => Some(PBValue::IntValue(int)),
+        Value::Float(float) => Some(PBValue::DoubleValue(float.into_inner())),
+        Value::Boolean(boolean) => Some(PBValue::BoolValue(boolean)),
+        Value::Timestamp(timestamp) => Some(PBValue::StringValue(timestamp.to_rfc3339())),
+        Value::Object(fields) => Some(PBValue::KvlistValue(KeyValueList {
+            values: object_into_kv_list(fields),
+        })),
+        Value::Array(values) => Some(PBValue::ArrayValue(ArrayValue {
+            values: values.into_iter().map(value_into_any_value).collect(),
+        })),
+        Value::Null => None,
+    };
+    AnyValue { value }
+}
+
+fn object_into_kv_list(fields: BTreeMap<String, Value>) -> Vec<KeyValue> {
+    fields
+        .into_iter()
+        .map(|(key, value)| KeyValue {
+            key,
+            value: Some(value_into_any_value(value)),
+        })
+        .collect()
+}
+
+fn bytes_into_string(bytes: Bytes) -> String {
+    String::from_utf8(bytes.to_vec())
+        .unwrap_or_else(|error| String::from_utf8_lossy(error.as_bytes()).into_owned())
+}
+
+fn take_resource(fields: &mut BTreeMap<String, Value>) -> Option<Resource> {
+    let attributes = take_object(fields, RESOURCE_KEY);
+    (!attributes.is_empty()).then(|| Resource {
+        attributes,
+        dropped_attributes_count: 0,
+    })
+}
+
+fn take_scope(fields: &mut BTreeMap<String, Value>) -> Option<InstrumentationScope> {
+    let mut scope = fields.remove(SCOPE_KEY)?.into_object()?;
+    Some(InstrumentationScope {
+        name: take_string(&mut scope, NAME_KEY),
+        version: take_string(&mut scope, VERSION_KEY),
+        attributes: take_object(&mut scope, ATTRIBUTES_KEY),
+        dropped_attributes_count: 0,
+    })
+}
+
+/// Takes the remaining fields as attributes, merged with the `attributes` field.
+fn take_attributes(mut fields: BTreeMap<String, Value>) -> Vec<KeyValue> {
+    let mut attributes = take_object(&mut fields, ATTRIBUTES_KEY);
+    fields.remove(log_schema().source_type_key());
+    attributes.extend(object_into_kv_list(fields));
+    attributes
+}
+
+fn take_object(fields: &mut BTreeMap<String, Value>, key: &str) -> Vec<KeyValue> {
+    match fields.remove(key) {
+        Some(Value::Object(object)) => object_into_kv_list(object),
+        _ => Vec::new(),
+    }
+}
+
+fn take_array(fields: &mut BTreeMap<String, Value>, key: &str) -> impl Iterator<Item = Value> {
+    match fields.remove(key) {
+        Some(Value::Array(values)) => values.into_iter(),
+        _ => Vec::new().into_iter(),
+    }
+}
+
+fn take_string(fields: &mut BTreeMap<String, Value>, key: &str) -> String {
+    match fields.remove(key) {
+        Some(Value::Bytes(bytes)) => bytes_into_string(bytes),
+        Some(value) => value.to_string_lossy().into_owned(),
+        None => String::new(),
+    }
+}
+
+fn take_integer(fields: &mut BTreeMap<String, Value>, key: &str) -> i64 {
+    match fields.remove(key) {
+        Some(Value::Integer(int)) => int,
+        _ => 0,
+    }
+}
+
+/// Takes a timestamp as nanoseconds since the Unix epoch, where 0 means that it's unknown.
+fn take_timestamp(fields: &mut BTreeMap<String, Value>, key: &str) -> u64 {
+    match fields.remove(key) {
+        Some(Value::Timestamp(timestamp)) => timestamp.timestamp_nanos() as u64,
+        _ => 0,
+    }
+}
+
+/// Takes a hex-encoded trace or span ID, where an empty ID means that it's unknown.
+fn take_id(fields: &mut BTreeMap<String, Value>, key: &str) -> Vec<u8> {
+    match fields.remove(key) {
+        Some(Value::Bytes(bytes)) => hex::decode(bytes).unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use vector_core::event::{
+        metric::{MetricTags, MetricValue, StatisticKind},
+        Event,
+    };
+
+    use super::*;
+
+    fn string_value(value: &str) -> Option<AnyValue> {
+        Some(AnyValue {
+            value: Some(PBValue::StringValue(value.to_owned())),
+        })
+    }
+
+    fn key_value(key: &str, value: &str) -> KeyValue {
+        KeyValue {
+            key: key.to_owned(),
+            value: string_value(value),
+        }
+    }
+
+    #[test]
+    fn logs_round_trip() {
+        let request = ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: Some(Resource {
+                    attributes: vec![key_value("service.name", "vector")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_logs: vec![ScopeLogs {
+                    scope: Some(InstrumentationScope {
+                        name: "scope".to_owned(),
+                        version: "1.0".to_owned(),
+                        attributes: Vec::new(),
+                        dropped_attributes_count: 0,
+                    }),
+                    log_records: vec![LogRecord {
+                        time_unix_nano: 1,
+                        observed_time_unix_nano: 2,
+                        severity_number: 9,
+                        severity_text: "info".to_owned(),
+                        body: string_value("log body"),
+                        attributes: vec![key_value("attr_key", "attr_val")],
+                        dropped_attributes_count: 3,
+                        flags: 4,
+                        trace_id: hex::decode("4ac52aadf321c2e531db005df08792f5").unwrap(),
+                        span_id: hex::decode("0b9e4bda2a55530d").unwrap(),
+                    }],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        };
+
+        let logs = request
+            .clone()
+            .resource_logs
+            .into_iter()
+            .flat_map(|resource_logs| resource_logs.into_iter())
+            .map(Event::into_log);
+
+        assert_eq!(logs.collect::<ExportLogsServiceRequest>(), request);
+    }
+
+    #[test]
+    fn logs_extra_fields_are_attributes() {
+        let mut log = LogEvent::from("foo");
+        log.insert("host", "localhost");
+        log.insert("attributes.attr_key", "attr_val");
+        log.insert("source_type", "file");
+
+        let request = std::iter::once(log).collect::<ExportLogsServiceRequest>();
+        let record = &request.resource_logs[0].scope_logs[0].log_records[0];
+
+        assert_eq!(record.body, string_value("foo"));
+        assert_eq!(
+            record.attributes,
+            vec![
+                key_value("attr_key", "attr_val"),
+                key_value("host", "localhost")
+            ]
+        );
+    }
+
+    #[test]
+    fn traces_round_trip() {
+        let request = ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: Some(Resource {
+                    attributes: vec![key_value("service.name", "vector")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_spans: vec![ScopeSpans {
+                    scope: None,
+                    spans: vec![Span {
+                        trace_id: hex::decode("4ac52aadf321c2e531db005df08792f5").unwrap(),
+                        span_id: hex::decode("0b9e4bda2a55530d").unwrap(),
+                        trace_state: "rojo=00f067aa0ba902b7".to_owned(),
+                        parent_span_id: hex::decode("8b9e4bda2a55530e").unwrap(),
+                        name: "GET /".to_owned(),
+                        kind: 2,
+                        start_time_unix_nano: 1,
+                        end_time_unix_nano: 2,
+                        attributes: vec![key_value("attr_key", "attr_val")],
+                        dropped_attributes_count: 1,
+                        events: vec![SpanEvent {
+                            time_unix_nano: 1,
+                            name: "event".to_owned(),
+                            attributes: vec![key_value("event_key", "event_val")],
+                            dropped_attributes_count: 0,
+                        }],
+                        dropped_events_count: 2,
+                        links: vec![Link {
+                            trace_id: hex::decode("5ac52aadf321c2e531db005df08792f5").unwrap(),
+                            span_id: hex::decode("1b9e4bda2a55530d").unwrap(),
+                            trace_state: String::new(),
+                            attributes: Vec::new(),
+                            dropped_attributes_count: 0,
+                        }],
+                        dropped_links_count: 3,
+                        status: Some(Status {
+                            message: "error".to_owned(),
+                            code: 2,
+                        }),
+                    }],
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        };
+
+        let traces = request
+            .clone()
+            .resource_spans
+            .into_iter()
+            .flat_map(|resource_spans| resource_spans.into_iter())
+            .map(|event| event.into_trace());
+
+        assert_eq!(traces.collect::<ExportTraceServiceRequest>(), request);
+    }
+
+    #[test]
+    fn metrics_into_request() {
+        let tags = MetricTags::from([
+            ("attr_key".to_owned(), "attr_val".to_owned()),
+            ("resource.service.name".to_owned(), "vector".to_owned()),
+        ]);
+        let timestamp = Utc.timestamp_nanos(1);
+        let metrics = vec![
+            Metric::new(
+                "requests",
+                MetricKind::Incremental,
+                MetricValue::Counter { value: 5.0 },
+            ),
+            Metric::new(
+                "latency",
+                MetricKind::Absolute,
+                MetricValue::AggregatedHistogram {
+                    buckets: vec![
+                        Bucket {
+                            upper_limit: 1.0,
+                            count: 1,
+                        },
+                        Bucket {
+                            upper_limit: 5.0,
+                            count: 2,
+                        },
+                    ],
+                    count: 6,
+                    sum: 12.0,
+                },
+            ),
+            Metric::new(
+                "samples",
+                MetricKind::Incremental,
+                MetricValue::Distribution {
+                    samples: vector_core::samples![1.0 => 2, 10.0 => 1],
+                    statistic: StatisticKind::Histogram,
+                },
+            ),
+        ]
+        .into_iter()
+        .map(|metric| {
+            metric
+                .with_timestamp(Some(timestamp))
+                .with_tags(Some(tags.clone()))
+        });
+
+        let request = metrics.collect::<ExportMetricsServiceRequest>();
+
+        assert_eq!(request.resource_metrics.len(), 1);
+        let resource_metrics = &request.resource_metrics[0];
+        assert_eq!(
+            resource_metrics.resource,
+            Some(Resource {
+                attributes: vec![key_value("service.name", "vector")],
+                dropped_attributes_count: 0,
+            })
+        );
+
+        let metrics = &resource_metrics.scope_metrics[0].metrics;
+        assert_eq!(metrics.len(), 3);
+        assert_eq!(
+            metrics[0].data,
+            Some(Data::Sum(Sum {
+                data_points: vec![NumberDataPoint {
+                    attributes: vec![key_value("attr_key", "attr_val")],
+                    start_time_unix_nano: 0,
+                    time_unix_nano: 1,
+                    exemplars: Vec::new(),
+                    flags: 0,
+                    value: Some(NumberValue::AsDouble(5.0)),
+                }],
+                aggregation_temporality: AggregationTemporality::Delta as i32,
+                is_monotonic: true,
+            }))
+        );
+        match &metrics[1].data {
+            Some(Data::Histogram(histogram)) => {
+                let point = &histogram.data_points[0];
+                assert_eq!(point.explicit_bounds, vec![1.0, 5.0]);
+                assert_eq!(point.bucket_counts, vec![1, 2, 3]);
+                assert_eq!(
+                    histogram.aggregation_temporality,
+                    AggregationTemporality::Cumulative as i32
+                );
+            }
+            data => panic!("unexpected metric data {:?}", data),
+        }
+        match &metrics[2].data {
+            Some(Data::Histogram(histogram)) => {
+                let point = &histogram.data_points[0];
+                assert_eq!(point.count, 3);
+                assert_eq!(point.sum, Some(12.0));
+                assert_eq!(point.min, Some(1.0));
+                assert_eq!(point.max, Some(10.0));
+                assert_eq!(point.explicit_bounds.len(), 2);
+                assert!(point.explicit_bounds[0] >= 1.0 && point.explicit_bounds[0] < 1.1);
+                assert!(point.explicit_bounds[1] > 10.0 && point.explicit_bounds[1] < 10.5);
+                assert_eq!(point.bucket_counts, vec![2, 1, 0]);
+                assert_eq!(
+                    histogram.aggregation_temporality,
+                    AggregationTemporality::Delta as i32
+                );
+            }
+            data => panic!("unexpected metric data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn sketch_bins_into_histogram() {
+        let mut sketch = AgentDDSketch::with_agent_defaults();
+        sketch.insert_many(&[-5.0, 0.0, 0.0, 3.0, 3.0, 3.0]);
+        let metric = Metric::new(
+            "sketch",
+            MetricKind::Incremental,
+            MetricValue::Sketch {
+                sketch: MetricSketch::AgentDDSketch(sketch),
+            },
+        );
+
+        match metric_into_proto(&metric, Vec::new()).data {
+            Some(Data::Histogram(histogram)) => {
+                let point = &histogram.data_points[0];
+                assert_eq!(point.count, 6);
+                assert_eq!(point.bucket_counts, vec![1, 2, 3, 0]);
+                let bounds = &point.explicit_bounds;
+                assert!((bounds[0] + 5.0).abs() < 0.1);
+                assert!(bounds[1] > 0.0 && bounds[1] < 1.0);
+                assert!(bounds[2] > 3.0 && bounds[2] < 3.1);
+            }
+            data => panic!("unexpected metric data {:?}", data),
+        }
+    }
+}
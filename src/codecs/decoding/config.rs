@@ -2,7 +2,7 @@ use codecs::decoding::{DeserializerConfig, FramingConfig};
 use serde::{Deserialize, Serialize};
 use vector_core::config::LogNamespace;
 
-use crate::codecs::Decoder;
+use crate::codecs::{Decoder, DecompressionConfig};
 
 /// Config used to build a `Decoder`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -13,6 +13,8 @@ pub struct DecodingConfig {
     decoding: DeserializerConfig,
     /// The namespace used when decoding.
     log_namespace: LogNamespace,
+    /// The decompression applied to the received data before framing.
+    decompression: DecompressionConfig,
 }
 
 impl DecodingConfig {
@@ -27,17 +29,26 @@ impl DecodingConfig {
             framing,
             decoding,
             log_namespace,
+            decompression: DecompressionConfig::None,
         }
     }
 
+    /// Sets the decompression applied to the received data before framing.
+    pub const fn with_decompression(mut self, decompression: DecompressionConfig) -> Self {
+        self.decompression = decompression;
+        self
+    }
+
     /// Builds a `Decoder` from the provided configuration.
-    pub fn build(&self) -> Decoder {
+    pub fn build(&self) -> crate::Result<Decoder> {
         // Build the framer.
         let framer = self.framing.build();
 
         // Build the deserializer.
-        let deserializer = self.decoding.build();
+        let deserializer = self.decoding.build()?;
 
-        Decoder::new(framer, deserializer).with_log_namespace(self.log_namespace)
+        Ok(Decoder::new(framer, deserializer)
+            .with_log_namespace(self.log_namespace)
+            .with_decompression(self.decompression))
     }
 }
@@ -6,9 +6,11 @@ use codecs::decoding::{
 use smallvec::SmallVec;
 use vector_core::config::LogNamespace;
 
+use super::Decompressor;
 use crate::{
+    codecs::DecompressionConfig,
     event::Event,
-    internal_events::{DecoderDeserializeError, DecoderFramingError},
+    internal_events::{DecoderDecompressionError, DecoderDeserializeError, DecoderFramingError},
 };
 
 /// A decoder that can decode structured events from a byte stream / byte
@@ -18,6 +20,7 @@ pub struct Decoder {
     framer: Framer,
     deserializer: Deserializer,
     log_namespace: LogNamespace,
+    decompressor: Option<Decompressor>,
 }
 
 impl Default for Decoder {
@@ -26,6 +29,7 @@ impl Default for Decoder {
             framer: Framer::NewlineDelimited(NewlineDelimitedDecoder::new()),
             deserializer: Deserializer::Bytes(BytesDeserializer::new()),
             log_namespace: LogNamespace::Legacy,
+            decompressor: None,
         }
     }
 }
@@ -39,6 +43,7 @@ impl Decoder {
             framer,
             deserializer,
             log_namespace: LogNamespace::Legacy,
+            decompressor: None,
         }
     }
 
@@ -48,6 +53,45 @@ impl Decoder {
         self
     }
 
+    /// Sets the decompression that is applied to the byte stream / byte
+    /// messages before they are framed.
+    pub fn with_decompression(mut self, decompression: DecompressionConfig) -> Self {
+        self.decompressor = decompression.build();
+        self
+    }
+
+    /// Produces the next frame from the byte stream / byte messages,
+    /// decompressing them first if configured.
+    fn decode_frame(
+        &mut self,
+        buf: &mut BytesMut,
+        eof: bool,
+    ) -> Result<Option<Bytes>, BoxedFramingError> {
+        let buf = match self.decompressor.as_mut() {
+            Some(decompressor) => {
+                let compression = decompressor.compression();
+                match decompressor.decompress(buf, eof) {
+                    Ok(Some(buf)) => buf,
+                    Ok(None) => return Ok(None),
+                    Err(error) => {
+                        emit!(DecoderDecompressionError {
+                            error: &error,
+                            compression,
+                        });
+                        return Err(error.into());
+                    }
+                }
+            }
+            None => buf,
+        };
+
+        if eof {
+            self.framer.decode_eof(buf)
+        } else {
+            self.framer.decode(buf)
+        }
+    }
+
     /// Handles the framing result and parses it into a structured event, if
     /// possible.
     ///
@@ -83,26 +127,33 @@ impl tokio_util::codec::Decoder for Decoder {
     type Error = Error;
 
     fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        let frame = self.framer.decode(buf);
+        let frame = self.decode_frame(buf, false);
         self.handle_framing_result(frame)
     }
 
     fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        let frame = self.framer.decode_eof(buf);
+        let frame = self.decode_frame(buf, true);
         self.handle_framing_result(frame)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::Write;
+
     use super::Decoder;
-    use bytes::Bytes;
+    use crate::codecs::DecompressionConfig;
+    use bytes::{Bytes, BytesMut};
     use codecs::{
         decoding::{Deserializer, Framer},
         JsonDeserializer, NewlineDelimitedDecoder, StreamDecodingError,
     };
+    use flate2::{write::GzEncoder, Compression};
     use futures::{stream, StreamExt};
-    use tokio_util::{codec::FramedRead, io::StreamReader};
+    use tokio_util::{
+        codec::{Decoder as _, FramedRead},
+        io::StreamReader,
+    };
     use value::Value;
 
     #[tokio::test]
@@ -132,4 +183,46 @@ mod tests {
         let event = next.unwrap().0.pop().unwrap().into_log();
         assert_eq!(event.get("bar").unwrap(), &Value::from(2));
     }
+
+    #[test]
+    fn decode_decompressed_frames() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(b"{ \"foo\": 1 }\n{ \"bar\": 2 }")
+            .unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let decoder = Decoder::new(
+            Framer::NewlineDelimited(NewlineDelimitedDecoder::new()),
+            Deserializer::Json(JsonDeserializer::new()),
+        )
+        .with_decompression(DecompressionConfig::Auto);
+
+        // Compressed data is framed as it is decompressed.
+        let mut buf = BytesMut::from(compressed.as_slice());
+        let mut gzip_decoder = decoder.clone();
+        let (mut events, byte_size) = gzip_decoder.decode(&mut buf).unwrap().unwrap();
+        assert_eq!(byte_size, 12);
+        let event = events.pop().unwrap().into_log();
+        assert_eq!(event.get("foo").unwrap(), &Value::from(1));
+        assert!(gzip_decoder.decode(&mut buf).unwrap().is_none());
+
+        let (mut events, _) = gzip_decoder.decode_eof(&mut buf).unwrap().unwrap();
+        let event = events.pop().unwrap().into_log();
+        assert_eq!(event.get("bar").unwrap(), &Value::from(2));
+        assert!(gzip_decoder.decode_eof(&mut buf).unwrap().is_none());
+
+        // Uncompressed data is framed as it arrives.
+        let mut buf = BytesMut::from("{ \"baz\": 3 }\n{");
+        let mut plain_decoder = decoder;
+        let (mut events, _) = plain_decoder.decode(&mut buf).unwrap().unwrap();
+        let event = events.pop().unwrap().into_log();
+        assert_eq!(event.get("baz").unwrap(), &Value::from(3));
+        assert!(plain_decoder.decode(&mut buf).unwrap().is_none());
+
+        let mut zstd_decoder = Decoder::default().with_decompression(DecompressionConfig::Zstd);
+        let mut buf = BytesMut::from(compressed.as_slice());
+        let error = zstd_decoder.decode_eof(&mut buf).unwrap_err();
+        assert!(!error.can_continue());
+    }
 }
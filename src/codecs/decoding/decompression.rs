@@ -0,0 +1,493 @@
+use std::io::{self, Write};
+
+use bytes::BytesMut;
+use flate2::write::GzDecoder;
+use vector_config::configurable_component;
+use zstd::stream::raw::Operation;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// The maximum size of the decompressed data waiting to be framed, which bounds the memory used
+/// by highly compressed data.
+const MAX_DECOMPRESSED_SIZE: usize = 100 * 1024 * 1024;
+
+/// The size of the chunks data is decompressed into with Zstandard.
+const ZSTD_CHUNK_SIZE: usize = 32 * 1024;
+
+/// Decompression to apply to the received data before it is framed and decoded.
+///
+/// Compressed data is decompressed as it is received, and is framed as it is decompressed. The
+/// decompressed data waiting to be framed is limited to 100 MiB, so an input whose frames exceed
+/// that once decompressed is rejected with an error.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DecompressionConfig {
+    /// Data is not decompressed.
+    None,
+
+    /// Data is decompressed with [Gzip][gzip].
+    ///
+    /// [gzip]: https://www.gzip.org/
+    Gzip,
+
+    /// Data is decompressed with [Zstandard][zstd].
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+
+    /// The compression is detected from the magic bytes at the start of the data.
+    ///
+    /// Data starting with the Gzip or Zstandard magic bytes is decompressed accordingly, and any
+    /// other data is passed through unchanged.
+    Auto,
+}
+
+impl Default for DecompressionConfig {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl DecompressionConfig {
+    /// Builds a `Decompressor` for this configuration, or `None` if no
+    /// decompression is applied.
+    pub fn build(&self) -> Option<Decompressor> {
+        let state = match self {
+            Self::None => return None,
+            Self::Gzip => State::Compressed(Algorithm::Gzip),
+            Self::Zstd => State::Compressed(Algorithm::Zstd),
+            Self::Auto => State::Detecting,
+        };
+
+        Some(Decompressor {
+            config: *self,
+            state,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Algorithm {
+    Gzip,
+    Zstd,
+}
+
+impl Algorithm {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+        }
+    }
+}
+
+/// Collects the decompressed data, up to `MAX_DECOMPRESSED_SIZE`.
+#[derive(Debug, Default)]
+struct Output(BytesMut);
+
+impl Write for Output {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.0.len() + data.len() > MAX_DECOMPRESSED_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "decompressed data exceeds the maximum size of {} bytes",
+                    MAX_DECOMPRESSED_SIZE
+                ),
+            ));
+        }
+        self.0.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Decompresses data as it is received.
+enum StreamDecoder {
+    Gzip {
+        decoder: Box<GzDecoder<Output>>,
+        /// Whether any data of the current gzip member has been received.
+        started: bool,
+    },
+    Zstd {
+        decoder: Box<zstd::stream::raw::Decoder<'static>>,
+        output: Output,
+        /// Whether the data received so far ends with a complete frame.
+        complete: bool,
+    },
+}
+
+impl StreamDecoder {
+    fn new(algorithm: Algorithm) -> io::Result<Self> {
+        Ok(match algorithm {
+            Algorithm::Gzip => Self::Gzip {
+                decoder: Box::new(GzDecoder::new(Output::default())),
+                started: false,
+            },
+            Algorithm::Zstd => Self::Zstd {
+                decoder: Box::new(zstd::stream::raw::Decoder::new()?),
+                output: Output::default(),
+                complete: true,
+            },
+        })
+    }
+
+    fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Gzip { .. } => Algorithm::Gzip,
+            Self::Zstd { .. } => Algorithm::Zstd,
+        }
+    }
+
+    fn output(&mut self) -> &mut BytesMut {
+        match self {
+            Self::Gzip { decoder, .. } => &mut decoder.get_mut().0,
+            Self::Zstd { output, .. } => &mut output.0,
+        }
+    }
+
+    fn write(&mut self, mut data: &[u8]) -> io::Result<()> {
+        match self {
+            Self::Gzip { decoder, started } => {
+                while !data.is_empty() {
+                    *started = true;
+                    let written = decoder.write(data)?;
+                    if written == 0 {
+                        // The data continues with another gzip member, which is decompressed by a
+                        // new decoder.
+                        next_gzip_member(decoder)?;
+                        *started = false;
+                    }
+                    data = &data[written..];
+                }
+                decoder.flush()
+            }
+            Self::Zstd {
+                decoder,
+                output,
+                complete,
+            } => {
+                let mut chunk = vec![0; ZSTD_CHUNK_SIZE];
+                // Keeps going while the chunk is filled, as more data may be pending.
+                let mut filled = true;
+                while !data.is_empty() || filled {
+                    let status = decoder.run_on_buffers(data, &mut chunk)?;
+                    output.write_all(&chunk[..status.bytes_written])?;
+                    if status.bytes_read > 0 || status.bytes_written > 0 {
+                        *complete = status.remaining == 0;
+                    }
+                    data = &data[status.bytes_read..];
+                    filled = status.bytes_written == chunk.len();
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks that the data received ends with complete compressed data.
+    fn finish(&mut self) -> io::Result<()> {
+        match self {
+            Self::Gzip { decoder, started } => {
+                if *started {
+                    decoder.try_finish()?;
+                    *started = false;
+                }
+                Ok(())
+            }
+            Self::Zstd { complete, .. } => {
+                if *complete {
+                    Ok(())
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "incomplete zstd frame",
+                    ))
+                }
+            }
+        }
+    }
+}
+
+fn next_gzip_member(decoder: &mut Box<GzDecoder<Output>>) -> io::Result<()> {
+    let finished = std::mem::replace(decoder, Box::new(GzDecoder::new(Output::default())));
+    **decoder = GzDecoder::new(finished.finish()?);
+    Ok(())
+}
+
+enum State {
+    /// Waiting for enough data to detect the compression from its magic bytes.
+    Detecting,
+    /// The data is not compressed and can be framed as it arrives.
+    Passthrough,
+    /// The data is compressed, with no data received yet.
+    Compressed(Algorithm),
+    /// The data is decompressed as it arrives, and framed from the decompressed data.
+    Decompressing(StreamDecoder),
+}
+
+impl std::fmt::Debug for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Detecting => write!(f, "Detecting"),
+            Self::Passthrough => write!(f, "Passthrough"),
+            Self::Compressed(algorithm) => write!(f, "Compressed({:?})", algorithm),
+            Self::Decompressing(decoder) => write!(f, "Decompressing({:?})", decoder.algorithm()),
+        }
+    }
+}
+
+/// Decompresses the data received by a `Decoder` before it is framed.
+///
+/// A decompressor holds the state of a single input, so cloning one yields a
+/// decompressor for a fresh input.
+#[derive(Debug)]
+pub struct Decompressor {
+    config: DecompressionConfig,
+    state: State,
+}
+
+impl Clone for Decompressor {
+    fn clone(&self) -> Self {
+        self.config
+            .build()
+            .expect("decompressor is only built for a decompression config")
+    }
+}
+
+impl Decompressor {
+    /// The name of the compression that is being decompressed, or of the
+    /// configured decompression while it is still unknown.
+    pub fn compression(&self) -> &'static str {
+        match (&self.state, self.config) {
+            (State::Compressed(algorithm), _) => algorithm.as_str(),
+            (State::Decompressing(decoder), _) => decoder.algorithm().as_str(),
+            (_, DecompressionConfig::Gzip) => "gzip",
+            (_, DecompressionConfig::Zstd) => "zstd",
+            (_, DecompressionConfig::Auto) => "auto",
+            (_, DecompressionConfig::None) => "none",
+        }
+    }
+
+    /// Consumes the received data and returns the buffer that frames should be
+    /// decoded from, or `None` if more data is needed first.
+    pub fn decompress<'a>(
+        &'a mut self,
+        buf: &'a mut BytesMut,
+        eof: bool,
+    ) -> Result<Option<&'a mut BytesMut>, io::Error> {
+        if let State::Detecting = self.state {
+            if buf.len() < ZSTD_MAGIC.len() && !eof {
+                return Ok(None);
+            }
+            self.state = if buf.starts_with(GZIP_MAGIC) {
+                State::Compressed(Algorithm::Gzip)
+            } else if buf.starts_with(ZSTD_MAGIC) {
+                State::Compressed(Algorithm::Zstd)
+            } else {
+                State::Passthrough
+            };
+        }
+
+        if let State::Compressed(algorithm) = self.state {
+            self.state = State::Decompressing(StreamDecoder::new(algorithm)?);
+        }
+
+        match &mut self.state {
+            State::Passthrough => Ok(Some(buf)),
+            State::Decompressing(decoder) => {
+                decoder.write(&buf.split())?;
+                if eof {
+                    decoder.finish()?;
+                }
+                Ok(Some(decoder.output()))
+            }
+            State::Detecting | State::Compressed(_) => unreachable!("state resolved above"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    fn gzip(data: &[u8]) -> BytesMut {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap().as_slice().into()
+    }
+
+    fn zstd(data: &[u8]) -> BytesMut {
+        zstd::stream::encode_all(data, 0).unwrap().as_slice().into()
+    }
+
+    fn decompress(config: DecompressionConfig, mut data: BytesMut) -> Option<BytesMut> {
+        let mut decompressor = config.build().unwrap();
+        let mut decompressed = decompressor
+            .decompress(&mut data, false)
+            .unwrap()
+            .map(|buf| buf.split())
+            .unwrap_or_default();
+        decompressed.unsplit(
+            decompressor
+                .decompress(&mut BytesMut::new(), true)
+                .unwrap()?
+                .split(),
+        );
+        Some(decompressed)
+    }
+
+    #[test]
+    fn decompress_none() {
+        assert!(DecompressionConfig::None.build().is_none());
+    }
+
+    #[test]
+    fn decompress_gzip() {
+        assert_eq!(
+            decompress(DecompressionConfig::Gzip, gzip(b"foo\nbar")).unwrap(),
+            "foo\nbar"
+        );
+
+        // Concatenated members are decompressed one after the other.
+        let mut data = gzip(b"foo\n");
+        data.unsplit(gzip(b"bar"));
+        assert_eq!(
+            decompress(DecompressionConfig::Gzip, data).unwrap(),
+            "foo\nbar"
+        );
+
+        let mut decompressor = DecompressionConfig::Gzip.build().unwrap();
+        assert!(decompressor
+            .decompress(&mut BytesMut::from("foo"), true)
+            .is_err());
+
+        let mut data = gzip(b"foo");
+        data.truncate(data.len() - 1);
+        let mut decompressor = DecompressionConfig::Gzip.build().unwrap();
+        assert!(decompressor.decompress(&mut data, true).is_err());
+    }
+
+    #[test]
+    fn decompress_zstd() {
+        assert_eq!(
+            decompress(DecompressionConfig::Zstd, zstd(b"foo\nbar")).unwrap(),
+            "foo\nbar"
+        );
+
+        // Concatenated frames are decompressed one after the other.
+        let mut data = zstd(b"foo\n");
+        data.unsplit(zstd(b"bar"));
+        assert_eq!(
+            decompress(DecompressionConfig::Zstd, data).unwrap(),
+            "foo\nbar"
+        );
+
+        let mut decompressor = DecompressionConfig::Zstd.build().unwrap();
+        assert!(decompressor
+            .decompress(&mut BytesMut::from("foo"), true)
+            .is_err());
+
+        let mut data = zstd(b"foo");
+        data.truncate(data.len() - 1);
+        let mut decompressor = DecompressionConfig::Zstd.build().unwrap();
+        assert!(decompressor.decompress(&mut data, true).is_err());
+    }
+
+    #[test]
+    fn decompress_streams() {
+        let data = (0..10_000)
+            .map(|i| format!("line {}\n", i))
+            .collect::<String>();
+        for (config, mut compressed) in [
+            (DecompressionConfig::Gzip, gzip(data.as_bytes())),
+            (DecompressionConfig::Zstd, zstd(data.as_bytes())),
+        ] {
+            // The data is decompressed as it is received, before the end of the input.
+            let mut decompressor = config.build().unwrap();
+            let mut decompressed = BytesMut::new();
+            while !compressed.is_empty() {
+                let mut chunk = compressed.split_to(compressed.len().min(100));
+                decompressed.unsplit(
+                    decompressor
+                        .decompress(&mut chunk, false)
+                        .unwrap()
+                        .unwrap()
+                        .split(),
+                );
+            }
+            assert_eq!(decompressed, data.as_bytes());
+        }
+    }
+
+    #[test]
+    fn decompress_limits_size() {
+        let data = vec![0; MAX_DECOMPRESSED_SIZE + 1];
+        let mut decompressor = DecompressionConfig::Zstd.build().unwrap();
+        assert!(decompressor.decompress(&mut zstd(&data), false).is_err());
+
+        // The limit only applies to the decompressed data that hasn't been consumed yet.
+        let mut decompressor = DecompressionConfig::Zstd.build().unwrap();
+        let mut compressed = zstd(&data);
+        while !compressed.is_empty() {
+            let mut chunk = compressed.split_to(compressed.len().min(256));
+            decompressor
+                .decompress(&mut chunk, false)
+                .unwrap()
+                .unwrap()
+                .clear();
+        }
+        decompressor.decompress(&mut BytesMut::new(), true).unwrap();
+    }
+
+    #[test]
+    fn decompress_auto() {
+        assert_eq!(
+            decompress(DecompressionConfig::Auto, gzip(b"foo")).unwrap(),
+            "foo"
+        );
+        assert_eq!(
+            decompress(DecompressionConfig::Auto, zstd(b"bar")).unwrap(),
+            "bar"
+        );
+
+        // Uncompressed data is passed through as soon as it is detected.
+        let mut decompressor = DecompressionConfig::Auto.build().unwrap();
+        let mut buf = BytesMut::from("ba");
+        assert!(decompressor.decompress(&mut buf, false).unwrap().is_none());
+        buf.extend_from_slice(b"z\n");
+        assert_eq!(
+            decompressor.decompress(&mut buf, false).unwrap().unwrap(),
+            "baz\n"
+        );
+        assert_eq!(decompressor.compression(), "auto");
+
+        // Short inputs are detected at the end of the input.
+        let mut decompressor = DecompressionConfig::Auto.build().unwrap();
+        assert_eq!(
+            decompressor
+                .decompress(&mut BytesMut::from("a"), true)
+                .unwrap()
+                .unwrap(),
+            "a"
+        );
+    }
+
+    #[test]
+    fn clone_resets_state() {
+        let mut decompressor = DecompressionConfig::Auto.build().unwrap();
+        let mut buf = gzip(b"foo");
+        buf.truncate(buf.len() - 1);
+        decompressor.decompress(&mut buf, false).unwrap();
+        assert_eq!(decompressor.compression(), "gzip");
+        assert_eq!(decompressor.clone().compression(), "auto");
+    }
+}
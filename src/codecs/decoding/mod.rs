@@ -1,5 +1,7 @@
 mod config;
 mod decoder;
+mod decompression;
 
 pub use config::DecodingConfig;
 pub use decoder::Decoder;
+pub use decompression::{DecompressionConfig, Decompressor};
@@ -103,15 +103,19 @@ impl EncodingConfigWithFraming {
                 SinkType::StreamBased => NewlineDelimitedEncoder::new().into(),
                 SinkType::MessageBased => CharacterDelimitedEncoder::new(b',').into(),
             },
-            (None, Serializer::Avro(_) | Serializer::Native(_)) => {
+            (None, Serializer::Avro(_) | Serializer::Native(_) | Serializer::Protobuf(_)) => {
                 LengthDelimitedEncoder::new().into()
             }
             (
                 None,
-                Serializer::Gelf(_)
+                Serializer::Cef(_)
+                | Serializer::Csv(_)
+                | Serializer::Gelf(_)
+                | Serializer::Leef(_)
                 | Serializer::Logfmt(_)
                 | Serializer::NativeJson(_)
                 | Serializer::RawMessage(_)
+                | Serializer::Syslog(_)
                 | Serializer::Text(_),
             ) => NewlineDelimitedEncoder::new().into(),
         };
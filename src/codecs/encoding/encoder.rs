@@ -82,16 +82,32 @@ impl Encoder<Framer> {
     }
 
     /// Get the prefix that encloses a batch of events.
-    pub const fn batch_prefix(&self) -> &[u8] {
+    pub fn batch_prefix(&self) -> Vec<u8> {
         match (&self.framer, &self.serializer) {
             (
                 Framer::CharacterDelimited(CharacterDelimitedEncoder { delimiter: b',' }),
                 Serializer::Json(_) | Serializer::NativeJson(_),
-            ) => b"[",
-            _ => &[],
+            ) => b"[".to_vec(),
+            _ => self
+                .header()
+                .map(|header| header.to_vec())
+                .unwrap_or_default(),
         }
     }
 
+    /// Get the framed header that starts a new output, such as an object or a
+    /// file, if the serializer writes one.
+    pub fn header(&self) -> Option<BytesMut> {
+        let mut header = match &self.serializer {
+            Serializer::Csv(serializer) => serializer.header()?,
+            _ => return None,
+        };
+        // Framing only fails for frames exceeding the maximum length, which
+        // headers don't.
+        self.framer.clone().encode((), &mut header).ok()?;
+        Some(header)
+    }
+
     /// Get the suffix that encloses a batch of events.
     pub const fn batch_suffix(&self) -> &[u8] {
         match (&self.framer, &self.serializer) {
@@ -114,13 +130,18 @@ impl Encoder<Framer> {
                 Framer::CharacterDelimited(CharacterDelimitedEncoder { delimiter: b',' }),
             ) => "application/json",
             (Serializer::Native(_), _) => "application/octet-stream",
+            (Serializer::Protobuf(_), _) => "application/x-protobuf",
+            (Serializer::Csv(_), _) => "text/csv",
             (
                 Serializer::Avro(_)
+                | Serializer::Cef(_)
                 | Serializer::Gelf(_)
                 | Serializer::Json(_)
+                | Serializer::Leef(_)
                 | Serializer::Logfmt(_)
                 | Serializer::NativeJson(_)
                 | Serializer::RawMessage(_)
+                | Serializer::Syslog(_)
                 | Serializer::Text(_),
                 _,
             ) => "text/plain",
@@ -183,7 +204,7 @@ impl tokio_util::codec::Encoder<Event> for Encoder<()> {
 #[cfg(test)]
 mod tests {
     use bytes::BufMut;
-    use codecs::{encoding::BoxedFramingError, TextSerializer};
+    use codecs::{encoding::BoxedFramingError, OctetCountingEncoder, TextSerializer};
     use futures_util::{SinkExt, StreamExt};
     use tokio_util::codec::FramedWrite;
     use vector_core::event::LogEvent;
@@ -321,4 +342,22 @@ mod tests {
         let sink = framed.into_inner();
         assert_eq!(sink, b"(foo)(bar)");
     }
+
+    #[tokio::test]
+    async fn test_encode_events_octet_counting() {
+        let encoder = Encoder::<Framer>::new(
+            OctetCountingEncoder::new().into(),
+            TextSerializer::new().into(),
+        );
+        let source = futures::stream::iter(vec![
+            Event::Log(LogEvent::from("foo\nbar")),
+            Event::Log(LogEvent::from("baz")),
+        ])
+        .map(Ok);
+        let sink = Vec::new();
+        let mut framed = FramedWrite::new(sink, encoder);
+        source.forward(&mut framed).await.unwrap();
+        let sink = framed.into_inner();
+        assert_eq!(sink, b"7 foo\nbar3 baz");
+    }
 }
@@ -52,6 +52,31 @@ impl<'a> InternalEvent for DecoderDeserializeError<'a> {
     }
 }
 
+#[derive(Debug)]
+pub struct DecoderDecompressionError<'a> {
+    pub error: &'a std::io::Error,
+    pub compression: &'static str,
+}
+
+impl<'a> InternalEvent for DecoderDecompressionError<'a> {
+    fn emit(self) {
+        counter!("decoder_decompression_errors_total", 1);
+        error!(
+            message = "Failed decompressing frame.",
+            error = %self.error,
+            compression = %self.compression,
+            error_type = error_type::PARSER_FAILED,
+            stage = error_stage::PROCESSING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::PARSER_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
+
 #[derive(Debug)]
 pub struct EncoderFramingError<'a> {
     pub error: &'a codecs::encoding::BoxedFramingError,
@@ -0,0 +1,16 @@
+use crate::emit;
+use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct JoinEventsUnmatched {
+    pub count: usize,
+}
+
+impl InternalEvent for JoinEventsUnmatched {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: "No matching event received within the join window.",
+        });
+    }
+}
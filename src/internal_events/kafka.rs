@@ -85,6 +85,30 @@ impl InternalEvent for KafkaOffsetUpdateError {
     }
 }
 
+#[derive(Debug)]
+pub struct KafkaPauseResumeError {
+    pub error: rdkafka::error::KafkaError,
+}
+
+impl InternalEvent for KafkaPauseResumeError {
+    fn emit(self) {
+        error!(
+            message = "Unable to pause or resume partitions.",
+            error = %self.error,
+            error_code = "kafka_pause_resume",
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "kafka_pause_resume",
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
 #[derive(Debug)]
 pub struct KafkaReadError {
     pub error: rdkafka::error::KafkaError,
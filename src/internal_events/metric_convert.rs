@@ -0,0 +1,16 @@
+use crate::emit;
+use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct MetricConvertMetricDropped {
+    pub reason: &'static str,
+}
+
+impl InternalEvent for MetricConvertMetricDropped {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: 1,
+            reason: self.reason,
+        });
+    }
+}
@@ -69,6 +69,8 @@ mod influxdb;
 mod internal_logs;
 #[cfg(feature = "sources-internal_metrics")]
 mod internal_metrics;
+#[cfg(feature = "transforms-join")]
+mod join;
 #[cfg(all(unix, feature = "sources-journald"))]
 mod journald;
 #[cfg(any(feature = "sources-kafka", feature = "sinks-kafka"))]
@@ -81,11 +83,15 @@ mod logplex;
 mod loki;
 #[cfg(feature = "transforms-lua")]
 mod lua;
+#[cfg(feature = "transforms-metric_convert")]
+mod metric_convert;
 #[cfg(feature = "transforms-metric_to_log")]
 mod metric_to_log;
 #[cfg(feature = "sources-mongodb_metrics")]
 mod mongodb_metrics;
-#[cfg(feature = "sinks-nats")]
+#[cfg(any(feature = "sources-mqtt", feature = "sinks-mqtt"))]
+mod mqtt;
+#[cfg(any(feature = "sources-nats", feature = "sinks-nats"))]
 mod nats;
 #[cfg(feature = "sources-nginx_metrics")]
 mod nginx_metrics;
@@ -109,6 +115,8 @@ mod sematext_metrics;
 mod socket;
 #[cfg(any(feature = "sources-splunk_hec", feature = "sinks-splunk_hec"))]
 mod splunk_hec;
+#[cfg(feature = "sources-sql")]
+mod sql;
 #[cfg(feature = "sinks-statsd")]
 mod statsd_sink;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
@@ -117,6 +125,8 @@ mod tcp;
 mod template;
 #[cfg(feature = "transforms-throttle")]
 mod throttle;
+#[cfg(feature = "transforms-trace_sampler")]
+mod trace_sampler;
 mod udp;
 mod unix;
 #[cfg(feature = "sinks-websocket")]
@@ -208,6 +218,8 @@ pub(crate) use self::influxdb::*;
 pub(crate) use self::internal_logs::*;
 #[cfg(feature = "sources-internal_metrics")]
 pub(crate) use self::internal_metrics::*;
+#[cfg(feature = "transforms-join")]
+pub(crate) use self::join::*;
 #[cfg(all(unix, feature = "sources-journald"))]
 pub(crate) use self::journald::*;
 #[cfg(any(feature = "sources-kafka", feature = "sinks-kafka"))]
@@ -221,9 +233,13 @@ pub(crate) use self::logplex::*;
 pub(crate) use self::loki::*;
 #[cfg(feature = "transforms-lua")]
 pub(crate) use self::lua::*;
+#[cfg(feature = "transforms-metric_convert")]
+pub(crate) use self::metric_convert::*;
 #[cfg(feature = "transforms-metric_to_log")]
 pub(crate) use self::metric_to_log::*;
-#[cfg(feature = "sinks-nats")]
+#[cfg(any(feature = "sources-mqtt", feature = "sinks-mqtt"))]
+pub(crate) use self::mqtt::*;
+#[cfg(any(feature = "sources-nats", feature = "sinks-nats"))]
 pub(crate) use self::nats::*;
 #[cfg(feature = "sources-nginx_metrics")]
 pub(crate) use self::nginx_metrics::*;
@@ -246,12 +262,16 @@ pub(crate) use self::sample::*;
 pub(crate) use self::sematext_metrics::*;
 #[cfg(any(feature = "sources-splunk_hec", feature = "sinks-splunk_hec"))]
 pub(crate) use self::splunk_hec::*;
+#[cfg(feature = "sources-sql")]
+pub(crate) use self::sql::*;
 #[cfg(feature = "sinks-statsd")]
 pub(crate) use self::statsd_sink::*;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub(crate) use self::tag_cardinality_limit::*;
 #[cfg(feature = "transforms-throttle")]
 pub(crate) use self::throttle::*;
+#[cfg(feature = "transforms-trace_sampler")]
+pub(crate) use self::trace_sampler::*;
 #[cfg(all(
     any(
         feature = "sinks-socket",
@@ -0,0 +1,103 @@
+use crate::emit;
+use metrics::counter;
+use rumqttc::{ClientError, ConnectionError};
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL,
+};
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct MqttConnectionError {
+    pub error: ConnectionError,
+    pub stage: &'static str,
+}
+
+impl InternalEvent for MqttConnectionError {
+    fn emit(self) {
+        error!(
+            message = "MQTT connection error.",
+            error = %self.error,
+            error_type = error_type::CONNECTION_FAILED,
+            stage = self.stage,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::CONNECTION_FAILED,
+            "stage" => self.stage,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct MqttSubscribeError {
+    pub error: ClientError,
+}
+
+impl InternalEvent for MqttSubscribeError {
+    fn emit(self) {
+        error!(
+            message = "Failed to subscribe to topics.",
+            error = %self.error,
+            error_type = error_type::READER_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::READER_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct MqttEventSendError {
+    pub error: ClientError,
+}
+
+impl InternalEvent for MqttEventSendError {
+    fn emit(self) {
+        let reason = "Failed to send message.";
+        error!(
+            message = reason,
+            error = %self.error,
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+}
+
+#[derive(Debug)]
+pub struct MqttMessagesUnacknowledged {
+    pub count: usize,
+}
+
+impl InternalEvent for MqttMessagesUnacknowledged {
+    fn emit(self) {
+        let reason = "Messages weren't acknowledged by the broker.";
+        error!(
+            message = reason,
+            count = self.count,
+            error_type = error_type::ACKNOWLEDGMENT_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::ACKNOWLEDGMENT_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason
+        });
+    }
+}
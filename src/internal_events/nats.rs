@@ -37,3 +37,51 @@ impl InternalEvent for NatsEventSendError {
         counter!("send_errors_total", 1);
     }
 }
+
+#[derive(Debug)]
+pub struct NatsReceiveError {
+    pub error: Error,
+}
+
+impl InternalEvent for NatsReceiveError {
+    fn emit(self) {
+        error!(
+            message = "Failed to receive messages.",
+            error = %self.error,
+            error_type = error_type::READER_FAILED,
+            error_code = io_error_code(&self.error),
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::READER_FAILED,
+            "error_code" => io_error_code(&self.error),
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct NatsAckError {
+    pub error: Error,
+}
+
+impl InternalEvent for NatsAckError {
+    fn emit(self) {
+        error!(
+            message = "Unable to ack.",
+            error = %self.error,
+            error_type = error_type::ACKNOWLEDGMENT_FAILED,
+            error_code = io_error_code(&self.error),
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::ACKNOWLEDGMENT_FAILED,
+            "error_code" => io_error_code(&self.error),
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
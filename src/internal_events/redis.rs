@@ -34,3 +34,35 @@ impl InternalEvent for RedisReceiveEventError {
         );
     }
 }
+
+#[derive(Debug)]
+pub struct RedisAckError {
+    error: redis::RedisError,
+    error_code: String,
+}
+
+impl From<redis::RedisError> for RedisAckError {
+    fn from(error: redis::RedisError) -> Self {
+        let error_code = error.code().unwrap_or("UNKNOWN").to_string();
+        Self { error, error_code }
+    }
+}
+
+impl InternalEvent for RedisAckError {
+    fn emit(self) {
+        error!(
+            message = "Failed to acknowledge stream entry.",
+            error = %self.error,
+            error_code = %self.error_code,
+            error_type = error_type::ACKNOWLEDGMENT_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => self.error_code,
+            "error_type" => error_type::ACKNOWLEDGMENT_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use crate::emit;
+use vector_core::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
+
+#[derive(Debug)]
+pub struct TraceSamplerTraceDiscarded {
+    pub count: usize,
+}
+
+impl InternalEvent for TraceSamplerTraceDiscarded {
+    fn emit(self) {
+        emit!(ComponentEventsDropped::<INTENTIONAL> {
+            count: self.count,
+            reason: "Trace discarded by sampling policies.",
+        });
+    }
+}
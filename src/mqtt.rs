@@ -0,0 +1,270 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use rumqttc::{Key, MqttOptions, TlsConfiguration, Transport};
+use snafu::{ResultExt, Snafu};
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+
+use crate::tls::{TlsConfig, TlsEnableableConfig, TlsError, TlsSettings};
+
+#[derive(Debug, Snafu)]
+pub enum MqttConfigError {
+    #[snafu(display("MQTT Auth Config Error: both `user` and `password` must be set"))]
+    IncompleteCredentials,
+    #[snafu(display(
+        "MQTT TLS Config Error: `ca_file` is not set, and no system certificate bundle was found"
+    ))]
+    TlsMissingCa,
+    #[snafu(display(
+        "MQTT TLS Config Error: `verify_certificate` and `verify_hostname` can't be disabled"
+    ))]
+    TlsVerificationRequired,
+    #[snafu(display("MQTT TLS Config Error: {}", source))]
+    TlsSettings { source: TlsError },
+    #[snafu(display("MQTT TLS Config Error: could not read {:?}: {}", path, source))]
+    TlsReadFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+/// Connection options for MQTT.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub(crate) struct MqttConnectionConfig {
+    /// The domain name or IP address of the MQTT broker.
+    pub(crate) host: String,
+
+    /// The TCP port of the MQTT broker.
+    #[serde(default = "default_port")]
+    pub(crate) port: u16,
+
+    /// The client ID used to identify this connection to the broker.
+    ///
+    /// Client IDs must be unique per broker. If not set, a random client ID is generated each time
+    /// the component connects.
+    pub(crate) client_id: Option<String>,
+
+    /// The interval, in seconds, at which keep-alive pings are sent to the broker.
+    #[serde(default = "default_keep_alive_secs")]
+    pub(crate) keep_alive_secs: u16,
+
+    /// The username to authenticate with.
+    pub(crate) user: Option<String>,
+
+    /// The password to authenticate with.
+    pub(crate) password: Option<SensitiveString>,
+
+    #[configurable(derived)]
+    pub(crate) tls: Option<TlsEnableableConfig>,
+}
+
+const fn default_port() -> u16 {
+    1883
+}
+
+const fn default_keep_alive_secs() -> u16 {
+    60
+}
+
+impl MqttConnectionConfig {
+    pub(crate) fn to_mqtt_options(&self) -> Result<MqttOptions, MqttConfigError> {
+        let client_id = self.client_id.clone().unwrap_or_else(random_client_id);
+        let mut options = MqttOptions::new(client_id, &self.host, self.port);
+        options.set_keep_alive(Duration::from_secs(self.keep_alive_secs.into()));
+
+        match (&self.user, &self.password) {
+            (None, None) => {}
+            (Some(user), Some(password)) => {
+                options.set_credentials(user, password.inner());
+            }
+            _ => return Err(MqttConfigError::IncompleteCredentials),
+        }
+
+        if let Some(tls) = &self.tls {
+            if tls.enabled.unwrap_or(false) {
+                options.set_transport(Transport::tls_with_config(tls_configuration(&tls.options)?));
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Generates a client ID of 23 characters, the longest that MQTT 3.1.1 brokers are required to
+/// accept.
+fn random_client_id() -> String {
+    let suffix: String = thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(16)
+        .map(char::from)
+        .collect();
+    format!("vector-{}", suffix)
+}
+
+fn tls_configuration(tls: &TlsConfig) -> Result<TlsConfiguration, MqttConfigError> {
+    // `rumqttc` always verifies the certificate of the broker against its hostname.
+    if tls.verify_certificate == Some(false) || tls.verify_hostname == Some(false) {
+        return Err(MqttConfigError::TlsVerificationRequired);
+    }
+
+    let settings = TlsSettings::from_options(&Some(tls.clone())).context(TlsSettingsSnafu)?;
+
+    let mut ca = settings.authorities_pem().flatten().collect::<Vec<u8>>();
+    if ca.is_empty() {
+        // Without a CA file, the broker is verified against the certificates trusted by the system.
+        ca = match openssl_probe::probe().cert_file {
+            Some(cert_file) => read_file(&cert_file)?,
+            None => return Err(MqttConfigError::TlsMissingCa),
+        };
+    }
+
+    // The identity is exported with its private key decrypted, as PKCS#8, which `rumqttc` reads as
+    // `Key::ECC` whatever the algorithm of the key.
+    let client_auth = settings
+        .identity_pem()
+        .map(|(crt, key)| (crt, Key::ECC(key)));
+
+    let alpn = tls.alpn_protocols.as_ref().map(|protocols| {
+        protocols
+            .iter()
+            .map(|protocol| protocol.as_bytes().to_vec())
+            .collect()
+    });
+
+    Ok(TlsConfiguration::Simple {
+        ca,
+        alpn,
+        client_auth,
+    })
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, MqttConfigError> {
+    std::fs::read(path).context(TlsReadFileSnafu { path })
+}
+
+/// The quality of service level used to deliver messages.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum MqttQoS {
+    /// Messages are delivered at most once, and may be lost.
+    AtMostOnce,
+
+    /// Messages are delivered at least once, and may be duplicated.
+    AtLeastOnce,
+
+    /// Messages are delivered exactly once.
+    ExactlyOnce,
+}
+
+impl Default for MqttQoS {
+    fn default() -> Self {
+        Self::AtLeastOnce
+    }
+}
+
+impl From<MqttQoS> for rumqttc::QoS {
+    fn from(qos: MqttQoS) -> Self {
+        match qos {
+            MqttQoS::AtMostOnce => rumqttc::QoS::AtMostOnce,
+            MqttQoS::AtLeastOnce => rumqttc::QoS::AtLeastOnce,
+            MqttQoS::ExactlyOnce => rumqttc::QoS::ExactlyOnce,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tls::TEST_PEM_CA_PATH;
+
+    fn config(toml: &str) -> MqttConnectionConfig {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn random_client_ids() {
+        let options = config(r#"host = "localhost""#).to_mqtt_options().unwrap();
+        assert_eq!(options.client_id().len(), 23);
+        assert!(options.client_id().starts_with("vector-"));
+
+        let options = config(
+            r#"
+            host = "localhost"
+            client_id = "my-client"
+            "#,
+        )
+        .to_mqtt_options()
+        .unwrap();
+        assert_eq!(options.client_id(), "my-client");
+    }
+
+    #[test]
+    fn credentials_require_user_and_password() {
+        let result = config(
+            r#"
+            host = "localhost"
+            user = "vector"
+            "#,
+        )
+        .to_mqtt_options();
+        assert!(matches!(
+            result,
+            Err(MqttConfigError::IncompleteCredentials)
+        ));
+    }
+
+    #[test]
+    fn tls_requires_verification() {
+        for option in ["verify_certificate", "verify_hostname"] {
+            let result = config(&format!(
+                r#"
+                host = "localhost"
+                tls.enabled = true
+                tls.ca_file = "{}"
+                tls.{} = false
+                "#,
+                TEST_PEM_CA_PATH, option
+            ))
+            .to_mqtt_options();
+            assert!(matches!(
+                result,
+                Err(MqttConfigError::TlsVerificationRequired)
+            ));
+        }
+    }
+
+    #[test]
+    fn tls_reads_encrypted_identity() {
+        let result = config(&format!(
+            r#"
+            host = "localhost"
+            tls.enabled = true
+            tls.ca_file = "{}"
+            tls.crt_file = "tests/data/ca/intermediate_client/private/localhost.p12"
+            tls.key_pass = "NOPASS"
+            "#,
+            TEST_PEM_CA_PATH
+        ))
+        .to_mqtt_options();
+        assert!(result.is_ok());
+
+        let result = config(&format!(
+            r#"
+            host = "localhost"
+            tls.enabled = true
+            tls.ca_file = "{}"
+            tls.crt_file = "tests/data/ca/intermediate_client/private/localhost.p12"
+            tls.key_pass = "wrong"
+            "#,
+            TEST_PEM_CA_PATH
+        ))
+        .to_mqtt_options();
+        assert!(matches!(result, Err(MqttConfigError::TlsSettings { .. })));
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use nkeys::error::Error as NKeysError;
 use snafu::{ResultExt, Snafu};
 use vector_common::sensitive_string::SensitiveString;
@@ -108,42 +110,105 @@ pub(crate) struct NatsAuthNKey {
     pub(crate) seed: String,
 }
 
+/// The builder methods shared by the connection options of the async client, and of the blocking
+/// client that JetStream requires, so that both are configured the same way.
+pub(crate) trait NatsOptions: Sized {
+    fn new() -> Self;
+    fn with_user_pass(user: &str, password: &str) -> Self;
+    fn with_credentials(path: &str) -> Self;
+    fn with_nkey<F>(nkey: &str, sign: F) -> Self
+    where
+        F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static;
+    fn with_token(token: &str) -> Self;
+    fn with_name(self, name: &str) -> Self;
+    fn reconnect_buffer_size(self, size: usize) -> Self;
+    fn tls_required(self, tls_required: bool) -> Self;
+    fn add_root_certificate(self, path: &Path) -> Self;
+    fn client_cert(self, cert: &Path, key: &Path) -> Self;
+}
+
+macro_rules! impl_nats_options {
+    ($options:ty) => {
+        impl NatsOptions for $options {
+            fn new() -> Self {
+                <$options>::new()
+            }
+
+            fn with_user_pass(user: &str, password: &str) -> Self {
+                <$options>::with_user_pass(user, password)
+            }
+
+            fn with_credentials(path: &str) -> Self {
+                <$options>::with_credentials(path)
+            }
+
+            fn with_nkey<F>(nkey: &str, sign: F) -> Self
+            where
+                F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static,
+            {
+                <$options>::with_nkey(nkey, sign)
+            }
+
+            fn with_token(token: &str) -> Self {
+                <$options>::with_token(token)
+            }
+
+            fn with_name(self, name: &str) -> Self {
+                <$options>::with_name(self, name)
+            }
+
+            fn reconnect_buffer_size(self, size: usize) -> Self {
+                <$options>::reconnect_buffer_size(self, size)
+            }
+
+            fn tls_required(self, tls_required: bool) -> Self {
+                <$options>::tls_required(self, tls_required)
+            }
+
+            fn add_root_certificate(self, path: &Path) -> Self {
+                <$options>::add_root_certificate(self, path)
+            }
+
+            fn client_cert(self, cert: &Path, key: &Path) -> Self {
+                <$options>::client_cert(self, cert, key)
+            }
+        }
+    };
+}
+
+impl_nats_options!(nats::Options);
+impl_nats_options!(nats::asynk::Options);
+
 impl NatsAuthConfig {
-    pub(crate) fn to_nats_options(&self) -> Result<nats::asynk::Options, NatsConfigError> {
+    pub(crate) fn to_nats_options<O: NatsOptions>(&self) -> Result<O, NatsConfigError> {
         match self {
-            NatsAuthConfig::UserPassword { user_password } => {
-                Ok(nats::asynk::Options::with_user_pass(
-                    user_password.user.as_str(),
-                    user_password.password.inner(),
-                ))
+            NatsAuthConfig::UserPassword { user_password } => Ok(O::with_user_pass(
+                user_password.user.as_str(),
+                user_password.password.inner(),
+            )),
+            NatsAuthConfig::CredentialsFile { credentials_file } => {
+                Ok(O::with_credentials(&credentials_file.path))
             }
-            NatsAuthConfig::CredentialsFile { credentials_file } => Ok(
-                nats::asynk::Options::with_credentials(&credentials_file.path),
-            ),
             NatsAuthConfig::Nkey { nkey } => nkeys::KeyPair::from_seed(&nkey.seed)
                 .context(AuthConfigSnafu)
                 .map(|kp| {
                     // The following unwrap is safe because the only way the sign method can fail is if
                     // keypair does not contain a seed. We are constructing the keypair from a seed in
                     // the preceding line.
-                    nats::asynk::Options::with_nkey(&nkey.nkey, move |nonce| {
-                        kp.sign(nonce).unwrap()
-                    })
+                    O::with_nkey(&nkey.nkey, move |nonce| kp.sign(nonce).unwrap())
                 }),
-            NatsAuthConfig::Token { token } => {
-                Ok(nats::asynk::Options::with_token(token.value.inner()))
-            }
+            NatsAuthConfig::Token { token } => Ok(O::with_token(token.value.inner())),
         }
     }
 }
 
-pub(crate) fn from_tls_auth_config(
+pub(crate) fn from_tls_auth_config<O: NatsOptions>(
     connection_name: &str,
     auth_config: &Option<NatsAuthConfig>,
     tls_config: &Option<TlsEnableableConfig>,
-) -> Result<nats::asynk::Options, NatsConfigError> {
+) -> Result<O, NatsConfigError> {
     let nats_options = match &auth_config {
-        None => nats::asynk::Options::new(),
+        None => O::new(),
         Some(auth) => auth.to_nats_options()?,
     };
 
@@ -178,6 +243,18 @@ pub(crate) fn from_tls_auth_config(
     }
 }
 
+/// Connects to JetStream, which is only supported by the blocking client.
+pub(crate) async fn connect_jetstream(
+    options: nats::Options,
+    url: &str,
+) -> std::io::Result<nats::jetstream::JetStream> {
+    let url = url.to_owned();
+    tokio::task::spawn_blocking(move || options.connect(&url))
+        .await
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::Other, error))?
+        .map(nats::jetstream::new)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,7 +262,11 @@ mod tests {
     fn parse_auth(s: &str) -> Result<nats::asynk::Options, crate::Error> {
         toml::from_str(s)
             .map_err(Into::into)
-            .and_then(|config: NatsAuthConfig| config.to_nats_options().map_err(Into::into))
+            .and_then(|config: NatsAuthConfig| {
+                config
+                    .to_nats_options::<nats::asynk::Options>()
+                    .map_err(Into::into)
+            })
     }
 
     #[test]
@@ -2,7 +2,7 @@ use std::convert::TryInto;
 
 use aws_sdk_s3::Client as S3Client;
 use codecs::{
-    encoding::{Framer, FramingConfig},
+    encoding::{BatchSerializerConfig, Framer, FramingConfig},
     TextSerializerConfig,
 };
 use tower::ServiceBuilder;
@@ -26,8 +26,8 @@ use crate::{
             sink::S3Sink,
         },
         util::{
-            BatchConfig, BulkSizeBasedDefaultBatchSettings, Compression, ServiceBuilderExt,
-            TowerRequestConfig,
+            encoding::BatchEncoder, BatchConfig, BulkSizeBasedDefaultBatchSettings, Compression,
+            ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck,
     },
@@ -97,6 +97,17 @@ pub struct S3SinkConfig {
     #[serde(flatten)]
     pub encoding: EncodingConfigWithFraming,
 
+    /// Encodes each batch of events as a whole, rather than event by event.
+    ///
+    /// When set, the events of each object are serialized with this codec instead of
+    /// `encoding.codec`, and framing is not applied. Codecs compress their output themselves, so
+    /// `compression` must be set to `none`. Only the `encoding` options that transform events, such
+    /// as `encoding.only_fields`, still take effect.
+    ///
+    /// Unless `filename_extension` or `content_type` are set, the objects are written with the file
+    /// extension and content type of the codec.
+    pub batch_encoding: Option<BatchSerializerConfig>,
+
     #[configurable(derived)]
     #[serde(default = "Compression::gzip_default")]
     pub compression: Compression,
@@ -136,6 +147,7 @@ impl GenerateConfig for S3SinkConfig {
             options: S3Options::default(),
             region: RegionOrEndpoint::default(),
             encoding: (None::<FramingConfig>, TextSerializerConfig::new()).into(),
+            batch_encoding: None,
             compression: Compression::gzip_default(),
             batch: BatchConfig::default(),
             request: TowerRequestConfig::default(),
@@ -157,7 +169,11 @@ impl SinkConfig for S3SinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type() & DataType::Log)
+        let input_type = match &self.batch_encoding {
+            Some(batch_encoding) => batch_encoding.input_type(),
+            None => self.encoding.config().1.input_type(),
+        };
+        Input::new(input_type & DataType::Log)
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -204,17 +220,41 @@ impl S3SinkConfig {
             .unwrap_or(DEFAULT_FILENAME_APPEND_UUID);
 
         let transformer = self.encoding.transformer();
-        let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let mut api_options = self.options.clone();
+        let mut filename_extension = self.filename_extension.clone();
+        let (encoder, compression) = match &self.batch_encoding {
+            Some(batch_encoding) => {
+                if self.compression != Compression::None {
+                    return Err("`compression` must be set to `none` with `batch_encoding`.".into());
+                }
+                let serializer = batch_encoding.build()?;
+                api_options
+                    .content_type
+                    .get_or_insert_with(|| serializer.content_type().to_owned());
+                filename_extension.get_or_insert_with(|| serializer.extension().to_owned());
+                (
+                    BatchEncoder::Batch((transformer, serializer)),
+                    Compression::None,
+                )
+            }
+            None => {
+                let (framer, serializer) = self.encoding.build(SinkType::MessageBased)?;
+                let encoder = Encoder::<Framer>::new(framer, serializer);
+                (
+                    BatchEncoder::Framed((transformer, encoder)),
+                    self.compression,
+                )
+            }
+        };
 
         let request_options = S3RequestOptions {
             bucket: self.bucket.clone(),
-            api_options: self.options.clone(),
-            filename_extension: self.filename_extension.clone(),
+            api_options,
+            filename_extension,
             filename_time_format,
             filename_append_uuid,
-            encoder: (transformer, encoder),
-            compression: self.compression,
+            encoder,
+            compression,
         };
 
         let sink = S3Sink::new(service, request_options, partitioner, batch_settings);
@@ -233,10 +273,36 @@ impl S3SinkConfig {
 
 #[cfg(test)]
 mod tests {
+    use codecs::encoding::{ParquetSerializerConfig, ParquetSerializerOptions};
+
     use super::S3SinkConfig;
+    use crate::{
+        aws::RegionOrEndpoint,
+        config::{GenerateConfig, SinkConfig, SinkContext},
+        sinks::util::Compression,
+    };
 
     #[test]
     fn generate_config() {
         crate::test_util::test_generate_config::<S3SinkConfig>();
     }
+
+    #[tokio::test]
+    async fn rejects_compression_with_batch_encoding() {
+        let config = S3SinkConfig {
+            bucket: "logs".to_owned(),
+            region: RegionOrEndpoint::with_both("local", "http://localhost:4566"),
+            batch_encoding: Some(
+                ParquetSerializerConfig::new(ParquetSerializerOptions::default()).into(),
+            ),
+            compression: Compression::gzip_default(),
+            ..S3SinkConfig::generate_config().try_into().unwrap()
+        };
+
+        let error = config.build(SinkContext::new_test()).await.err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "`compression` must be set to `none` with `batch_encoding`."
+        );
+    }
 }
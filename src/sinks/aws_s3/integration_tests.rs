@@ -16,7 +16,10 @@ use aws_sdk_s3::{
     Client as S3Client,
 };
 use bytes::Buf;
-use codecs::{encoding::FramingConfig, TextSerializerConfig};
+use codecs::{
+    encoding::{FramingConfig, ParquetSerializerOptions},
+    ParquetSerializerConfig, TextSerializerConfig,
+};
 use flate2::read::MultiGzDecoder;
 use futures::{stream, Stream};
 use similar_asserts::assert_eq;
@@ -249,6 +252,49 @@ async fn s3_gzip() {
     assert_eq!(lines, response_lines);
 }
 
+#[tokio::test]
+async fn s3_parquet() {
+    let cx = SinkContext::new_test();
+
+    let bucket = uuid::Uuid::new_v4().to_string();
+
+    create_bucket(&bucket, false).await;
+
+    let batch_size = 100;
+    let config = S3SinkConfig {
+        batch_encoding: Some(
+            ParquetSerializerConfig::new(ParquetSerializerOptions {
+                schema: Some("message logs { required binary message (UTF8); }".to_owned()),
+                ..Default::default()
+            })
+            .into(),
+        ),
+        compression: Compression::None,
+        ..config(&bucket, batch_size)
+    };
+
+    let prefix = config.key_prefix.clone();
+    let service = config.create_service(&cx.globals.proxy).await.unwrap();
+    let sink = config.build_processor(service).unwrap();
+
+    let (_lines, events, receiver) = make_events_batch(100, batch_size);
+    run_and_assert_sink_compliance(sink, events, &AWS_SINK_TAGS).await;
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+
+    let keys = get_keys(&bucket, prefix.unwrap()).await;
+    assert_eq!(keys.len(), 1);
+    assert!(keys[0].ends_with(".parquet"));
+
+    let obj = get_object(&bucket, keys[0].clone()).await;
+    assert_eq!(obj.content_type(), Some("application/vnd.apache.parquet"));
+    assert_eq!(obj.content_encoding(), None);
+
+    // Parquet files start and end with the `PAR1` magic bytes.
+    let body = obj.body.collect().await.unwrap().into_bytes();
+    assert!(body.starts_with(b"PAR1"));
+    assert!(body.ends_with(b"PAR1"));
+}
+
 // NOTE: this test doesn't actually validate anything because localstack
 // doesn't enforce the required Content-MD5 header on the request for
 // buckets with object lock enabled
@@ -389,6 +435,7 @@ fn config(bucket: &str, batch_size: usize) -> S3SinkConfig {
         options: S3Options::default(),
         region: RegionOrEndpoint::with_both("minio", s3_address()),
         encoding: (None::<FramingConfig>, TextSerializerConfig::new()).into(),
+        batch_encoding: None,
         compression: Compression::None,
         batch,
         request: TowerRequestConfig::default(),
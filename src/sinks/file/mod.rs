@@ -91,6 +91,9 @@ pub enum Compression {
     /// Zstandard compression.
     Zstd,
 
+    /// Snappy compression, using the framed format.
+    Snappy,
+
     /// No compression.
     None,
 }
@@ -105,6 +108,7 @@ enum OutFile {
     Regular(File),
     Gzip(GzipEncoder<File>),
     Zstd(ZstdEncoder<File>),
+    Snappy(File),
 }
 
 impl OutFile {
@@ -113,12 +117,13 @@ impl OutFile {
             Compression::None => OutFile::Regular(file),
             Compression::Gzip => OutFile::Gzip(GzipEncoder::new(file)),
             Compression::Zstd => OutFile::Zstd(ZstdEncoder::new(file)),
+            Compression::Snappy => OutFile::Snappy(file),
         }
     }
 
     async fn sync_all(&mut self) -> Result<(), std::io::Error> {
         match self {
-            OutFile::Regular(file) => file.sync_all().await,
+            OutFile::Regular(file) | OutFile::Snappy(file) => file.sync_all().await,
             OutFile::Gzip(gzip) => gzip.get_mut().sync_all().await,
             OutFile::Zstd(zstd) => zstd.get_mut().sync_all().await,
         }
@@ -126,7 +131,7 @@ impl OutFile {
 
     async fn shutdown(&mut self) -> Result<(), std::io::Error> {
         match self {
-            OutFile::Regular(file) => file.shutdown().await,
+            OutFile::Regular(file) | OutFile::Snappy(file) => file.shutdown().await,
             OutFile::Gzip(gzip) => gzip.shutdown().await,
             OutFile::Zstd(zstd) => zstd.shutdown().await,
         }
@@ -137,6 +142,7 @@ impl OutFile {
             OutFile::Regular(file) => file.write_all(src).await,
             OutFile::Gzip(gzip) => gzip.write_all(src).await,
             OutFile::Zstd(zstd) => zstd.write_all(src).await,
+            OutFile::Snappy(file) => file.write_all(&snappy_frame(src)?).await,
         }
     }
 
@@ -148,6 +154,18 @@ impl OutFile {
     }
 }
 
+/// Compresses `src` as a complete Snappy framed stream.
+///
+/// Framed streams can be concatenated, so every write is appended to the file
+/// as its own stream rather than holding a synchronous encoder across writes.
+fn snappy_frame(src: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    use std::io::Write as _;
+
+    let mut encoder = snap::write::FrameEncoder::new(Vec::new());
+    encoder.write_all(src)?;
+    encoder.into_inner().map_err(|error| error.into_error())
+}
+
 #[async_trait::async_trait]
 impl SinkConfig for FileSinkConfig {
     async fn build(
@@ -323,7 +341,28 @@ impl FileSink {
                 }
             };
 
-            let outfile = OutFile::new(file, self.compression);
+            // Serializers with a header, such as CSV, write it once at the
+            // start of every file.
+            let header = match self.encoder.header() {
+                Some(header) if is_empty(&file).await => Some(header),
+                _ => None,
+            };
+
+            let mut outfile = OutFile::new(file, self.compression);
+
+            if let Some(header) = header {
+                if let Err(error) = outfile.write_all(&header).await {
+                    emit!(FileIoError {
+                        code: "failed_writing_file",
+                        message: "Failed to write the file header.",
+                        error,
+                        path: &path,
+                        dropped_events: 1,
+                    });
+                    event.metadata().update_status(EventStatus::Errored);
+                    return;
+                }
+            }
 
             self.files.insert_at(path.clone(), outfile, next_deadline);
             emit!(FileOpen {
@@ -378,6 +417,12 @@ async fn open_file(path: impl AsRef<std::path::Path>) -> std::io::Result<File> {
         .await
 }
 
+async fn is_empty(file: &File) -> bool {
+    file.metadata()
+        .await
+        .map_or(false, |metadata| metadata.len() == 0)
+}
+
 async fn write_event_to_file(
     file: &mut OutFile,
     mut event: Event,
@@ -406,6 +451,7 @@ impl StreamSink<Event> for FileSink {
 mod tests {
     use std::convert::TryInto;
 
+    use codecs::{encoding::CsvSerializerOptions, CsvSerializerConfig};
     use futures::{stream, SinkExt};
     use similar_asserts::assert_eq;
     use vector_core::{event::LogEvent, sink::VectorSink};
@@ -415,8 +461,8 @@ mod tests {
         config::log_schema,
         test_util::{
             components::{run_and_assert_sink_compliance, FILE_SINK_TAGS},
-            lines_from_file, lines_from_gzip_file, lines_from_zstd_file, random_events_with_stream,
-            random_lines_with_stream, temp_dir, temp_file, trace_init,
+            lines_from_file, lines_from_gzip_file, lines_from_snappy_file, lines_from_zstd_file,
+            random_events_with_stream, random_lines_with_stream, temp_dir, temp_file, trace_init,
         },
     };
 
@@ -461,6 +507,53 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn single_partition_csv_header() {
+        trace_init();
+
+        let template = temp_file();
+
+        let config = FileSinkConfig {
+            path: template.clone().try_into().unwrap(),
+            idle_timeout_secs: None,
+            encoding: (
+                None::<FramingConfig>,
+                CsvSerializerConfig::new(CsvSerializerOptions {
+                    fields: vec!["message".to_string()],
+                    delimiter: b',',
+                    quote_style: Default::default(),
+                    include_header: true,
+                }),
+            )
+                .into(),
+            compression: Compression::None,
+            acknowledgements: Default::default(),
+        };
+
+        let sink = FileSink::new(&config).unwrap();
+        let (input, _events) = random_lines_with_stream(10, 64, None);
+
+        let events = Box::pin(stream::iter(
+            input
+                .clone()
+                .into_iter()
+                .map(|e| Event::Log(LogEvent::from(e))),
+        ));
+        run_and_assert_sink_compliance(
+            VectorSink::from_event_streamsink(sink),
+            events,
+            &FILE_SINK_TAGS,
+        )
+        .await;
+
+        let output = lines_from_file(template);
+        assert_eq!(output.len(), input.len() + 1);
+        assert_eq!(output[0], "message");
+        for (input, output) in input.into_iter().zip(output.into_iter().skip(1)) {
+            assert_eq!(input, output);
+        }
+    }
+
     #[tokio::test]
     async fn single_partition_gzip() {
         trace_init();
@@ -533,6 +626,43 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn single_partition_snappy() {
+        trace_init();
+
+        let template = temp_file();
+
+        let config = FileSinkConfig {
+            path: template.clone().try_into().unwrap(),
+            idle_timeout_secs: None,
+            encoding: (None::<FramingConfig>, TextSerializerConfig::new()).into(),
+            compression: Compression::Snappy,
+            acknowledgements: Default::default(),
+        };
+
+        let sink = FileSink::new(&config).unwrap();
+        let (input, _) = random_lines_with_stream(100, 64, None);
+
+        let events = Box::pin(stream::iter(
+            input
+                .clone()
+                .into_iter()
+                .map(|e| Event::Log(LogEvent::from(e))),
+        ));
+        run_and_assert_sink_compliance(
+            VectorSink::from_event_streamsink(sink),
+            events,
+            &FILE_SINK_TAGS,
+        )
+        .await;
+
+        let output = lines_from_snappy_file(template);
+        assert_eq!(output.len(), input.len());
+        for (input, output) in input.into_iter().zip(output) {
+            assert_eq!(input, output);
+        }
+    }
+
     #[tokio::test]
     async fn many_partitions() {
         trace_init();
@@ -2,7 +2,7 @@ use std::{collections::HashMap, convert::TryFrom, io};
 
 use bytes::Bytes;
 use chrono::Utc;
-use codecs::encoding::Framer;
+use codecs::encoding::{BatchSerializerConfig, Framer};
 use http::header::{HeaderName, HeaderValue};
 use indoc::indoc;
 use snafu::ResultExt;
@@ -15,7 +15,7 @@ use vector_core::event::{EventFinalizers, Finalizable};
 
 use crate::sinks::util::metadata::RequestMetadataBuilder;
 use crate::{
-    codecs::{Encoder, EncodingConfigWithFraming, SinkType, Transformer},
+    codecs::{Encoder, EncodingConfigWithFraming, SinkType},
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     event::Event,
     gcp::{GcpAuthConfig, GcpAuthenticator, Scope},
@@ -30,9 +30,9 @@ use crate::{
             sink::GcsSink,
         },
         util::{
-            batch::BatchConfig, partitioner::KeyPartitioner, request_builder::EncodeResult,
-            BulkSizeBasedDefaultBatchSettings, Compression, RequestBuilder, ServiceBuilderExt,
-            TowerRequestConfig,
+            batch::BatchConfig, encoding::BatchEncoder, partitioner::KeyPartitioner,
+            request_builder::EncodeResult, BulkSizeBasedDefaultBatchSettings, Compression,
+            RequestBuilder, ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck, VectorSink,
     },
@@ -118,6 +118,17 @@ pub struct GcsSinkConfig {
     #[serde(flatten)]
     encoding: EncodingConfigWithFraming,
 
+    /// Encodes each batch of events as a whole, rather than event by event.
+    ///
+    /// When set, the events of each object are serialized with this codec instead of
+    /// `encoding.codec`, and framing is not applied. Codecs compress their output themselves, so
+    /// `compression` must be set to `none`. Only the `encoding` options that transform events, such
+    /// as `encoding.only_fields`, still take effect.
+    ///
+    /// The objects are written with the content type of the codec and, unless `filename_extension`
+    /// is set, with its file extension.
+    batch_encoding: Option<BatchSerializerConfig>,
+
     #[configurable(derived)]
     #[serde(default)]
     compression: Compression,
@@ -157,6 +168,7 @@ fn default_config(encoding: EncodingConfigWithFraming) -> GcsSinkConfig {
         filename_append_uuid: Default::default(),
         filename_extension: Default::default(),
         encoding,
+        batch_encoding: None,
         compression: Compression::gzip_default(),
         batch: Default::default(),
         request: Default::default(),
@@ -197,7 +209,11 @@ impl SinkConfig for GcsSinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::new(self.encoding.config().1.input_type() & DataType::Log)
+        let input_type = match &self.batch_encoding {
+            Some(batch_encoding) => batch_encoding.input_type(),
+            None => self.encoding.config().1.input_type(),
+        };
+        Input::new(input_type & DataType::Log)
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -253,14 +269,14 @@ struct RequestSettings {
     extension: String,
     time_format: String,
     append_uuid: bool,
-    encoder: (Transformer, Encoder<Framer>),
+    encoder: BatchEncoder,
     compression: Compression,
 }
 
 impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
     type Metadata = (String, EventFinalizers);
     type Events = Vec<Event>;
-    type Encoder = (Transformer, Encoder<Framer>);
+    type Encoder = BatchEncoder;
     type Payload = Bytes;
     type Request = GcsRequest;
     type Error = io::Error;
@@ -325,14 +341,34 @@ impl RequestBuilder<(String, Vec<Event>)> for RequestSettings {
 impl RequestSettings {
     fn new(config: &GcsSinkConfig) -> crate::Result<Self> {
         let transformer = config.encoding.transformer();
-        let (framer, serializer) = config.encoding.build(SinkType::MessageBased)?;
-        let encoder = Encoder::<Framer>::new(framer, serializer);
+        let (encoder, compression, default_extension) = match &config.batch_encoding {
+            Some(batch_encoding) => {
+                if config.compression != Compression::None {
+                    return Err("`compression` must be set to `none` with `batch_encoding`.".into());
+                }
+                let serializer = batch_encoding.build()?;
+                let extension = serializer.extension();
+                (
+                    BatchEncoder::Batch((transformer, serializer)),
+                    Compression::None,
+                    extension,
+                )
+            }
+            None => {
+                let (framer, serializer) = config.encoding.build(SinkType::MessageBased)?;
+                let encoder = Encoder::<Framer>::new(framer, serializer);
+                (
+                    BatchEncoder::Framed((transformer, encoder)),
+                    config.compression,
+                    config.compression.extension(),
+                )
+            }
+        };
         let acl = config
             .acl
             .map(|acl| HeaderValue::from_str(&to_string(acl)).unwrap());
         let content_type = HeaderValue::from_str(encoder.content_type()).unwrap();
-        let content_encoding = config
-            .compression
+        let content_encoding = compression
             .content_encoding()
             .map(|ce| HeaderValue::from_str(&to_string(ce)).unwrap());
         let storage_class = config.storage_class.unwrap_or_default();
@@ -350,7 +386,7 @@ impl RequestSettings {
         let extension = config
             .filename_extension
             .clone()
-            .unwrap_or_else(|| config.compression.extension().into());
+            .unwrap_or_else(|| default_extension.into());
         let time_format = config
             .filename_time_format
             .clone()
@@ -365,8 +401,8 @@ impl RequestSettings {
             extension,
             time_format,
             append_uuid,
-            compression: config.compression,
-            encoder: (transformer, encoder),
+            compression,
+            encoder,
         })
     }
 }
@@ -382,7 +418,10 @@ fn make_header((name, value): (&String, &String)) -> crate::Result<(HeaderName,
 #[cfg(test)]
 mod tests {
     use codecs::encoding::FramingConfig;
-    use codecs::{JsonSerializerConfig, NewlineDelimitedEncoderConfig, TextSerializerConfig};
+    use codecs::{
+        JsonSerializerConfig, NewlineDelimitedEncoderConfig, ParquetSerializerConfig,
+        TextSerializerConfig,
+    };
     use futures_util::{future::ready, stream};
     use vector_core::partition::Partitioner;
 
@@ -487,4 +526,35 @@ mod tests {
         let req = build_request(None, true, Compression::gzip_default());
         assert_ne!(req.key, "key/date.log.gz".to_string());
     }
+
+    #[test]
+    fn gcs_build_request_parquet() {
+        let sink_config = GcsSinkConfig {
+            key_prefix: Some("key/".into()),
+            filename_time_format: Some("date".into()),
+            filename_append_uuid: Some(false),
+            batch_encoding: Some(ParquetSerializerConfig::new(Default::default()).into()),
+            compression: Compression::None,
+            ..default_config((None::<FramingConfig>, JsonSerializerConfig::new()).into())
+        };
+        let request_settings = request_settings(&sink_config);
+        assert_eq!(
+            request_settings.content_type,
+            "application/vnd.apache.parquet"
+        );
+        assert!(request_settings.content_encoding.is_none());
+
+        let (metadata, metadata_request_builder, _events) =
+            request_settings.split_input(("key/".into(), vec![LogEvent::default().into()]));
+        let payload = EncodeResult::uncompressed(Bytes::new());
+        let request_metadata = metadata_request_builder.build(&payload);
+        let req = request_settings.build_request(metadata, request_metadata, payload);
+        assert_eq!(req.key, "key/date.parquet".to_string());
+
+        let sink_config = GcsSinkConfig {
+            compression: Compression::gzip_default(),
+            ..sink_config
+        };
+        assert!(RequestSettings::new(&sink_config).is_err());
+    }
 }
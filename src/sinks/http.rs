@@ -2,7 +2,6 @@ use std::io::Write;
 
 use bytes::{BufMut, Bytes, BytesMut};
 use codecs::encoding::{CharacterDelimitedEncoder, Framer, Serializer};
-use flate2::write::{GzEncoder, ZlibEncoder};
 use futures::{future, FutureExt, SinkExt};
 use http::{
     header::{self, HeaderName, HeaderValue},
@@ -22,7 +21,7 @@ use crate::{
     sinks::util::{
         self,
         http::{BatchedHttpSink, HttpEventEncoder, RequestConfig},
-        BatchConfig, Buffer, Compression, RealtimeSizeBasedDefaultBatchSettings,
+        BatchConfig, Buffer, Compression, Compressor, RealtimeSizeBasedDefaultBatchSettings,
         TowerRequestConfig, UriSerde,
     },
     tls::{TlsConfig, TlsSettings},
@@ -281,6 +280,8 @@ impl util::http::HttpSink for HttpSink {
             use Serializer::*;
             match (self.encoder.serializer(), self.encoder.framer()) {
                 (RawMessage(_) | Text(_), _) => Some("text/plain"),
+                (Protobuf(_), _) => Some("application/x-protobuf"),
+                (Csv(_), _) => Some("text/csv"),
                 (Json(_), NewlineDelimited(_)) => {
                     if !body.is_empty() {
                         // Remove trailing newline for backwards-compatibility
@@ -314,24 +315,14 @@ impl util::http::HttpSink for HttpSink {
             builder = builder.header("Content-Type", content_type);
         }
 
-        match self.compression {
-            Compression::Gzip(level) => {
-                builder = builder.header("Content-Encoding", "gzip");
+        if let Some(content_encoding) = self.compression.content_encoding() {
+            builder = builder.header("Content-Encoding", content_encoding);
 
-                let buffer = BytesMut::new();
-                let mut w = GzEncoder::new(buffer.writer(), level.as_flate2());
-                w.write_all(&body).expect("Writing to Vec can't fail");
-                body = w.finish().expect("Writing to Vec can't fail").into_inner();
-            }
-            Compression::Zlib(level) => {
-                builder = builder.header("Content-Encoding", "deflate");
-
-                let buffer = BytesMut::new();
-                let mut w = ZlibEncoder::new(buffer.writer(), level.as_flate2());
-                w.write_all(&body).expect("Writing to Vec can't fail");
-                body = w.finish().expect("Writing to Vec can't fail").into_inner();
-            }
-            Compression::None => {}
+            let mut compressor = Compressor::from(self.compression);
+            compressor
+                .write_all(&body)
+                .expect("Writing to Vec can't fail");
+            body = compressor.finish().expect("Writing to Vec can't fail");
         }
 
         for (header, value) in self.request.headers.iter() {
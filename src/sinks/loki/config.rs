@@ -28,14 +28,16 @@ pub enum ExtendedCompression {
 }
 
 /// Compose with basic compression and Loki-specific compression.
+// Untagged variants are tried in order, and basic compression also accepts `snappy`, so the
+// Loki-specific variant must come first for `snappy` to keep implying Protocol Buffers.
 #[configurable_component]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum CompressionConfigAdapter {
-    /// Basic compression.
-    Original(#[configurable(derived)] Compression),
     /// Loki-specific compression.
     Extended(#[configurable(derived)] ExtendedCompression),
+    /// Basic compression.
+    Original(#[configurable(derived)] Compression),
 }
 
 impl CompressionConfigAdapter {
@@ -252,7 +254,8 @@ pub fn valid_label_name(label: &Template) -> bool {
 mod tests {
     use std::convert::TryInto;
 
-    use super::valid_label_name;
+    use super::{valid_label_name, CompressionConfigAdapter, ExtendedCompression};
+    use crate::sinks::util::Compression;
 
     #[test]
     fn valid_label_names() {
@@ -270,4 +273,19 @@ mod tests {
 
         assert!(valid_label_name(&"{{field}}".try_into().unwrap()));
     }
+
+    #[test]
+    fn snappy_compression_implies_protobuf() {
+        let compression: CompressionConfigAdapter = serde_json::from_str(r#""snappy""#).unwrap();
+        assert_eq!(
+            compression,
+            CompressionConfigAdapter::Extended(ExtendedCompression::Snappy)
+        );
+
+        let compression: CompressionConfigAdapter = serde_json::from_str(r#""zstd""#).unwrap();
+        assert_eq!(
+            compression,
+            CompressionConfigAdapter::Original(Compression::zstd_default())
+        );
+    }
 }
@@ -66,12 +66,18 @@ pub mod kafka;
 pub mod logdna;
 #[cfg(feature = "sinks-loki")]
 pub mod loki;
+#[cfg(feature = "sinks-mqtt")]
+pub mod mqtt;
 #[cfg(feature = "sinks-nats")]
 pub mod nats;
 #[cfg(feature = "sinks-new_relic")]
 pub mod new_relic;
+#[cfg(feature = "sinks-opentelemetry")]
+pub mod opentelemetry;
 #[cfg(feature = "sinks-papertrail")]
 pub mod papertrail;
+#[cfg(feature = "sinks-postgres")]
+pub mod postgres;
 #[cfg(feature = "sinks-prometheus")]
 pub mod prometheus;
 #[cfg(feature = "sinks-pulsar")]
@@ -91,6 +97,8 @@ pub mod socket;
 pub mod splunk_hec;
 #[cfg(feature = "sinks-statsd")]
 pub mod statsd;
+#[cfg(feature = "sinks-syslog")]
+pub mod syslog;
 #[cfg(feature = "sinks-vector")]
 pub mod vector;
 #[cfg(feature = "sinks-websocket")]
@@ -272,6 +280,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-loki")]
     Loki(#[configurable(derived)] loki::LokiConfig),
 
+    /// MQTT.
+    #[cfg(feature = "sinks-mqtt")]
+    Mqtt(#[configurable(derived)] mqtt::MqttSinkConfig),
+
     /// NATS.
     #[cfg(feature = "sinks-nats")]
     Nats(#[configurable(derived)] self::nats::NatsSinkConfig),
@@ -280,10 +292,18 @@ pub enum Sinks {
     #[cfg(feature = "sinks-new_relic")]
     NewRelic(#[configurable(derived)] new_relic::NewRelicConfig),
 
+    /// OpenTelemetry.
+    #[cfg(feature = "sinks-opentelemetry")]
+    Opentelemetry(#[configurable(derived)] opentelemetry::OpentelemetryConfig),
+
     /// Papertrail.
     #[cfg(feature = "sinks-papertrail")]
     Papertrail(#[configurable(derived)] papertrail::PapertrailConfig),
 
+    /// PostgreSQL.
+    #[cfg(feature = "sinks-postgres")]
+    Postgres(#[configurable(derived)] postgres::PostgresConfig),
+
     /// Prometheus Exporter.
     #[cfg(feature = "sinks-prometheus")]
     PrometheusExporter(#[configurable(derived)] prometheus::exporter::PrometheusExporterConfig),
@@ -324,6 +344,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-statsd")]
     Statsd(#[configurable(derived)] statsd::StatsdSinkConfig),
 
+    /// Syslog.
+    #[cfg(feature = "sinks-syslog")]
+    Syslog(#[configurable(derived)] syslog::SyslogSinkConfig),
+
     /// Test (adaptive concurrency).
     #[cfg(all(test, feature = "sources-demo_logs"))]
     TestArc(#[configurable(derived)] self::util::adaptive_concurrency::tests::TestConfig),
@@ -438,12 +462,18 @@ impl NamedComponent for Sinks {
             Self::Logdna(config) => config.get_component_name(),
             #[cfg(feature = "sinks-loki")]
             Self::Loki(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-mqtt")]
+            Self::Mqtt(config) => config.get_component_name(),
             #[cfg(feature = "sinks-nats")]
             Self::Nats(config) => config.get_component_name(),
             #[cfg(feature = "sinks-new_relic")]
             Self::NewRelic(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-opentelemetry")]
+            Self::Opentelemetry(config) => config.get_component_name(),
             #[cfg(feature = "sinks-papertrail")]
             Self::Papertrail(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-postgres")]
+            Self::Postgres(config) => config.get_component_name(),
             #[cfg(feature = "sinks-prometheus")]
             Self::PrometheusExporter(config) => config.get_component_name(),
             #[cfg(feature = "sinks-prometheus")]
@@ -464,6 +494,8 @@ impl NamedComponent for Sinks {
             Self::SplunkHecMetrics(config) => config.get_component_name(),
             #[cfg(feature = "sinks-statsd")]
             Self::Statsd(config) => config.get_component_name(),
+            #[cfg(feature = "sinks-syslog")]
+            Self::Syslog(config) => config.get_component_name(),
             #[cfg(all(test, feature = "sources-demo_logs"))]
             Self::TestArc(config) => config.get_component_name(),
             #[cfg(test)]
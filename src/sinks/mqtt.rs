@@ -0,0 +1,548 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use bytes::BytesMut;
+use codecs::JsonSerializerConfig;
+use futures::{future, stream::BoxStream, FutureExt, StreamExt};
+use rumqttc::{AsyncClient, ConnectionError, EventLoop, Outgoing, Packet};
+use snafu::{ResultExt, Snafu};
+use tokio::sync::Notify;
+use tokio_util::codec::Encoder as _;
+use vector_common::{
+    estimated_json_encoded_size_of::EstimatedJsonEncodedSizeOf,
+    internal_event::{
+        error_stage, ByteSize, BytesSent, EventsSent, InternalEventHandle, Protocol, Registered,
+    },
+};
+use vector_config::configurable_component;
+
+use crate::{
+    codecs::{Encoder, EncodingConfig, Transformer},
+    config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
+    event::{Event, EventFinalizers, EventStatus, Finalizable},
+    internal_events::{
+        MqttConnectionError, MqttEventSendError, MqttMessagesUnacknowledged, TemplateRenderingError,
+    },
+    mqtt::{MqttConfigError, MqttConnectionConfig, MqttQoS},
+    sinks::util::StreamSink,
+    template::{Template, TemplateParseError},
+};
+
+/// How long to wait before polling the event loop again after a connection error, which makes the
+/// client reconnect.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// How long the sink waits, when its input ends, for the broker to acknowledge the messages that are
+/// still pending.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("invalid encoding: {}", source))]
+    Encoding {
+        source: codecs::encoding::BuildError,
+    },
+    #[snafu(display("invalid topic template: {}", source))]
+    TopicTemplate { source: TemplateParseError },
+    #[snafu(display("MQTT Config Error: {}", source))]
+    Config { source: MqttConfigError },
+}
+
+/// Configuration for the `mqtt` sink.
+#[configurable_component(sink("mqtt"))]
+#[derive(Clone, Debug)]
+pub struct MqttSinkConfig {
+    #[serde(flatten)]
+    connection: MqttConnectionConfig,
+
+    /// The topic to publish messages to.
+    #[configurable(metadata(docs::templateable))]
+    topic: String,
+
+    /// The quality of service level used to deliver messages.
+    ///
+    /// Events are acknowledged once the broker acknowledges their message. With `at_most_once`, the
+    /// broker doesn't acknowledge messages, so events are acknowledged as soon as their message is
+    /// written to the connection.
+    #[serde(default)]
+    qos: MqttQoS,
+
+    /// Whether the broker should retain the last message published to each topic, and deliver it
+    /// to new subscribers.
+    #[serde(default)]
+    retain: bool,
+
+    #[configurable(derived)]
+    encoding: EncodingConfig,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+impl GenerateConfig for MqttSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            connection: toml::from_str(r#"host = "127.0.0.1""#).unwrap(),
+            topic: "vector".into(),
+            qos: MqttQoS::default(),
+            retain: false,
+            encoding: JsonSerializerConfig::new().into(),
+            acknowledgements: Default::default(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for MqttSinkConfig {
+    async fn build(
+        &self,
+        _cx: SinkContext,
+    ) -> crate::Result<(super::VectorSink, super::Healthcheck)> {
+        let sink = MqttSink::new(self)?;
+        // Connecting a second client just to check the broker could take over the session of the
+        // sink's own client, so connection errors are only reported by the sink as they happen.
+        let healthcheck = future::ok(()).boxed();
+        Ok((super::VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::new(self.encoding.config().input_type() & DataType::Log)
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+pub struct MqttSink {
+    transformer: Transformer,
+    encoder: Encoder<()>,
+    client: AsyncClient,
+    topic: Template,
+    qos: rumqttc::QoS,
+    retain: bool,
+    pending: Arc<PendingMessages>,
+}
+
+impl MqttSink {
+    fn new(config: &MqttSinkConfig) -> Result<Self, BuildError> {
+        let options = config.connection.to_mqtt_options().context(ConfigSnafu)?;
+        let transformer = config.encoding.transformer();
+        let serializer = config.encoding.build().context(EncodingSnafu)?;
+        let encoder = Encoder::<()>::new(serializer);
+        let topic = Template::try_from(config.topic.clone()).context(TopicTemplateSnafu)?;
+
+        let (client, eventloop) = AsyncClient::new(options, 1024);
+        let pending = Arc::new(PendingMessages::default());
+        tokio::spawn(run_eventloop(eventloop, Arc::clone(&pending)));
+
+        Ok(MqttSink {
+            transformer,
+            encoder,
+            client,
+            topic,
+            qos: config.qos.into(),
+            retain: config.retain,
+            pending,
+        })
+    }
+}
+
+/// A message published by the sink, which is waiting to be acknowledged by the broker.
+struct PendingMessage {
+    finalizers: EventFinalizers,
+    event_byte_size: usize,
+    bytes_len: usize,
+}
+
+/// The messages published by the sink that haven't been acknowledged yet.
+///
+/// The event loop sends the messages in the order they were published, so the messages that have
+/// been queued by the client are matched to their packet ID as the event loop sends them.
+#[derive(Default)]
+struct PendingMessages {
+    state: Mutex<PendingState>,
+    drained: Notify,
+}
+
+#[derive(Default)]
+struct PendingState {
+    /// Messages queued by the client, which the event loop hasn't sent yet.
+    queued: VecDeque<PendingMessage>,
+    /// Messages sent to the broker, by packet ID.
+    in_flight: HashMap<u16, PendingMessage>,
+    /// A message that can't be sent until the broker acknowledges the message currently using its
+    /// packet ID, and whether it has been sent since.
+    collision: Option<(u16, PendingMessage, bool)>,
+}
+
+impl PendingState {
+    fn is_empty(&self) -> bool {
+        self.queued.is_empty() && self.in_flight.is_empty() && self.collision.is_none()
+    }
+}
+
+impl PendingMessages {
+    fn push(&self, message: PendingMessage) {
+        self.state.lock().unwrap().queued.push_back(message);
+    }
+
+    /// Removes the last message pushed, when the client failed to queue it.
+    fn pop(&self) -> Option<PendingMessage> {
+        self.state.lock().unwrap().queued.pop_back()
+    }
+
+    /// Waits until all of the pending messages have been acknowledged.
+    async fn drain(&self) {
+        while !self.state.lock().unwrap().is_empty() {
+            self.drained.notified().await;
+        }
+    }
+
+    /// Handles the event loop sending a message with the given packet ID, returning the message
+    /// if it's already delivered.
+    fn sent(&self, pkid: u16) -> Option<PendingMessage> {
+        let mut state = self.state.lock().unwrap();
+        match &mut state.collision {
+            Some((collision, _, sent)) if *collision == pkid => {
+                *sent = true;
+                return None;
+            }
+            _ => {}
+        }
+        if state.in_flight.contains_key(&pkid) {
+            // The message is sent again after reconnecting to the broker.
+            return None;
+        }
+
+        let message = state.queued.pop_front()?;
+        // Messages sent with a QoS of 0 all have a packet ID of 0, and aren't acknowledged.
+        if pkid == 0 {
+            return Some(message);
+        }
+        state.in_flight.insert(pkid, message);
+        None
+    }
+
+    /// Handles the event loop holding back the next message until the given packet ID is
+    /// acknowledged.
+    fn await_ack(&self, pkid: u16) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(message) = state.queued.pop_front() {
+            state.collision = Some((pkid, message, false));
+        }
+    }
+
+    /// Handles the broker acknowledging the message with the given packet ID, returning that
+    /// message.
+    fn acked(&self, pkid: u16) -> Option<PendingMessage> {
+        let mut state = self.state.lock().unwrap();
+        let message = state.in_flight.remove(&pkid);
+        if matches!(state.collision, Some((collision, _, true)) if collision == pkid) {
+            let (pkid, message, _) = state.collision.take().expect("collision is set");
+            state.in_flight.insert(pkid, message);
+        }
+        message
+    }
+
+    /// Fails all of the pending messages, which won't be acknowledged anymore.
+    fn fail(&self) {
+        let state = std::mem::take(&mut *self.state.lock().unwrap());
+        let messages = state
+            .queued
+            .into_iter()
+            .chain(state.in_flight.into_values())
+            .chain(state.collision.map(|(_, message, _)| message));
+        let mut count = 0;
+        for message in messages {
+            message.finalizers.update_status(EventStatus::Errored);
+            count += 1;
+        }
+        if count > 0 {
+            emit!(MqttMessagesUnacknowledged { count });
+        }
+        self.drained.notify_one();
+    }
+
+    fn delivered(&self, message: PendingMessage, bytes_sent: &Registered<BytesSent>) {
+        message.finalizers.update_status(EventStatus::Delivered);
+        emit!(EventsSent {
+            byte_size: message.event_byte_size,
+            count: 1,
+            output: None
+        });
+        bytes_sent.emit(ByteSize(message.bytes_len));
+
+        if self.state.lock().unwrap().is_empty() {
+            self.drained.notify_one();
+        }
+    }
+}
+
+/// Drives the connection to the broker, which sends the messages queued by the client, and
+/// acknowledges the events of the messages the broker acknowledges.
+async fn run_eventloop(mut eventloop: EventLoop, pending: Arc<PendingMessages>) {
+    let bytes_sent = register!(BytesSent::from(Protocol::TCP));
+
+    loop {
+        let message = match eventloop.poll().await {
+            Ok(rumqttc::Event::Outgoing(Outgoing::Disconnect)) => break,
+            Ok(rumqttc::Event::Outgoing(Outgoing::Publish(pkid))) => pending.sent(pkid),
+            Ok(rumqttc::Event::Outgoing(Outgoing::AwaitAck(pkid))) => {
+                pending.await_ack(pkid);
+                None
+            }
+            Ok(rumqttc::Event::Incoming(Packet::PubAck(ack))) => pending.acked(ack.pkid),
+            Ok(rumqttc::Event::Incoming(Packet::PubComp(comp))) => pending.acked(comp.pkid),
+            Ok(_) => None,
+            // The client has been dropped, so nothing else will be sent.
+            Err(ConnectionError::RequestsDone) => break,
+            Err(error) => {
+                emit!(MqttConnectionError {
+                    error,
+                    stage: error_stage::SENDING,
+                });
+                tokio::time::sleep(RECONNECT_DELAY).await;
+                None
+            }
+        };
+
+        if let Some(message) = message {
+            pending.delivered(message, &bytes_sent);
+        }
+    }
+
+    // Nothing is sent once the event loop stops, so the messages still pending are never delivered.
+    pending.fail();
+}
+
+#[async_trait]
+impl StreamSink<Event> for MqttSink {
+    async fn run(mut self: Box<Self>, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
+        while let Some(mut event) = input.next().await {
+            let finalizers = event.take_finalizers();
+
+            let topic = match self.topic.render_string(&event) {
+                Ok(topic) => topic,
+                Err(error) => {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("topic"),
+                        drop_event: true,
+                    });
+                    finalizers.update_status(EventStatus::Rejected);
+                    continue;
+                }
+            };
+
+            self.transformer.transform(&mut event);
+
+            let event_byte_size = event.estimated_json_encoded_size_of();
+
+            let mut bytes = BytesMut::new();
+            if self.encoder.encode(event, &mut bytes).is_err() {
+                // Error is handled by `Encoder`.
+                finalizers.update_status(EventStatus::Rejected);
+                continue;
+            }
+
+            // The message is only queued here, its events are acknowledged once the event loop has
+            // delivered it to the broker.
+            self.pending.push(PendingMessage {
+                finalizers,
+                event_byte_size,
+                bytes_len: bytes.len(),
+            });
+            if let Err(error) = self
+                .client
+                .publish(topic, self.qos, self.retain, bytes.to_vec())
+                .await
+            {
+                if let Some(message) = self.pending.pop() {
+                    message.finalizers.update_status(EventStatus::Errored);
+                }
+
+                emit!(MqttEventSendError { error });
+            }
+        }
+
+        // Let the event loop deliver whatever is still pending before closing the connection, as
+        // long as the broker keeps acknowledging the messages.
+        if tokio::time::timeout(DRAIN_TIMEOUT, self.pending.drain())
+            .await
+            .is_err()
+        {
+            self.pending.fail();
+        }
+        let _ = self.client.disconnect().await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<MqttSinkConfig>();
+    }
+
+    fn pending(messages: usize) -> PendingMessages {
+        let pending = PendingMessages::default();
+        for bytes_len in 0..messages {
+            pending.push(PendingMessage {
+                finalizers: EventFinalizers::default(),
+                event_byte_size: 0,
+                bytes_len,
+            });
+        }
+        pending
+    }
+
+    fn bytes_len(message: Option<PendingMessage>) -> Option<usize> {
+        message.map(|message| message.bytes_len)
+    }
+
+    #[test]
+    fn pending_messages_are_acked_by_packet_id() {
+        let pending = pending(2);
+        assert_eq!(bytes_len(pending.sent(1)), None);
+        assert_eq!(bytes_len(pending.sent(2)), None);
+        // Messages sent again after reconnecting are still matched to their first packet ID.
+        assert_eq!(bytes_len(pending.sent(1)), None);
+
+        assert_eq!(bytes_len(pending.acked(2)), Some(1));
+        assert_eq!(bytes_len(pending.acked(1)), Some(0));
+        assert!(pending.state.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn pending_messages_without_packet_id_are_sent() {
+        let pending = pending(2);
+        assert_eq!(bytes_len(pending.sent(0)), Some(0));
+        assert_eq!(bytes_len(pending.sent(0)), Some(1));
+        assert!(pending.state.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn pending_messages_fail() {
+        let pending = pending(3);
+        assert_eq!(bytes_len(pending.sent(1)), None);
+        pending.await_ack(1);
+
+        pending.fail();
+        assert!(pending.state.lock().unwrap().is_empty());
+        // The message is already failed once the broker acknowledges it.
+        assert_eq!(bytes_len(pending.acked(1)), None);
+    }
+
+    #[tokio::test]
+    async fn failing_pending_messages_ends_drain() {
+        let pending = Arc::new(pending(1));
+        let drain = tokio::spawn({
+            let pending = Arc::clone(&pending);
+            async move { pending.drain().await }
+        });
+        tokio::task::yield_now().await;
+
+        pending.fail();
+        drain.await.unwrap();
+    }
+
+    #[test]
+    fn pending_messages_wait_for_colliding_packet_id() {
+        let pending = pending(2);
+        assert_eq!(bytes_len(pending.sent(1)), None);
+        pending.await_ack(1);
+        // The held back message is sent before the ack of the previous one is handled.
+        assert_eq!(bytes_len(pending.sent(1)), None);
+
+        assert_eq!(bytes_len(pending.acked(1)), Some(0));
+        assert_eq!(bytes_len(pending.acked(1)), Some(1));
+        assert!(pending.state.lock().unwrap().is_empty());
+    }
+}
+
+#[cfg(feature = "mqtt-integration-tests")]
+#[cfg(test)]
+mod integration_tests {
+    use futures::stream;
+    use rumqttc::{MqttOptions, Packet, QoS};
+
+    use super::*;
+    use crate::{
+        sinks::VectorSink,
+        test_util::{
+            components::{run_and_assert_sink_compliance, SINK_TAGS},
+            random_lines, random_string, trace_init,
+        },
+    };
+
+    fn mqtt_host() -> String {
+        std::env::var("MQTT_HOST").unwrap_or_else(|_| "localhost".into())
+    }
+
+    #[tokio::test]
+    async fn mqtt_happy() {
+        trace_init();
+
+        let topic = format!("test-{}", random_string(10));
+        let config: MqttSinkConfig = toml::from_str(&format!(
+            r#"
+            host = "{}"
+            topic = "{}/{{{{ host }}}}"
+            encoding.codec = "text"
+            "#,
+            mqtt_host(),
+            topic,
+        ))
+        .unwrap();
+
+        // Subscribe before anything is published, so all of the messages are received.
+        let (subscriber, mut subscriber_loop) =
+            AsyncClient::new(MqttOptions::new(random_string(10), mqtt_host(), 1883), 10);
+        subscriber
+            .subscribe(format!("{}/#", topic), QoS::AtLeastOnce)
+            .await
+            .unwrap();
+        while !matches!(
+            subscriber_loop.poll().await.unwrap(),
+            rumqttc::Event::Incoming(Packet::SubAck(_))
+        ) {}
+
+        let num_events = 100;
+        let input: Vec<String> = random_lines(100).take(num_events).collect();
+        let events = input.iter().map(|line| {
+            let mut event = Event::from(line.as_str());
+            event.as_mut_log().insert("host", "my-host");
+            event
+        });
+
+        let sink = VectorSink::from_event_streamsink(MqttSink::new(&config).unwrap());
+        run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+
+        let mut output = Vec::new();
+        while output.len() < num_events {
+            if let rumqttc::Event::Incoming(Packet::Publish(publish)) =
+                subscriber_loop.poll().await.unwrap()
+            {
+                assert_eq!(publish.topic, format!("{}/my-host", topic));
+                output.push(String::from_utf8(publish.payload.to_vec()).unwrap());
+            }
+        }
+
+        assert_eq!(output, input);
+    }
+}
@@ -1,7 +1,7 @@
-use std::convert::TryFrom;
+use std::{convert::TryFrom, io};
 
 use async_trait::async_trait;
-use bytes::BytesMut;
+use bytes::{Bytes, BytesMut};
 use codecs::JsonSerializerConfig;
 use futures::{stream::BoxStream, FutureExt, StreamExt, TryFutureExt};
 use snafu::{ResultExt, Snafu};
@@ -17,7 +17,7 @@ use crate::{
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     event::{Event, EventStatus, Finalizable},
     internal_events::{NatsEventSendError, TemplateRenderingError},
-    nats::{from_tls_auth_config, NatsAuthConfig, NatsConfigError},
+    nats::{connect_jetstream, from_tls_auth_config, NatsAuthConfig, NatsConfigError},
     sinks::util::StreamSink,
     template::{Template, TemplateParseError},
     tls::TlsEnableableConfig,
@@ -75,6 +75,13 @@ pub struct NatsSinkConfig {
 
     #[configurable(derived)]
     auth: Option<NatsAuthConfig>,
+
+    /// Publish messages to JetStream.
+    ///
+    /// Each publish then waits for the server to acknowledge that the message was stored by the
+    /// stream bound to the subject, and fails if there is no such stream.
+    #[serde(default)]
+    jetstream: bool,
 }
 
 fn default_name() -> String {
@@ -85,6 +92,7 @@ impl GenerateConfig for NatsSinkConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
             acknowledgements: Default::default(),
+            jetstream: false,
             auth: None,
             connection_name: "vector".into(),
             encoding: JsonSerializerConfig::new().into(),
@@ -124,34 +132,74 @@ impl std::convert::TryFrom<&NatsSinkConfig> for nats::asynk::Options {
     }
 }
 
+impl std::convert::TryFrom<&NatsSinkConfig> for nats::Options {
+    type Error = NatsConfigError;
+
+    fn try_from(config: &NatsSinkConfig) -> Result<Self, Self::Error> {
+        from_tls_auth_config(&config.connection_name, &config.auth, &config.tls)
+    }
+}
+
 impl NatsSinkConfig {
     async fn connect(&self) -> Result<nats::asynk::Connection, BuildError> {
         let options: nats::asynk::Options = self.try_into().context(ConfigSnafu)?;
 
         options.connect(&self.url).await.context(ConnectSnafu)
     }
+
+    async fn publisher(&self) -> Result<NatsPublisher, BuildError> {
+        if self.jetstream {
+            let options: nats::Options = self.try_into().context(ConfigSnafu)?;
+            connect_jetstream(options, &self.url)
+                .await
+                .map(NatsPublisher::JetStream)
+                .context(ConnectSnafu)
+        } else {
+            self.connect().await.map(NatsPublisher::Core)
+        }
+    }
 }
 
 async fn healthcheck(config: NatsSinkConfig) -> crate::Result<()> {
     config.connect().map_ok(|_| ()).map_err(|e| e.into()).await
 }
 
+enum NatsPublisher {
+    Core(nats::asynk::Connection),
+    JetStream(nats::jetstream::JetStream),
+}
+
+impl NatsPublisher {
+    async fn publish(&self, subject: String, payload: Bytes) -> io::Result<()> {
+        match self {
+            Self::Core(connection) => connection.publish(&subject, payload).await,
+            Self::JetStream(js) => {
+                // The JetStream client blocks until the publish is acknowledged.
+                let js = js.clone();
+                tokio::task::spawn_blocking(move || js.publish(&subject, payload).map(|_ack| ()))
+                    .await
+                    .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?
+            }
+        }
+    }
+}
+
 pub struct NatsSink {
     transformer: Transformer,
     encoder: Encoder<()>,
-    connection: nats::asynk::Connection,
+    publisher: NatsPublisher,
     subject: Template,
 }
 
 impl NatsSink {
     async fn new(config: NatsSinkConfig) -> Result<Self, BuildError> {
-        let connection = config.connect().await?;
+        let publisher = config.publisher().await?;
         let transformer = config.encoding.transformer();
         let serializer = config.encoding.build().context(EncodingSnafu)?;
         let encoder = Encoder::<()>::new(serializer);
 
         Ok(NatsSink {
-            connection,
+            publisher,
             transformer,
             encoder,
             subject: Template::try_from(config.subject).context(SubjectTemplateSnafu)?,
@@ -191,7 +239,8 @@ impl StreamSink<Event> for NatsSink {
                 continue;
             }
 
-            match self.connection.publish(&subject, &bytes).await {
+            let bytes_len = bytes.len();
+            match self.publisher.publish(subject, bytes.freeze()).await {
                 Err(error) => {
                     finalizers.update_status(EventStatus::Errored);
 
@@ -205,7 +254,7 @@ impl StreamSink<Event> for NatsSink {
                         count: 1,
                         output: None
                     });
-                    bytes_sent.emit(ByteSize(bytes.len()));
+                    bytes_sent.emit(ByteSize(bytes_len));
                 }
             }
         }
@@ -292,6 +341,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -308,6 +358,50 @@ mod integration_tests {
         );
     }
 
+    #[tokio::test]
+    async fn nats_jetstream() {
+        trace_init();
+
+        let subject = format!("test-{}", random_string(10));
+        let url = std::env::var("NATS_JETSTREAM_ADDRESS")
+            .unwrap_or_else(|_| String::from("nats://localhost:4222"));
+
+        let conf = NatsSinkConfig {
+            acknowledgements: Default::default(),
+            jetstream: true,
+            encoding: TextSerializerConfig::new().into(),
+            connection_name: "".to_owned(),
+            subject: subject.clone(),
+            url: url.clone(),
+            tls: None,
+            auth: None,
+        };
+
+        let js = connect_jetstream(nats::Options::new(), &url).await.unwrap();
+        let stream_config = nats::jetstream::StreamConfig {
+            name: subject.clone(),
+            subjects: vec![subject.clone()],
+            ..Default::default()
+        };
+        let js_admin = js.clone();
+        tokio::task::spawn_blocking(move || js_admin.add_stream(stream_config))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let sink = VectorSink::from_event_streamsink(NatsSink::new(conf).await.unwrap());
+        let num_events = 100;
+        let (_input, events) = random_lines_with_stream(100, num_events, None);
+        run_and_assert_sink_compliance(sink, events, &SINK_TAGS).await;
+
+        // Every publish was acknowledged, so the messages are already stored by the stream.
+        let info = tokio::task::spawn_blocking(move || js.stream_info(&subject))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.state.messages, num_events as u64);
+    }
+
     #[tokio::test]
     async fn nats_userpass_auth_valid() {
         trace_init();
@@ -318,6 +412,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -346,6 +441,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -377,6 +473,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -407,6 +504,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -437,6 +535,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -468,6 +567,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -499,6 +599,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -531,6 +632,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -557,6 +659,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -591,6 +694,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -623,6 +727,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
@@ -659,6 +764,7 @@ mod integration_tests {
 
         let conf = NatsSinkConfig {
             acknowledgements: Default::default(),
+            jetstream: false,
             encoding: TextSerializerConfig::new().into(),
             connection_name: "".to_owned(),
             subject: subject.clone(),
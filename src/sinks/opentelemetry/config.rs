@@ -0,0 +1,182 @@
+use futures::FutureExt;
+use http::Uri;
+use tower::ServiceBuilder;
+use vector_config::configurable_component;
+
+use super::{
+    service::{OpentelemetryResponse, OpentelemetryService, Transport},
+    sink::OpentelemetrySink,
+    OpentelemetrySinkError,
+};
+use crate::{
+    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    http::HttpClient,
+    sinks::{
+        util::{
+            retries::RetryLogic, BatchConfig, RealtimeEventBasedDefaultBatchSettings,
+            ServiceBuilderExt, TowerRequestConfig,
+        },
+        vector::{new_client, with_default_scheme},
+        Healthcheck, VectorSink,
+    },
+    tls::{MaybeTlsSettings, TlsEnableableConfig},
+};
+
+/// Configuration for the `opentelemetry` sink.
+#[configurable_component(sink("opentelemetry"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct OpentelemetryConfig {
+    /// The endpoint to send OTLP requests to.
+    ///
+    /// With the `grpc` protocol, this is the address of the gRPC server, such as
+    /// `http://localhost:4317`. With the `http` protocol, this is the base URL, such as
+    /// `http://localhost:4318`, to which `/v1/logs`, `/v1/metrics` and `/v1/traces` are appended.
+    endpoint: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    protocol: OpentelemetryProtocol,
+
+    /// Whether or not to compress requests.
+    ///
+    /// If set to `true`, requests will be compressed with [`gzip`][gzip_docs].
+    ///
+    /// [gzip_docs]: https://en.wikipedia.org/wiki/Gzip
+    #[serde(default)]
+    compression: bool,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<RealtimeEventBasedDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    acknowledgements: AcknowledgementsConfig,
+}
+
+/// The protocol used to send OTLP requests.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OpentelemetryProtocol {
+    /// OTLP over gRPC.
+    Grpc,
+
+    /// OTLP over HTTP, with Protobuf-encoded request bodies.
+    Http,
+}
+
+impl Default for OpentelemetryProtocol {
+    fn default() -> Self {
+        Self::Grpc
+    }
+}
+
+impl GenerateConfig for OpentelemetryConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            endpoint: "http://127.0.0.1:4317".to_owned(),
+            protocol: OpentelemetryProtocol::Grpc,
+            compression: false,
+            batch: BatchConfig::default(),
+            request: TowerRequestConfig::default(),
+            tls: None,
+            acknowledgements: Default::default(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for OpentelemetryConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let tls = MaybeTlsSettings::from_config(&self.tls, false)?;
+
+        let (transport, uri) = match self.protocol {
+            OpentelemetryProtocol::Grpc => {
+                let uri = with_default_scheme(&self.endpoint, tls.is_tls())?;
+                let client = new_client(&tls, cx.proxy())?;
+                (Transport::grpc(client, uri.clone(), self.compression), uri)
+            }
+            OpentelemetryProtocol::Http => {
+                let uri: Uri = self.endpoint.parse()?;
+                let client = HttpClient::new(tls, cx.proxy())?;
+                (Transport::http(client, &uri, self.compression)?, uri)
+            }
+        };
+
+        let service = OpentelemetryService::new(transport, uri);
+        let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
+        let batch_settings = self.batch.into_batcher_settings()?;
+
+        let service = ServiceBuilder::new()
+            .settings(request_settings, OpentelemetryRetryLogic)
+            .service(service);
+
+        let sink = OpentelemetrySink {
+            batch_settings,
+            service,
+        };
+
+        // OTLP doesn't define a health check, and export requests can't be sent without data.
+        let healthcheck = futures::future::ok(()).boxed();
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OpentelemetryRetryLogic;
+
+impl RetryLogic for OpentelemetryRetryLogic {
+    type Error = OpentelemetrySinkError;
+    type Response = OpentelemetryResponse;
+
+    fn is_retriable_error(&self, err: &Self::Error) -> bool {
+        use tonic::Code::*;
+
+        match err {
+            OpentelemetrySinkError::Request { source } => !matches!(
+                source.code(),
+                // List taken from
+                //
+                // <https://github.com/grpc/grpc/blob/ed1b20777c69bd47e730a63271eafc1b299f6ca0/doc/statuscodes.md>
+                NotFound
+                    | InvalidArgument
+                    | AlreadyExists
+                    | PermissionDenied
+                    | OutOfRange
+                    | Unimplemented
+                    | Unauthenticated
+            ),
+            OpentelemetrySinkError::Http { source } => source.is_retriable(),
+            // OTLP/HTTP only allows retrying throttled requests and the server errors that are
+            // usually temporary.
+            //
+            // <https://github.com/open-telemetry/opentelemetry-specification/blob/main/specification/protocol/otlp.md#failures-1>
+            OpentelemetrySinkError::Status { status } => matches!(status.as_u16(), 429 | 502..=504),
+            OpentelemetrySinkError::BuildRequest { .. } => false,
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use snafu::Snafu;
+
+mod config;
+mod service;
+mod sink;
+
+pub use config::OpentelemetryConfig;
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub))]
+pub enum OpentelemetrySinkError {
+    #[snafu(display("Request failed: {}", source))]
+    Request { source: tonic::Status },
+
+    #[snafu(display("HTTP request failed: {}", source))]
+    Http { source: crate::http::HttpError },
+
+    #[snafu(display("Server responded with an error: {}", status))]
+    Status { status: http::StatusCode },
+
+    #[snafu(display("Failed to build HTTP request: {}", source))]
+    BuildRequest { source: http::Error },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, io::Read};
+
+    use flate2::read::GzDecoder;
+    use futures::StreamExt;
+    use http::request::Parts;
+    use hyper::Method;
+    use opentelemetry_proto::proto::{
+        collector::{
+            logs::v1::{
+                logs_service_server::{LogsService, LogsServiceServer},
+                ExportLogsServiceRequest, ExportLogsServiceResponse,
+            },
+            metrics::v1::{
+                metrics_service_server::{MetricsService, MetricsServiceServer},
+                ExportMetricsServiceRequest, ExportMetricsServiceResponse,
+            },
+            trace::v1::{
+                trace_service_server::{TraceService, TraceServiceServer},
+                ExportTraceServiceRequest, ExportTraceServiceResponse,
+            },
+        },
+        metrics::v1::metric::Data,
+    };
+    use prost::Message;
+    use tokio::{net::TcpListener, sync::mpsc};
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::{codec::CompressionEncoding, transport::Server, Request, Response, Status};
+    use vector_core::event::{BatchNotifier, BatchStatus};
+
+    use super::{service::OpentelemetryPayload, *};
+    use crate::{
+        config::{SinkConfig as _, SinkContext},
+        event::{Event, LogEvent, Metric, MetricKind, MetricValue, TraceEvent, Value},
+        sinks::util::test::build_test_server_generic,
+        test_util::{
+            components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+            next_addr,
+        },
+    };
+
+    /// Records the requests received by the gRPC services, along with their `grpc-encoding`.
+    #[derive(Clone)]
+    struct GrpcServer {
+        tx: mpsc::UnboundedSender<(Option<String>, OpentelemetryPayload)>,
+    }
+
+    impl GrpcServer {
+        fn record<T>(&self, request: Request<T>, into_payload: fn(T) -> OpentelemetryPayload) {
+            let encoding = request
+                .metadata()
+                .get("grpc-encoding")
+                .and_then(|encoding| encoding.to_str().ok())
+                .map(str::to_owned);
+            self.tx
+                .send((encoding, into_payload(request.into_inner())))
+                .unwrap();
+        }
+    }
+
+    #[tonic::async_trait]
+    impl LogsService for GrpcServer {
+        async fn export(
+            &self,
+            request: Request<ExportLogsServiceRequest>,
+        ) -> Result<Response<ExportLogsServiceResponse>, Status> {
+            self.record(request, OpentelemetryPayload::Logs);
+            Ok(Response::new(ExportLogsServiceResponse {}))
+        }
+    }
+
+    #[tonic::async_trait]
+    impl MetricsService for GrpcServer {
+        async fn export(
+            &self,
+            request: Request<ExportMetricsServiceRequest>,
+        ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+            self.record(request, OpentelemetryPayload::Metrics);
+            Ok(Response::new(ExportMetricsServiceResponse {}))
+        }
+    }
+
+    #[tonic::async_trait]
+    impl TraceService for GrpcServer {
+        async fn export(
+            &self,
+            request: Request<ExportTraceServiceRequest>,
+        ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+            self.record(request, OpentelemetryPayload::Traces);
+            Ok(Response::new(ExportTraceServiceResponse {}))
+        }
+    }
+
+    fn metric_event(batch: &BatchNotifier) -> Event {
+        Event::from(Metric::new(
+            "requests",
+            MetricKind::Absolute,
+            MetricValue::Counter { value: 1.0 },
+        ))
+        .with_batch_notifier(batch)
+    }
+
+    fn trace_event(batch: &BatchNotifier) -> Event {
+        let fields = BTreeMap::from([("name".to_owned(), Value::from("span"))]);
+        Event::from(TraceEvent::from(fields)).with_batch_notifier(batch)
+    }
+
+    /// Checks that the payload holds the event built for its type, returning the name of the type.
+    fn assert_payload(payload: OpentelemetryPayload) -> &'static str {
+        match payload {
+            OpentelemetryPayload::Logs(request) => {
+                let records = &request.resource_logs[0].scope_logs[0].log_records;
+                assert_eq!(records.len(), 1);
+                "logs"
+            }
+            OpentelemetryPayload::Metrics(request) => {
+                let metrics = &request.resource_metrics[0].scope_metrics[0].metrics;
+                assert_eq!(metrics.len(), 1);
+                assert_eq!(metrics[0].name, "requests");
+                assert!(matches!(metrics[0].data, Some(Data::Sum(_))));
+                "metrics"
+            }
+            OpentelemetryPayload::Traces(request) => {
+                let spans = &request.resource_spans[0].scope_spans[0].spans;
+                assert_eq!(spans.len(), 1);
+                assert_eq!(spans[0].name, "span");
+                "traces"
+            }
+        }
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<OpentelemetryConfig>();
+    }
+
+    #[tokio::test]
+    async fn deliver_logs_over_http() {
+        let in_addr = next_addr();
+
+        let config = format!(
+            r#"
+            endpoint = "http://{}/"
+            protocol = "http"
+            "#,
+            in_addr
+        );
+        let config: OpentelemetryConfig = toml::from_str(&config).unwrap();
+        let cx = SinkContext::new_test();
+        let (sink, _) = config.build(cx).await.unwrap();
+
+        let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+            hyper::Response::builder()
+                .status(200)
+                .body(hyper::Body::empty())
+                .unwrap()
+        });
+        tokio::spawn(server);
+
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let events = (0..10).map(|i| {
+            let mut log = LogEvent::from(format!("line {}", i)).with_batch_notifier(&batch);
+            log.insert("level", "info");
+            Event::from(log)
+        });
+        drop(batch);
+
+        run_and_assert_sink_compliance(sink, futures::stream::iter(events), &HTTP_SINK_TAGS).await;
+        drop(trigger);
+
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+        let requests = rx.collect::<Vec<(Parts, bytes::Bytes)>>().await;
+        assert_eq!(requests.len(), 1);
+
+        let (parts, body) = &requests[0];
+        assert_eq!(parts.method, Method::POST);
+        assert_eq!(parts.uri.path(), "/v1/logs");
+        assert_eq!(
+            parts.headers.get("content-type").unwrap(),
+            "application/x-protobuf"
+        );
+
+        let request = ExportLogsServiceRequest::decode(body.clone()).unwrap();
+        let records = &request.resource_logs[0].scope_logs[0].log_records;
+        assert_eq!(records.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn deliver_over_grpc() {
+        let in_addr = next_addr();
+        let listener = TcpListener::bind(in_addr).await.unwrap();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let server = GrpcServer { tx };
+        tokio::spawn(
+            Server::builder()
+                .add_service(
+                    LogsServiceServer::new(server.clone())
+                        .accept_compressed(CompressionEncoding::Gzip),
+                )
+                .add_service(
+                    MetricsServiceServer::new(server.clone())
+                        .accept_compressed(CompressionEncoding::Gzip),
+                )
+                .add_service(
+                    TraceServiceServer::new(server).accept_compressed(CompressionEncoding::Gzip),
+                )
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        let config = format!(
+            r#"
+            endpoint = "http://{}"
+            compression = true
+            "#,
+            in_addr
+        );
+        let config: OpentelemetryConfig = toml::from_str(&config).unwrap();
+        let cx = SinkContext::new_test();
+        let (sink, _) = config.build(cx).await.unwrap();
+
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let events = vec![
+            Event::from(LogEvent::from("line").with_batch_notifier(&batch)),
+            metric_event(&batch),
+            trace_event(&batch),
+        ];
+        drop(batch);
+
+        run_and_assert_sink_compliance(sink, futures::stream::iter(events), &HTTP_SINK_TAGS).await;
+
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+        let mut types = Vec::new();
+        for _ in 0..3 {
+            let (encoding, payload) = rx.recv().await.unwrap();
+            assert_eq!(encoding.as_deref(), Some("gzip"));
+            types.push(assert_payload(payload));
+        }
+        types.sort_unstable();
+        assert_eq!(types, ["logs", "metrics", "traces"]);
+    }
+
+    #[tokio::test]
+    async fn deliver_metrics_and_traces_over_http() {
+        let in_addr = next_addr();
+
+        let config = format!(
+            r#"
+            endpoint = "http://{}/"
+            protocol = "http"
+            compression = true
+            "#,
+            in_addr
+        );
+        let config: OpentelemetryConfig = toml::from_str(&config).unwrap();
+        let cx = SinkContext::new_test();
+        let (sink, _) = config.build(cx).await.unwrap();
+
+        let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+            hyper::Response::builder()
+                .status(200)
+                .body(hyper::Body::empty())
+                .unwrap()
+        });
+        tokio::spawn(server);
+
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let events = vec![metric_event(&batch), trace_event(&batch)];
+        drop(batch);
+
+        run_and_assert_sink_compliance(sink, futures::stream::iter(events), &HTTP_SINK_TAGS).await;
+        drop(trigger);
+
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+        let requests = rx.collect::<Vec<(Parts, bytes::Bytes)>>().await;
+        let mut types = requests
+            .into_iter()
+            .map(|(parts, body)| {
+                assert_eq!(parts.headers.get("content-encoding").unwrap(), "gzip");
+                let mut decoded = Vec::new();
+                GzDecoder::new(body.as_ref())
+                    .read_to_end(&mut decoded)
+                    .unwrap();
+                let payload = match parts.uri.path() {
+                    "/v1/metrics" => OpentelemetryPayload::Metrics(
+                        ExportMetricsServiceRequest::decode(decoded.as_slice()).unwrap(),
+                    ),
+                    "/v1/traces" => OpentelemetryPayload::Traces(
+                        ExportTraceServiceRequest::decode(decoded.as_slice()).unwrap(),
+                    ),
+                    path => panic!("unexpected path {}", path),
+                };
+                assert_payload(payload)
+            })
+            .collect::<Vec<_>>();
+        types.sort_unstable();
+        assert_eq!(types, ["metrics", "traces"]);
+    }
+}
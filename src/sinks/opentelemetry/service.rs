@@ -0,0 +1,260 @@
+use std::{
+    io::Write,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use http::{
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+    Uri,
+};
+use hyper::{client::HttpConnector, Body};
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use opentelemetry_proto::proto::collector::{
+    logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+    metrics::v1::{metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest},
+    trace::v1::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+};
+use prost::Message;
+use tonic::{body::BoxBody, codec::CompressionEncoding};
+use tower::Service;
+use vector_common::request_metadata::{MetaDescriptive, RequestMetadata};
+use vector_core::{internal_event::CountByteSize, stream::DriverResponse};
+
+use super::OpentelemetrySinkError;
+use crate::{
+    event::{EventFinalizers, EventStatus, Finalizable},
+    http::HttpClient,
+    internal_events::EndpointBytesSent,
+    sinks::{
+        util::{uri, Compression, Compressor},
+        vector::HyperSvc,
+    },
+    Error,
+};
+
+/// An OTLP export request for one type of telemetry.
+#[derive(Clone, Debug)]
+pub enum OpentelemetryPayload {
+    Logs(ExportLogsServiceRequest),
+    Metrics(ExportMetricsServiceRequest),
+    Traces(ExportTraceServiceRequest),
+}
+
+impl OpentelemetryPayload {
+    pub fn encoded_len(&self) -> usize {
+        match self {
+            Self::Logs(request) => request.encoded_len(),
+            Self::Metrics(request) => request.encoded_len(),
+            Self::Traces(request) => request.encoded_len(),
+        }
+    }
+
+    fn encode_to_vec(&self) -> Vec<u8> {
+        match self {
+            Self::Logs(request) => request.encode_to_vec(),
+            Self::Metrics(request) => request.encode_to_vec(),
+            Self::Traces(request) => request.encode_to_vec(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct OpentelemetryRequest {
+    pub finalizers: EventFinalizers,
+    pub metadata: RequestMetadata,
+    pub payload: OpentelemetryPayload,
+}
+
+impl Finalizable for OpentelemetryRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for OpentelemetryRequest {
+    fn get_metadata(&self) -> RequestMetadata {
+        self.metadata
+    }
+}
+
+pub struct OpentelemetryResponse {
+    events_count: usize,
+    events_byte_size: usize,
+}
+
+impl DriverResponse for OpentelemetryResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> CountByteSize {
+        CountByteSize(self.events_count, self.events_byte_size)
+    }
+}
+
+/// The clients used to send the requests, depending on the configured protocol.
+#[derive(Clone, Debug)]
+pub enum Transport {
+    Grpc {
+        logs: LogsServiceClient<HyperSvc>,
+        metrics: MetricsServiceClient<HyperSvc>,
+        traces: TraceServiceClient<HyperSvc>,
+    },
+    Http {
+        client: HttpClient,
+        logs_uri: Uri,
+        metrics_uri: Uri,
+        traces_uri: Uri,
+        compression: bool,
+    },
+}
+
+impl Transport {
+    pub fn grpc(
+        client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
+        uri: Uri,
+        compression: bool,
+    ) -> Self {
+        let mut logs = LogsServiceClient::new(HyperSvc::new(uri.clone(), client.clone()));
+        let mut metrics = MetricsServiceClient::new(HyperSvc::new(uri.clone(), client.clone()));
+        let mut traces = TraceServiceClient::new(HyperSvc::new(uri, client));
+
+        if compression {
+            logs = logs.send_compressed(CompressionEncoding::Gzip);
+            metrics = metrics.send_compressed(CompressionEncoding::Gzip);
+            traces = traces.send_compressed(CompressionEncoding::Gzip);
+        }
+
+        Self::Grpc {
+            logs,
+            metrics,
+            traces,
+        }
+    }
+
+    pub fn http(client: HttpClient, uri: &Uri, compression: bool) -> crate::Result<Self> {
+        let base = uri.to_string();
+        let base = base.trim_end_matches('/');
+        Ok(Self::Http {
+            client,
+            logs_uri: format!("{}/v1/logs", base).parse()?,
+            metrics_uri: format!("{}/v1/metrics", base).parse()?,
+            traces_uri: format!("{}/v1/traces", base).parse()?,
+            compression,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OpentelemetryService {
+    transport: Transport,
+    protocol: String,
+    endpoint: String,
+}
+
+impl OpentelemetryService {
+    pub fn new(transport: Transport, uri: Uri) -> Self {
+        let (protocol, endpoint) = uri::protocol_endpoint(uri);
+        Self {
+            transport,
+            protocol,
+            endpoint,
+        }
+    }
+}
+
+impl Service<OpentelemetryRequest> for OpentelemetryService {
+    type Response = OpentelemetryResponse;
+    type Error = Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    // Emission of an internal event in case of errors is handled upstream by the caller.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Readiness of the gRPC clients is checked when the `export()` call happening inside
+        // `call()` is made, the same as in the `vector` sink.
+        Poll::Ready(Ok(()))
+    }
+
+    // Emission of internal events for errors and dropped events is handled upstream by the caller.
+    fn call(&mut self, request: OpentelemetryRequest) -> Self::Future {
+        let service = self.clone();
+        let byte_size = request.payload.encoded_len();
+        let events_count = request.get_metadata().event_count();
+        let events_byte_size = request.get_metadata().events_byte_size();
+
+        let future = async move {
+            match service.transport {
+                Transport::Grpc {
+                    mut logs,
+                    mut metrics,
+                    mut traces,
+                } => {
+                    let result = match request.payload {
+                        OpentelemetryPayload::Logs(request) => logs.export(request).await.map(drop),
+                        OpentelemetryPayload::Metrics(request) => {
+                            metrics.export(request).await.map(drop)
+                        }
+                        OpentelemetryPayload::Traces(request) => {
+                            traces.export(request).await.map(drop)
+                        }
+                    };
+                    result.map_err(|source| OpentelemetrySinkError::Request { source })?;
+                }
+                Transport::Http {
+                    client,
+                    logs_uri,
+                    metrics_uri,
+                    traces_uri,
+                    compression,
+                } => {
+                    let uri = match request.payload {
+                        OpentelemetryPayload::Logs(_) => logs_uri,
+                        OpentelemetryPayload::Metrics(_) => metrics_uri,
+                        OpentelemetryPayload::Traces(_) => traces_uri,
+                    };
+                    let body = request.payload.encode_to_vec();
+
+                    let mut builder =
+                        http::Request::post(uri).header(CONTENT_TYPE, "application/x-protobuf");
+                    let body = if compression {
+                        builder = builder.header(CONTENT_ENCODING, "gzip");
+                        let mut compressor = Compressor::from(Compression::gzip_default());
+                        compressor
+                            .write_all(&body)
+                            .expect("gzip writer should not fail to write to memory");
+                        compressor.into_inner().freeze()
+                    } else {
+                        Bytes::from(body)
+                    };
+                    let http_request = builder
+                        .body(Body::from(body))
+                        .map_err(|source| OpentelemetrySinkError::BuildRequest { source })?;
+
+                    let response = client
+                        .send(http_request)
+                        .await
+                        .map_err(|source| OpentelemetrySinkError::Http { source })?;
+                    let status = response.status();
+                    if !status.is_success() {
+                        return Err(OpentelemetrySinkError::Status { status }.into());
+                    }
+                }
+            }
+
+            emit!(EndpointBytesSent {
+                byte_size,
+                protocol: &service.protocol,
+                endpoint: &service.endpoint,
+            });
+            Ok(OpentelemetryResponse {
+                events_count,
+                events_byte_size,
+            })
+        };
+
+        Box::pin(future)
+    }
+}
@@ -0,0 +1,106 @@
+use std::{fmt, num::NonZeroUsize};
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use tower::Service;
+use vector_core::{
+    partition::Partitioner,
+    stream::{BatcherSettings, DriverResponse},
+    ByteSizeOf,
+};
+
+use super::service::{OpentelemetryPayload, OpentelemetryRequest};
+use crate::{
+    event::{Event, EventFinalizers, Finalizable},
+    sinks::util::{metadata::RequestMetadataBuilder, SinkBuilderExt, StreamSink},
+};
+
+/// The type of telemetry an event is exported as.
+///
+/// Each type is sent with its own export request, so events are batched by type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum TelemetryType {
+    Logs,
+    Metrics,
+    Traces,
+}
+
+struct TelemetryTypePartitioner;
+
+impl Partitioner for TelemetryTypePartitioner {
+    type Item = Event;
+    type Key = TelemetryType;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        match item {
+            Event::Log(_) => TelemetryType::Logs,
+            Event::Metric(_) => TelemetryType::Metrics,
+            Event::Trace(_) => TelemetryType::Traces,
+        }
+    }
+}
+
+pub struct OpentelemetrySink<S> {
+    pub batch_settings: BatcherSettings,
+    pub service: S,
+}
+
+impl<S> OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        input
+            .batched_partitioned(TelemetryTypePartitioner, self.batch_settings)
+            .map(|(telemetry_type, mut events)| {
+                let events_count = events.len();
+                let events_byte_size = events.size_of();
+                let finalizers = events.take_finalizers();
+                let builder =
+                    RequestMetadataBuilder::new(events_count, events_byte_size, events_byte_size);
+
+                let events = events.into_iter();
+                let payload = match telemetry_type {
+                    TelemetryType::Logs => {
+                        OpentelemetryPayload::Logs(events.map(Event::into_log).collect())
+                    }
+                    TelemetryType::Metrics => {
+                        OpentelemetryPayload::Metrics(events.map(Event::into_metric).collect())
+                    }
+                    TelemetryType::Traces => {
+                        OpentelemetryPayload::Traces(events.map(Event::into_trace).collect())
+                    }
+                };
+
+                // An empty export request encodes to zero bytes, such as when all of the metrics
+                // in a batch are of a type that OTLP can't represent.
+                let bytes_len = NonZeroUsize::new(payload.encoded_len().max(1))
+                    .expect("payload length should never be zero");
+
+                OpentelemetryRequest {
+                    finalizers,
+                    metadata: builder.with_request_size(bytes_len),
+                    payload,
+                }
+            })
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
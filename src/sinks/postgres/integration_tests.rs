@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+use futures::stream;
+use tokio_postgres::{Client, NoTls};
+use vector_core::event::{BatchNotifier, BatchStatus, BatchStatusReceiver, Event, LogEvent};
+
+use super::*;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    test_util::{
+        components::{run_and_assert_sink_compliance, SINK_TAGS},
+        random_string, trace_init,
+    },
+};
+
+fn pg_url() -> String {
+    std::env::var("PG_URL").unwrap_or_else(|_| {
+        let host = std::env::var("PG_HOST").unwrap_or_else(|_| "localhost".into());
+        format!("postgres://vector:vector@{}/postgres", host)
+    })
+}
+
+async fn connect() -> Client {
+    let (client, connection) = tokio_postgres::connect(&pg_url(), NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+fn make_events(count: usize) -> (Vec<Event>, BatchStatusReceiver) {
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = (0..count)
+        .map(|i| {
+            let mut log =
+                LogEvent::from(format!("line {}\twith a tab", i)).with_batch_notifier(&batch);
+            log.insert("count", i as i64);
+            log.insert("labels.kind", "test");
+            Event::from(log)
+        })
+        .collect();
+    (events, receiver)
+}
+
+async fn run_sink(config: PostgresConfig, events: Vec<Event>) {
+    let (sink, healthcheck) = config.build(SinkContext::new_test()).await.unwrap();
+    healthcheck.await.unwrap();
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+}
+
+#[tokio::test]
+async fn copies_events_into_table() {
+    trace_init();
+
+    let table = format!("test_{}", random_string(10).to_lowercase());
+    let client = connect().await;
+    client
+        .batch_execute(&format!(
+            r#"CREATE TABLE {} (
+                id SERIAL PRIMARY KEY,
+                message TEXT NOT NULL,
+                count BIGINT,
+                labels JSONB,
+                "timestamp" TIMESTAMPTZ
+            )"#,
+            table
+        ))
+        .await
+        .unwrap();
+
+    let config: PostgresConfig = toml::from_str(&format!(
+        r#"
+        endpoint = "{}"
+        table = "{}"
+        batch.max_events = 10
+        "#,
+        pg_url(),
+        table
+    ))
+    .unwrap();
+
+    let (events, mut receiver) = make_events(25);
+    let timestamps: Vec<DateTime<Utc>> = events
+        .iter()
+        .map(|event| {
+            *event
+                .as_log()
+                .get("timestamp")
+                .unwrap()
+                .as_timestamp()
+                .unwrap()
+        })
+        .collect();
+    run_sink(config, events).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let rows = client
+        .query(
+            &format!(
+                r#"SELECT message, count, labels->>'kind', "timestamp" FROM {} ORDER BY id"#,
+                table
+            ),
+            &[],
+        )
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 25);
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.get::<_, String>(0), format!("line {}\twith a tab", i));
+        assert_eq!(row.get::<_, i64>(1), i as i64);
+        assert_eq!(row.get::<_, String>(2), "test");
+        // Timestamps are stored with a precision of microseconds.
+        assert_eq!(
+            row.get::<_, DateTime<Utc>>(3).timestamp_micros(),
+            timestamps[i].timestamp_micros()
+        );
+    }
+}
+
+#[tokio::test]
+async fn rejects_events_for_unknown_columns() {
+    trace_init();
+
+    let table = format!("test_{}", random_string(10).to_lowercase());
+    let client = connect().await;
+    client
+        .batch_execute(&format!("CREATE TABLE {} (message TEXT)", table))
+        .await
+        .unwrap();
+
+    let config: PostgresConfig = toml::from_str(&format!(
+        r#"
+        endpoint = "{}"
+        table = "{}"
+        "#,
+        pg_url(),
+        table
+    ))
+    .unwrap();
+
+    let (events, mut receiver) = make_events(5);
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+    sink.run_events(events).await.unwrap();
+    // The table has no column for the other fields, which isn't fixed by retrying.
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+
+    let rows = client
+        .query(&format!("SELECT * FROM {}", table), &[])
+        .await
+        .unwrap();
+    assert!(rows.is_empty());
+}
+
+#[tokio::test]
+async fn only_copies_encoded_fields() {
+    trace_init();
+
+    let table = format!("test_{}", random_string(10).to_lowercase());
+    let client = connect().await;
+    client
+        .batch_execute(&format!("CREATE TABLE {} (message TEXT)", table))
+        .await
+        .unwrap();
+
+    let config: PostgresConfig = toml::from_str(&format!(
+        r#"
+        endpoint = "{}"
+        table = "{}"
+        encoding.only_fields = ["message"]
+        "#,
+        pg_url(),
+        table
+    ))
+    .unwrap();
+
+    let (events, mut receiver) = make_events(5);
+    run_sink(config, events).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let rows = client
+        .query(&format!("SELECT message FROM {}", table), &[])
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 5);
+}
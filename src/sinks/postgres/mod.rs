@@ -0,0 +1,8 @@
+mod config;
+mod service;
+mod sink;
+
+#[cfg(all(test, feature = "postgres-integration-tests"))]
+mod integration_tests;
+
+pub use self::config::PostgresConfig;
@@ -0,0 +1,173 @@
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::{future::BoxFuture, pin_mut, SinkExt};
+use postgres_openssl::MakeTlsConnector;
+use tokio::sync::Mutex;
+use tokio_postgres::{Client, Config, Error as PgError, NoTls};
+use tower::Service;
+use vector_common::{
+    internal_event::{ByteSize, BytesSent, InternalEventHandle as _, Protocol, Registered},
+    request_metadata::{MetaDescriptive, RequestMetadata},
+};
+use vector_core::{internal_event::CountByteSize, stream::DriverResponse};
+
+use super::config::quote_identifier;
+use crate::event::{EventFinalizers, EventStatus, Finalizable};
+
+/// Connects to the PostgreSQL server, with TLS if it is configured.
+#[derive(Clone)]
+pub struct Connector {
+    config: Config,
+    tls: Option<MakeTlsConnector>,
+}
+
+impl Connector {
+    pub const fn new(config: Config, tls: Option<MakeTlsConnector>) -> Self {
+        Self { config, tls }
+    }
+
+    pub async fn connect(&self) -> Result<Client, PgError> {
+        let client = match &self.tls {
+            Some(tls) => {
+                let (client, connection) = self.config.connect(tls.clone()).await?;
+                tokio::spawn(connection);
+                client
+            }
+            None => {
+                let (client, connection) = self.config.connect(NoTls).await?;
+                tokio::spawn(connection);
+                client
+            }
+        };
+        Ok(client)
+    }
+}
+
+/// A batch of rows, encoded in the text format of `COPY`.
+#[derive(Clone)]
+pub struct PostgresRequest {
+    pub finalizers: EventFinalizers,
+    pub metadata: RequestMetadata,
+    pub columns: Vec<String>,
+    pub rows: Bytes,
+}
+
+impl Finalizable for PostgresRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for PostgresRequest {
+    fn get_metadata(&self) -> RequestMetadata {
+        self.metadata
+    }
+}
+
+pub struct PostgresResponse {
+    events_count: usize,
+    events_byte_size: usize,
+}
+
+impl DriverResponse for PostgresResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> CountByteSize {
+        CountByteSize(self.events_count, self.events_byte_size)
+    }
+}
+
+#[derive(Clone)]
+pub struct PostgresService {
+    connector: Connector,
+    // The connection is made on the first request, and made again once it has been closed.
+    client: Arc<Mutex<Option<Client>>>,
+    table: String,
+    bytes_sent: Registered<BytesSent>,
+}
+
+impl PostgresService {
+    pub fn new(connector: Connector, table: String) -> Self {
+        Self {
+            connector,
+            client: Arc::new(Mutex::new(None)),
+            table,
+            bytes_sent: register!(BytesSent::from(Protocol::TCP)),
+        }
+    }
+
+    async fn copy(&self, columns: &[String], rows: Bytes) -> Result<(), PgError> {
+        let mut client = self.client.lock().await;
+        if client.as_ref().map_or(true, Client::is_closed) {
+            *client = Some(self.connector.connect().await?);
+        }
+        let client = client.as_ref().expect("client should be connected");
+
+        let statement = copy_statement(&self.table, columns);
+        let sink = client.copy_in::<_, Bytes>(statement.as_str()).await?;
+        pin_mut!(sink);
+        sink.send(rows).await?;
+        sink.as_mut().finish().await?;
+        Ok(())
+    }
+}
+
+fn copy_statement(table: &str, columns: &[String]) -> String {
+    let columns = columns
+        .iter()
+        .map(|column| quote_identifier(column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("COPY {} ({}) FROM STDIN", table, columns)
+}
+
+impl Service<PostgresRequest> for PostgresService {
+    type Response = PostgresResponse;
+    type Error = PgError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    // Emission of an internal event in case of errors is handled upstream by the caller.
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    // Emission of internal events for errors and dropped events is handled upstream by the caller.
+    fn call(&mut self, request: PostgresRequest) -> Self::Future {
+        let service = self.clone();
+        let byte_size = request.rows.len();
+        let events_count = request.get_metadata().event_count();
+        let events_byte_size = request.get_metadata().events_byte_size();
+
+        Box::pin(async move {
+            // Events without any fields have nothing to fill a row with.
+            if !request.columns.is_empty() {
+                service.copy(&request.columns, request.rows).await?;
+                service.bytes_sent.emit(ByteSize(byte_size));
+            }
+
+            Ok(PostgresResponse {
+                events_count,
+                events_byte_size,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_copy_statement() {
+        assert_eq!(
+            copy_statement(r#""logs""#, &["message".into(), "Host".into()]),
+            r#"COPY "logs" ("message", "Host") FROM STDIN"#
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::{collections::BTreeSet, fmt, num::NonZeroUsize};
+
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes, BytesMut};
+use chrono::SecondsFormat;
+use futures::{stream::BoxStream, StreamExt};
+use tower::Service;
+use value::Value;
+use vector_core::{
+    stream::{BatcherSettings, DriverResponse},
+    ByteSizeOf,
+};
+
+use super::service::PostgresRequest;
+use crate::{
+    codecs::Transformer,
+    event::{Event, Finalizable, LogEvent},
+    sinks::util::{metadata::RequestMetadataBuilder, SinkBuilderExt, StreamSink},
+};
+
+pub struct PostgresSink<S> {
+    pub transformer: Transformer,
+    pub batch_settings: BatcherSettings,
+    pub service: S,
+}
+
+impl<S> PostgresSink<S>
+where
+    S: Service<PostgresRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let transformer = self.transformer;
+
+        input
+            .map(move |mut event| {
+                transformer.transform(&mut event);
+                event.into_log()
+            })
+            .batched(self.batch_settings.into_byte_size_config())
+            .map(|mut logs| {
+                let events_count = logs.len();
+                let events_byte_size = logs.size_of();
+                let finalizers = logs.take_finalizers();
+                let builder =
+                    RequestMetadataBuilder::new(events_count, events_byte_size, events_byte_size);
+
+                let (columns, rows) = encode_rows(&logs);
+                let bytes_len =
+                    NonZeroUsize::new(rows.len().max(1)).expect("payload should never be zero");
+
+                PostgresRequest {
+                    finalizers,
+                    metadata: builder.with_request_size(bytes_len),
+                    columns,
+                    rows,
+                }
+            })
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for PostgresSink<S>
+where
+    S: Service<PostgresRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
+
+/// Encodes the events as rows in the text format of `COPY`, returning the columns they are
+/// written to along with the rows.
+///
+/// The columns are the top-level fields found in any of the events, and the rows of the events
+/// that are missing one of these fields are `NULL` in its column.
+fn encode_rows(logs: &[LogEvent]) -> (Vec<String>, Bytes) {
+    let columns = logs
+        .iter()
+        .filter_map(LogEvent::as_map)
+        .flat_map(|fields| fields.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut rows = BytesMut::new();
+    for log in logs {
+        for (index, column) in columns.iter().enumerate() {
+            if index > 0 {
+                rows.put_u8(b'\t');
+            }
+            match log.as_map().and_then(|fields| fields.get(column)) {
+                Some(value) => encode_value(value, &mut rows),
+                None => rows.put_slice(b"\\N"),
+            }
+        }
+        rows.put_u8(b'\n');
+    }
+
+    (columns, rows.freeze())
+}
+
+/// Encodes a value in the text format of `COPY`, which the server parses into the type of its
+/// column.
+///
+/// <https://www.postgresql.org/docs/current/sql-copy.html#id-1.9.3.55.9.2>
+fn encode_value(value: &Value, buf: &mut BytesMut) {
+    match value {
+        Value::Null => buf.put_slice(b"\\N"),
+        Value::Bytes(bytes) => encode_text(&String::from_utf8_lossy(bytes), buf),
+        Value::Integer(integer) => buf.put_slice(integer.to_string().as_bytes()),
+        Value::Float(float) => buf.put_slice(float.to_string().as_bytes()),
+        Value::Boolean(boolean) => buf.put_slice(boolean.to_string().as_bytes()),
+        Value::Timestamp(timestamp) => buf.put_slice(
+            timestamp
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                .as_bytes(),
+        ),
+        Value::Regex(regex) => encode_text(regex.as_str(), buf),
+        // Objects and arrays are written as JSON, for `json` and `jsonb` columns.
+        Value::Object(_) | Value::Array(_) => encode_text(
+            &serde_json::to_string(value).expect("values should be valid JSON"),
+            buf,
+        ),
+    }
+}
+
+fn encode_text(text: &str, buf: &mut BytesMut) {
+    for c in text.chars() {
+        match c {
+            '\\' => buf.put_slice(b"\\\\"),
+            '\n' => buf.put_slice(b"\\n"),
+            '\r' => buf.put_slice(b"\\r"),
+            '\t' => buf.put_slice(b"\\t"),
+            c => {
+                let mut utf8 = [0; 4];
+                buf.put_slice(c.encode_utf8(&mut utf8).as_bytes());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn encodes_rows() {
+        let mut first = LogEvent::default();
+        first.insert("message", "first\tline");
+        first.insert("count", 1);
+        let mut second = LogEvent::default();
+        second.insert("message", "second\\line\n");
+        second.insert("host", "my-host");
+
+        let (columns, rows) = encode_rows(&[first, second]);
+
+        assert_eq!(columns, ["count", "host", "message"]);
+        assert_eq!(
+            rows,
+            "1\t\\N\tfirst\\tline\n\\N\tmy-host\tsecond\\\\line\\n\n"
+        );
+    }
+
+    #[test]
+    fn encodes_values() {
+        let encode = |value: Value| {
+            let mut buf = BytesMut::new();
+            encode_value(&value, &mut buf);
+            buf.freeze()
+        };
+
+        assert_eq!(encode(Value::Null), "\\N");
+        assert_eq!(encode(Value::from(-4.5)), "-4.5");
+        assert_eq!(encode(Value::from(true)), "true");
+        assert_eq!(
+            encode(Value::from(
+                Utc.ymd(2022, 11, 14).and_hms_milli(10, 30, 0, 123)
+            )),
+            "2022-11-14T10:30:00.123Z"
+        );
+        assert_eq!(
+            encode(Value::from(vec![Value::from("a\tb"), Value::from(1)])),
+            "[\"a\\\\tb\",1]"
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::task::{Context, Poll};
 
 use bytes::{Bytes, BytesMut};
 use futures::{future::BoxFuture, stream, FutureExt, SinkExt, StreamExt};
-use redis::{aio::ConnectionManager, RedisError, RedisResult};
+use redis::{aio::ConnectionManager, streams::StreamMaxlen, RedisError, RedisResult};
 use snafu::{ResultExt, Snafu};
 use tokio_util::codec::Encoder as _;
 use tower::{Service, ServiceBuilder};
@@ -53,6 +53,11 @@ pub enum DataTypeConfig {
     ///
     /// Redis channels function in a pub/sub fashion, allowing many-to-many broadcasting and receiving.
     Channel,
+
+    /// The Redis `stream` type.
+    ///
+    /// Redis streams are append-only logs, which can be read by consumer groups.
+    Stream,
 }
 
 /// List-specific options.
@@ -64,7 +69,35 @@ pub struct ListOption {
     method: Method,
 }
 
-#[derive(Clone, Copy, Debug, Derivative)]
+/// Stream-specific options.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields)]
+pub struct StreamOption {
+    /// The field of stream entries to store messages in.
+    #[serde(default = "default_stream_field")]
+    #[derivative(Default(value = "default_stream_field()"))]
+    field: String,
+
+    /// The maximum length to trim the stream to when adding entries.
+    ///
+    /// By default, the stream is not trimmed.
+    max_len: Option<usize>,
+
+    /// Whether the stream can be trimmed to about `max_len` entries, rather than exactly.
+    ///
+    /// Approximate trimming only removes whole nodes of the stream, which is much more efficient.
+    #[serde(default = "crate::serde::default_true")]
+    #[derivative(Default(value = "true"))]
+    approximate: bool,
+}
+
+fn default_stream_field() -> String {
+    "message".into()
+}
+
+#[derive(Clone, Debug, Derivative)]
 #[derivative(Default)]
 pub enum DataType {
     /// The Redis `list` type.
@@ -77,6 +110,14 @@ pub enum DataType {
     ///
     /// Redis channels function in a pub/sub fashion, allowing many-to-many broadcasting and receiving.
     Channel,
+
+    /// The Redis `stream` type.
+    ///
+    /// Redis streams are append-only logs, trimmed to `max_len` entries when set.
+    Stream {
+        field: String,
+        max_len: Option<StreamMaxlen>,
+    },
 }
 
 /// Method for pushing messages into a `list`.
@@ -124,6 +165,10 @@ pub struct RedisSinkConfig {
     #[serde(alias = "list")]
     list_option: Option<ListOption>,
 
+    #[configurable(derived)]
+    #[serde(alias = "stream")]
+    stream_option: Option<StreamOption>,
+
     /// The Redis URL to connect to.
     ///
     /// The URL _must_ take the form of `protocol://server:port/db` where the protocol can either be
@@ -209,6 +254,19 @@ impl RedisSinkConfig {
         let data_type = match self.data_type {
             DataTypeConfig::Channel => DataType::Channel,
             DataTypeConfig::List => DataType::List(method.unwrap_or_default()),
+            DataTypeConfig::Stream => {
+                let option = self.stream_option.clone().unwrap_or_default();
+                DataType::Stream {
+                    field: option.field,
+                    max_len: option.max_len.map(|max_len| {
+                        if option.approximate {
+                            StreamMaxlen::Approx(max_len)
+                        } else {
+                            StreamMaxlen::Equals(max_len)
+                        }
+                    }),
+                }
+            }
         };
 
         let batch = self.batch.into_batch_settings()?;
@@ -356,7 +414,7 @@ impl Service<Vec<RedisKvEntry>> for RedisSink {
 
         for kv in kvs {
             byte_size += kv.encoded_length();
-            match self.data_type {
+            match &self.data_type {
                 DataType::List(method) => match method {
                     Method::LPush => {
                         if count > 1 {
@@ -380,6 +438,18 @@ impl Service<Vec<RedisKvEntry>> for RedisSink {
                         pipe.publish(kv.key, kv.value.as_ref());
                     }
                 }
+                DataType::Stream { field, max_len } => {
+                    if count > 1 {
+                        pipe.atomic();
+                    }
+                    let items = [(field.as_str(), kv.value.as_ref())];
+                    // `XADD` replies with the ID of the new entry rather than a status, so its reply is
+                    // left out of the results. Errors are still returned.
+                    match max_len {
+                        Some(max_len) => pipe.xadd_maxlen(kv.key, *max_len, "*", &items).ignore(),
+                        None => pipe.xadd(kv.key, "*", &items).ignore(),
+                    };
+                }
             }
         }
 
@@ -506,6 +576,7 @@ mod integration_tests {
             list_option: Some(ListOption {
                 method: Method::LPush,
             }),
+            stream_option: None,
             batch: BatchConfig::default(),
             request: TowerRequestConfig {
                 rate_limit_num: Option::from(u64::MAX),
@@ -569,6 +640,7 @@ mod integration_tests {
             list_option: Some(ListOption {
                 method: Method::RPush,
             }),
+            stream_option: None,
             batch: BatchConfig::default(),
             request: TowerRequestConfig {
                 rate_limit_num: Option::from(u64::MAX),
@@ -646,6 +718,7 @@ mod integration_tests {
             encoding: JsonSerializerConfig::new().into(),
             data_type: DataTypeConfig::Channel,
             list_option: None,
+            stream_option: None,
             batch: BatchConfig::default(),
             request: TowerRequestConfig {
                 rate_limit_num: Option::from(u64::MAX),
@@ -676,4 +749,58 @@ mod integration_tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn redis_sink_stream() {
+        trace_init();
+
+        let key = Template::try_from(format!("test-{}", random_string(10)))
+            .expect("should not fail to create key template");
+        debug!("Test key name: {}.", key);
+        let num_events = 100;
+
+        let cnf = RedisSinkConfig {
+            url: redis_server(),
+            key: key.clone(),
+            encoding: JsonSerializerConfig::new().into(),
+            data_type: DataTypeConfig::Stream,
+            list_option: None,
+            stream_option: Some(StreamOption {
+                max_len: Some(10),
+                approximate: false,
+                ..Default::default()
+            }),
+            batch: BatchConfig::default(),
+            request: TowerRequestConfig {
+                rate_limit_num: Option::from(u64::MAX),
+                ..Default::default()
+            },
+            acknowledgements: Default::default(),
+        };
+
+        let events: Vec<Event> = (0..num_events)
+            .map(|i| LogEvent::from(i.to_string()).into())
+            .collect();
+        let input = stream::iter(events.clone().into_iter().map(Into::into));
+
+        // Publish events.
+        let cnf2 = cnf.clone();
+        assert_sink_compliance(&SINK_TAGS, async move {
+            let conn = cnf2.build_client().await.unwrap();
+            cnf2.new(conn).unwrap().run(input).await
+        })
+        .await
+        .expect("Running sink failed");
+
+        let mut conn = cnf.build_client().await.unwrap();
+
+        // The stream is trimmed to the last 10 entries.
+        let reply: redis::streams::StreamRangeReply =
+            conn.xrange_all(key.to_string()).await.unwrap();
+        assert_eq!(reply.ids.len(), 10);
+        for (entry, event) in reply.ids.iter().zip(&events[num_events - 10..]) {
+            let message: String = entry.get("message").unwrap();
+            assert_eq!(message, serde_json::to_string(event.as_log()).unwrap());
+        }
+    }
 }
@@ -0,0 +1,379 @@
+use bytes::BytesMut;
+use codecs::{
+    encoding::{Framer, FramingConfig},
+    SyslogSerializerConfig, SyslogSerializerOptions,
+};
+use vector_config::configurable_component;
+
+#[cfg(unix)]
+use crate::sinks::util::unix::UnixSinkConfig;
+use crate::{
+    codecs::{Encoder, Transformer},
+    config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
+    event::Event,
+    internal_events::TemplateRenderingError,
+    sinks::util::{tcp::TcpSinkConfig, udp::UdpSinkConfig},
+    template::Template,
+};
+
+/// Configuration for the `syslog` sink.
+#[configurable_component(sink("syslog"))]
+#[derive(Clone, Debug)]
+pub struct SyslogSinkConfig {
+    #[serde(flatten)]
+    mode: Mode,
+
+    #[serde(flatten)]
+    syslog: SyslogSerializerOptions,
+
+    /// The facility to send events with, either as a code or as a name such as `local0`.
+    ///
+    /// This can be a template, and takes precedence over the field set by `facility_field`.
+    facility: Option<Template>,
+
+    /// The severity to send events with, either as a code or as a name such as `warning`.
+    ///
+    /// This can be a template, and takes precedence over the field set by `severity_field`.
+    severity: Option<Template>,
+
+    /// The application name to send events with.
+    ///
+    /// This can be a template, and takes precedence over the field set by `appname_field`.
+    app_name: Option<Template>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    encoding: Transformer,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    acknowledgements: AcknowledgementsConfig,
+}
+
+/// Connection mode for the `syslog` sink.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum Mode {
+    /// Send over TCP.
+    Tcp(#[configurable(transparent)] TcpMode),
+
+    /// Send over UDP.
+    Udp(#[configurable(transparent)] UdpMode),
+
+    /// Send over UDS. (Unix domain socket)
+    #[cfg(unix)]
+    Unix(#[configurable(transparent)] UnixMode),
+}
+
+/// TCP configuration.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct TcpMode {
+    #[serde(flatten)]
+    config: TcpSinkConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    framing: SyslogFraming,
+}
+
+/// UDP configuration.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct UdpMode {
+    #[serde(flatten)]
+    config: UdpSinkConfig,
+}
+
+/// Unix Domain Socket configuration.
+#[cfg(unix)]
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct UnixMode {
+    #[serde(flatten)]
+    config: UnixSinkConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    framing: SyslogFraming,
+}
+
+/// The framing used to delimit messages sent over a stream.
+///
+/// Messages sent over UDP are never framed, as each datagram holds a single message.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SyslogFraming {
+    /// Each message is prefixed with its length, as described in [RFC 6587][rfc6587].
+    ///
+    /// [rfc6587]: https://datatracker.ietf.org/doc/html/rfc6587#section-3.4.1
+    OctetCounting,
+
+    /// Each message is followed by a newline.
+    NewlineDelimited,
+}
+
+impl Default for SyslogFraming {
+    fn default() -> Self {
+        Self::OctetCounting
+    }
+}
+
+impl From<SyslogFraming> for FramingConfig {
+    fn from(framing: SyslogFraming) -> Self {
+        match framing {
+            SyslogFraming::OctetCounting => FramingConfig::OctetCounting,
+            SyslogFraming::NewlineDelimited => FramingConfig::NewlineDelimited,
+        }
+    }
+}
+
+impl GenerateConfig for SyslogSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"address = "127.0.0.1:514"
+            mode = "tcp""#,
+        )
+        .unwrap()
+    }
+}
+
+impl SyslogSinkConfig {
+    fn build_encoder<F>(&self, encoder: Encoder<F>) -> SyslogEncoder<F>
+    where
+        F: Clone,
+    {
+        SyslogEncoder {
+            options: self.syslog.clone(),
+            facility: self.facility.clone(),
+            severity: self.severity.clone(),
+            app_name: self.app_name.clone(),
+            transformer: self.encoding.clone(),
+            encoder,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SinkConfig for SyslogSinkConfig {
+    async fn build(
+        &self,
+        _cx: SinkContext,
+    ) -> crate::Result<(super::VectorSink, super::Healthcheck)> {
+        let serializer = SyslogSerializerConfig::new(self.syslog.clone()).build();
+
+        // The transformer is applied by the encoder, as the templates need to be rendered first.
+        match &self.mode {
+            Mode::Tcp(TcpMode { config, framing }) => {
+                let framer = FramingConfig::from(*framing).build();
+                let encoder = Encoder::<Framer>::new(framer, serializer.into());
+                config.build(Transformer::default(), self.build_encoder(encoder))
+            }
+            Mode::Udp(UdpMode { config }) => {
+                let encoder = Encoder::<()>::new(serializer.into());
+                config.build(Transformer::default(), self.build_encoder(encoder))
+            }
+            #[cfg(unix)]
+            Mode::Unix(UnixMode { config, framing }) => {
+                let framer = FramingConfig::from(*framing).build();
+                let encoder = Encoder::<Framer>::new(framer, serializer.into());
+                config.build(Transformer::default(), self.build_encoder(encoder))
+            }
+        }
+    }
+
+    fn input(&self) -> Input {
+        Input::new(DataType::Log)
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SyslogEncoder<F>
+where
+    F: Clone,
+{
+    options: SyslogSerializerOptions,
+    facility: Option<Template>,
+    severity: Option<Template>,
+    app_name: Option<Template>,
+    transformer: Transformer,
+    encoder: Encoder<F>,
+}
+
+impl<F> SyslogEncoder<F>
+where
+    F: Clone,
+{
+    fn render(template: &Option<Template>, event: &Event, field: &'static str) -> Option<String> {
+        template.as_ref().and_then(|template| {
+            template
+                .render_string(event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some(field),
+                        drop_event: false,
+                    })
+                })
+                .ok()
+        })
+    }
+}
+
+impl<F> tokio_util::codec::Encoder<Event> for SyslogEncoder<F>
+where
+    F: Clone,
+    Encoder<F>: tokio_util::codec::Encoder<Event, Error = codecs::encoding::Error>,
+{
+    type Error = codecs::encoding::Error;
+
+    fn encode(&mut self, mut event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let facility = Self::render(&self.facility, &event, "facility");
+        let severity = Self::render(&self.severity, &event, "severity");
+        let app_name = Self::render(&self.app_name, &event, "app_name");
+
+        self.transformer.transform(&mut event);
+
+        let log = event.as_mut_log();
+        for (field, value) in [
+            (&self.options.facility_field, facility),
+            (&self.options.severity_field, severity),
+            (&self.options.appname_field, app_name),
+        ] {
+            if let Some(value) = value {
+                log.insert(field.as_str(), value);
+            }
+        }
+
+        self.encoder.encode(event, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::ready, net::UdpSocket};
+
+    use chrono::{TimeZone, Utc};
+    use futures_util::stream;
+    use tokio_util::codec::Encoder as _;
+
+    use super::*;
+    use crate::{
+        event::LogEvent,
+        test_util::{
+            components::{assert_sink_compliance, SINK_TAGS},
+            next_addr, trace_init,
+        },
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<SyslogSinkConfig>();
+    }
+
+    fn config(toml: &str) -> SyslogSinkConfig {
+        toml::from_str(toml).unwrap()
+    }
+
+    fn encode(config: &SyslogSinkConfig, event: Event) -> String {
+        let serializer = SyslogSerializerConfig::new(config.syslog.clone()).build();
+        let framer = FramingConfig::from(SyslogFraming::OctetCounting).build();
+        let mut encoder = config.build_encoder(Encoder::<Framer>::new(framer, serializer.into()));
+
+        let mut buffer = BytesMut::new();
+        encoder.encode(event, &mut buffer).unwrap();
+        String::from_utf8(buffer.to_vec()).unwrap()
+    }
+
+    fn event() -> Event {
+        let mut log = LogEvent::from("hello");
+        log.insert("timestamp", Utc.ymd(2022, 11, 14).and_hms(10, 30, 0));
+        log.insert("hostname", "host");
+        log.insert("appname", "app");
+        log.insert("severity", "err");
+        log.insert("level", "warning");
+        Event::from(log)
+    }
+
+    #[test]
+    fn encodes_fields_with_octet_counting() {
+        let config = config(
+            r#"
+            mode = "tcp"
+            address = "127.0.0.1:514"
+            "#,
+        );
+
+        assert_eq!(
+            encode(&config, event()),
+            "47 <11>1 2022-11-14T10:30:00Z host app - - - hello"
+        );
+    }
+
+    #[test]
+    fn templates_take_precedence_over_fields() {
+        let mut config = config(
+            r#"
+            mode = "tcp"
+            address = "127.0.0.1:514"
+            rfc = "rfc3164"
+            "#,
+        );
+        config.facility = Some(Template::try_from("local0").unwrap());
+        config.severity = Some(Template::try_from("{{ level }}").unwrap());
+        config.app_name = Some(Template::try_from("vector").unwrap());
+        config.encoding = Transformer::new(None, Some(vec!["level".into()]), None).unwrap();
+
+        assert_eq!(
+            encode(&config, event()),
+            "39 <132>Nov 14 10:30:00 host vector: hello"
+        );
+    }
+
+    #[tokio::test]
+    async fn udp() {
+        trace_init();
+
+        let addr = next_addr();
+        let receiver = UdpSocket::bind(addr).unwrap();
+
+        let config = config(&format!(
+            r#"
+            mode = "udp"
+            address = "{}"
+            "#,
+            addr
+        ));
+
+        let context = SinkContext::new_test();
+        assert_sink_compliance(&SINK_TAGS, async move {
+            let (sink, _healthcheck) = config.build(context).await.unwrap();
+            sink.run(stream::once(ready(event().into()))).await
+        })
+        .await
+        .expect("Running sink failed");
+
+        let mut buf = [0; 256];
+        let (size, _src_addr) = receiver
+            .recv_from(&mut buf)
+            .expect("Did not receive message");
+
+        assert_eq!(
+            &buf[..size],
+            b"<11>1 2022-11-14T10:30:00Z host app - - - hello"
+        );
+    }
+}
@@ -30,6 +30,18 @@ pub enum Compression {
     ///
     /// [zlib]: https://en.wikipedia.org/wiki/Zlib
     Zlib(CompressionLevel),
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd(CompressionLevel),
+
+    /// [Snappy][snappy] compression.
+    ///
+    /// The payload is compressed as a single block, using the raw (unframed) Snappy format.
+    ///
+    /// [snappy]: https://github.com/google/snappy/blob/main/docs/README.md
+    Snappy,
 }
 
 impl Compression {
@@ -53,11 +65,17 @@ impl Compression {
         Compression::Zlib(CompressionLevel::const_default())
     }
 
+    pub const fn zstd_default() -> Compression {
+        Compression::Zstd(CompressionLevel::const_default())
+    }
+
     pub const fn content_encoding(self) -> Option<&'static str> {
         match self {
             Self::None => None,
             Self::Gzip(_) => Some("gzip"),
             Self::Zlib(_) => Some("deflate"),
+            Self::Zstd(_) => Some("zstd"),
+            Self::Snappy => Some("snappy"),
         }
     }
 
@@ -66,12 +84,14 @@ impl Compression {
             Self::None => "log",
             Self::Gzip(_) => "log.gz",
             Self::Zlib(_) => "log.zz",
+            Self::Zstd(_) => "log.zst",
+            Self::Snappy => "log.snappy",
         }
     }
 
     pub const fn level(self) -> flate2::Compression {
         match self {
-            Self::None => flate2::Compression::none(),
+            Self::None | Self::Zstd(_) | Self::Snappy => flate2::Compression::none(),
             Self::Gzip(level) | Self::Zlib(level) => level.as_flate2(),
         }
     }
@@ -83,6 +103,8 @@ impl fmt::Display for Compression {
             Compression::None => write!(f, "none"),
             Compression::Gzip(ref level) => write!(f, "gzip({})", level.as_flate2().level()),
             Compression::Zlib(ref level) => write!(f, "zlib({})", level.as_flate2().level()),
+            Compression::Zstd(ref level) => write!(f, "zstd({})", level.as_zstd()),
+            Compression::Snappy => write!(f, "snappy"),
         }
     }
 }
@@ -109,9 +131,11 @@ impl<'de> de::Deserialize<'de> for Compression {
                     "none" => Ok(Compression::None),
                     "gzip" => Ok(Compression::gzip_default()),
                     "zlib" => Ok(Compression::zlib_default()),
+                    "zstd" => Ok(Compression::zstd_default()),
+                    "snappy" => Ok(Compression::Snappy),
                     _ => Err(de::Error::invalid_value(
                         de::Unexpected::Str(s),
-                        &r#""none" or "gzip" or "zlib""#,
+                        &r#""none", "gzip", "zlib", "zstd" or "snappy""#,
                     )),
                 }
             }
@@ -149,11 +173,16 @@ impl<'de> de::Deserialize<'de> for Compression {
                         Some(_) => Err(de::Error::unknown_field("level", &[])),
                         None => Ok(Compression::None),
                     },
-                    "gzip" => Ok(Compression::Gzip(level.unwrap_or_default())),
-                    "zlib" => Ok(Compression::Zlib(level.unwrap_or_default())),
+                    "gzip" => Ok(Compression::Gzip(flate2_level(level)?)),
+                    "zlib" => Ok(Compression::Zlib(flate2_level(level)?)),
+                    "zstd" => Ok(Compression::Zstd(level.unwrap_or_default())),
+                    "snappy" => match level {
+                        Some(_) => Err(de::Error::unknown_field("level", &[])),
+                        None => Ok(Compression::Snappy),
+                    },
                     algorithm => Err(de::Error::unknown_variant(
                         algorithm,
-                        &["none", "gzip", "zlib"],
+                        &["none", "gzip", "zlib", "zstd", "snappy"],
                     )),
                 }
             }
@@ -163,6 +192,17 @@ impl<'de> de::Deserialize<'de> for Compression {
     }
 }
 
+/// Checks that a level is within the range supported by gzip and zlib.
+fn flate2_level<E: de::Error>(level: Option<CompressionLevel>) -> Result<CompressionLevel, E> {
+    match level.unwrap_or_default() {
+        CompressionLevel::Val(level) if level > 9 => Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(u64::from(level)),
+            &"0, 1, 2, 3, 4, 5, 6, 7, 8 or 9",
+        )),
+        level => Ok(level),
+    }
+}
+
 impl ser::Serialize for Compression {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -182,6 +222,11 @@ impl ser::Serialize for Compression {
                 map.serialize_entry("algorithm", "zlib")?;
                 level = Some(*zlib_level);
             }
+            Compression::Zstd(zstd_level) => {
+                map.serialize_entry("algorithm", "zstd")?;
+                level = Some(*zstd_level);
+            }
+            Compression::Snappy => map.serialize_entry("algorithm", "snappy")?,
         }
 
         // If there's a level present, and it's _not_ the default compression level, then serialize it. We already
@@ -217,9 +262,11 @@ impl Configurable for Compression {
         const NONE_NAME: &str = "none";
         const GZIP_NAME: &str = "gzip";
         const ZLIB_NAME: &str = "zlib";
+        const ZSTD_NAME: &str = "zstd";
+        const SNAPPY_NAME: &str = "snappy";
 
         // First, we need to be able to handle all of the string-only variants.
-        let const_values = [NONE_NAME, GZIP_NAME, ZLIB_NAME]
+        let const_values = [NONE_NAME, GZIP_NAME, ZLIB_NAME, ZSTD_NAME, SNAPPY_NAME]
             .iter()
             .map(|s| serde_json::Value::from(*s))
             .collect();
@@ -269,9 +316,9 @@ impl Configurable for Compression {
             ALGORITHM_NAME.to_string(),
             generate_const_string_schema(ZLIB_NAME.to_string()),
         );
-        zlib_properties.insert(LEVEL_NAME.to_string(), compression_level_schema);
+        zlib_properties.insert(LEVEL_NAME.to_string(), compression_level_schema.clone());
 
-        let mut zlib_schema = generate_struct_schema(zlib_properties, required, None);
+        let mut zlib_schema = generate_struct_schema(zlib_properties, required.clone(), None);
         let mut zlib_metadata = Metadata::<()>::with_title("[Zlib]][zlib] compression.");
         zlib_metadata.set_description("[zlib]: https://en.wikipedia.org/wiki/Zlib");
         zlib_metadata.add_custom_attribute(CustomAttribute::KeyValue {
@@ -280,6 +327,38 @@ impl Configurable for Compression {
         });
         apply_metadata(&mut zlib_schema, zlib_metadata);
 
+        // Build the Zstd schema.
+        let mut zstd_properties = IndexMap::new();
+        zstd_properties.insert(
+            ALGORITHM_NAME.to_string(),
+            generate_const_string_schema(ZSTD_NAME.to_string()),
+        );
+        zstd_properties.insert(LEVEL_NAME.to_string(), compression_level_schema);
+
+        let mut zstd_schema = generate_struct_schema(zstd_properties, required, None);
+        let mut zstd_metadata = Metadata::<()>::with_title("[Zstandard][zstd] compression.");
+        zstd_metadata.set_description("[zstd]: https://facebook.github.io/zstd/");
+        zstd_metadata.add_custom_attribute(CustomAttribute::KeyValue {
+            key: "logical_name".to_string(),
+            value: "Zstd".to_string(),
+        });
+        apply_metadata(&mut zstd_schema, zstd_metadata);
+
+        // Build the Snappy schema.
+        let mut snappy_schema = generate_internal_tagged_variant_schema(
+            ALGORITHM_NAME.to_string(),
+            SNAPPY_NAME.to_string(),
+        );
+        let mut snappy_metadata = Metadata::<()>::with_title("[Snappy][snappy] compression.");
+        snappy_metadata.set_description(
+            "The payload is compressed as a single block, using the raw (unframed) Snappy format.\n\n[snappy]: https://github.com/google/snappy/blob/main/docs/README.md",
+        );
+        snappy_metadata.add_custom_attribute(CustomAttribute::KeyValue {
+            key: "logical_name".to_string(),
+            value: "Snappy".to_string(),
+        });
+        apply_metadata(&mut snappy_schema, snappy_metadata);
+
         Ok(generate_one_of_schema(&[
             // Handle the condensed string form.
             generate_enum_schema(const_values),
@@ -287,38 +366,80 @@ impl Configurable for Compression {
             none_schema,
             gzip_schema,
             zlib_schema,
+            zstd_schema,
+            snappy_schema,
         ]))
     }
 }
 
 /// Compression level.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-pub struct CompressionLevel(flate2::Compression);
+///
+/// The numeric range of a level depends on the algorithm: gzip and zlib support levels 0 to 9,
+/// while zstd supports levels up to 21.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompressionLevel {
+    /// No compression, or the fastest level for algorithms that always compress.
+    None,
+
+    /// The default level of the algorithm.
+    Default,
+
+    /// The best, and slowest, level of the algorithm.
+    Best,
+
+    /// The fastest level of the algorithm.
+    Fast,
+
+    /// A specific numeric level.
+    Val(u32),
+}
+
+impl Default for CompressionLevel {
+    fn default() -> Self {
+        Self::const_default()
+    }
+}
 
 impl CompressionLevel {
     #[cfg(test)]
     const fn new(level: u32) -> Self {
-        Self(flate2::Compression::new(level))
+        Self::Val(level)
     }
 
     const fn const_default() -> Self {
-        Self(flate2::Compression::new(6))
+        Self::Default
     }
 
     const fn none() -> Self {
-        Self(flate2::Compression::none())
+        Self::None
     }
 
     const fn best() -> Self {
-        Self(flate2::Compression::best())
+        Self::Best
     }
 
     const fn fast() -> Self {
-        Self(flate2::Compression::fast())
+        Self::Fast
     }
 
     pub const fn as_flate2(self) -> flate2::Compression {
-        self.0
+        match self {
+            Self::None => flate2::Compression::none(),
+            Self::Default => flate2::Compression::new(6),
+            Self::Best => flate2::Compression::best(),
+            Self::Fast => flate2::Compression::fast(),
+            Self::Val(level) => flate2::Compression::new(level),
+        }
+    }
+
+    pub const fn as_zstd(self) -> i32 {
+        match self {
+            // Zstandard always compresses, so the closest to no compression is the fastest level.
+            Self::None | Self::Fast => 1,
+            Self::Default => zstd::DEFAULT_COMPRESSION_LEVEL,
+            Self::Best => 21,
+            Self::Val(level) => level as i32,
+        }
     }
 }
 
@@ -360,7 +481,7 @@ impl<'de> de::Deserialize<'de> for CompressionLevel {
             {
                 Err(de::Error::invalid_value(
                     de::Unexpected::Other(&v.to_string()),
-                    &"0, 1, 2, 3, 4, 5, 6, 7, 8 or 9",
+                    &"an integer between 0 and 21",
                 ))
             }
 
@@ -368,12 +489,12 @@ impl<'de> de::Deserialize<'de> for CompressionLevel {
             where
                 E: de::Error,
             {
-                if v <= 9 {
-                    Ok(CompressionLevel(flate2::Compression::new(v as u32)))
+                if v <= 21 {
+                    Ok(CompressionLevel::Val(v as u32))
                 } else {
                     return Err(de::Error::invalid_value(
                         de::Unexpected::Unsigned(v),
-                        &"0, 1, 2, 3, 4, 5, 6, 7, 8 or 9",
+                        &"an integer between 0 and 21",
                     ));
                 }
             }
@@ -388,15 +509,12 @@ impl ser::Serialize for CompressionLevel {
     where
         S: ser::Serializer,
     {
-        const NONE: CompressionLevel = CompressionLevel::none();
-        const FAST: CompressionLevel = CompressionLevel::fast();
-        const BEST: CompressionLevel = CompressionLevel::best();
-
         match *self {
-            NONE => serializer.serialize_str("none"),
-            FAST => serializer.serialize_str("fast"),
-            BEST => serializer.serialize_str("best"),
-            level => serializer.serialize_u64(u64::from(level.0.level())),
+            CompressionLevel::None => serializer.serialize_str("none"),
+            CompressionLevel::Default => serializer.serialize_str("default"),
+            CompressionLevel::Fast => serializer.serialize_str("fast"),
+            CompressionLevel::Best => serializer.serialize_str("best"),
+            CompressionLevel::Val(level) => serializer.serialize_u64(u64::from(level)),
         }
     }
 }
@@ -418,7 +536,7 @@ impl Configurable for CompressionLevel {
             .iter()
             .map(|s| serde_json::Value::from(*s));
 
-        let level_consts = (0u32..=9).map(serde_json::Value::from);
+        let level_consts = (0u32..=21).map(serde_json::Value::from);
 
         let valid_values = string_consts.chain(level_consts).collect();
         Ok(generate_enum_schema(valid_values))
@@ -466,6 +584,20 @@ mod test {
                 r#"{"algorithm": "zlib", "level": 8}"#,
                 Compression::Zlib(CompressionLevel::new(8)),
             ),
+            (
+                r#""zstd""#,
+                Compression::Zstd(CompressionLevel::const_default()),
+            ),
+            (
+                r#"{"algorithm": "zstd", "level": 19}"#,
+                Compression::Zstd(CompressionLevel::new(19)),
+            ),
+            (
+                r#"{"algorithm": "zstd", "level": "fast"}"#,
+                Compression::Zstd(CompressionLevel::fast()),
+            ),
+            (r#""snappy""#, Compression::Snappy),
+            (r#"{"algorithm": "snappy"}"#, Compression::Snappy),
         ];
         for (sources, result) in fixtures_valid.iter() {
             let deserialized: Result<Compression, _> = serde_json::from_str(sources);
@@ -479,11 +611,11 @@ mod test {
             ),
             (
                 r#""b42""#,
-                r#"invalid value: string "b42", expected "none" or "gzip" or "zlib" at line 1 column 5"#,
+                r#"invalid value: string "b42", expected "none", "gzip", "zlib", "zstd" or "snappy" at line 1 column 5"#,
             ),
             (
                 r#"{"algorithm": "b42"}"#,
-                r#"unknown variant `b42`, expected one of `none`, `gzip`, `zlib` at line 1 column 20"#,
+                r#"unknown variant `b42`, expected one of `none`, `gzip`, `zlib`, `zstd`, `snappy` at line 1 column 20"#,
             ),
             (
                 r#"{"algorithm": "none", "level": "default"}"#,
@@ -491,7 +623,19 @@ mod test {
             ),
             (
                 r#"{"algorithm": "gzip", "level": -1}"#,
-                r#"invalid value: -1, expected 0, 1, 2, 3, 4, 5, 6, 7, 8 or 9 at line 1 column 33"#,
+                r#"invalid value: -1, expected an integer between 0 and 21 at line 1 column 33"#,
+            ),
+            (
+                r#"{"algorithm": "gzip", "level": 12}"#,
+                r#"invalid value: integer `12`, expected 0, 1, 2, 3, 4, 5, 6, 7, 8 or 9 at line 1 column 34"#,
+            ),
+            (
+                r#"{"algorithm": "zstd", "level": 22}"#,
+                r#"invalid value: integer `22`, expected an integer between 0 and 21 at line 1 column 33"#,
+            ),
+            (
+                r#"{"algorithm": "snappy", "level": "best"}"#,
+                r#"unknown field `level`, there are no fields at line 1 column 40"#,
             ),
             (
                 r#"{"algorithm": "gzip", "level": "good"}"#,
@@ -521,6 +665,9 @@ mod test {
             Compression::Gzip(CompressionLevel::new(7)),
             Compression::Zlib(CompressionLevel::best()),
             Compression::Zlib(CompressionLevel::new(7)),
+            Compression::Zstd(CompressionLevel::const_default()),
+            Compression::Zstd(CompressionLevel::new(15)),
+            Compression::Snappy,
         ];
 
         for v in fixtures_valid {
@@ -28,6 +28,10 @@ pub enum InnerBuffer {
     Plain(bytes::buf::Writer<BytesMut>),
     Gzip(GzEncoder<bytes::buf::Writer<BytesMut>>),
     Zlib(ZlibEncoder<bytes::buf::Writer<BytesMut>>),
+    Zstd(zstd::stream::write::Encoder<'static, bytes::buf::Writer<BytesMut>>),
+    /// Buffers the uncompressed input, which is compressed as a single block
+    /// when the batch is finished.
+    Snappy(bytes::buf::Writer<BytesMut>),
 }
 
 impl Buffer {
@@ -54,6 +58,11 @@ impl Buffer {
                 Compression::Zlib(level) => {
                     InnerBuffer::Zlib(ZlibEncoder::new(writer, level.as_flate2()))
                 }
+                Compression::Zstd(level) => InnerBuffer::Zstd(
+                    zstd::stream::write::Encoder::new(writer, level.as_zstd())
+                        .expect("zstd compression level should be valid"),
+                ),
+                Compression::Snappy => InnerBuffer::Snappy(writer),
             }
         })
     }
@@ -61,7 +70,7 @@ impl Buffer {
     pub fn push(&mut self, input: &[u8]) {
         self.num_items += 1;
         match self.buffer() {
-            InnerBuffer::Plain(inner) => {
+            InnerBuffer::Plain(inner) | InnerBuffer::Snappy(inner) => {
                 inner.write_all(input).unwrap();
             }
             InnerBuffer::Gzip(inner) => {
@@ -70,6 +79,9 @@ impl Buffer {
             InnerBuffer::Zlib(inner) => {
                 inner.write_all(input).unwrap();
             }
+            InnerBuffer::Zstd(inner) => {
+                inner.write_all(input).unwrap();
+            }
         }
     }
 
@@ -77,9 +89,12 @@ impl Buffer {
         self.inner
             .as_ref()
             .map(|inner| match inner {
-                InnerBuffer::Plain(inner) => inner.get_ref().is_empty(),
+                InnerBuffer::Plain(inner) | InnerBuffer::Snappy(inner) => {
+                    inner.get_ref().is_empty()
+                }
                 InnerBuffer::Gzip(inner) => inner.get_ref().get_ref().is_empty(),
                 InnerBuffer::Zlib(inner) => inner.get_ref().get_ref().is_empty(),
+                InnerBuffer::Zstd(inner) => inner.get_ref().get_ref().is_empty(),
             })
             .unwrap_or(true)
     }
@@ -126,6 +141,14 @@ impl Batch for Buffer {
                 .finish()
                 .expect("This can't fail because the inner writer is a Vec")
                 .into_inner(),
+            Some(InnerBuffer::Zstd(inner)) => inner
+                .finish()
+                .expect("This can't fail because the inner writer is a Vec")
+                .into_inner(),
+            Some(InnerBuffer::Snappy(inner)) => snap::raw::Encoder::new()
+                .compress_vec(inner.get_ref())
+                .map(|compressed| BytesMut::from(compressed.as_slice()))
+                .expect("This can't fail because the input fits in memory"),
             None => BytesMut::new(),
         }
     }
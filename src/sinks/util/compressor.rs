@@ -9,16 +9,37 @@ enum Writer {
     Plain(bytes::buf::Writer<BytesMut>),
     Gzip(GzEncoder<bytes::buf::Writer<BytesMut>>),
     Zlib(ZlibEncoder<bytes::buf::Writer<BytesMut>>),
+    Zstd(zstd::stream::write::Encoder<'static, bytes::buf::Writer<BytesMut>>),
+    /// Snappy's raw format compresses a single block, so the input is
+    /// buffered as-is and only compressed when the compressor is finished.
+    Snappy(bytes::buf::Writer<BytesMut>),
 }
 
 impl Writer {
     pub fn get_ref(&self) -> &BytesMut {
         match self {
-            Writer::Plain(inner) => inner.get_ref(),
+            Writer::Plain(inner) | Writer::Snappy(inner) => inner.get_ref(),
             Writer::Gzip(inner) => inner.get_ref().get_ref(),
             Writer::Zlib(inner) => inner.get_ref().get_ref(),
+            Writer::Zstd(inner) => inner.get_ref().get_ref(),
         }
     }
+
+    fn finish(self) -> io::Result<BytesMut> {
+        let buf = match self {
+            Writer::Plain(writer) => writer,
+            Writer::Gzip(writer) => writer.finish()?,
+            Writer::Zlib(writer) => writer.finish()?,
+            Writer::Zstd(writer) => writer.finish()?,
+            Writer::Snappy(writer) => {
+                let compressed = snap::raw::Encoder::new().compress_vec(writer.get_ref())?;
+                BytesMut::from(compressed.as_slice()).writer()
+            }
+        }
+        .into_inner();
+
+        Ok(buf)
+    }
 }
 
 impl From<Compression> for Writer {
@@ -28,6 +49,11 @@ impl From<Compression> for Writer {
             Compression::None => Writer::Plain(writer),
             Compression::Gzip(level) => Writer::Gzip(GzEncoder::new(writer, level.as_flate2())),
             Compression::Zlib(level) => Writer::Zlib(ZlibEncoder::new(writer, level.as_flate2())),
+            Compression::Zstd(level) => Writer::Zstd(
+                zstd::stream::write::Encoder::new(writer, level.as_zstd())
+                    .expect("zstd compression level should be valid"),
+            ),
+            Compression::Snappy => Writer::Snappy(writer),
         }
     }
 }
@@ -36,17 +62,19 @@ impl io::Write for Writer {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         #[allow(clippy::disallowed_methods)] // Caller handles the result of `write`.
         match self {
-            Writer::Plain(inner_buf) => inner_buf.write(buf),
+            Writer::Plain(inner_buf) | Writer::Snappy(inner_buf) => inner_buf.write(buf),
             Writer::Gzip(writer) => writer.write(buf),
             Writer::Zlib(writer) => writer.write(buf),
+            Writer::Zstd(writer) => writer.write(buf),
         }
     }
 
     fn flush(&mut self) -> io::Result<()> {
         match self {
-            Writer::Plain(writer) => writer.flush(),
+            Writer::Plain(writer) | Writer::Snappy(writer) => writer.flush(),
             Writer::Gzip(writer) => writer.flush(),
             Writer::Zlib(writer) => writer.flush(),
+            Writer::Zstd(writer) => writer.flush(),
         }
     }
 }
@@ -84,14 +112,7 @@ impl Compressor {
     /// If the compressor encounters an I/O error while finalizing the payload, an error
     /// variant will be returned.
     pub fn finish(self) -> io::Result<BytesMut> {
-        let buf = match self.inner {
-            Writer::Plain(writer) => writer,
-            Writer::Gzip(writer) => writer.finish()?,
-            Writer::Zlib(writer) => writer.finish()?,
-        }
-        .into_inner();
-
-        Ok(buf)
+        self.inner.finish()
     }
 
     /// Consumes the compressor, returning the internal buffer used by the compressor.
@@ -104,16 +125,13 @@ impl Compressor {
     ///
     /// Consider using `finish` if catching these scenarios is important.
     pub fn into_inner(self) -> BytesMut {
-        match self.inner {
-            Writer::Plain(writer) => writer,
-            Writer::Gzip(writer) => writer
-                .finish()
-                .expect("gzip writer should not fail to finish"),
-            Writer::Zlib(writer) => writer
-                .finish()
-                .expect("zlib writer should not fail to finish"),
-        }
-        .into_inner()
+        let compression = self.compression;
+        self.inner.finish().unwrap_or_else(|error| {
+            panic!(
+                "{} writer should not fail to finish: {}",
+                compression, error
+            )
+        })
     }
 }
 
@@ -1,6 +1,8 @@
 use std::io;
 
 use bytes::BytesMut;
+#[cfg(feature = "codecs-parquet")]
+use codecs::encoding::BatchSerializer;
 use codecs::encoding::Framer;
 use tokio_util::codec::Encoder as _;
 
@@ -25,7 +27,7 @@ impl Encoder<Vec<Event>> for (Transformer, crate::codecs::Encoder<Framer>) {
         let mut bytes_written = 0;
         let mut n_events_pending = events.len();
         let batch_prefix = encoder.batch_prefix();
-        write_all(writer, n_events_pending, batch_prefix)?;
+        write_all(writer, n_events_pending, &batch_prefix)?;
         bytes_written += batch_prefix.len();
         if let Some(last) = events.pop() {
             for mut event in events {
@@ -57,6 +59,58 @@ impl Encoder<Vec<Event>> for (Transformer, crate::codecs::Encoder<Framer>) {
     }
 }
 
+#[cfg(feature = "codecs-parquet")]
+impl Encoder<Vec<Event>> for (Transformer, BatchSerializer) {
+    fn encode_input(
+        &self,
+        mut events: Vec<Event>,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<usize> {
+        for event in &mut events {
+            self.0.transform(event);
+        }
+        let n_events_pending = events.len();
+        let mut bytes = BytesMut::new();
+        self.1
+            .encode(events, &mut bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        write_all(writer, n_events_pending, &bytes)?;
+        Ok(bytes.len())
+    }
+}
+
+/// Encodes a batch of events either event by event, framing each of them, or
+/// as a whole, for formats that are defined over the entire batch.
+#[derive(Clone, Debug)]
+pub enum BatchEncoder {
+    /// Each event is serialized and framed on its own.
+    Framed((Transformer, crate::codecs::Encoder<Framer>)),
+    /// The batch is serialized as a whole.
+    #[cfg(feature = "codecs-parquet")]
+    Batch((Transformer, BatchSerializer)),
+}
+
+impl BatchEncoder {
+    /// Get the HTTP content type of the encoded batch.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Framed((_, encoder)) => encoder.content_type(),
+            #[cfg(feature = "codecs-parquet")]
+            Self::Batch((_, serializer)) => serializer.content_type(),
+        }
+    }
+}
+
+impl Encoder<Vec<Event>> for BatchEncoder {
+    fn encode_input(&self, events: Vec<Event>, writer: &mut dyn io::Write) -> io::Result<usize> {
+        match self {
+            Self::Framed(encoder) => encoder.encode_input(events, writer),
+            #[cfg(feature = "codecs-parquet")]
+            Self::Batch(encoder) => encoder.encode_input(events, writer),
+        }
+    }
+}
+
 impl Encoder<Event> for (Transformer, crate::codecs::Encoder<()>) {
     fn encode_input(&self, mut event: Event, writer: &mut dyn io::Write) -> io::Result<usize> {
         let mut encoder = self.1.clone();
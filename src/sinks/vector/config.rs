@@ -187,7 +187,7 @@ pub fn with_default_scheme(address: &str, tls: bool) -> crate::Result<Uri> {
     }
 }
 
-fn new_client(
+pub(crate) fn new_client(
     tls_settings: &MaybeTlsSettings,
     proxy_config: &ProxyConfig,
 ) -> crate::Result<hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>> {
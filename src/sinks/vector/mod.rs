@@ -7,6 +7,8 @@ mod service;
 mod sink;
 
 pub use config::VectorConfig;
+pub(crate) use config::{new_client, with_default_scheme};
+pub(crate) use service::HyperSvc;
 
 /// Marker type for the version two of the configuration for the `vector` sink.
 #[configurable_component]
@@ -173,6 +173,13 @@ impl SourceSender {
         recv
     }
 
+    /// Returns the fraction of the buffer of the default output that is available, from `0.0` when
+    /// it is full to `1.0` once the events sent to it have all been drained downstream.
+    pub fn available_capacity_ratio(&self) -> f64 {
+        let inner = &self.inner.as_ref().expect("no default output").inner;
+        inner.available_capacity() as f64 / inner.max_capacity() as f64
+    }
+
     pub async fn send_event(&mut self, event: impl Into<EventArray>) -> Result<(), ClosedError> {
         self.inner
             .as_mut()
@@ -402,6 +409,23 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn reports_available_capacity_ratio() {
+        let (mut sender, mut recv) = SourceSender::new_with_buffer(4);
+        assert_eq!(sender.available_capacity_ratio(), 1.0);
+
+        for _ in 0..3 {
+            sender
+                .send_event(Event::Log(LogEvent::from("Log message")))
+                .await
+                .expect("Send should not fail");
+        }
+        assert_eq!(sender.available_capacity_ratio(), 0.25);
+
+        recv.next().await.expect("An event should be received");
+        assert_eq!(sender.available_capacity_ratio(), 0.5);
+    }
+
     async fn emit_and_test(make_event: impl FnOnce(DateTime<Utc>) -> Event) {
         metrics::init_test();
         let (mut sender, _stream) = SourceSender::new_test();
@@ -117,7 +117,7 @@ fn default_offset_key() -> String {
 impl_generate_config_from_default!(AmqpSourceConfig);
 
 impl AmqpSourceConfig {
-    fn decoder(&self, log_namespace: LogNamespace) -> Decoder {
+    fn decoder(&self, log_namespace: LogNamespace) -> crate::Result<Decoder> {
         DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build()
     }
 }
@@ -197,6 +197,7 @@ pub(crate) async fn amqp_source(
     acknowledgements: bool,
 ) -> crate::Result<super::Source> {
     let config = config.clone();
+    let decoder = config.decoder(log_namespace)?;
     let (_conn, channel) = config
         .connection
         .connect()
@@ -208,6 +209,7 @@ pub(crate) async fn amqp_source(
         shutdown,
         out,
         channel,
+        decoder,
         log_namespace,
         acknowledgements,
     )))
@@ -289,12 +291,13 @@ fn populate_event(
 async fn receive_event(
     config: &AmqpSourceConfig,
     out: &mut SourceSender,
+    decoder: &Decoder,
     log_namespace: LogNamespace,
     finalizer: Option<&UnorderedFinalizer<FinalizerEntry>>,
     msg: Delivery,
 ) -> Result<(), ()> {
     let payload = Cursor::new(Bytes::copy_from_slice(&msg.data));
-    let mut stream = FramedRead::new(payload, config.decoder(log_namespace));
+    let mut stream = FramedRead::new(payload, decoder.clone());
 
     // Extract timestamp from AMQP message
     let timestamp = msg
@@ -396,6 +399,7 @@ async fn run_amqp_source(
     shutdown: ShutdownSignal,
     mut out: SourceSender,
     channel: Channel,
+    decoder: Decoder,
     log_namespace: LogNamespace,
     acknowledgements: bool,
 ) -> Result<(), ()> {
@@ -432,7 +436,7 @@ async fn run_amqp_source(
                             return Err(());
                         }
                         Ok(msg) => {
-                            receive_event(&config, &mut out, log_namespace, finalizer.as_ref(), msg).await?
+                            receive_event(&config, &mut out, &decoder, log_namespace, finalizer.as_ref(), msg).await?
                         }
                     }
                 } else {
@@ -5,7 +5,7 @@ use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
 
 use crate::aws::create_client;
-use crate::codecs::DecodingConfig;
+use crate::codecs::{DecodingConfig, DecompressionConfig};
 use crate::common::sqs::SqsClientBuilder;
 use crate::tls::TlsConfig;
 use crate::{
@@ -79,6 +79,10 @@ pub struct AwsSqsConfig {
     #[derivative(Default(value = "default_decoding()"))]
     pub decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub decompression: DecompressionConfig,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     pub acknowledgements: SourceAcknowledgementsConfig,
@@ -96,7 +100,8 @@ impl SourceConfig for AwsSqsConfig {
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .with_decompression(self.decompression)
+        .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
         Ok(Box::pin(
@@ -248,7 +248,8 @@ impl SourceConfig for DemoLogsConfig {
 
         self.format.validate()?;
         let decoder =
-            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build();
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
         Ok(Box::pin(demo_logs_source(
             self.interval,
             self.count,
@@ -306,7 +307,8 @@ mod tests {
             default_decoding(),
             LogNamespace::Legacy,
         )
-        .build();
+        .build()
+        .unwrap();
 
         assert_source_compliance(&SOURCE_TAGS, async {
             demo_logs_source(
@@ -26,7 +26,7 @@ use vector_config::{configurable_component, NamedComponent};
 use vector_core::{config::LegacyKey, ByteSizeOf};
 
 use crate::{
-    codecs::{Decoder, DecodingConfig},
+    codecs::{Decoder, DecodingConfig, DecompressionConfig},
     config::{Output, SourceConfig, SourceContext},
     event::Event,
     internal_events::{
@@ -77,6 +77,10 @@ pub struct ExecConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    decompression: DecompressionConfig,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -143,6 +147,7 @@ impl Default for ExecConfig {
             maximum_buffer_size_bytes: default_maximum_buffer_size(),
             framing: None,
             decoding: default_decoding(),
+            decompression: Default::default(),
             log_namespace: None,
         }
     }
@@ -229,8 +234,9 @@ impl SourceConfig for ExecConfig {
             .framing
             .clone()
             .unwrap_or_else(|| self.decoding.default_stream_framing());
-        let decoder =
-            DecodingConfig::new(framing, self.decoding.clone(), LogNamespace::Legacy).build();
+        let decoder = DecodingConfig::new(framing, self.decoding.clone(), LogNamespace::Legacy)
+            .with_decompression(self.decompression)
+            .build()?;
 
         match &self.mode {
             Mode::Scheduled => {
@@ -896,6 +902,7 @@ mod tests {
             maximum_buffer_size_bytes: default_maximum_buffer_size(),
             framing: None,
             decoding: default_decoding(),
+            decompression: Default::default(),
             log_namespace: None,
         };
 
@@ -1105,6 +1112,7 @@ mod tests {
             maximum_buffer_size_bytes: default_maximum_buffer_size(),
             framing: None,
             decoding: default_decoding(),
+            decompression: Default::default(),
             log_namespace: None,
         }
     }
@@ -276,7 +276,7 @@ impl SourceConfig for PubsubConfig {
                 self.decoding.clone(),
                 LogNamespace::Legacy,
             )
-            .build(),
+            .build()?,
             acknowledgements: cx.do_acknowledgements(self.acknowledgements),
             shutdown: cx.shutdown,
             out: cx.out,
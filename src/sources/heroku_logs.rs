@@ -108,7 +108,7 @@ impl SourceConfig for LogplexConfig {
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .build()?;
         let source = LogplexSource {
             query_parameters: self.query_parameters.clone(),
             decoder,
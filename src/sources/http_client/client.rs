@@ -128,7 +128,8 @@ impl SourceConfig for HttpClientConfig {
 
         // build the decoder
         let decoder =
-            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build();
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
 
         let content_type = self.decoding.content_type(&self.framing).to_string();
 
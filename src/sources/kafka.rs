@@ -1,7 +1,9 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
+    future::Future,
     io::Cursor,
-    sync::Arc,
+    sync::{Arc, Weak},
+    time::Duration,
 };
 
 use async_stream::stream;
@@ -14,9 +16,11 @@ use codecs::{
 use futures::{Stream, StreamExt};
 use once_cell::sync::OnceCell;
 use rdkafka::{
-    consumer::{Consumer, ConsumerContext, Rebalance, StreamConsumer},
+    consumer::{CommitMode, Consumer, ConsumerContext, Rebalance, StreamConsumer},
+    error::KafkaError,
     message::{BorrowedMessage, Headers as _, Message},
-    ClientConfig, ClientContext, Statistics,
+    types::RDKafkaErrorCode,
+    ClientConfig, ClientContext, Statistics, TopicPartitionList,
 };
 use snafu::{ResultExt, Snafu};
 use tokio_util::codec::FramedRead;
@@ -27,14 +31,14 @@ use vector_core::config::LogNamespace;
 use vector_common::{byte_size_of::ByteSizeOf, finalizer::OrderedFinalizer};
 
 use crate::{
-    codecs::{Decoder, DecodingConfig},
+    codecs::{Decoder, DecodingConfig, DecompressionConfig},
     config::{
         log_schema, LogSchema, Output, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
     },
     event::{BatchNotifier, BatchStatus, Event, Value},
     internal_events::{
-        KafkaBytesReceived, KafkaEventsReceived, KafkaOffsetUpdateError, KafkaReadError,
-        StreamClosedError,
+        KafkaBytesReceived, KafkaEventsReceived, KafkaOffsetUpdateError, KafkaPauseResumeError,
+        KafkaReadError, StreamClosedError,
     },
     kafka,
     serde::{bool_or_struct, default_decoding, default_framing_message_based},
@@ -50,6 +54,18 @@ enum BuildError {
     KafkaSubscribeError { source: rdkafka::error::KafkaError },
 }
 
+/// How long the events of a message can be held back by downstream backpressure before fetching
+/// from the assigned partitions is paused.
+const BACKPRESSURE_PAUSE_DELAY: Duration = Duration::from_millis(100);
+
+/// How often the paused partitions are checked for being resumed.
+const BACKPRESSURE_RESUME_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The fraction of the output buffer that must be available before the paused partitions are
+/// resumed, so that they aren't resumed and paused again on every message under sustained
+/// backpressure.
+const BACKPRESSURE_RESUME_CAPACITY: f64 = 0.5;
+
 /// Configuration for the `kafka` source.
 #[configurable_component(source("kafka"))]
 #[derive(Clone, Debug, Derivative)]
@@ -94,6 +110,14 @@ pub struct KafkaSourceConfig {
     #[serde(default = "default_commit_interval_ms")]
     commit_interval_ms: u64,
 
+    /// Whether to synchronously commit the offsets of the partitions revoked by a consumer group
+    /// rebalance, before they are assigned to another consumer.
+    ///
+    /// Otherwise, offsets are only committed every `commit_interval_ms`, and the consumer that is
+    /// assigned a revoked partition reads again the messages processed since the last commit.
+    #[serde(default)]
+    sync_commit_on_rebalance: bool,
+
     /// Overrides the name of the log field used to add the message key to each event.
     ///
     /// The value will be the message key of the Kafka message itself.
@@ -152,6 +176,10 @@ pub struct KafkaSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    decompression: DecompressionConfig,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -208,7 +236,8 @@ impl SourceConfig for KafkaSourceConfig {
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .with_decompression(self.decompression)
+        .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
         Ok(Box::pin(kafka_source(
@@ -249,13 +278,27 @@ async fn kafka_source(
             .set(Arc::clone(finalizer))
             .expect("Finalizer is only set once");
     }
+    if config.sync_commit_on_rebalance {
+        consumer
+            .context()
+            .consumer
+            .set(Arc::downgrade(&consumer))
+            .expect("Consumer is only set once");
+    }
 
     let mut stream = consumer.stream();
     let keys = Keys::from(log_schema(), &config);
+    let mut paused = PausedPartitions::default();
+    let mut resume_interval = tokio::time::interval(BACKPRESSURE_RESUME_INTERVAL);
 
     loop {
         tokio::select! {
             _ = &mut shutdown => break,
+            _ = resume_interval.tick(), if !paused.is_empty() => {
+                if out.available_capacity_ratio() >= BACKPRESSURE_RESUME_CAPACITY {
+                    paused.resume(&consumer);
+                }
+            },
             entry = ack_stream.next() => if let Some((status, entry)) = entry {
                 if status == BatchStatus::Delivered {
                     if let Err(error) =
@@ -276,7 +319,16 @@ async fn kafka_source(
                         partition: msg.partition(),
                     });
 
-                    parse_message(msg, decoder.clone(), keys, &finalizer, &mut out, &consumer).await;
+                    parse_message(
+                        msg,
+                        decoder.clone(),
+                        keys,
+                        &finalizer,
+                        &mut out,
+                        &consumer,
+                        &mut paused,
+                    )
+                    .await;
                 }
             },
         }
@@ -292,13 +344,16 @@ async fn parse_message(
     finalizer: &Option<Arc<OrderedFinalizer<FinalizerEntry>>>,
     out: &mut SourceSender,
     consumer: &Arc<StreamConsumer<CustomContext>>,
+    paused: &mut PausedPartitions,
 ) {
     if let Some((count, mut stream)) = parse_stream(&msg, decoder, keys) {
         match finalizer {
             Some(finalizer) => {
                 let (batch, receiver) = BatchNotifier::new_with_receiver();
                 let mut stream = stream.map(|event| event.with_batch_notifier(&batch));
-                match out.send_event_stream(&mut stream).await {
+                match send_with_backpressure(consumer, paused, out.send_event_stream(&mut stream))
+                    .await
+                {
                     Err(error) => {
                         emit!(StreamClosedError { error, count });
                     }
@@ -310,22 +365,125 @@ async fn parse_message(
                     }
                 }
             }
-            None => match out.send_event_stream(&mut stream).await {
-                Err(error) => {
-                    emit!(StreamClosedError { error, count });
-                }
-                Ok(_) => {
-                    if let Err(error) =
-                        consumer.store_offset(msg.topic(), msg.partition(), msg.offset())
-                    {
-                        emit!(KafkaOffsetUpdateError { error });
+            None => {
+                match send_with_backpressure(consumer, paused, out.send_event_stream(&mut stream))
+                    .await
+                {
+                    Err(error) => {
+                        emit!(StreamClosedError { error, count });
+                    }
+                    Ok(_) => {
+                        if let Err(error) =
+                            consumer.store_offset(msg.topic(), msg.partition(), msg.offset())
+                        {
+                            emit!(KafkaOffsetUpdateError { error });
+                        }
                     }
                 }
-            },
+            }
         }
     }
 }
 
+/// Sends the events of a message, pausing the assigned partitions if downstream is applying
+/// backpressure.
+///
+/// Otherwise, the consumer keeps fetching messages from every partition while the source is
+/// blocked. The partitions stay paused once the events are sent, until the output has drained.
+async fn send_with_backpressure<F: Future>(
+    consumer: &StreamConsumer<CustomContext>,
+    paused: &mut PausedPartitions,
+    send: F,
+) -> F::Output {
+    tokio::pin!(send);
+    tokio::select! {
+        output = &mut send => return output,
+        _ = tokio::time::sleep(BACKPRESSURE_PAUSE_DELAY) => {},
+    }
+
+    paused.pause(consumer);
+    send.await
+}
+
+/// The partitions paused while downstream is applying backpressure.
+///
+/// Partitions are tracked individually, as the assignment of the consumer can change while they are
+/// paused. Each partition is resumed from the last message read from it.
+#[derive(Default)]
+struct PausedPartitions {
+    partitions: HashSet<(String, i32)>,
+}
+
+impl PausedPartitions {
+    fn is_empty(&self) -> bool {
+        self.partitions.is_empty()
+    }
+
+    /// Pauses the assigned partitions that aren't paused yet.
+    fn pause(&mut self, consumer: &StreamConsumer<CustomContext>) {
+        let assignment = match consumer.assignment() {
+            Ok(assignment) => assignment,
+            Err(error) => {
+                emit!(KafkaPauseResumeError { error });
+                return;
+            }
+        };
+        let partitions = assignment
+            .elements()
+            .into_iter()
+            .map(|element| (element.topic().to_owned(), element.partition()))
+            .filter(|partition| !self.partitions.contains(partition))
+            .collect::<Vec<_>>();
+        if partitions.is_empty() {
+            return;
+        }
+
+        debug!(
+            message = "Pausing partitions while downstream is applying backpressure.",
+            partitions = partitions.len(),
+        );
+        match consumer.pause(&partition_list(&partitions)) {
+            Ok(()) => self.partitions.extend(partitions),
+            Err(error) => emit!(KafkaPauseResumeError { error }),
+        }
+    }
+
+    /// Resumes the paused partitions that are still assigned to the consumer.
+    fn resume(&mut self, consumer: &StreamConsumer<CustomContext>) {
+        let assignment = match consumer.assignment() {
+            Ok(assignment) => assignment,
+            Err(error) => {
+                emit!(KafkaPauseResumeError { error });
+                return;
+            }
+        };
+        // Partitions revoked since they were paused are no longer tracked.
+        let partitions = assignment
+            .elements()
+            .into_iter()
+            .map(|element| (element.topic().to_owned(), element.partition()))
+            .filter(|partition| self.partitions.contains(partition))
+            .collect::<Vec<_>>();
+
+        debug!(
+            message = "Resuming partitions.",
+            partitions = partitions.len()
+        );
+        match consumer.resume(&partition_list(&partitions)) {
+            Ok(()) => self.partitions.clear(),
+            Err(error) => emit!(KafkaPauseResumeError { error }),
+        }
+    }
+}
+
+fn partition_list(partitions: &[(String, i32)]) -> TopicPartitionList {
+    let mut list = TopicPartitionList::new();
+    for (topic, partition) in partitions {
+        list.add_partition(topic, *partition);
+    }
+    list
+}
+
 // Turn the received message into a stream of parsed events.
 fn parse_stream<'a>(
     msg: &BorrowedMessage<'a>,
@@ -509,6 +667,8 @@ fn create_consumer(config: &KafkaSourceConfig) -> crate::Result<StreamConsumer<C
 struct CustomContext {
     stats: kafka::KafkaStatisticsContext,
     finalizer: OnceCell<Arc<OrderedFinalizer<FinalizerEntry>>>,
+    /// Only set with `sync_commit_on_rebalance`, to commit offsets before partitions are revoked.
+    consumer: OnceCell<Weak<StreamConsumer<CustomContext>>>,
 }
 
 impl ClientContext for CustomContext {
@@ -518,6 +678,19 @@ impl ClientContext for CustomContext {
 }
 
 impl ConsumerContext for CustomContext {
+    fn pre_rebalance(&self, rebalance: &Rebalance) {
+        if matches!(rebalance, Rebalance::Revoke(_)) {
+            if let Some(consumer) = self.consumer.get().and_then(Weak::upgrade) {
+                match consumer.commit_consumer_state(CommitMode::Sync) {
+                    // There is nothing to commit if no offset has been stored since the last
+                    // commit.
+                    Ok(()) | Err(KafkaError::ConsumerCommit(RDKafkaErrorCode::NoOffset)) => {}
+                    Err(error) => emit!(KafkaOffsetUpdateError { error }),
+                }
+            }
+        }
+    }
+
     fn post_rebalance(&self, rebalance: &Rebalance) {
         if matches!(rebalance, Rebalance::Revoke(_)) {
             if let Some(finalizer) = self.finalizer.get() {
@@ -729,6 +902,39 @@ mod integration_test {
         }
     }
 
+    #[tokio::test]
+    async fn consumes_events_under_backpressure() {
+        const SEND_COUNT: usize = 10;
+
+        let (topic, group_id, config) = make_rand_config();
+        send_events(topic.clone(), SEND_COUNT).await;
+
+        // Each message is read slower than the source waits before pausing its partitions.
+        let (tx, rx) = SourceSender::new_with_buffer(1);
+        let rx = rx
+            .into_stream()
+            .then(|events| async move {
+                sleep(BACKPRESSURE_PAUSE_DELAY * 2).await;
+                futures::stream::iter(events.into_events())
+            })
+            .flatten();
+        let (trigger_shutdown, shutdown_done) = spawn_kafka(tx, config, false);
+        let events = collect_n(rx, SEND_COUNT).await;
+        tokio::task::yield_now().await;
+        drop(trigger_shutdown);
+        shutdown_done.await;
+
+        let offset = fetch_tpl_offset(&group_id, &topic, 0);
+        assert_eq!(offset, Offset::from_raw(SEND_COUNT as i64));
+
+        for (i, event) in events.into_iter().enumerate() {
+            assert_eq!(
+                event.as_log()[log_schema().message_key()],
+                format!("{} {:03}", TEXT, i).into()
+            );
+        }
+    }
+
     fn make_rand_config() -> (String, String, KafkaSourceConfig) {
         let topic = format!("test-topic-{}", random_string(10));
         let group_id = format!("test-group-{}", random_string(10));
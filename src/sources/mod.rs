@@ -58,6 +58,8 @@ pub mod kubernetes_logs;
 pub mod logstash;
 #[cfg(feature = "sources-mongodb_metrics")]
 pub mod mongodb_metrics;
+#[cfg(feature = "sources-mqtt")]
+pub mod mqtt;
 #[cfg(all(feature = "sources-nats"))]
 pub mod nats;
 #[cfg(feature = "sources-nginx_metrics")]
@@ -74,6 +76,8 @@ pub mod redis;
 pub mod socket;
 #[cfg(feature = "sources-splunk_hec")]
 pub mod splunk_hec;
+#[cfg(feature = "sources-sql")]
+pub mod sql;
 #[cfg(feature = "sources-statsd")]
 pub mod statsd;
 #[cfg(feature = "sources-syslog")]
@@ -220,6 +224,10 @@ pub enum Sources {
     #[cfg(feature = "sources-mongodb_metrics")]
     MongodbMetrics(#[configurable(derived)] mongodb_metrics::MongoDbMetricsConfig),
 
+    /// MQTT.
+    #[cfg(feature = "sources-mqtt")]
+    Mqtt(#[configurable(derived)] mqtt::MqttSourceConfig),
+
     /// NATS.
     #[cfg(all(feature = "sources-nats"))]
     Nats(#[configurable(derived)] nats::NatsSourceConfig),
@@ -278,6 +286,10 @@ pub enum Sources {
     #[cfg(feature = "sources-splunk_hec")]
     SplunkHec(#[configurable(derived)] splunk_hec::SplunkConfig),
 
+    /// SQL.
+    #[cfg(feature = "sources-sql")]
+    Sql(#[configurable(derived)] sql::SqlSourceConfig),
+
     /// Statsd.
     #[cfg(feature = "sources-statsd")]
     Statsd(#[configurable(derived)] statsd::StatsdConfig),
@@ -363,6 +375,8 @@ impl NamedComponent for Sources {
             Self::Logstash(config) => config.get_component_name(),
             #[cfg(feature = "sources-mongodb_metrics")]
             Self::MongodbMetrics(config) => config.get_component_name(),
+            #[cfg(feature = "sources-mqtt")]
+            Self::Mqtt(config) => config.get_component_name(),
             #[cfg(all(feature = "sources-nats"))]
             Self::Nats(config) => config.get_component_name(),
             #[cfg(feature = "sources-nginx_metrics")]
@@ -391,6 +405,8 @@ impl NamedComponent for Sources {
             Self::Socket(config) => config.get_component_name(),
             #[cfg(feature = "sources-splunk_hec")]
             Self::SplunkHec(config) => config.get_component_name(),
+            #[cfg(feature = "sources-sql")]
+            Self::Sql(config) => config.get_component_name(),
             #[cfg(feature = "sources-statsd")]
             Self::Statsd(config) => config.get_component_name(),
             #[cfg(feature = "sources-stdin")]
@@ -0,0 +1,359 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use chrono::Utc;
+use codecs::decoding::{DeserializerConfig, FramingConfig};
+use futures::StreamExt;
+use rumqttc::{AsyncClient, EventLoop, Packet, Publish, SubscribeFilter};
+use snafu::{ResultExt, Snafu};
+use tokio_util::codec::FramedRead;
+use vector_common::internal_event::{
+    error_stage, ByteSize, BytesReceived, EventsReceived, InternalEventHandle as _, Protocol,
+};
+use vector_config::configurable_component;
+use vector_core::{config::LogNamespace, ByteSizeOf};
+
+use crate::{
+    codecs::{Decoder, DecodingConfig},
+    config::{log_schema, GenerateConfig, Output, SourceConfig, SourceContext},
+    event::Event,
+    internal_events::{MqttConnectionError, MqttSubscribeError, StreamClosedError},
+    mqtt::{MqttConfigError, MqttConnectionConfig, MqttQoS},
+    serde::{default_decoding, default_framing_message_based},
+    shutdown::ShutdownSignal,
+    SourceSender,
+};
+
+/// How long to wait before polling the event loop again after a connection error, which makes the
+/// client reconnect.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("MQTT Config Error: {}", source))]
+    Config { source: MqttConfigError },
+    #[snafu(display("At least one topic must be set"))]
+    NoTopics,
+    #[snafu(display("`client_id` must be set when `clean_session` is disabled"))]
+    PersistentSessionWithoutClientId,
+}
+
+/// Configuration for the `mqtt` source.
+#[configurable_component(source("mqtt"))]
+#[derive(Clone, Debug)]
+pub struct MqttSourceConfig {
+    #[serde(flatten)]
+    connection: MqttConnectionConfig,
+
+    /// The topics to subscribe to.
+    ///
+    /// Topic filters can contain the single-level wildcard `+` and the multi-level wildcard `#`,
+    /// such as `sensors/+/temperature` or `logs/#`.
+    topics: Vec<String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    qos: MqttQoS,
+
+    /// Whether to start a clean session when connecting to the broker.
+    ///
+    /// When disabled, the broker keeps the subscriptions, and queues messages at a QoS above
+    /// `at_most_once`, while Vector is disconnected. This requires `client_id` to be set.
+    #[serde(default = "crate::serde::default_true")]
+    clean_session: bool,
+
+    /// The log field name to use for the topic a message was published to.
+    #[serde(default = "default_topic_key")]
+    topic_key: String,
+
+    #[configurable(derived)]
+    #[serde(default = "default_framing_message_based")]
+    framing: FramingConfig,
+
+    #[configurable(derived)]
+    #[serde(default = "default_decoding")]
+    decoding: DeserializerConfig,
+}
+
+fn default_topic_key() -> String {
+    "topic".into()
+}
+
+impl GenerateConfig for MqttSourceConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"
+            host = "127.0.0.1"
+            topics = ["vector/#"]"#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl SourceConfig for MqttSourceConfig {
+    async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
+        let (client, eventloop) = self.connect()?;
+        let decoder = DecodingConfig::new(
+            self.framing.clone(),
+            self.decoding.clone(),
+            LogNamespace::Legacy,
+        )
+        .build()?;
+
+        Ok(Box::pin(mqtt_source(
+            client,
+            eventloop,
+            self.subscriptions(),
+            decoder,
+            self.topic_key.clone(),
+            cx.shutdown,
+            cx.out,
+        )))
+    }
+
+    fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
+        vec![Output::default(self.decoding.output_type())]
+    }
+
+    fn can_acknowledge(&self) -> bool {
+        false
+    }
+}
+
+impl MqttSourceConfig {
+    fn connect(&self) -> Result<(AsyncClient, EventLoop), BuildError> {
+        if self.topics.is_empty() {
+            return Err(BuildError::NoTopics);
+        }
+        if !self.clean_session && self.connection.client_id.is_none() {
+            return Err(BuildError::PersistentSessionWithoutClientId);
+        }
+
+        let mut options = self.connection.to_mqtt_options().context(ConfigSnafu)?;
+        options.set_clean_session(self.clean_session);
+
+        // The client is only used to subscribe, so it doesn't need much room to queue requests.
+        Ok(AsyncClient::new(options, 10))
+    }
+
+    fn subscriptions(&self) -> Vec<SubscribeFilter> {
+        self.topics
+            .iter()
+            .map(|topic| SubscribeFilter::new(topic.clone(), self.qos.into()))
+            .collect()
+    }
+}
+
+async fn mqtt_source(
+    client: AsyncClient,
+    mut eventloop: EventLoop,
+    subscriptions: Vec<SubscribeFilter>,
+    decoder: Decoder,
+    topic_key: String,
+    mut shutdown: ShutdownSignal,
+    mut out: SourceSender,
+) -> Result<(), ()> {
+    let bytes_received = register!(BytesReceived::from(Protocol::TCP));
+
+    loop {
+        let notification = tokio::select! {
+            _ = &mut shutdown => break,
+            notification = eventloop.poll() => notification,
+        };
+
+        match notification {
+            Ok(rumqttc::Event::Incoming(Packet::ConnAck(_))) => {
+                // Subscriptions only outlive a connection for persistent sessions, so they are
+                // renewed every time the client (re)connects.
+                if let Err(error) = client.try_subscribe_many(subscriptions.clone()) {
+                    emit!(MqttSubscribeError { error });
+                    return Err(());
+                }
+            }
+            Ok(rumqttc::Event::Incoming(Packet::Publish(publish))) => {
+                bytes_received.emit(ByteSize(publish.payload.len()));
+                handle_message(publish, &decoder, &topic_key, &mut out).await?;
+            }
+            Ok(_) => {}
+            Err(error) => {
+                emit!(MqttConnectionError {
+                    error,
+                    stage: error_stage::RECEIVING,
+                });
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        }
+    }
+
+    // Nothing is waiting on the disconnect, so a full request queue can be ignored.
+    let _ = client.try_disconnect();
+    Ok(())
+}
+
+async fn handle_message(
+    publish: Publish,
+    decoder: &Decoder,
+    topic_key: &str,
+    out: &mut SourceSender,
+) -> Result<(), ()> {
+    let mut stream = FramedRead::new(publish.payload.as_ref(), decoder.clone());
+    while let Some(next) = stream.next().await {
+        match next {
+            Ok((events, _byte_size)) => {
+                let count = events.len();
+                emit!(EventsReceived {
+                    count,
+                    byte_size: events.size_of()
+                });
+
+                let now = Utc::now();
+
+                let events = events.into_iter().map(|mut event| {
+                    if let Event::Log(ref mut log) = event {
+                        log.try_insert(log_schema().source_type_key(), Bytes::from("mqtt"));
+                        log.try_insert(log_schema().timestamp_key(), now);
+                        log.try_insert(topic_key, publish.topic.clone());
+                    }
+                    event
+                });
+
+                out.send_batch(events).await.map_err(|error| {
+                    emit!(StreamClosedError { error, count });
+                })?;
+            }
+            Err(error) => {
+                // Error is logged by `crate::codecs`, no further
+                // handling is needed here.
+                if !error.can_continue() {
+                    break;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<MqttSourceConfig>();
+    }
+
+    fn config(toml: &str) -> MqttSourceConfig {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn requires_topics() {
+        let config = config(
+            r#"
+            host = "localhost"
+            topics = []
+            "#,
+        );
+        assert!(matches!(config.connect(), Err(BuildError::NoTopics)));
+    }
+
+    #[test]
+    fn persistent_session_requires_client_id() {
+        let config = config(
+            r#"
+            host = "localhost"
+            topics = ["vector/#"]
+            clean_session = false
+            "#,
+        );
+        assert!(matches!(
+            config.connect(),
+            Err(BuildError::PersistentSessionWithoutClientId)
+        ));
+    }
+}
+
+#[cfg(feature = "mqtt-integration-tests")]
+#[cfg(test)]
+mod integration_tests {
+    use rumqttc::{MqttOptions, QoS};
+
+    use super::*;
+    use crate::test_util::{
+        collect_n,
+        components::{assert_source_compliance, SOURCE_TAGS},
+        random_string, trace_init,
+    };
+
+    fn mqtt_host() -> String {
+        std::env::var("MQTT_HOST").unwrap_or_else(|_| "localhost".into())
+    }
+
+    #[tokio::test]
+    async fn mqtt_happy() {
+        trace_init();
+
+        let prefix = format!("test-{}", random_string(10));
+        let config: MqttSourceConfig = toml::from_str(&format!(
+            r#"
+            host = "{}"
+            topics = ["{}/+/logs"]
+            "#,
+            mqtt_host(),
+            prefix,
+        ))
+        .unwrap();
+
+        let (publisher, mut publisher_loop) =
+            AsyncClient::new(MqttOptions::new(random_string(10), mqtt_host(), 1883), 10);
+        tokio::spawn(async move { while publisher_loop.poll().await.is_ok() {} });
+
+        let events = assert_source_compliance(&SOURCE_TAGS, async move {
+            let (tx, rx) = SourceSender::new_test();
+            let (client, mut eventloop) = config.connect().unwrap();
+            let decoder = DecodingConfig::new(
+                config.framing.clone(),
+                config.decoding.clone(),
+                LogNamespace::Legacy,
+            )
+            .build()
+            .unwrap();
+
+            // Wait for the subscription to be in place before publishing.
+            client.try_subscribe_many(config.subscriptions()).unwrap();
+            while !matches!(
+                eventloop.poll().await.unwrap(),
+                rumqttc::Event::Incoming(Packet::SubAck(_))
+            ) {}
+
+            tokio::spawn(mqtt_source(
+                client,
+                eventloop,
+                config.subscriptions(),
+                decoder,
+                config.topic_key.clone(),
+                ShutdownSignal::noop(),
+                tx,
+            ));
+
+            publisher
+                .publish(
+                    format!("{}/host/logs", prefix),
+                    QoS::AtLeastOnce,
+                    false,
+                    "my message",
+                )
+                .await
+                .unwrap();
+
+            collect_n(rx, 1).await
+        })
+        .await;
+
+        let log = events[0].as_log();
+        assert_eq!(log[log_schema().message_key()], "my message".into());
+        assert_eq!(log["topic"], format!("{}/host/logs", prefix).into());
+        assert_eq!(log[log_schema().source_type_key()], "mqtt".into());
+    }
+}
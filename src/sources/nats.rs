@@ -1,27 +1,41 @@
+use std::{io, time::Duration};
+
 use bytes::Bytes;
 use chrono::Utc;
 use codecs::decoding::{DeserializerConfig, FramingConfig, StreamDecodingError};
-use futures::{pin_mut, stream, Stream, StreamExt};
+use futures::{pin_mut, stream, FutureExt, Stream, StreamExt};
+use nats::jetstream::{
+    AckKind, JetStream, PullSubscribeOptions, PullSubscription, PushSubscription, SubscribeOptions,
+};
 use snafu::{ResultExt, Snafu};
+use tokio::sync::mpsc;
 use tokio_util::codec::FramedRead;
-use vector_common::internal_event::{
-    ByteSize, BytesReceived, EventsReceived, InternalEventHandle as _, Protocol,
+use vector_common::{
+    finalizer::UnorderedFinalizer,
+    internal_event::{ByteSize, BytesReceived, EventsReceived, InternalEventHandle as _, Protocol},
 };
 use vector_config::configurable_component;
 use vector_core::{config::LogNamespace, ByteSizeOf};
 
 use crate::{
     codecs::{Decoder, DecodingConfig},
-    config::{log_schema, GenerateConfig, Output, SourceConfig, SourceContext},
-    event::Event,
-    internal_events::StreamClosedError,
-    nats::{from_tls_auth_config, NatsAuthConfig, NatsConfigError},
-    serde::{default_decoding, default_framing_message_based},
+    config::{
+        log_schema, GenerateConfig, Output, SourceAcknowledgementsConfig, SourceConfig,
+        SourceContext,
+    },
+    event::{BatchNotifier, BatchStatus, Event},
+    internal_events::{NatsAckError, NatsReceiveError, StreamClosedError},
+    nats::{connect_jetstream, from_tls_auth_config, NatsAuthConfig, NatsConfigError},
+    serde::{bool_or_struct, default_decoding, default_framing_message_based},
     shutdown::ShutdownSignal,
     tls::TlsEnableableConfig,
     SourceSender,
 };
 
+/// How long the blocking JetStream client waits for messages before checking whether the source
+/// has shut down.
+const JETSTREAM_POLL_TIMEOUT: Duration = Duration::from_secs(1);
+
 #[derive(Debug, Snafu)]
 enum BuildError {
     #[snafu(display("NATS Config Error: {}", source))]
@@ -30,6 +44,10 @@ enum BuildError {
     Connect { source: std::io::Error },
     #[snafu(display("NATS Subscribe Error: {}", source))]
     Subscribe { source: std::io::Error },
+    #[snafu(display(
+        "NATS Queue Groups are not supported with JetStream, use a shared `durable_name` instead"
+    ))]
+    JetStreamQueue,
 }
 
 /// Configuration for the `nats` source.
@@ -60,6 +78,9 @@ pub struct NatsSourceConfig {
     #[configurable(derived)]
     auth: Option<NatsAuthConfig>,
 
+    #[configurable(derived)]
+    jetstream: Option<NatsJetStreamConfig>,
+
     #[configurable(derived)]
     #[serde(default = "default_framing_message_based")]
     #[derivative(Default(value = "default_framing_message_based()"))]
@@ -69,6 +90,61 @@ pub struct NatsSourceConfig {
     #[serde(default = "default_decoding")]
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
+}
+
+/// Consume messages from a JetStream stream, through a durable consumer.
+///
+/// Messages are only acknowledged once they have been processed, or once they have been delivered
+/// by the sinks when end-to-end acknowledgements are enabled, so the messages in flight are
+/// redelivered if Vector stops before then.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+struct NatsJetStreamConfig {
+    /// The name of the durable consumer.
+    ///
+    /// The consumer is created if it doesn't exist. Instances of Vector sharing a consumer share
+    /// its messages between them.
+    durable_name: String,
+
+    /// The name of the stream to consume from.
+    ///
+    /// If not set, the stream is looked up from `subject`.
+    stream: Option<String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    consumer: NatsJetStreamConsumerMode,
+
+    /// The maximum number of messages fetched by each request of a `pull` consumer.
+    #[serde(default = "default_batch_size")]
+    batch_size: usize,
+}
+
+const fn default_batch_size() -> usize {
+    100
+}
+
+/// How messages are delivered by the JetStream consumer.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum NatsJetStreamConsumerMode {
+    /// The server pushes messages to Vector as they become available.
+    Push,
+
+    /// Vector fetches messages from the server in batches.
+    Pull,
+}
+
+impl Default for NatsJetStreamConsumerMode {
+    fn default() -> Self {
+        Self::Push
+    }
 }
 
 impl GenerateConfig for NatsSourceConfig {
@@ -86,13 +162,27 @@ impl GenerateConfig for NatsSourceConfig {
 #[async_trait::async_trait]
 impl SourceConfig for NatsSourceConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
-        let (connection, subscription) = create_subscription(self).await?;
         let decoder = DecodingConfig::new(
             self.framing.clone(),
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .build()?;
+
+        if let Some(jetstream) = &self.jetstream {
+            let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
+            let consumer = create_jetstream_consumer(self, jetstream.clone()).await?;
+
+            return Ok(Box::pin(nats_jetstream_source(
+                consumer,
+                decoder,
+                acknowledgements,
+                cx.shutdown,
+                cx.out,
+            )));
+        }
+
+        let (connection, subscription) = create_subscription(self).await?;
 
         Ok(Box::pin(nats_source(
             connection,
@@ -108,7 +198,7 @@ impl SourceConfig for NatsSourceConfig {
     }
 
     fn can_acknowledge(&self) -> bool {
-        false
+        self.jetstream.is_some()
     }
 }
 
@@ -117,6 +207,13 @@ impl NatsSourceConfig {
         let options: nats::asynk::Options = self.try_into().context(ConfigSnafu)?;
         options.connect(&self.url).await.context(ConnectSnafu)
     }
+
+    async fn connect_jetstream(&self) -> Result<JetStream, BuildError> {
+        let options: nats::Options = self.try_into().context(ConfigSnafu)?;
+        connect_jetstream(options, &self.url)
+            .await
+            .context(ConnectSnafu)
+    }
 }
 
 impl std::convert::TryFrom<&NatsSourceConfig> for nats::asynk::Options {
@@ -127,6 +224,14 @@ impl std::convert::TryFrom<&NatsSourceConfig> for nats::asynk::Options {
     }
 }
 
+impl std::convert::TryFrom<&NatsSourceConfig> for nats::Options {
+    type Error = NatsConfigError;
+
+    fn try_from(config: &NatsSourceConfig) -> Result<Self, Self::Error> {
+        from_tls_auth_config(&config.connection_name, &config.auth, &config.tls)
+    }
+}
+
 fn get_subscription_stream(
     subscription: nats::asynk::Subscription,
 ) -> impl Stream<Item = nats::asynk::Message> {
@@ -148,43 +253,186 @@ async fn nats_source(
     let bytes_received = register!(BytesReceived::from(Protocol::TCP));
     while let Some(msg) = stream.next().await {
         bytes_received.emit(ByteSize(msg.data.len()));
-        let mut stream = FramedRead::new(msg.data.as_ref(), decoder.clone());
-        while let Some(next) = stream.next().await {
-            match next {
-                Ok((events, _byte_size)) => {
-                    let count = events.len();
-                    emit!(EventsReceived {
-                        count,
-                        byte_size: events.size_of()
-                    });
-
-                    let now = Utc::now();
-
-                    let events = events.into_iter().map(|mut event| {
-                        if let Event::Log(ref mut log) = event {
-                            log.try_insert(log_schema().source_type_key(), Bytes::from("nats"));
-                            log.try_insert(log_schema().timestamp_key(), now);
-                        }
-                        event
-                    });
+        send_message(&msg.data, &decoder, None, &mut out).await?;
+    }
+    Ok(())
+}
+
+/// Decodes the events of a message and sends them, attached to `batch` if there is one.
+async fn send_message(
+    data: &[u8],
+    decoder: &Decoder,
+    batch: Option<&BatchNotifier>,
+    out: &mut SourceSender,
+) -> Result<(), ()> {
+    let mut stream = FramedRead::new(data, decoder.clone());
+    while let Some(next) = stream.next().await {
+        match next {
+            Ok((events, _byte_size)) => {
+                let count = events.len();
+                emit!(EventsReceived {
+                    count,
+                    byte_size: events.size_of()
+                });
+
+                let now = Utc::now();
+
+                let events = events.into_iter().map(|mut event| {
+                    if let Event::Log(ref mut log) = event {
+                        log.try_insert(log_schema().source_type_key(), Bytes::from("nats"));
+                        log.try_insert(log_schema().timestamp_key(), now);
+                    }
+                    match batch {
+                        Some(batch) => event.with_batch_notifier(batch),
+                        None => event,
+                    }
+                });
 
-                    out.send_batch(events).await.map_err(|error| {
-                        emit!(StreamClosedError { error, count });
-                    })?;
+                out.send_batch(events).await.map_err(|error| {
+                    emit!(StreamClosedError { error, count });
+                })?;
+            }
+            Err(error) => {
+                // Error is logged by `crate::codecs`, no further
+                // handling is needed here.
+                if !error.can_continue() {
+                    break;
                 }
-                Err(error) => {
-                    // Error is logged by `crate::codecs`, no further
-                    // handling is needed here.
-                    if !error.can_continue() {
-                        break;
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn nats_jetstream_source(
+    consumer: JetStreamConsumer,
+    decoder: Decoder,
+    acknowledgements: bool,
+    shutdown: ShutdownSignal,
+    mut out: SourceSender,
+) -> Result<(), ()> {
+    let (finalizer, mut ack_stream) =
+        UnorderedFinalizer::<nats::Message>::maybe_new(acknowledgements, shutdown.clone());
+
+    // The JetStream client blocks, so it's driven by its own thread, which stops once `messages` is
+    // dropped.
+    let (tx, mut messages) = mpsc::channel(consumer.buffer_size());
+    tokio::task::spawn_blocking(move || consumer.forward(tx));
+
+    let bytes_received = register!(BytesReceived::from(Protocol::TCP));
+    let mut shutdown = shutdown.fuse();
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => break,
+            entry = ack_stream.next() => {
+                if let Some((status, msg)) = entry {
+                    handle_ack(status, msg).await;
+                }
+            },
+            msg = messages.recv() => {
+                let msg = match msg {
+                    Some(msg) => msg,
+                    None => break,
+                };
+                bytes_received.emit(ByteSize(msg.data.len()));
+
+                match &finalizer {
+                    Some(finalizer) => {
+                        let (batch, receiver) = BatchNotifier::new_with_receiver();
+                        send_message(&msg.data, &decoder, Some(&batch), &mut out).await?;
+                        finalizer.add(msg, receiver);
+                    }
+                    None => {
+                        send_message(&msg.data, &decoder, None, &mut out).await?;
+                        ack(msg, AckKind::Ack).await;
                     }
                 }
             }
         }
     }
+
     Ok(())
 }
 
+async fn handle_ack(status: BatchStatus, msg: nats::Message) {
+    let kind = match status {
+        BatchStatus::Delivered => AckKind::Ack,
+        // Have the server redeliver the message.
+        BatchStatus::Errored => AckKind::Nak,
+        // Redelivering the message would only have it rejected again.
+        BatchStatus::Rejected => AckKind::Term,
+    };
+    ack(msg, kind).await;
+}
+
+async fn ack(msg: nats::Message, kind: AckKind) {
+    let result = tokio::task::spawn_blocking(move || msg.ack_kind(kind))
+        .await
+        .map_err(|error| io::Error::new(io::ErrorKind::Other, error))
+        .and_then(|result| result);
+    if let Err(error) = result {
+        emit!(NatsAckError { error });
+    }
+}
+
+enum JetStreamConsumer {
+    Push(PushSubscription),
+    Pull {
+        subscription: PullSubscription,
+        batch_size: usize,
+    },
+}
+
+impl JetStreamConsumer {
+    fn buffer_size(&self) -> usize {
+        match self {
+            Self::Push(_) => default_batch_size(),
+            Self::Pull { batch_size, .. } => *batch_size,
+        }
+    }
+
+    /// Sends the messages of the consumer to `tx` until it's closed.
+    ///
+    /// This blocks, so it must be run on a blocking thread.
+    fn forward(self, tx: mpsc::Sender<nats::Message>) {
+        while !tx.is_closed() {
+            let messages = match &self {
+                Self::Push(subscription) => subscription
+                    .next_timeout(JETSTREAM_POLL_TIMEOUT)
+                    .map(|msg| vec![Ok(msg)]),
+                Self::Pull {
+                    subscription,
+                    batch_size,
+                } => subscription
+                    .timeout_fetch(*batch_size, JETSTREAM_POLL_TIMEOUT)
+                    .map(Iterator::collect),
+            };
+
+            let messages: Vec<io::Result<nats::Message>> = match messages {
+                Ok(messages) => messages,
+                Err(error) => vec![Err(error)],
+            };
+
+            for msg in messages {
+                match msg {
+                    Ok(msg) => {
+                        if tx.blocking_send(msg).is_err() {
+                            return;
+                        }
+                    }
+                    // There were no more messages available.
+                    Err(error) if error.kind() == io::ErrorKind::TimedOut => break,
+                    Err(error) => {
+                        emit!(NatsReceiveError { error });
+                        std::thread::sleep(JETSTREAM_POLL_TIMEOUT);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
 async fn create_subscription(
     config: &NatsSourceConfig,
 ) -> Result<(nats::asynk::Connection, nats::asynk::Subscription), BuildError> {
@@ -200,6 +448,45 @@ async fn create_subscription(
     Ok((nc, subscription))
 }
 
+async fn create_jetstream_consumer(
+    config: &NatsSourceConfig,
+    jetstream_config: NatsJetStreamConfig,
+) -> Result<JetStreamConsumer, BuildError> {
+    if config.queue.is_some() {
+        return Err(BuildError::JetStreamQueue);
+    }
+
+    let js = config.connect_jetstream().await?;
+    let subject = config.subject.clone();
+
+    tokio::task::spawn_blocking(move || match jetstream_config.consumer {
+        NatsJetStreamConsumerMode::Push => {
+            let mut options = SubscribeOptions::new().durable_name(jetstream_config.durable_name);
+            if let Some(stream) = jetstream_config.stream {
+                options = options.bind_stream(stream);
+            }
+            js.subscribe_with_options(&subject, &options)
+                .map(JetStreamConsumer::Push)
+        }
+        NatsJetStreamConsumerMode::Pull => {
+            let mut options =
+                PullSubscribeOptions::new().durable_name(jetstream_config.durable_name);
+            if let Some(stream) = jetstream_config.stream {
+                options = options.bind_stream(stream);
+            }
+            js.pull_subscribe_with_options(&subject, &options)
+                .map(|subscription| JetStreamConsumer::Pull {
+                    subscription,
+                    batch_size: jetstream_config.batch_size,
+                })
+        }
+    })
+    .await
+    .map_err(|error| io::Error::new(io::ErrorKind::Other, error))
+    .and_then(|result| result)
+    .context(SubscribeSnafu)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::print_stdout)] //tests
@@ -210,6 +497,45 @@ mod tests {
     fn generate_config() {
         crate::test_util::test_generate_config::<NatsSourceConfig>();
     }
+
+    #[test]
+    fn jetstream_config() {
+        let config: NatsSourceConfig = toml::from_str(
+            r#"
+            connection_name = "vector"
+            subject = "from.vector"
+            url = "nats://127.0.0.1:4222"
+            jetstream.durable_name = "vector"
+            "#,
+        )
+        .unwrap();
+
+        let jetstream = config.jetstream.as_ref().unwrap();
+        assert_eq!(jetstream.consumer, NatsJetStreamConsumerMode::Push);
+        assert_eq!(jetstream.batch_size, 100);
+        assert!(jetstream.stream.is_none());
+        assert!(config.can_acknowledge());
+    }
+
+    #[tokio::test]
+    async fn jetstream_rejects_queue() {
+        let config: NatsSourceConfig = toml::from_str(
+            r#"
+            connection_name = "vector"
+            subject = "from.vector"
+            queue = "vector"
+            url = "nats://127.0.0.1:4222"
+            jetstream.durable_name = "vector"
+            "#,
+        )
+        .unwrap();
+
+        let jetstream = config.jetstream.clone().unwrap();
+        assert!(matches!(
+            create_jetstream_consumer(&config, jetstream).await,
+            Err(BuildError::JetStreamQueue)
+        ));
+    }
 }
 
 #[cfg(feature = "nats-integration-tests")]
@@ -218,6 +544,7 @@ mod integration_tests {
     #![allow(clippy::print_stdout)] //tests
 
     use super::*;
+    use crate::event::EventStatus;
     use crate::nats::{NatsAuthCredentialsFile, NatsAuthNKey, NatsAuthToken, NatsAuthUserPassword};
     use crate::test_util::{
         collect_n,
@@ -239,7 +566,8 @@ mod integration_tests {
                 conf.decoding.clone(),
                 LogNamespace::Legacy,
             )
-            .build();
+            .build()
+            .unwrap();
             tokio::spawn(nats_source(nc, sub, decoder, ShutdownSignal::noop(), tx));
             nc_pub.publish(&subject, msg).await.unwrap();
 
@@ -263,6 +591,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -288,6 +618,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -318,6 +650,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -348,6 +682,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -377,6 +713,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -406,6 +744,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -436,6 +776,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -466,6 +808,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: Some(TlsEnableableConfig {
@@ -497,6 +841,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: None,
@@ -522,6 +868,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: Some(TlsEnableableConfig {
@@ -555,6 +903,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: Some(TlsEnableableConfig {
@@ -586,6 +936,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: Some(TlsEnableableConfig {
@@ -621,6 +973,8 @@ mod integration_tests {
             subject: subject.clone(),
             url,
             queue: None,
+            jetstream: None,
+            acknowledgements: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             tls: Some(TlsEnableableConfig {
@@ -644,4 +998,79 @@ mod integration_tests {
             r
         );
     }
+
+    async fn jetstream_publish_and_check(consumer: NatsJetStreamConsumerMode) {
+        let subject = format!("test-{}", random_string(10));
+        let url = std::env::var("NATS_JETSTREAM_ADDRESS")
+            .unwrap_or_else(|_| String::from("nats://localhost:4222"));
+
+        let conf = NatsSourceConfig {
+            connection_name: "".to_owned(),
+            subject: subject.clone(),
+            url,
+            queue: None,
+            jetstream: Some(NatsJetStreamConfig {
+                durable_name: format!("test-{}", random_string(10)),
+                stream: None,
+                consumer,
+                batch_size: 10,
+            }),
+            acknowledgements: true.into(),
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            tls: None,
+            auth: None,
+        };
+
+        let js = conf.connect_jetstream().await.unwrap();
+        let stream_config = nats::jetstream::StreamConfig {
+            name: subject.clone(),
+            subjects: vec![subject.clone()],
+            ..Default::default()
+        };
+        let js_pub = js.clone();
+        let msg = "my message";
+        tokio::task::spawn_blocking(move || {
+            js_pub.add_stream(stream_config).unwrap();
+            js_pub.publish(&subject, msg).unwrap();
+        })
+        .await
+        .unwrap();
+
+        let events = assert_source_compliance(&SOURCE_TAGS, async move {
+            let (tx, rx) = SourceSender::new_test_finalize(EventStatus::Delivered);
+            let decoder = DecodingConfig::new(
+                conf.framing.clone(),
+                conf.decoding.clone(),
+                LogNamespace::Legacy,
+            )
+            .build()
+            .unwrap();
+            let consumer = create_jetstream_consumer(&conf, conf.jetstream.clone().unwrap())
+                .await
+                .unwrap();
+            tokio::spawn(nats_jetstream_source(
+                consumer,
+                decoder,
+                true,
+                ShutdownSignal::noop(),
+                tx,
+            ));
+
+            collect_n(rx, 1).await
+        })
+        .await;
+
+        assert_eq!(events[0].as_log()[log_schema().message_key()], msg.into());
+    }
+
+    #[tokio::test]
+    async fn nats_jetstream_push() {
+        jetstream_publish_and_check(NatsJetStreamConsumerMode::Push).await;
+    }
+
+    #[tokio::test]
+    async fn nats_jetstream_pull() {
+        jetstream_publish_and_check(NatsJetStreamConsumerMode::Pull).await;
+    }
 }
@@ -1,14 +1,23 @@
 use crate::{
     internal_events::{EventsReceived, StreamClosedError},
-    sources::opentelemetry::LOGS,
+    sources::opentelemetry::{LOGS, METRICS, TRACES},
     SourceSender,
 };
 use futures::TryFutureExt;
 
 use tonic::{Request, Response, Status};
 
-use opentelemetry_proto::proto::collector::logs::v1::{
-    logs_service_server::LogsService, ExportLogsServiceRequest, ExportLogsServiceResponse,
+use opentelemetry_proto::proto::collector::{
+    logs::v1::{
+        logs_service_server::LogsService, ExportLogsServiceRequest, ExportLogsServiceResponse,
+    },
+    metrics::v1::{
+        metrics_service_server::MetricsService, ExportMetricsServiceRequest,
+        ExportMetricsServiceResponse,
+    },
+    trace::v1::{
+        trace_service_server::TraceService, ExportTraceServiceRequest, ExportTraceServiceResponse,
+    },
 };
 use vector_core::{
     event::{BatchNotifier, BatchStatus, BatchStatusReceiver, Event},
@@ -27,13 +36,56 @@ impl LogsService for Service {
         &self,
         request: Request<ExportLogsServiceRequest>,
     ) -> Result<Response<ExportLogsServiceResponse>, Status> {
-        let mut events: Vec<Event> = request
+        let events: Vec<Event> = request
             .into_inner()
             .resource_logs
             .into_iter()
             .flat_map(|v| v.into_iter())
             .collect();
 
+        self.handle_events(events, LOGS).await?;
+        Ok(Response::new(ExportLogsServiceResponse {}))
+    }
+}
+
+#[tonic::async_trait]
+impl TraceService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportTraceServiceRequest>,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        let events: Vec<Event> = request
+            .into_inner()
+            .resource_spans
+            .into_iter()
+            .flat_map(|v| v.into_iter())
+            .collect();
+
+        self.handle_events(events, TRACES).await?;
+        Ok(Response::new(ExportTraceServiceResponse {}))
+    }
+}
+
+#[tonic::async_trait]
+impl MetricsService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportMetricsServiceRequest>,
+    ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+        let events: Vec<Event> = request
+            .into_inner()
+            .resource_metrics
+            .into_iter()
+            .flat_map(|v| v.into_iter())
+            .collect();
+
+        self.handle_events(events, METRICS).await?;
+        Ok(Response::new(ExportMetricsServiceResponse {}))
+    }
+}
+
+impl Service {
+    async fn handle_events(&self, mut events: Vec<Event>, output: &str) -> Result<(), Status> {
         let count = events.len();
         let byte_size = events.size_of();
 
@@ -43,15 +95,14 @@ impl LogsService for Service {
 
         self.pipeline
             .clone()
-            .send_batch_named(LOGS, events)
+            .send_batch_named(output, events)
             .map_err(|error| {
                 let message = error.to_string();
                 emit!(StreamClosedError { error, count });
                 Status::unavailable(message)
             })
             .and_then(|_| handle_batch_status(receiver))
-            .await?;
-        Ok(Response::new(ExportLogsServiceResponse {}))
+            .await
     }
 }
 
@@ -3,8 +3,10 @@ use std::net::SocketAddr;
 use bytes::Bytes;
 use futures_util::FutureExt;
 use http::StatusCode;
-use opentelemetry_proto::proto::collector::logs::v1::{
-    ExportLogsServiceRequest, ExportLogsServiceResponse,
+use opentelemetry_proto::proto::collector::{
+    logs::v1::{ExportLogsServiceRequest, ExportLogsServiceResponse},
+    metrics::v1::{ExportMetricsServiceRequest, ExportMetricsServiceResponse},
+    trace::v1::{ExportTraceServiceRequest, ExportTraceServiceResponse},
 };
 use prost::Message;
 use snafu::Snafu;
@@ -63,8 +65,72 @@ pub(crate) fn build_warp_filter(
     out: SourceSender,
     bytes_received: Registered<BytesReceived>,
 ) -> BoxedFilter<(Response,)> {
+    let log_filters = build_ingest_filter::<ExportLogsServiceResponse, _>(
+        super::LOGS,
+        acknowledgements,
+        out.clone(),
+        bytes_received.clone(),
+        |body| {
+            decode_body(body, |request: ExportLogsServiceRequest| {
+                request
+                    .resource_logs
+                    .into_iter()
+                    .flat_map(|v| v.into_iter())
+            })
+        },
+    );
+    let trace_filters = build_ingest_filter::<ExportTraceServiceResponse, _>(
+        super::TRACES,
+        acknowledgements,
+        out.clone(),
+        bytes_received.clone(),
+        |body| {
+            decode_body(body, |request: ExportTraceServiceRequest| {
+                request
+                    .resource_spans
+                    .into_iter()
+                    .flat_map(|v| v.into_iter())
+            })
+        },
+    );
+    let metrics_filters = build_ingest_filter::<ExportMetricsServiceResponse, _>(
+        super::METRICS,
+        acknowledgements,
+        out,
+        bytes_received,
+        |body| {
+            decode_body(body, |request: ExportMetricsServiceRequest| {
+                request
+                    .resource_metrics
+                    .into_iter()
+                    .flat_map(|v| v.into_iter())
+            })
+        },
+    );
+
+    log_filters
+        .or(trace_filters)
+        .unify()
+        .or(metrics_filters)
+        .unify()
+        .boxed()
+}
+
+fn build_ingest_filter<Resp, F>(
+    telemetry_type: &'static str,
+    acknowledgements: bool,
+    out: SourceSender,
+    bytes_received: Registered<BytesReceived>,
+    make_events: F,
+) -> BoxedFilter<(Response,)>
+where
+    Resp: Message + Default + Send + 'static,
+    F: Fn(Bytes) -> Result<Vec<Event>, ErrorMessage> + Clone + Send + Sync + 'static,
+{
     warp::post()
-        .and(warp::path!("v1" / "logs"))
+        .and(warp::path("v1"))
+        .and(warp::path(telemetry_type))
+        .and(warp::path::end())
         .and(warp::header::exact_ignore_case(
             "content-type",
             "application/x-protobuf",
@@ -74,27 +140,30 @@ pub(crate) fn build_warp_filter(
         .and_then(move |encoding_header: Option<String>, body: Bytes| {
             let events = decode(&encoding_header, body).and_then(|body| {
                 bytes_received.emit(ByteSize(body.len()));
-                decode_body(body)
+                make_events(body)
             });
 
-            handle_request(events, acknowledgements, out.clone(), super::LOGS)
+            handle_request::<Resp>(events, acknowledgements, out.clone(), telemetry_type)
         })
         .boxed()
 }
 
-fn decode_body(body: Bytes) -> Result<Vec<Event>, ErrorMessage> {
-    let request = ExportLogsServiceRequest::decode(body).map_err(|error| {
+fn decode_body<T, I>(
+    body: Bytes,
+    into_events: impl FnOnce(T) -> I,
+) -> Result<Vec<Event>, ErrorMessage>
+where
+    T: Message + Default,
+    I: Iterator<Item = Event>,
+{
+    let request = T::decode(body).map_err(|error| {
         ErrorMessage::new(
             StatusCode::BAD_REQUEST,
             format!("Could not decode request: {}", error),
         )
     })?;
 
-    let events: Vec<Event> = request
-        .resource_logs
-        .into_iter()
-        .flat_map(|v| v.into_iter())
-        .collect();
+    let events: Vec<Event> = into_events(request).collect();
 
     emit!(EventsReceived {
         byte_size: events.size_of(),
@@ -104,7 +173,7 @@ fn decode_body(body: Bytes) -> Result<Vec<Event>, ErrorMessage> {
     Ok(events)
 }
 
-async fn handle_request(
+async fn handle_request<Resp: Message + Default>(
     events: Result<Vec<Event>, ErrorMessage>,
     acknowledgements: bool,
     mut out: SourceSender,
@@ -123,11 +192,9 @@ async fn handle_request(
                 })?;
 
             match receiver {
-                None => Ok(protobuf(ExportLogsServiceResponse {}).into_response()),
+                None => Ok(protobuf(Resp::default()).into_response()),
                 Some(receiver) => match receiver.await {
-                    BatchStatus::Delivered => {
-                        Ok(protobuf(ExportLogsServiceResponse {}).into_response())
-                    }
+                    BatchStatus::Delivered => Ok(protobuf(Resp::default()).into_response()),
                     BatchStatus::Errored => Err(warp::reject::custom(Status {
                         code: 2, // UNKNOWN - OTLP doesn't require use of status.code, but we can't encode a None here
                         message: "Error delivering contents to sink".into(),
@@ -12,7 +12,12 @@ use std::net::SocketAddr;
 
 use futures::{future::join, FutureExt, TryFutureExt};
 
-use opentelemetry_proto::proto::collector::logs::v1::logs_service_server::LogsServiceServer;
+use opentelemetry_proto::proto::collector::{
+    logs::v1::logs_service_server::LogsServiceServer,
+    metrics::v1::metrics_service_server::MetricsServiceServer,
+    trace::v1::trace_service_server::TraceServiceServer,
+};
+use tonic::{codec::CompressionEncoding, transport::server::Routes};
 use vector_common::internal_event::{BytesReceived, Protocol};
 use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
@@ -23,7 +28,7 @@ use crate::{
         SourceContext,
     },
     serde::bool_or_struct,
-    sources::{util::grpc::run_grpc_server, Source},
+    sources::{util::grpc::run_grpc_server_with_routes, Source},
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
 
@@ -33,6 +38,8 @@ use self::{
 };
 
 pub const LOGS: &str = "logs";
+pub const TRACES: &str = "traces";
+pub const METRICS: &str = "metrics";
 
 /// Configuration for the `opentelemetry` source.
 #[configurable_component(source("opentelemetry"))]
@@ -103,15 +110,25 @@ impl SourceConfig for OpentelemetryConfig {
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
         let grpc_tls_settings = MaybeTlsSettings::from_config(&self.grpc.tls, true)?;
-        let grpc_service = LogsServiceServer::new(Service {
+        let grpc_service = Service {
             pipeline: cx.out.clone(),
             acknowledgements,
-        })
-        .accept_compressed(tonic::codec::CompressionEncoding::Gzip);
-        let grpc_source = run_grpc_server(
+        };
+        let grpc_routes = Routes::new(
+            LogsServiceServer::new(grpc_service.clone())
+                .accept_compressed(CompressionEncoding::Gzip),
+        )
+        .add_service(
+            TraceServiceServer::new(grpc_service.clone())
+                .accept_compressed(CompressionEncoding::Gzip),
+        )
+        .add_service(
+            MetricsServiceServer::new(grpc_service).accept_compressed(CompressionEncoding::Gzip),
+        );
+        let grpc_source = run_grpc_server_with_routes(
             self.grpc.address,
             grpc_tls_settings,
-            grpc_service,
+            grpc_routes,
             cx.shutdown.clone(),
         )
         .map_err(|error| {
@@ -129,7 +146,11 @@ impl SourceConfig for OpentelemetryConfig {
     }
 
     fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
-        vec![Output::default(DataType::Log).with_port(LOGS)]
+        vec![
+            Output::default(DataType::Log).with_port(LOGS),
+            Output::default(DataType::Trace).with_port(TRACES),
+            Output::default(DataType::Metric).with_port(METRICS),
+        ]
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -1,7 +1,11 @@
 use crate::{
     config::{SourceConfig, SourceContext},
-    event::{into_event_stream, Event, EventStatus, LogEvent, Value},
-    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS},
+    event::{
+        into_event_stream,
+        metric::{Bucket, Metric, MetricKind, MetricTags, MetricValue},
+        Event, EventStatus, LogEvent, TraceEvent, Value,
+    },
+    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS, METRICS, TRACES},
     test_util::{
         self,
         components::{assert_source_compliance, SOURCE_TAGS},
@@ -13,10 +17,20 @@ use chrono::{TimeZone, Utc};
 use futures::Stream;
 use futures_util::StreamExt;
 use opentelemetry_proto::proto::{
-    collector::logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
-    common::v1::{any_value, AnyValue, KeyValue},
+    collector::{
+        logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+        metrics::v1::{metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest},
+        trace::v1::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+    },
+    common::v1::{any_value, AnyValue, InstrumentationScope, KeyValue},
     logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
+    metrics::v1::{
+        metric::Data, number_data_point, AggregationTemporality, Gauge, Histogram,
+        HistogramDataPoint, Metric as OtelMetric, NumberDataPoint, ResourceMetrics, ScopeMetrics,
+        Sum,
+    },
     resource::v1::Resource as OtelResource,
+    trace::v1::{span::SpanKind, ResourceSpans, ScopeSpans, Span, Status as OtelStatus},
 };
 use std::collections::BTreeMap;
 use tonic::Request;
@@ -43,7 +57,7 @@ async fn receive_grpc_logs() {
             },
             acknowledgements: Default::default(),
         };
-        let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+        let (sender, logs_output, _) = new_source(EventStatus::Delivered, LOGS);
         let server = source
             .build(SourceContext::new_test(sender, None))
             .await
@@ -124,18 +138,319 @@ async fn receive_grpc_logs() {
     .await;
 }
 
+#[tokio::test]
+async fn receive_grpc_traces() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let (source, grpc_addr) = test_config();
+        let (sender, traces_output, _) = new_source(EventStatus::Delivered, TRACES);
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(grpc_addr).await;
+
+        let mut client = TraceServiceClient::connect(format!("http://{}", grpc_addr))
+            .await
+            .unwrap();
+        let req = Request::new(ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: Some(test_resource()),
+                scope_spans: vec![ScopeSpans {
+                    scope: None,
+                    spans: vec![Span {
+                        trace_id: str_into_hex_bytes("4ac52aadf321c2e531db005df08792f5"),
+                        span_id: str_into_hex_bytes("0b9e4bda2a55530d"),
+                        trace_state: "".into(),
+                        parent_span_id: str_into_hex_bytes("8b9e4bda2a55530e"),
+                        name: "GET /".into(),
+                        kind: SpanKind::Server as i32,
+                        start_time_unix_nano: 1,
+                        end_time_unix_nano: 2,
+                        attributes: vec![test_attribute("attr_key", "attr_val")],
+                        dropped_attributes_count: 3,
+                        events: vec![],
+                        dropped_events_count: 0,
+                        links: vec![],
+                        dropped_links_count: 0,
+                        status: Some(OtelStatus {
+                            message: "ok".into(),
+                            code: 1,
+                        }),
+                    }],
+                    schema_url: "v1".into(),
+                }],
+                schema_url: "v1".into(),
+            }],
+        });
+        let _ = client.export(req).await;
+        let mut output = test_util::collect_ready(traces_output).await;
+        assert_eq!(output.len(), 1);
+        let actual_event = output.pop().unwrap();
+        let expect_vec = vec_into_btmap(vec![
+            (
+                "attributes",
+                Value::Object(vec_into_btmap(vec![("attr_key", "attr_val".into())])),
+            ),
+            (
+                "resources",
+                Value::Object(vec_into_btmap(vec![("res_key", "res_val".into())])),
+            ),
+            ("trace_id", "4ac52aadf321c2e531db005df08792f5".into()),
+            ("span_id", "0b9e4bda2a55530d".into()),
+            ("parent_span_id", "8b9e4bda2a55530e".into()),
+            ("name", "GET /".into()),
+            ("kind", 2.into()),
+            ("start_time", Utc.timestamp_nanos(1).into()),
+            ("end_time", Utc.timestamp_nanos(2).into()),
+            (
+                "status",
+                Value::Object(vec_into_btmap(vec![
+                    ("message", "ok".into()),
+                    ("code", 1.into()),
+                ])),
+            ),
+            ("dropped_attributes_count", 3.into()),
+            ("dropped_events_count", 0.into()),
+            ("dropped_links_count", 0.into()),
+            ("source_type", "opentelemetry".into()),
+        ]);
+        let expect_event = Event::from(TraceEvent::from(expect_vec));
+        assert_eq!(actual_event, expect_event);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn receive_grpc_metrics() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let (source, grpc_addr) = test_config();
+        let (sender, metrics_output, _) = new_source(EventStatus::Delivered, METRICS);
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(grpc_addr).await;
+
+        let number_point = |value| NumberDataPoint {
+            attributes: vec![test_attribute("attr_key", "attr_val")],
+            start_time_unix_nano: 0,
+            time_unix_nano: 1,
+            exemplars: vec![],
+            flags: 0,
+            value: Some(value),
+        };
+        let metric = |name: &str, data| OtelMetric {
+            name: name.into(),
+            description: "".into(),
+            unit: "".into(),
+            data: Some(data),
+        };
+
+        let mut client = MetricsServiceClient::connect(format!("http://{}", grpc_addr))
+            .await
+            .unwrap();
+        let req = Request::new(ExportMetricsServiceRequest {
+            resource_metrics: vec![ResourceMetrics {
+                resource: Some(test_resource()),
+                scope_metrics: vec![ScopeMetrics {
+                    scope: None,
+                    metrics: vec![
+                        metric(
+                            "requests",
+                            Data::Sum(Sum {
+                                data_points: vec![number_point(number_data_point::Value::AsInt(5))],
+                                aggregation_temporality: AggregationTemporality::Delta as i32,
+                                is_monotonic: true,
+                            }),
+                        ),
+                        metric(
+                            "temperature",
+                            Data::Gauge(Gauge {
+                                data_points: vec![number_point(
+                                    number_data_point::Value::AsDouble(21.5),
+                                )],
+                            }),
+                        ),
+                        metric(
+                            "latency",
+                            Data::Histogram(Histogram {
+                                data_points: vec![HistogramDataPoint {
+                                    attributes: vec![test_attribute("attr_key", "attr_val")],
+                                    start_time_unix_nano: 0,
+                                    time_unix_nano: 1,
+                                    count: 6,
+                                    sum: Some(12.0),
+                                    bucket_counts: vec![1, 2, 3],
+                                    explicit_bounds: vec![1.0, 5.0],
+                                    exemplars: vec![],
+                                    flags: 0,
+                                    min: None,
+                                    max: None,
+                                }],
+                                aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                            }),
+                        ),
+                    ],
+                    schema_url: "v1".into(),
+                }],
+                schema_url: "v1".into(),
+            }],
+        });
+        let _ = client.export(req).await;
+        let output = test_util::collect_ready(metrics_output).await;
+
+        let tags = MetricTags::from([
+            ("attr_key".to_owned(), "attr_val".to_owned()),
+            ("resource.res_key".to_owned(), "res_val".to_owned()),
+        ]);
+        let expect_metric = |name: &str, kind, value| {
+            Event::from(
+                Metric::new(name, kind, value)
+                    .with_timestamp(Some(Utc.timestamp_nanos(1)))
+                    .with_tags(Some(tags.clone())),
+            )
+        };
+        assert_eq!(
+            output,
+            vec![
+                expect_metric(
+                    "requests",
+                    MetricKind::Incremental,
+                    MetricValue::Counter { value: 5.0 }
+                ),
+                expect_metric(
+                    "temperature",
+                    MetricKind::Absolute,
+                    MetricValue::Gauge { value: 21.5 }
+                ),
+                expect_metric(
+                    "latency",
+                    MetricKind::Absolute,
+                    MetricValue::AggregatedHistogram {
+                        buckets: vec![
+                            Bucket {
+                                upper_limit: 1.0,
+                                count: 1
+                            },
+                            Bucket {
+                                upper_limit: 5.0,
+                                count: 2
+                            },
+                        ],
+                        count: 6,
+                        sum: 12.0,
+                    }
+                ),
+            ]
+        );
+    })
+    .await;
+}
+
+#[test]
+fn instrumentation_scope_into_field() {
+    let scope = InstrumentationScope {
+        name: "scope".into(),
+        version: "1.0".into(),
+        attributes: vec![test_attribute("scope_key", "scope_val")],
+        dropped_attributes_count: 0,
+    };
+    let expected = Value::Object(vec_into_btmap(vec![
+        ("name", "scope".into()),
+        ("version", "1.0".into()),
+        (
+            "attributes",
+            Value::Object(vec_into_btmap(vec![("scope_key", "scope_val".into())])),
+        ),
+    ]));
+
+    let logs = |scope| {
+        ResourceLogs {
+            resource: None,
+            scope_logs: vec![ScopeLogs {
+                scope,
+                log_records: vec![LogRecord::default()],
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }
+        .into_iter()
+        .map(Event::into_log)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(logs(Some(scope.clone()))[0].get("scope"), Some(&expected));
+    // An empty scope is the same as an unknown one.
+    assert_eq!(
+        logs(Some(InstrumentationScope::default()))[0].get("scope"),
+        None
+    );
+    assert_eq!(logs(None)[0].get("scope"), None);
+
+    let traces = |scope| {
+        ResourceSpans {
+            resource: None,
+            scope_spans: vec![ScopeSpans {
+                scope,
+                spans: vec![Span::default()],
+                schema_url: "v1".into(),
+            }],
+            schema_url: "v1".into(),
+        }
+        .into_iter()
+        .map(Event::into_trace)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(traces(Some(scope))[0].get("scope"), Some(&expected));
+    assert_eq!(traces(None)[0].get("scope"), None);
+}
+
+fn test_config() -> (OpentelemetryConfig, std::net::SocketAddr) {
+    let grpc_addr = next_addr();
+    let config = OpentelemetryConfig {
+        grpc: GrpcConfig {
+            address: grpc_addr,
+            tls: Default::default(),
+        },
+        http: HttpConfig {
+            address: next_addr(),
+            tls: Default::default(),
+        },
+        acknowledgements: Default::default(),
+    };
+    (config, grpc_addr)
+}
+
+fn test_resource() -> OtelResource {
+    OtelResource {
+        attributes: vec![test_attribute("res_key", "res_val")],
+        dropped_attributes_count: 0,
+    }
+}
+
+fn test_attribute(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    }
+}
+
 fn new_source(
     status: EventStatus,
+    output: &str,
 ) -> (
     SourceSender,
     impl Stream<Item = Event>,
     impl Stream<Item = Event>,
 ) {
     let (mut sender, recv) = SourceSender::new_test_finalize(status);
-    let logs_output = sender
-        .add_outputs(status, LOGS.to_string())
+    let output = sender
+        .add_outputs(status, output.to_string())
         .flat_map(into_event_stream);
-    (sender, logs_output, recv)
+    (sender, output, recv)
 }
 
 fn str_into_hex_bytes(s: &str) -> Vec<u8> {
@@ -63,6 +63,7 @@ pub async fn subscribe(input: SubscribeInputs) -> crate::Result<Source> {
                         &input.bytes_received,
                         &mut tx,
                         input.log_namespace,
+                        None,
                     )
                     .await
                     {
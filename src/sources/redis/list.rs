@@ -58,6 +58,7 @@ pub async fn watch(input: WatchInputs) -> crate::Result<Source> {
                         &input.bytes_received,
                         &mut tx,
                         input.log_namespace,
+                        None,
                     )
                     .await
                     {
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bytes::Bytes;
 use chrono::Utc;
 use codecs::{
@@ -18,20 +20,26 @@ use vector_core::ByteSizeOf;
 
 use crate::{
     codecs::{Decoder, DecodingConfig},
-    config::{log_schema, GenerateConfig, Output, SourceConfig, SourceContext},
-    event::Event,
+    config::{
+        log_schema, GenerateConfig, Output, SourceAcknowledgementsConfig, SourceConfig,
+        SourceContext,
+    },
+    event::{BatchNotifier, Event},
     internal_events::{EventsReceived, StreamClosedError},
-    serde::{default_decoding, default_framing_message_based},
+    serde::{bool_or_struct, default_decoding, default_framing_message_based},
     SourceSender,
 };
 
 mod channel;
 mod list;
+mod stream;
 
 #[derive(Debug, Snafu)]
 enum BuildError {
     #[snafu(display("Failed to build redis client: {}", source))]
     Client { source: redis::RedisError },
+    #[snafu(display("Failed to get the hostname to use as the stream consumer: {}", source))]
+    Hostname { source: std::io::Error },
 }
 
 /// Data type to use for reading messages from Redis.
@@ -48,6 +56,11 @@ pub enum DataTypeConfig {
     ///
     /// This is based on Redis' Pub/Sub capabilities.
     Channel,
+
+    /// The `stream` data type.
+    ///
+    /// Entries are read as a member of a consumer group, and acknowledged once processed.
+    Stream,
 }
 
 /// Options for the Redis `list` data type.
@@ -73,6 +86,65 @@ pub enum Method {
     Rpop,
 }
 
+/// Options for the Redis `stream` data type.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields)]
+pub struct StreamOption {
+    /// The consumer group to read the stream as.
+    ///
+    /// The group is created if it doesn't exist, starting from the entries added after its creation. The
+    /// stream is created as well if it doesn't exist.
+    #[serde(default = "default_group")]
+    #[derivative(Default(value = "default_group()"))]
+    group: String,
+
+    /// The name of the consumer to read the stream as, within the consumer group.
+    ///
+    /// Each instance of Vector reading the stream with the same group must use a different consumer name.
+    ///
+    /// By default, the hostname is used.
+    consumer: Option<String>,
+
+    /// The field of stream entries holding the message to decode.
+    ///
+    /// Entries without this field are acknowledged and dropped.
+    #[serde(default = "default_field")]
+    #[derivative(Default(value = "default_field()"))]
+    field: String,
+
+    /// The maximum number of entries to read from the stream at once.
+    #[serde(default = "default_batch_size")]
+    #[derivative(Default(value = "default_batch_size()"))]
+    batch_size: usize,
+
+    /// The minimum time, in seconds, that entries delivered to any consumer of the group must remain
+    /// unacknowledged before they are claimed and read again.
+    ///
+    /// This recovers the entries left pending by consumers that stopped, or failed to process them. Pending
+    /// entries are checked for at the same interval.
+    #[serde(default = "default_claim_idle_secs")]
+    #[derivative(Default(value = "default_claim_idle_secs()"))]
+    claim_idle_secs: u64,
+}
+
+fn default_group() -> String {
+    "vector".into()
+}
+
+fn default_field() -> String {
+    "message".into()
+}
+
+const fn default_batch_size() -> usize {
+    100
+}
+
+const fn default_claim_idle_secs() -> u64 {
+    60
+}
+
 pub struct ConnectionInfo {
     protocol: &'static str,
     endpoint: String,
@@ -97,13 +169,16 @@ impl From<&redis::ConnectionInfo> for ConnectionInfo {
 #[derive(Clone, Debug, Derivative)]
 #[serde(deny_unknown_fields)]
 pub struct RedisSourceConfig {
-    /// The Redis data type (`list` or `channel`) to use.
+    /// The Redis data type (`list`, `channel`, or `stream`) to use.
     #[serde(default)]
     data_type: DataTypeConfig,
 
     #[configurable(derived)]
     list: Option<ListOption>,
 
+    #[configurable(derived)]
+    stream: Option<StreamOption>,
+
     /// The Redis URL to connect to.
     ///
     /// The URL must take the form of `protocol://server:port/db` where the `protocol` can either be `redis` or `rediss` for connections secured via TLS.
@@ -133,6 +208,10 @@ pub struct RedisSourceConfig {
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
     log_namespace: Option<bool>,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    acknowledgements: SourceAcknowledgementsConfig,
 }
 
 impl GenerateConfig for RedisSourceConfig {
@@ -163,7 +242,8 @@ impl SourceConfig for RedisSourceConfig {
         let client = redis::Client::open(self.url.as_str()).context(ClientSnafu {})?;
         let connection_info = ConnectionInfo::from(client.get_connection_info());
         let decoder =
-            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build();
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .build()?;
 
         let bytes_received = register!(BytesReceived::from(Protocol::from(
             connection_info.protocol
@@ -197,6 +277,30 @@ impl SourceConfig for RedisSourceConfig {
                 })
                 .await
             }
+            DataTypeConfig::Stream => {
+                let stream = self.stream.clone().unwrap_or_default();
+                let consumer = match stream.consumer {
+                    Some(consumer) => consumer,
+                    None => crate::get_hostname().context(HostnameSnafu)?,
+                };
+                let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
+                stream::read(stream::ReadInputs {
+                    client,
+                    bytes_received: bytes_received.clone(),
+                    key: self.key.clone(),
+                    redis_key: self.redis_key.clone(),
+                    group: stream.group,
+                    consumer,
+                    field: stream.field,
+                    batch_size: stream.batch_size,
+                    claim_idle: Duration::from_secs(stream.claim_idle_secs),
+                    decoder,
+                    cx,
+                    log_namespace,
+                    acknowledgements,
+                })
+                .await
+            }
         }
     }
 
@@ -225,10 +329,12 @@ impl SourceConfig for RedisSourceConfig {
     }
 
     fn can_acknowledge(&self) -> bool {
-        false
+        // Only stream entries stay around to be read again until they are acknowledged.
+        matches!(self.data_type, DataTypeConfig::Stream)
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn handle_line(
     line: String,
     key: &str,
@@ -237,6 +343,7 @@ async fn handle_line(
     bytes_received: &Registered<BytesReceived>,
     out: &mut SourceSender,
     log_namespace: LogNamespace,
+    batch: Option<&BatchNotifier>,
 ) -> Result<(), ()> {
     let now = Utc::now();
 
@@ -278,7 +385,10 @@ async fn handle_line(
                         );
                     };
 
-                    event
+                    match batch {
+                        Some(batch) => event.with_batch_notifier(batch),
+                        None => event,
+                    }
                 });
 
                 if let Err(error) = out.send_batch(events).await {
@@ -306,16 +416,42 @@ mod test {
     fn generate_config() {
         crate::test_util::test_generate_config::<RedisSourceConfig>();
     }
+
+    #[test]
+    fn stream_config() {
+        let config: RedisSourceConfig = toml::from_str(
+            r#"
+            url = "redis://127.0.0.1:6379/0"
+            key = "vector"
+            data_type = "stream"
+            stream.consumer = "vector-0"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.can_acknowledge());
+        assert_eq!(
+            config.stream,
+            Some(StreamOption {
+                group: "vector".into(),
+                consumer: Some("vector-0".into()),
+                field: "message".into(),
+                batch_size: 100,
+                claim_idle_secs: 60,
+            })
+        );
+    }
 }
 
 #[cfg(all(test, feature = "redis-integration-tests"))]
 mod integration_test {
-    use redis::AsyncCommands;
+    use redis::{streams::StreamPendingReply, AsyncCommands};
 
     use super::*;
     use crate::config::log_schema;
     use crate::test_util::components::{run_and_assert_source_compliance_n, SOURCE_TAGS};
     use crate::{
+        event::EventStatus,
         test_util::{collect_n, random_string},
         SourceSender,
     };
@@ -341,12 +477,14 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Rpop,
             }),
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             log_namespace: Some(false),
+            acknowledgements: Default::default(),
         };
 
         let events = run_and_assert_source_compliance_n(config, 3, &SOURCE_TAGS).await;
@@ -373,12 +511,14 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Rpop,
             }),
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: Some("remapped_key".into()),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             log_namespace: Some(true),
+            acknowledgements: Default::default(),
         };
 
         let events = run_and_assert_source_compliance_n(config, 1, &SOURCE_TAGS).await;
@@ -414,12 +554,14 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Lpop,
             }),
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             log_namespace: Some(false),
+            acknowledgements: Default::default(),
         };
 
         let events = run_and_assert_source_compliance_n(config, 3, &SOURCE_TAGS).await;
@@ -438,12 +580,14 @@ mod integration_test {
         let config = RedisSourceConfig {
             data_type: DataTypeConfig::Channel,
             list: None,
+            stream: None,
             url: REDIS_SERVER.to_owned(),
             key: key.clone(),
             redis_key: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
             log_namespace: Some(false),
+            acknowledgements: Default::default(),
         };
 
         let (tx, rx) = SourceSender::new_test();
@@ -484,4 +628,106 @@ mod integration_test {
             );
         }
     }
+
+    fn stream_config(key: &str, claim_idle_secs: u64) -> RedisSourceConfig {
+        RedisSourceConfig {
+            data_type: DataTypeConfig::Stream,
+            list: None,
+            stream: Some(StreamOption {
+                consumer: Some("vector".into()),
+                claim_idle_secs,
+                ..Default::default()
+            }),
+            url: REDIS_SERVER.to_owned(),
+            key: key.to_owned(),
+            redis_key: None,
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            log_namespace: Some(false),
+            acknowledgements: true.into(),
+        }
+    }
+
+    async fn pending_count(conn: &mut redis::aio::ConnectionManager, key: &str) -> usize {
+        let reply: StreamPendingReply = conn.xpending(key, "vector").await.unwrap();
+        reply.count()
+    }
+
+    #[tokio::test]
+    async fn redis_source_stream_acknowledges_entries() {
+        let client = redis::Client::open(REDIS_SERVER).unwrap();
+        let mut conn = client.get_tokio_connection_manager().await.unwrap();
+
+        let key = format!("test-stream-{}", random_string(10));
+        debug!("Test key name: {}.", key);
+
+        // The consumer group is created when the source is built, starting from the entries added
+        // after that.
+        let (tx, rx) = SourceSender::new_test_finalize(EventStatus::Delivered);
+        let source = stream_config(&key, 60)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .expect("source should not fail to build");
+        tokio::spawn(source);
+
+        for i in 0..3 {
+            let _: String = conn
+                .xadd(&key, "*", &[("message", i.to_string())])
+                .await
+                .unwrap();
+        }
+
+        let events = collect_n(rx, 3).await;
+        for (i, event) in events.iter().enumerate() {
+            assert_eq!(
+                event.as_log()[log_schema().message_key()],
+                i.to_string().into()
+            );
+        }
+
+        // Entries are acknowledged once the events have been finalized.
+        for _ in 0..10 {
+            if pending_count(&mut conn, &key).await == 0 {
+                return;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+        panic!("Stream entries were not acknowledged.");
+    }
+
+    #[tokio::test]
+    async fn redis_source_stream_claims_pending_entries() {
+        let client = redis::Client::open(REDIS_SERVER).unwrap();
+        let mut conn = client.get_tokio_connection_manager().await.unwrap();
+
+        let key = format!("test-stream-{}", random_string(10));
+        debug!("Test key name: {}.", key);
+
+        let _: () = conn
+            .xgroup_create_mkstream(&key, "vector", "$")
+            .await
+            .unwrap();
+        let _: String = conn.xadd(&key, "*", &[("message", "stale")]).await.unwrap();
+
+        // Read the entry as another consumer of the group, which never acknowledges it.
+        let options = redis::streams::StreamReadOptions::default().group("vector", "stopped");
+        let _: redis::streams::StreamReadReply =
+            conn.xread_options(&[&key], &[">"], &options).await.unwrap();
+        assert_eq!(pending_count(&mut conn, &key).await, 1);
+
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+        let (tx, rx) = SourceSender::new_test_finalize(EventStatus::Delivered);
+        let source = stream_config(&key, 1)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .expect("source should not fail to build");
+        tokio::spawn(source);
+
+        let events = collect_n(rx, 1).await;
+        assert_eq!(
+            events[0].as_log()[log_schema().message_key()],
+            "stale".into()
+        );
+    }
 }
@@ -0,0 +1,296 @@
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use redis::{
+    aio::ConnectionManager,
+    from_redis_value,
+    streams::{StreamId, StreamRangeReply, StreamReadOptions, StreamReadReply},
+    AsyncCommands, ErrorKind, RedisError, RedisResult, Value,
+};
+use snafu::{ResultExt, Snafu};
+use vector_common::{
+    finalizer::UnorderedFinalizer,
+    internal_event::{BytesReceived, Registered},
+};
+use vector_core::config::LogNamespace;
+
+use super::handle_line;
+use crate::{
+    codecs,
+    config::SourceContext,
+    event::{BatchNotifier, BatchStatus},
+    internal_events::{RedisAckError, RedisReceiveEventError},
+    sources::Source,
+    SourceSender,
+};
+
+/// How long a read waits for new entries, so that pending entries are still claimed while the stream
+/// is idle.
+const READ_BLOCK: Duration = Duration::from_secs(1);
+
+/// The ID to start reading pending entries from, which is also returned once they have all been
+/// claimed.
+const CLAIM_START: &str = "0-0";
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("Failed to create connection: {}", source))]
+    Connection { source: redis::RedisError },
+    #[snafu(display("Failed to create consumer group: {}", source))]
+    CreateGroup { source: redis::RedisError },
+}
+
+pub struct ReadInputs {
+    pub client: redis::Client,
+    pub bytes_received: Registered<BytesReceived>,
+    pub key: String,
+    pub redis_key: Option<String>,
+    pub group: String,
+    pub consumer: String,
+    pub field: String,
+    pub batch_size: usize,
+    pub claim_idle: Duration,
+    pub decoder: codecs::Decoder,
+    pub cx: SourceContext,
+    pub log_namespace: LogNamespace,
+    pub acknowledgements: bool,
+}
+
+pub async fn read(input: ReadInputs) -> crate::Result<Source> {
+    let mut conn = input
+        .client
+        .get_tokio_connection_manager()
+        .await
+        .context(ConnectionSnafu {})?;
+
+    create_group(&mut conn, &input.key, &input.group)
+        .await
+        .context(CreateGroupSnafu {})?;
+
+    let (finalizer, mut ack_stream) =
+        UnorderedFinalizer::<String>::maybe_new(input.acknowledgements, input.cx.shutdown.clone());
+    if finalizer.is_some() {
+        // Reads block the connection they are made on, so entries are acknowledged on their own
+        // connection to not wait behind them.
+        let mut ack_conn = input
+            .client
+            .get_tokio_connection_manager()
+            .await
+            .context(ConnectionSnafu {})?;
+        let key = input.key.clone();
+        let group = input.group.clone();
+        tokio::spawn(async move {
+            while let Some((status, id)) = ack_stream.next().await {
+                handle_ack(&mut ack_conn, &key, &group, status, id).await;
+            }
+        });
+    }
+
+    Ok(Box::pin(async move {
+        let mut shutdown = input.cx.shutdown;
+        let mut tx = input.cx.out;
+        let options = StreamReadOptions::default()
+            .group(&input.group, &input.consumer)
+            .count(input.batch_size)
+            .block(READ_BLOCK.as_millis() as usize);
+        let reader = EntryReader {
+            key: &input.key,
+            redis_key: input.redis_key.as_deref(),
+            group: &input.group,
+            field: &input.field,
+            decoder: &input.decoder,
+            bytes_received: &input.bytes_received,
+            log_namespace: input.log_namespace,
+            finalizer: finalizer.as_ref(),
+        };
+
+        // Pending entries are claimed as soon as the source starts, as they may have been left
+        // behind by a previous run.
+        let mut last_claim: Option<Instant> = None;
+        loop {
+            if last_claim.map_or(true, |instant| instant.elapsed() >= input.claim_idle) {
+                let mut start = CLAIM_START.to_owned();
+                loop {
+                    let res = tokio::select! {
+                        res = autoclaim(
+                            &mut conn,
+                            &input.key,
+                            &input.group,
+                            &input.consumer,
+                            input.claim_idle,
+                            &start,
+                            input.batch_size,
+                        ) => res,
+                        _ = &mut shutdown => return Ok(()),
+                    };
+                    match res {
+                        Err(error) => {
+                            emit!(RedisReceiveEventError::from(error));
+                            break;
+                        }
+                        Ok((next, entries)) => {
+                            reader.handle_entries(&mut conn, &mut tx, entries).await?;
+                            if next == CLAIM_START {
+                                break;
+                            }
+                            start = next;
+                        }
+                    }
+                }
+                last_claim = Some(Instant::now());
+            }
+
+            let res: RedisResult<StreamReadReply> = tokio::select! {
+                res = conn.xread_options(&[&input.key], &[">"], &options) => res,
+                _ = &mut shutdown => break,
+            };
+            match res {
+                Err(error) => emit!(RedisReceiveEventError::from(error)),
+                Ok(reply) => {
+                    for stream in reply.keys {
+                        reader
+                            .handle_entries(&mut conn, &mut tx, stream.ids)
+                            .await?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }))
+}
+
+struct EntryReader<'a> {
+    key: &'a str,
+    redis_key: Option<&'a str>,
+    group: &'a str,
+    field: &'a str,
+    decoder: &'a codecs::Decoder,
+    bytes_received: &'a Registered<BytesReceived>,
+    log_namespace: LogNamespace,
+    finalizer: Option<&'a UnorderedFinalizer<String>>,
+}
+
+impl<'a> EntryReader<'a> {
+    async fn handle_entries(
+        &self,
+        conn: &mut ConnectionManager,
+        out: &mut SourceSender,
+        entries: Vec<StreamId>,
+    ) -> Result<(), ()> {
+        for entry in entries {
+            let line = match entry.get::<String>(self.field) {
+                Some(line) => line,
+                None => {
+                    emit!(RedisReceiveEventError::from(RedisError::from((
+                        ErrorKind::TypeError,
+                        "Stream entry is missing the message field",
+                        format!("entry {} has no field {:?}", entry.id, self.field),
+                    ))));
+                    // The entry would otherwise be claimed again and again.
+                    ack(conn, self.key, self.group, &entry.id).await;
+                    continue;
+                }
+            };
+
+            match self.finalizer {
+                Some(finalizer) => {
+                    let (batch, receiver) = BatchNotifier::new_with_receiver();
+                    self.handle_line(line, out, Some(&batch)).await?;
+                    finalizer.add(entry.id, receiver);
+                }
+                None => {
+                    self.handle_line(line, out, None).await?;
+                    ack(conn, self.key, self.group, &entry.id).await;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_line(
+        &self,
+        line: String,
+        out: &mut SourceSender,
+        batch: Option<&BatchNotifier>,
+    ) -> Result<(), ()> {
+        handle_line(
+            line,
+            self.key,
+            self.redis_key,
+            self.decoder.clone(),
+            self.bytes_received,
+            out,
+            self.log_namespace,
+            batch,
+        )
+        .await
+    }
+}
+
+async fn create_group(conn: &mut ConnectionManager, key: &str, group: &str) -> RedisResult<()> {
+    match conn
+        .xgroup_create_mkstream::<_, _, _, ()>(key, group, "$")
+        .await
+    {
+        Err(error) if error.code() == Some("BUSYGROUP") => Ok(()),
+        res => res,
+    }
+}
+
+/// Claims the entries that have been pending for at least `min_idle`, returning them along with the
+/// ID to continue claiming from.
+async fn autoclaim(
+    conn: &mut ConnectionManager,
+    key: &str,
+    group: &str,
+    consumer: &str,
+    min_idle: Duration,
+    start: &str,
+    count: usize,
+) -> RedisResult<(String, Vec<StreamId>)> {
+    // `redis` has no typed `XAUTOCLAIM` command, and the length of its reply depends on the version of
+    // Redis, so only the first two elements are parsed.
+    let reply: Vec<Value> = redis::cmd("XAUTOCLAIM")
+        .arg(key)
+        .arg(group)
+        .arg(consumer)
+        .arg(min_idle.as_millis() as u64)
+        .arg(start)
+        .arg("COUNT")
+        .arg(count)
+        .query_async(conn)
+        .await?;
+
+    match reply.as_slice() {
+        [next, entries, ..] => {
+            let entries: StreamRangeReply = from_redis_value(entries)?;
+            Ok((from_redis_value(next)?, entries.ids))
+        }
+        _ => Err(RedisError::from((
+            ErrorKind::TypeError,
+            "Unexpected XAUTOCLAIM reply",
+        ))),
+    }
+}
+
+async fn ack(conn: &mut ConnectionManager, key: &str, group: &str, id: &str) {
+    if let Err(error) = conn.xack::<_, _, _, ()>(key, group, &[id]).await {
+        emit!(RedisAckError::from(error));
+    }
+}
+
+async fn handle_ack(
+    conn: &mut ConnectionManager,
+    key: &str,
+    group: &str,
+    status: BatchStatus,
+    id: String,
+) {
+    match status {
+        // Rejected entries will never be processed, so they are acknowledged to not be claimed again.
+        BatchStatus::Delivered | BatchStatus::Rejected => ack(conn, key, group, &id).await,
+        // Errored entries are left pending, to be claimed and read again once `claim_idle_secs` has
+        // passed.
+        BatchStatus::Errored => {}
+    }
+}
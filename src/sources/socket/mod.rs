@@ -133,7 +133,9 @@ impl SourceConfig for SocketConfig {
                     }
                 };
 
-                let decoder = DecodingConfig::new(framing, decoding, LogNamespace::Legacy).build();
+                let decoder = DecodingConfig::new(framing, decoding, LogNamespace::Legacy)
+                    .with_decompression(config.decompression())
+                    .build()?;
                 let log_namespace = cx.log_namespace(config.log_namespace);
 
                 let tcp = tcp::RawTcpSource::new(config.clone(), decoder, log_namespace);
@@ -162,7 +164,8 @@ impl SourceConfig for SocketConfig {
                     config.decoding().clone(),
                     LogNamespace::Legacy,
                 )
-                .build();
+                .with_decompression(config.decompression)
+                .build()?;
                 Ok(udp::udp(
                     config,
                     decoder,
@@ -181,7 +184,8 @@ impl SourceConfig for SocketConfig {
                     config.decoding.clone(),
                     LogNamespace::Legacy,
                 )
-                .build();
+                .with_decompression(config.decompression)
+                .build()?;
 
                 let log_namespace = cx.log_namespace(config.log_namespace);
 
@@ -210,7 +214,9 @@ impl SourceConfig for SocketConfig {
                     }
                 };
 
-                let decoder = DecodingConfig::new(framing, decoding, LogNamespace::Legacy).build();
+                let decoder = DecodingConfig::new(framing, decoding, LogNamespace::Legacy)
+                    .with_decompression(config.decompression)
+                    .build()?;
 
                 let log_namespace = cx.log_namespace(config.log_namespace);
 
@@ -402,6 +408,7 @@ mod test {
 
     use super::{tcp::TcpConfig, udp::UdpConfig, SocketConfig};
     use crate::{
+        codecs::DecompressionConfig,
         config::{log_schema, ComponentKey, GlobalOptions, SourceConfig, SourceContext},
         event::{Event, LogEvent},
         shutdown::{ShutdownSignal, SourceShutdownCoordinator},
@@ -510,6 +517,43 @@ mod test {
         .await;
     }
 
+    #[tokio::test]
+    async fn tcp_decompresses_gzip() {
+        use std::io::Write;
+
+        use tokio::io::AsyncWriteExt;
+
+        assert_source_compliance(&SOCKET_HIGH_CARDINALITY_PUSH_SOURCE_TAGS, async {
+            let (tx, rx) = SourceSender::new_test();
+            let addr = next_addr();
+
+            let mut config = TcpConfig::from_address(addr.into());
+            config.set_decompression(DecompressionConfig::Auto);
+            let server = SocketConfig::from(config)
+                .build(SourceContext::new_test(tx, None))
+                .await
+                .unwrap();
+            tokio::spawn(server);
+
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(b"foo\nbar\n").unwrap();
+            let compressed = encoder.finish().unwrap();
+
+            wait_for_tcp(addr).await;
+            let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+            stream.write_all(&compressed).await.unwrap();
+            stream.shutdown().await.unwrap();
+
+            let events = collect_n(rx, 2).await;
+
+            assert_eq!(events.len(), 2);
+            assert_eq!(events[0].as_log()[log_schema().message_key()], "foo".into());
+            assert_eq!(events[1].as_log()[log_schema().message_key()], "bar".into());
+        })
+        .await;
+    }
+
     #[tokio::test]
     async fn tcp_it_includes_source_type() {
         assert_source_compliance(&SOCKET_HIGH_CARDINALITY_PUSH_SOURCE_TAGS, async {
@@ -6,7 +6,7 @@ use vector_config::{configurable_component, NamedComponent};
 use vector_core::config::{LegacyKey, LogNamespace};
 
 use crate::{
-    codecs::Decoder,
+    codecs::{Decoder, DecompressionConfig},
     config::log_schema,
     event::Event,
     serde::default_decoding,
@@ -70,6 +70,10 @@ pub struct TcpConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    decompression: DecompressionConfig,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     pub log_namespace: Option<bool>,
@@ -92,6 +96,7 @@ impl TcpConfig {
             receive_buffer_bytes: None,
             framing: None,
             decoding: default_decoding(),
+            decompression: Default::default(),
             connection_limit: None,
             log_namespace: None,
         }
@@ -117,6 +122,10 @@ impl TcpConfig {
         &self.decoding
     }
 
+    pub const fn decompression(&self) -> DecompressionConfig {
+        self.decompression
+    }
+
     pub const fn address(&self) -> SocketListenAddr {
         self.address
     }
@@ -162,6 +171,11 @@ impl TcpConfig {
         self
     }
 
+    pub fn set_decompression(&mut self, val: DecompressionConfig) -> &mut Self {
+        self.decompression = val;
+        self
+    }
+
     pub fn set_log_namespace(&mut self, val: Option<bool>) -> &mut Self {
         self.log_namespace = val;
         self
@@ -16,7 +16,7 @@ use vector_core::{
 };
 
 use crate::{
-    codecs::Decoder,
+    codecs::{Decoder, DecompressionConfig},
     config::log_schema,
     event::Event,
     internal_events::{
@@ -75,6 +75,10 @@ pub struct UdpConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub(super) decompression: DecompressionConfig,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     pub log_namespace: Option<bool>,
@@ -110,6 +114,7 @@ impl UdpConfig {
             receive_buffer_bytes: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            decompression: Default::default(),
             log_namespace: None,
         }
     }
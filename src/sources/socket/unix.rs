@@ -9,7 +9,7 @@ use vector_config::{configurable_component, NamedComponent};
 use vector_core::config::{LegacyKey, LogNamespace};
 
 use crate::{
-    codecs::Decoder,
+    codecs::{Decoder, DecompressionConfig},
     config::log_schema,
     event::Event,
     serde::default_decoding,
@@ -60,6 +60,10 @@ pub struct UnixConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub decompression: DecompressionConfig,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     pub log_namespace: Option<bool>,
@@ -74,6 +78,7 @@ impl UnixConfig {
             host_key: None,
             framing: None,
             decoding: default_decoding(),
+            decompression: Default::default(),
             log_namespace: None,
         }
     }
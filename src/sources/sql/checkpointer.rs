@@ -0,0 +1,161 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::TrackingValue;
+
+const TMP_FILE_NAME: &str = "checkpoint.new.json";
+const CHECKPOINT_FILE_NAME: &str = "checkpoint.json";
+
+/// The file format of the checkpoint persisted to disk. Any incompatible change requires a new
+/// variant to be added here.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "version", rename_all = "snake_case")]
+enum State {
+    #[serde(rename = "1")]
+    V1 { tracking_value: TaggedValue },
+}
+
+/// A tracking value tagged with its type.
+///
+/// `TrackingValue` is untagged so that it can be configured as a plain value, which would read a
+/// persisted string that looks like a timestamp back as a timestamp.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+enum TaggedValue {
+    Integer(i64),
+    Float(f64),
+    Timestamp(DateTime<Utc>),
+    Text(String),
+}
+
+impl From<TrackingValue> for TaggedValue {
+    fn from(value: TrackingValue) -> Self {
+        match value {
+            TrackingValue::Integer(value) => Self::Integer(value),
+            TrackingValue::Float(value) => Self::Float(value),
+            TrackingValue::Timestamp(value) => Self::Timestamp(value),
+            TrackingValue::Text(value) => Self::Text(value),
+        }
+    }
+}
+
+impl From<TaggedValue> for TrackingValue {
+    fn from(value: TaggedValue) -> Self {
+        match value {
+            TaggedValue::Integer(value) => Self::Integer(value),
+            TaggedValue::Float(value) => Self::Float(value),
+            TaggedValue::Timestamp(value) => Self::Timestamp(value),
+            TaggedValue::Text(value) => Self::Text(value),
+        }
+    }
+}
+
+/// Persists the last value of the tracking column, so that queries pick up where they left off
+/// across restarts.
+pub(super) struct Checkpointer {
+    tmp_file_path: PathBuf,
+    stable_file_path: PathBuf,
+}
+
+impl Checkpointer {
+    pub(super) fn new(data_dir: &Path) -> Self {
+        Self {
+            tmp_file_path: data_dir.join(TMP_FILE_NAME),
+            stable_file_path: data_dir.join(CHECKPOINT_FILE_NAME),
+        }
+    }
+
+    pub(super) fn path(&self) -> &Path {
+        &self.stable_file_path
+    }
+
+    /// Reads the persisted tracking value, if there is one.
+    pub(super) fn read(&self) -> io::Result<Option<TrackingValue>> {
+        match fs::read(&self.stable_file_path) {
+            Ok(contents) => {
+                let State::V1 { tracking_value } = serde_json::from_slice(&contents)?;
+                Ok(Some(tracking_value.into()))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Persists the tracking value, making our best effort to do so in an atomic way that allows
+    /// recovering the previous value in the event of a crash.
+    pub(super) fn write(&self, tracking_value: &TrackingValue) -> io::Result<()> {
+        let state = State::V1 {
+            tracking_value: tracking_value.clone().into(),
+        };
+
+        // If Vector dies while the tmp file is written, the stable file is still in its previous
+        // valid state.
+        let mut file = io::BufWriter::new(fs::File::create(&self.tmp_file_path)?);
+        serde_json::to_writer(&mut file, &state)?;
+        file.flush()?;
+        file.into_inner()?.sync_all()?;
+
+        fs::rename(&self.tmp_file_path, &self.stable_file_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn round_trips_tracking_values() {
+        let data_dir = tempdir().unwrap();
+        let checkpointer = Checkpointer::new(data_dir.path());
+        assert_eq!(checkpointer.read().unwrap(), None);
+
+        for tracking_value in [
+            TrackingValue::Integer(42),
+            TrackingValue::Float(4.2),
+            TrackingValue::Timestamp(Utc.ymd(2022, 11, 14).and_hms(10, 30, 0)),
+            TrackingValue::Text("b".into()),
+            TrackingValue::Text("2022-11-14T10:30:00Z".into()),
+        ] {
+            checkpointer.write(&tracking_value).unwrap();
+            assert_eq!(checkpointer.read().unwrap(), Some(tracking_value));
+        }
+    }
+
+    #[test]
+    fn reads_versioned_checkpoint() {
+        let data_dir = tempdir().unwrap();
+        let checkpointer = Checkpointer::new(data_dir.path());
+        fs::write(
+            checkpointer.path(),
+            r#"{"version":"1","tracking_value":{"type":"timestamp","value":"2022-11-14T10:30:00Z"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            checkpointer.read().unwrap(),
+            Some(TrackingValue::Timestamp(
+                Utc.ymd(2022, 11, 14).and_hms(10, 30, 0)
+            ))
+        );
+
+        fs::write(
+            checkpointer.path(),
+            r#"{"version":"1","tracking_value":{"type":"text","value":"2022-11-14T10:30:00Z"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            checkpointer.read().unwrap(),
+            Some(TrackingValue::Text("2022-11-14T10:30:00Z".into()))
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use postgres_openssl::MakeTlsConnector;
+#[cfg(feature = "sources-sql-mysql")]
+use sqlx::{
+    mysql::{MySqlArguments, MySqlPool, MySqlPoolOptions, MySqlRow},
+    query::Query,
+    Column as _, MySql, Row as _, TypeInfo as _,
+};
+use tokio_postgres::{
+    types::{ToSql, Type},
+    Client, Config, Error as PgError, NoTls, Row as PgRow,
+};
+use value::Value;
+
+use super::TrackingValue;
+
+/// A row returned by a query, as the name and value of each of its columns.
+pub(super) type Columns = Vec<(String, Value)>;
+
+/// A connection to one of the supported databases.
+pub(super) enum Pool {
+    Postgres(PostgresClient),
+    #[cfg(feature = "sources-sql-mysql")]
+    MySql(MySqlPool),
+}
+
+impl Pool {
+    /// Creates a pool for the PostgreSQL server configured by `config`, which only connects once it
+    /// runs a query.
+    pub(super) const fn postgres(config: Config, tls: Option<MakeTlsConnector>) -> Self {
+        Self::Postgres(PostgresClient {
+            config,
+            tls,
+            client: None,
+        })
+    }
+
+    /// Creates a pool for the MySQL server at `url`, which only connects once it runs a query.
+    #[cfg(feature = "sources-sql-mysql")]
+    pub(super) fn mysql(url: &str) -> Result<Self, sqlx::Error> {
+        // Queries run one at a time, so a single connection is enough.
+        MySqlPoolOptions::new()
+            .max_connections(1)
+            .connect_lazy(url)
+            .map(Self::MySql)
+    }
+
+    /// Runs `query`, binding `tracking_value` as its only parameter if it is set.
+    pub(super) async fn fetch(
+        &mut self,
+        query: &str,
+        tracking_value: Option<&TrackingValue>,
+    ) -> crate::Result<Vec<Columns>> {
+        match self {
+            Self::Postgres(client) => Ok(client.fetch(query, tracking_value).await?),
+            #[cfg(feature = "sources-sql-mysql")]
+            Self::MySql(pool) => {
+                let rows = bind(sqlx::query::<MySql>(query), tracking_value)
+                    .fetch_all(&*pool)
+                    .await?;
+                Ok(rows.iter().map(mysql_columns).collect())
+            }
+        }
+    }
+}
+
+/// A PostgreSQL client, which connects on the first query and connects again once the connection
+/// has been closed.
+pub(super) struct PostgresClient {
+    config: Config,
+    tls: Option<MakeTlsConnector>,
+    client: Option<Client>,
+}
+
+impl PostgresClient {
+    async fn connect(&self) -> Result<Client, PgError> {
+        let client = match &self.tls {
+            Some(tls) => {
+                let (client, connection) = self.config.connect(tls.clone()).await?;
+                tokio::spawn(connection);
+                client
+            }
+            None => {
+                let (client, connection) = self.config.connect(NoTls).await?;
+                tokio::spawn(connection);
+                client
+            }
+        };
+        Ok(client)
+    }
+
+    async fn fetch(
+        &mut self,
+        query: &str,
+        tracking_value: Option<&TrackingValue>,
+    ) -> Result<Vec<Columns>, PgError> {
+        if self.client.as_ref().map_or(true, Client::is_closed) {
+            self.client = Some(self.connect().await?);
+        }
+        let client = self.client.as_ref().expect("client should be connected");
+
+        let rows = match tracking_value {
+            None => client.query(query, &[]).await?,
+            Some(TrackingValue::Integer(value)) => {
+                query_typed(client, query, Type::INT8, value).await?
+            }
+            Some(TrackingValue::Float(value)) => {
+                query_typed(client, query, Type::FLOAT8, value).await?
+            }
+            Some(TrackingValue::Timestamp(value)) => {
+                query_typed(client, query, Type::TIMESTAMPTZ, value).await?
+            }
+            Some(TrackingValue::Text(value)) => {
+                query_typed(client, query, Type::TEXT, value).await?
+            }
+        };
+        Ok(rows.iter().map(postgres_columns).collect())
+    }
+}
+
+/// Runs `query` with `param` as its only parameter.
+///
+/// The type of the parameter is set from the tracking value, rather than inferred by the server,
+/// so that it can be compared with a column of any compatible type.
+async fn query_typed(
+    client: &Client,
+    query: &str,
+    param_type: Type,
+    param: &(dyn ToSql + Sync),
+) -> Result<Vec<PgRow>, PgError> {
+    let statement = client.prepare_typed(query, &[param_type]).await?;
+    client.query(&statement, &[param]).await
+}
+
+#[cfg(feature = "sources-sql-mysql")]
+fn bind<'q>(
+    query: Query<'q, MySql, MySqlArguments>,
+    tracking_value: Option<&TrackingValue>,
+) -> Query<'q, MySql, MySqlArguments> {
+    match tracking_value.cloned() {
+        None => query,
+        Some(TrackingValue::Integer(value)) => query.bind(value),
+        Some(TrackingValue::Float(value)) => query.bind(value),
+        Some(TrackingValue::Timestamp(value)) => query.bind(value),
+        Some(TrackingValue::Text(value)) => query.bind(value),
+    }
+}
+
+/// Decodes the column at `$index` of `$row` as the first of the given types it is compatible
+/// with, returning from the enclosing function.
+///
+/// `NULL` values are compatible with any type, and are decoded as `Value::Null`.
+macro_rules! decode_as {
+    ($row:expr, $index:expr, $($ty:ty => $into:expr),+ $(,)?) => {
+        $(
+            let value: Result<Option<$ty>, _> = $row.try_get($index);
+            if let Ok(value) = value {
+                return Some(value.map($into).unwrap_or(Value::Null));
+            }
+        )+
+    };
+}
+
+fn postgres_value(row: &PgRow, index: usize) -> Option<Value> {
+    decode_as!(row, index,
+        bool => Value::from,
+        i16 => |value| Value::from(i64::from(value)),
+        i32 => |value| Value::from(i64::from(value)),
+        i64 => Value::from,
+        f32 => |value| Value::from(f64::from(value)),
+        f64 => Value::from,
+        String => Value::from,
+        DateTime<Utc> => Value::from,
+        NaiveDateTime => |value| Value::from(DateTime::<Utc>::from_utc(value, Utc)),
+        NaiveDate => |value| Value::from(value.to_string()),
+        NaiveTime => |value| Value::from(value.to_string()),
+        serde_json::Value => Value::from,
+        Vec<u8> => |value| Value::from(Bytes::from(value)),
+    );
+    None
+}
+
+#[cfg(feature = "sources-sql-mysql")]
+fn mysql_value(row: &MySqlRow, index: usize) -> Option<Value> {
+    // Booleans are stored as `TINYINT(1)`, which is compatible with any integer type.
+    if row.column(index).type_info().name() == "BOOLEAN" {
+        decode_as!(row, index, bool => Value::from);
+    }
+    decode_as!(row, index,
+        i64 => Value::from,
+        // Unsigned integers beyond the range of `i64` are kept as strings, rather than wrapped.
+        u64 => |value| match i64::try_from(value) {
+            Ok(value) => Value::from(value),
+            Err(_) => Value::from(value.to_string()),
+        },
+        f64 => Value::from,
+        String => Value::from,
+        DateTime<Utc> => Value::from,
+        NaiveDateTime => |value| Value::from(DateTime::<Utc>::from_utc(value, Utc)),
+        NaiveDate => |value| Value::from(value.to_string()),
+        NaiveTime => |value| Value::from(value.to_string()),
+        serde_json::Value => Value::from,
+        Vec<u8> => |value| Value::from(Bytes::from(value)),
+    );
+    None
+}
+
+fn postgres_columns(row: &PgRow) -> Columns {
+    row.columns()
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            column_value(
+                column.name(),
+                column.type_().name(),
+                postgres_value(row, index),
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "sources-sql-mysql")]
+fn mysql_columns(row: &MySqlRow) -> Columns {
+    row.columns()
+        .iter()
+        .map(|column| {
+            column_value(
+                column.name(),
+                column.type_info().name(),
+                mysql_value(row, column.ordinal()),
+            )
+        })
+        .collect()
+}
+
+fn column_value(name: &str, type_name: &str, value: Option<Value>) -> (String, Value) {
+    let value = value.unwrap_or_else(|| {
+        warn!(
+            message = "Unsupported column type, the column is set to null. Cast it to a supported type in the query.",
+            column = %name,
+            column_type = %type_name,
+            internal_log_rate_limit = true,
+        );
+        Value::Null
+    });
+    (name.to_owned(), value)
+}
@@ -2,15 +2,16 @@ use std::net::SocketAddr;
 #[cfg(unix)]
 use std::path::PathBuf;
 
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use chrono::Utc;
 use codecs::{
-    decoding::{Deserializer, Framer},
-    BytesDecoder, OctetCountingDecoder, SyslogDeserializer,
+    decoding::{format::Deserializer as _, Deserializer, Framer},
+    BytesDecoder, OctetCountingDecoder, StreamDecodingError, SyslogDeserializer,
 };
 use futures::StreamExt;
 use listenfd::ListenFd;
 use smallvec::SmallVec;
+use snafu::Snafu;
 use tokio_util::udp::UdpFramed;
 use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
@@ -19,12 +20,19 @@ use vector_core::config::LogNamespace;
 use crate::sources::util::build_unix_stream_source;
 use crate::{
     codecs::Decoder,
-    config::{log_schema, DataType, GenerateConfig, Output, Resource, SourceConfig, SourceContext},
+    config::{
+        log_schema, DataType, GenerateConfig, Output, Resource, SourceAcknowledgementsConfig,
+        SourceConfig, SourceContext,
+    },
     event::Event,
     internal_events::StreamClosedError,
     internal_events::{SocketBindError, SocketMode, SocketReceiveError},
+    serde::bool_or_struct,
     shutdown::ShutdownSignal,
-    sources::util::net::{try_bind_udp_socket, SocketListenAddr, TcpNullAcker, TcpSource},
+    sources::util::net::{
+        try_bind_udp_socket, SocketListenAddr, TcpNullAcker, TcpSource, TcpSourceAck,
+        TcpSourceAcker,
+    },
     tcp::TcpKeepaliveConfig,
     tls::{MaybeTlsSettings, TlsSourceConfig},
     udp, SourceSender,
@@ -79,6 +87,33 @@ pub enum Mode {
         connection_limit: Option<u32>,
     },
 
+    /// Listen on TCP for RELP (Reliable Event Logging Protocol) sessions.
+    ///
+    /// Each message is acknowledged to the client once it has been accepted, which means once it
+    /// has been delivered to all connected sinks when acknowledgements are enabled.
+    Relp {
+        /// The address to listen for connections on.
+        address: SocketListenAddr,
+
+        #[configurable(derived)]
+        keepalive: Option<TcpKeepaliveConfig>,
+
+        #[configurable(derived)]
+        tls: Option<TlsSourceConfig>,
+
+        /// The size, in bytes, of the receive buffer used for each connection.
+        ///
+        /// This should not typically needed to be changed.
+        receive_buffer_bytes: Option<usize>,
+
+        /// The maximum number of TCP connections that will be allowed at any given time.
+        connection_limit: Option<u32>,
+
+        #[configurable(derived)]
+        #[serde(default, deserialize_with = "bool_or_struct")]
+        acknowledgements: SourceAcknowledgementsConfig,
+    },
+
     /// Listen on UDP.
     Udp {
         /// The address to listen for messages on.
@@ -170,6 +205,35 @@ impl SourceConfig for SyslogConfig {
                     connection_limit,
                 )
             }
+            Mode::Relp {
+                address,
+                keepalive,
+                tls,
+                receive_buffer_bytes,
+                connection_limit,
+                acknowledgements,
+            } => {
+                let source = SyslogRelpSource {
+                    max_length: self.max_length,
+                    host_key,
+                };
+                let shutdown_secs = 30;
+                let tls_config = tls.as_ref().map(|tls| tls.tls_config.clone());
+                let tls_client_metadata_key =
+                    tls.as_ref().and_then(|tls| tls.client_metadata_key.clone());
+                let tls = MaybeTlsSettings::from_config(&tls_config, true)?;
+                source.run(
+                    address,
+                    keepalive,
+                    shutdown_secs,
+                    tls,
+                    tls_client_metadata_key,
+                    receive_buffer_bytes,
+                    cx,
+                    acknowledgements,
+                    connection_limit,
+                )
+            }
             Mode::Udp {
                 address,
                 receive_buffer_bytes,
@@ -211,7 +275,9 @@ impl SourceConfig for SyslogConfig {
 
     fn resources(&self) -> Vec<Resource> {
         match self.mode.clone() {
-            Mode::Tcp { address, .. } => vec![address.as_tcp_resource()],
+            Mode::Tcp { address, .. } | Mode::Relp { address, .. } => {
+                vec![address.as_tcp_resource()]
+            }
             Mode::Udp { address, .. } => vec![address.as_udp_resource()],
             #[cfg(unix)]
             Mode::Unix { .. } => vec![],
@@ -219,7 +285,7 @@ impl SourceConfig for SyslogConfig {
     }
 
     fn can_acknowledge(&self) -> bool {
-        false
+        matches!(self.mode, Mode::Relp { .. })
     }
 }
 
@@ -251,6 +317,251 @@ impl TcpSource for SyslogTcpSource {
     }
 }
 
+#[derive(Debug, Clone)]
+struct SyslogRelpSource {
+    max_length: usize,
+    host_key: String,
+}
+
+impl TcpSource for SyslogRelpSource {
+    type Error = RelpDecodeError;
+    type Item = RelpFrame;
+    type Decoder = RelpDecoder;
+    type Acker = RelpAcker;
+
+    fn decoder(&self) -> Self::Decoder {
+        RelpDecoder::new(self.max_length)
+    }
+
+    fn handle_events(&self, events: &mut [Event], host: SocketAddr) {
+        handle_events(events, &self.host_key, Some(host.ip().to_string().into()));
+    }
+
+    fn build_acker(&self, frames: &[Self::Item]) -> Self::Acker {
+        RelpAcker::new(frames)
+    }
+}
+
+/// The longest transaction number, command or data length accepted in a RELP frame header.
+const RELP_MAX_HEADER_FIELD_LENGTH: usize = 32;
+
+/// The offers sent in response to an `open` command.
+const RELP_OPEN_OFFERS: &str = "relp_version=0\nrelp_software=vector\ncommands=syslog";
+
+/// A RELP command, along with the events it carries.
+#[derive(Debug)]
+enum RelpCommand {
+    Open,
+    Syslog(SmallVec<[Event; 1]>),
+    Close,
+    /// A command that could not be processed, which is answered with an error response.
+    Failed(String),
+}
+
+/// A frame received from a RELP client.
+#[derive(Debug)]
+struct RelpFrame {
+    txnr: u32,
+    command: RelpCommand,
+}
+
+impl From<RelpFrame> for SmallVec<[Event; 1]> {
+    fn from(frame: RelpFrame) -> Self {
+        match frame.command {
+            RelpCommand::Syslog(events) => events,
+            _ => SmallVec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Snafu)]
+enum RelpDecodeError {
+    #[snafu(display("i/o error: {}", source))]
+    IO { source: std::io::Error },
+    #[snafu(display("Invalid RELP frame header"))]
+    InvalidHeader,
+    #[snafu(display("RELP frame is not terminated by a newline"))]
+    InvalidTrailer,
+    #[snafu(display("RELP frame of {} bytes exceeds the maximum of {}", length, max_length))]
+    FrameTooLong { length: usize, max_length: usize },
+}
+
+impl StreamDecodingError for RelpDecodeError {
+    fn can_continue(&self) -> bool {
+        // The frames can't be re-synchronized after an invalid one, as the data may hold anything.
+        false
+    }
+}
+
+impl From<std::io::Error> for RelpDecodeError {
+    fn from(source: std::io::Error) -> Self {
+        RelpDecodeError::IO { source }
+    }
+}
+
+/// Decodes RELP frames, as described in the [RELP specification][relp].
+///
+/// Each frame is made of a transaction number, a command and the length of the data, followed by
+/// the data itself and a newline: `TXNR SP COMMAND SP DATALEN [SP DATA] LF`.
+///
+/// [relp]: https://github.com/rsyslog/librelp/blob/master/doc/relp.html
+#[derive(Debug)]
+struct RelpDecoder {
+    max_length: usize,
+}
+
+impl RelpDecoder {
+    const fn new(max_length: usize) -> Self {
+        Self { max_length }
+    }
+}
+
+/// Finds the end of the header field starting at `start`, which is terminated by one of
+/// `delimiters`.
+fn relp_header_field(
+    src: &[u8],
+    start: usize,
+    delimiters: &[u8],
+) -> Result<Option<usize>, RelpDecodeError> {
+    let field = &src[start.min(src.len())..];
+    match field
+        .iter()
+        .take(RELP_MAX_HEADER_FIELD_LENGTH + 1)
+        .position(|byte| delimiters.contains(byte))
+    {
+        Some(0) => Err(RelpDecodeError::InvalidHeader),
+        Some(end) => Ok(Some(start + end)),
+        None if field.len() > RELP_MAX_HEADER_FIELD_LENGTH => Err(RelpDecodeError::InvalidHeader),
+        None => Ok(None),
+    }
+}
+
+fn relp_header_number<T: std::str::FromStr>(field: &[u8]) -> Result<T, RelpDecodeError> {
+    std::str::from_utf8(field)
+        .ok()
+        .filter(|field| field.bytes().all(|byte| byte.is_ascii_digit()))
+        .and_then(|field| field.parse().ok())
+        .ok_or(RelpDecodeError::InvalidHeader)
+}
+
+impl tokio_util::codec::Decoder for RelpDecoder {
+    type Item = (RelpFrame, usize);
+    type Error = RelpDecodeError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let txnr_end = match relp_header_field(src, 0, b" ")? {
+            Some(end) => end,
+            None => return Ok(None),
+        };
+        let command_end = match relp_header_field(src, txnr_end + 1, b" ")? {
+            Some(end) => end,
+            None => return Ok(None),
+        };
+        let datalen_end = match relp_header_field(src, command_end + 1, b" \n")? {
+            Some(end) => end,
+            None => return Ok(None),
+        };
+
+        let txnr = relp_header_number::<u32>(&src[..txnr_end])?;
+        let datalen = relp_header_number::<usize>(&src[command_end + 1..datalen_end])?;
+        if datalen > self.max_length {
+            return Err(RelpDecodeError::FrameTooLong {
+                length: datalen,
+                max_length: self.max_length,
+            });
+        }
+
+        let (data_start, frame_length) = if src[datalen_end] == b'\n' {
+            if datalen != 0 {
+                return Err(RelpDecodeError::InvalidHeader);
+            }
+            (datalen_end, datalen_end + 1)
+        } else {
+            let data_start = datalen_end + 1;
+            let trailer = data_start + datalen;
+            if src.len() <= trailer {
+                src.reserve(trailer + 1 - src.len());
+                return Ok(None);
+            }
+            if src[trailer] != b'\n' {
+                return Err(RelpDecodeError::InvalidTrailer);
+            }
+            (data_start, trailer + 1)
+        };
+
+        let frame = src.split_to(frame_length).freeze();
+        let data = frame.slice(data_start..data_start + datalen);
+        let command = match &frame[txnr_end + 1..command_end] {
+            b"open" => RelpCommand::Open,
+            b"close" => RelpCommand::Close,
+            b"syslog" => match SyslogDeserializer.parse(data, LogNamespace::Legacy) {
+                Ok(events) => RelpCommand::Syslog(events),
+                Err(error) => RelpCommand::Failed(format!("invalid syslog message: {}", error)),
+            },
+            command => RelpCommand::Failed(format!(
+                "command {} not supported",
+                String::from_utf8_lossy(command)
+            )),
+        };
+
+        Ok(Some((RelpFrame { txnr, command }, frame_length)))
+    }
+}
+
+/// Builds the responses to the frames of a batch, once its events have been accepted.
+struct RelpAcker {
+    frames: Vec<(u32, RelpResponse)>,
+}
+
+enum RelpResponse {
+    Open,
+    Syslog,
+    Close,
+    Failed(String),
+}
+
+impl RelpAcker {
+    fn new(frames: &[RelpFrame]) -> Self {
+        let frames = frames
+            .iter()
+            .map(|frame| {
+                let response = match &frame.command {
+                    RelpCommand::Open => RelpResponse::Open,
+                    RelpCommand::Syslog(_) => RelpResponse::Syslog,
+                    RelpCommand::Close => RelpResponse::Close,
+                    RelpCommand::Failed(reason) => RelpResponse::Failed(reason.clone()),
+                };
+                (frame.txnr, response)
+            })
+            .collect();
+        Self { frames }
+    }
+}
+
+impl TcpSourceAcker for RelpAcker {
+    fn build_ack(self, ack: TcpSourceAck) -> Option<Bytes> {
+        let mut bytes = BytesMut::new();
+        for (txnr, response) in self.frames {
+            let data = match (response, ack) {
+                (RelpResponse::Open, _) => format!("200 OK\n{}", RELP_OPEN_OFFERS),
+                (RelpResponse::Syslog, TcpSourceAck::Ack) => "200 OK".to_owned(),
+                (RelpResponse::Syslog, TcpSourceAck::Error) => {
+                    "500 error delivering events".to_owned()
+                }
+                (RelpResponse::Syslog, TcpSourceAck::Reject) => "500 events rejected".to_owned(),
+                (RelpResponse::Close, _) => String::new(),
+                (RelpResponse::Failed(reason), _) => format!("500 {}", reason),
+            };
+            if data.is_empty() {
+                bytes.put_slice(format!("{} rsp 0\n", txnr).as_bytes());
+            } else {
+                bytes.put_slice(format!("{} rsp {} {}\n", txnr, data.len(), data).as_bytes());
+            }
+        }
+        Some(bytes.freeze())
+    }
+}
+
 pub fn udp(
     addr: SocketListenAddr,
     _max_length: usize,
@@ -370,8 +681,11 @@ mod test {
     use codecs::decoding::format::Deserializer;
     use rand::{thread_rng, Rng};
     use serde::Deserialize;
-    use tokio::time::{sleep, Duration, Instant};
-    use tokio_util::codec::BytesCodec;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        time::{sleep, Duration, Instant},
+    };
+    use tokio_util::codec::{BytesCodec, Decoder as _};
     use value::Value;
     use vector_common::assert_event_data_eq;
     use vector_core::config::ComponentKey;
@@ -379,11 +693,11 @@ mod test {
     use super::*;
     use crate::{
         config::log_schema,
-        event::{Event, LogEvent},
+        event::{Event, EventStatus, LogEvent},
         test_util::{
             components::{assert_source_compliance, SOCKET_PUSH_SOURCE_TAGS},
-            next_addr, random_maps, random_string, send_encodable, send_lines, wait_for_tcp,
-            CountReceiver,
+            next_addr, random_maps, random_string, send_encodable, send_lines, spawn_collect_n,
+            wait_for_tcp, CountReceiver,
         },
     };
 
@@ -789,6 +1103,128 @@ mod test {
         );
     }
 
+    #[test]
+    fn config_relp() {
+        let config: SyslogConfig = toml::from_str(
+            r#"
+            mode = "relp"
+            address = "127.0.0.1:1235"
+            acknowledgements = true
+          "#,
+        )
+        .unwrap();
+        assert!(matches!(config.mode, Mode::Relp { .. }));
+        assert!(config.can_acknowledge());
+    }
+
+    fn relp_frame(txnr: u32, command: &str, data: &str) -> String {
+        if data.is_empty() {
+            format!("{} {} 0\n", txnr, command)
+        } else {
+            format!("{} {} {} {}\n", txnr, command, data.len(), data)
+        }
+    }
+
+    #[test]
+    fn relp_decode_frames() {
+        let message = "<13>1 2019-02-13T19:48:34+00:00 74794bfb6795 root 8449 - - hello world";
+        let mut buffer = BytesMut::from(
+            [
+                relp_frame(1, "open", RELP_OPEN_OFFERS),
+                relp_frame(2, "syslog", message),
+                relp_frame(3, "starttls", ""),
+                relp_frame(4, "close", ""),
+            ]
+            .concat()
+            .as_str(),
+        );
+        let mut decoder = RelpDecoder::new(crate::serde::default_max_length());
+
+        let (frame, _) = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(frame.txnr, 1);
+        assert!(matches!(frame.command, RelpCommand::Open));
+
+        let (frame, _) = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(frame.txnr, 2);
+        let events = match frame.command {
+            RelpCommand::Syslog(events) => events,
+            command => panic!("unexpected command {:?}", command),
+        };
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_log()["message"], "hello world".into());
+
+        let (frame, _) = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(frame.txnr, 3);
+        assert!(matches!(frame.command, RelpCommand::Failed(_)));
+
+        let (frame, _) = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(frame.txnr, 4);
+        assert!(matches!(frame.command, RelpCommand::Close));
+
+        assert!(decoder.decode(&mut buffer).unwrap().is_none());
+    }
+
+    #[test]
+    fn relp_decode_partial_frame() {
+        let frame = relp_frame(1, "syslog", "<13>1 - - - - - hello");
+        let mut decoder = RelpDecoder::new(crate::serde::default_max_length());
+
+        for length in 0..frame.len() {
+            let mut buffer = BytesMut::from(&frame[..length]);
+            assert!(decoder.decode(&mut buffer).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn relp_decode_invalid_frames() {
+        let mut decoder = RelpDecoder::new(10);
+
+        for frame in [
+            "x syslog 5 hello\n",
+            "1 syslog 5 hello!",
+            "1 syslog 11 hello world\n",
+            "1 close 5\n",
+        ] {
+            let mut buffer = BytesMut::from(frame);
+            assert!(decoder.decode(&mut buffer).is_err(), "{:?}", frame);
+        }
+    }
+
+    #[test]
+    fn relp_build_ack() {
+        let frames = [
+            RelpFrame {
+                txnr: 1,
+                command: RelpCommand::Open,
+            },
+            RelpFrame {
+                txnr: 2,
+                command: RelpCommand::Syslog(SmallVec::new()),
+            },
+            RelpFrame {
+                txnr: 3,
+                command: RelpCommand::Close,
+            },
+        ];
+
+        let ack = RelpAcker::new(&frames)
+            .build_ack(TcpSourceAck::Ack)
+            .unwrap();
+        assert_eq!(
+            ack,
+            format!(
+                "1 rsp {} 200 OK\n{}\n2 rsp 6 200 OK\n3 rsp 0\n",
+                RELP_OPEN_OFFERS.len() + 7,
+                RELP_OPEN_OFFERS
+            )
+        );
+
+        let ack = RelpAcker::new(&frames[1..2])
+            .build_ack(TcpSourceAck::Reject)
+            .unwrap();
+        assert_eq!(ack, "2 rsp 19 500 events rejected\n");
+    }
+
     #[tokio::test]
     async fn test_tcp_syslog() {
         assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
@@ -855,6 +1291,81 @@ mod test {
         .await;
     }
 
+    #[tokio::test]
+    async fn test_relp_delivered() {
+        test_relp(EventStatus::Delivered, "200 OK").await;
+    }
+
+    #[tokio::test]
+    async fn test_relp_rejected() {
+        test_relp(EventStatus::Rejected, "500 events rejected").await;
+    }
+
+    async fn test_relp(status: EventStatus, response: &'static str) {
+        let events = assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
+            let (sender, recv) = SourceSender::new_test_finalize(status);
+            let address = next_addr();
+            let source = SyslogConfig::from_mode(Mode::Relp {
+                address: address.into(),
+                keepalive: None,
+                tls: None,
+                receive_buffer_bytes: None,
+                connection_limit: None,
+                acknowledgements: true.into(),
+            })
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+            tokio::spawn(source);
+            wait_for_tcp(address).await;
+
+            spawn_collect_n(
+                async move {
+                    let mut socket = tokio::net::TcpStream::connect(address).await.unwrap();
+
+                    socket
+                        .write_all(relp_frame(1, "open", "relp_version=0").as_bytes())
+                        .await
+                        .unwrap();
+                    let expected = format!(
+                        "1 rsp {} 200 OK\n{}\n",
+                        RELP_OPEN_OFFERS.len() + 7,
+                        RELP_OPEN_OFFERS
+                    );
+                    assert_eq!(
+                        read_relp_response(&mut socket, expected.len()).await,
+                        expected
+                    );
+
+                    socket
+                        .write_all(relp_frame(2, "syslog", "<13>1 - - - - - hello").as_bytes())
+                        .await
+                        .unwrap();
+                    let expected = format!("2 rsp {} {}\n", response.len(), response);
+                    assert_eq!(
+                        read_relp_response(&mut socket, expected.len()).await,
+                        expected
+                    );
+                },
+                recv,
+                1,
+            )
+            .await
+        })
+        .await;
+
+        assert_eq!(events.len(), 1);
+        let log = events[0].as_log();
+        assert_eq!(log["message"], "hello".into());
+        assert_eq!(log["source_type"], "syslog".into());
+    }
+
+    async fn read_relp_response(socket: &mut tokio::net::TcpStream, length: usize) -> String {
+        let mut response = vec![0; length];
+        socket.read_exact(&mut response).await.unwrap();
+        String::from_utf8(response).unwrap()
+    }
+
     #[cfg(unix)]
     #[tokio::test]
     async fn test_unix_stream_syslog() {
@@ -1,5 +1,5 @@
 use std::{
-    collections::{hash_map::Entry, HashMap},
+    collections::{hash_map::Entry, HashMap, HashSet},
     pin::Pin,
     time::Duration,
 };
@@ -12,6 +12,7 @@ use crate::{
     config::{DataType, Input, Output, TransformConfig, TransformContext},
     event::{metric, Event, EventMetadata},
     internal_events::{AggregateEventRecorded, AggregateFlushed, AggregateUpdateFailed},
+    metrics::AgentDDSketch,
     schema,
     transforms::{TaskTransform, Transform},
 };
@@ -26,6 +27,51 @@ pub struct AggregateConfig {
     /// Over this period metrics with the same series data (name, namespace, tags, …) will be aggregated.
     #[serde(default = "default_interval_ms")]
     pub interval_ms: u64,
+
+    /// The tags to keep on metrics before they are aggregated.
+    ///
+    /// All other tags are dropped, so that metrics only differing by them are aggregated together.
+    /// Can't be used along with `drop_tags`.
+    #[configurable(metadata(docs::examples = "host"))]
+    pub keep_tags: Option<Vec<String>>,
+
+    /// The tags to drop from metrics before they are aggregated.
+    ///
+    /// Metrics only differing by these tags are aggregated together. Can't be used along with
+    /// `keep_tags`.
+    #[configurable(metadata(docs::examples = "pod_name"))]
+    #[serde(default)]
+    pub drop_tags: Vec<String>,
+
+    /// The quantiles to turn incremental distributions into summaries with.
+    ///
+    /// When set, the samples of incremental distributions are summarized into these quantiles when
+    /// flushed, along with their count and sum. Each quantile must be between 0.0 and 1.0.
+    pub quantiles: Option<Vec<f64>>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub gauge_mode: GaugeMode,
+}
+
+/// How absolute gauges with the same series are aggregated.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GaugeMode {
+    /// The latest value is kept.
+    #[derivative(Default)]
+    Latest,
+
+    /// The lowest value is kept.
+    Min,
+
+    /// The highest value is kept.
+    Max,
+
+    /// The mean of the values is kept.
+    Mean,
 }
 
 const fn default_interval_ms() -> u64 {
@@ -55,18 +101,69 @@ type MetricEntry = (metric::MetricData, EventMetadata);
 pub struct Aggregate {
     interval: Duration,
     map: HashMap<metric::MetricSeries, MetricEntry>,
+    keep_tags: Option<HashSet<String>>,
+    drop_tags: HashSet<String>,
+    quantiles: Option<Vec<f64>>,
+    gauge_mode: GaugeMode,
+    // The number of values averaged for each absolute gauge, with the `mean` gauge mode.
+    gauge_counts: HashMap<metric::MetricSeries, u64>,
 }
 
 impl Aggregate {
     pub fn new(config: &AggregateConfig) -> crate::Result<Self> {
+        if config.keep_tags.is_some() && !config.drop_tags.is_empty() {
+            return Err("Only one of `keep_tags` or `drop_tags` can be set.".into());
+        }
+        if let Some(quantiles) = &config.quantiles {
+            if quantiles.is_empty() {
+                return Err("`quantiles` must not be empty.".into());
+            }
+            if let Some(quantile) = quantiles.iter().find(|q| !(0.0..=1.0).contains(*q)) {
+                return Err(format!("Quantile {} must be between 0.0 and 1.0.", quantile).into());
+            }
+        }
+
         Ok(Self {
             interval: Duration::from_millis(config.interval_ms),
             map: Default::default(),
+            keep_tags: config
+                .keep_tags
+                .as_ref()
+                .map(|tags| tags.iter().cloned().collect()),
+            drop_tags: config.drop_tags.iter().cloned().collect(),
+            quantiles: config.quantiles.clone(),
+            gauge_mode: config.gauge_mode,
+            gauge_counts: Default::default(),
         })
     }
 
+    fn filter_tags(&self, series: &mut metric::MetricSeries) {
+        if self.keep_tags.is_none() && self.drop_tags.is_empty() {
+            return;
+        }
+
+        let tags = series.tags_mut();
+        if let Some(existing) = tags.as_mut() {
+            let dropped = existing
+                .keys()
+                .filter(|key| match &self.keep_tags {
+                    Some(keep_tags) => !keep_tags.contains(*key),
+                    None => self.drop_tags.contains(*key),
+                })
+                .map(ToOwned::to_owned)
+                .collect::<Vec<_>>();
+            for key in dropped {
+                existing.remove(&key);
+            }
+            if existing.is_empty() {
+                *tags = None;
+            }
+        }
+    }
+
     fn record(&mut self, event: Event) {
-        let (series, data, metadata) = event.into_metric().into_parts();
+        let (mut series, data, metadata) = event.into_metric().into_parts();
+        self.filter_tags(&mut series);
 
         match data.kind {
             metric::MetricKind::Incremental => match self.map.entry(series) {
@@ -84,10 +181,52 @@ impl Aggregate {
                     entry.insert((data, metadata));
                 }
             },
-            metric::MetricKind::Absolute => {
-                // Always replace/store
-                self.map.insert(series, (data, metadata));
-            }
+            metric::MetricKind::Absolute => match (self.gauge_mode, self.map.entry(series)) {
+                (GaugeMode::Latest, Entry::Occupied(mut entry)) => {
+                    // Always replace/store
+                    entry.insert((data, metadata));
+                }
+                (mode, Entry::Occupied(mut entry)) => {
+                    let gauge_count = (mode == GaugeMode::Mean)
+                        .then(|| self.gauge_counts.entry(entry.key().clone()).or_insert(1));
+                    let existing = entry.get_mut();
+                    let same_kind = existing.0.kind == data.kind;
+                    match (existing.0.value_mut(), data.value()) {
+                        (
+                            metric::MetricValue::Gauge {
+                                value: existing_value,
+                            },
+                            metric::MetricValue::Gauge { value },
+                        ) if same_kind => {
+                            let count = gauge_count.map_or(1, |count| {
+                                *count += 1;
+                                *count
+                            });
+                            *existing_value = match mode {
+                                GaugeMode::Latest => *value,
+                                GaugeMode::Min => existing_value.min(*value),
+                                GaugeMode::Max => existing_value.max(*value),
+                                GaugeMode::Mean => {
+                                    *existing_value + (*value - *existing_value) / count as f64
+                                }
+                            };
+                            existing.0.time = data.time;
+                            existing.1.merge(metadata);
+                        }
+                        // Other absolute metrics are always replaced, and the mean starts over from
+                        // the replacing metric.
+                        _ => {
+                            if let Some(count) = gauge_count {
+                                *count = 1;
+                            }
+                            *existing = (data, metadata);
+                        }
+                    }
+                }
+                (_, Entry::Vacant(entry)) => {
+                    entry.insert((data, metadata));
+                }
+            },
         };
 
         emit!(AggregateEventRecorded);
@@ -95,7 +234,13 @@ impl Aggregate {
 
     fn flush_into(&mut self, output: &mut Vec<Event>) {
         let map = std::mem::take(&mut self.map);
-        for (series, entry) in map.into_iter() {
+        self.gauge_counts.clear();
+        for (series, mut entry) in map.into_iter() {
+            if let Some(quantiles) = &self.quantiles {
+                if !summarize(&mut entry.0, quantiles) {
+                    continue;
+                }
+            }
             let metric = metric::Metric::from_parts(series, entry.0, entry.1);
             output.push(Event::Metric(metric));
         }
@@ -104,6 +249,41 @@ impl Aggregate {
     }
 }
 
+/// Turns an incremental distribution into a summary of its samples, estimating the quantiles with
+/// a sketch.
+///
+/// Returns `false` for a distribution without samples, which has nothing to summarize and is
+/// skipped.
+fn summarize(data: &mut metric::MetricData, quantiles: &[f64]) -> bool {
+    if data.kind != metric::MetricKind::Incremental {
+        return true;
+    }
+    let sketch = match data.value() {
+        metric::MetricValue::Distribution { samples, .. } if samples.is_empty() => return false,
+        metric::MetricValue::Distribution { samples, .. } => {
+            let mut sketch = AgentDDSketch::with_agent_defaults();
+            for sample in samples {
+                sketch.insert_n(sample.value, sample.rate);
+            }
+            sketch
+        }
+        _ => return true,
+    };
+
+    *data.value_mut() = metric::MetricValue::AggregatedSummary {
+        quantiles: quantiles
+            .iter()
+            .map(|&quantile| metric::Quantile {
+                quantile,
+                value: sketch.quantile(quantile).unwrap_or(0.0),
+            })
+            .collect(),
+        count: u64::from(sketch.count()),
+        sum: sketch.sum().unwrap_or(0.0),
+    };
+    true
+}
+
 impl TaskTransform<Event> for Aggregate {
     fn transform(
         mut self: Box<Self>,
@@ -147,6 +327,7 @@ mod tests {
     use futures::stream;
     use tokio::sync::mpsc;
     use tokio_stream::wrappers::ReceiverStream;
+    use vector_core::metric_tags;
 
     use super::*;
     use crate::{
@@ -172,6 +353,7 @@ mod tests {
     fn incremental() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -242,6 +424,7 @@ mod tests {
     fn absolute() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -307,6 +490,7 @@ mod tests {
     fn conflicting_value_type() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -364,6 +548,7 @@ mod tests {
     fn conflicting_kinds() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -476,6 +661,195 @@ interval_ms = 999999
         assert_eq!(2, count);
     }
 
+    #[test]
+    fn filters_tags() {
+        let make_tagged = |value: f64, pod_name: &str| {
+            Event::Metric(
+                Metric::new(
+                    "counter_a",
+                    metric::MetricKind::Incremental,
+                    metric::MetricValue::Counter { value },
+                )
+                .with_tags(Some(metric_tags!("host" => "a", "pod_name" => pod_name))),
+            )
+        };
+
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            drop_tags: vec!["pod_name".into()],
+            ..Default::default()
+        })
+        .unwrap();
+        agg.record(make_tagged(1.0, "pod-1"));
+        agg.record(make_tagged(2.0, "pod-2"));
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(1, out.len());
+        let metric = out[0].as_metric();
+        assert_eq!(metric.tags(), Some(&metric_tags!("host" => "a")));
+        assert_eq!(metric.value(), &metric::MetricValue::Counter { value: 3.0 });
+
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            keep_tags: Some(vec!["pod_name".into()]),
+            ..Default::default()
+        })
+        .unwrap();
+        agg.record(make_tagged(1.0, "pod-1"));
+        agg.record(make_tagged(2.0, "pod-2"));
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(2, out.len());
+        for event in out {
+            let tags = event.as_metric().tags().unwrap();
+            assert!(!tags.contains_key("host"));
+            assert!(tags.contains_key("pod_name"));
+        }
+    }
+
+    #[test]
+    fn gauge_modes() {
+        let gauge = |value: f64| {
+            make_metric(
+                "gauge_a",
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value },
+            )
+        };
+
+        for (gauge_mode, expected) in [
+            (GaugeMode::Latest, 2.0),
+            (GaugeMode::Min, 1.0),
+            (GaugeMode::Max, 6.0),
+            (GaugeMode::Mean, 3.0),
+        ] {
+            let mut agg = Aggregate::new(&AggregateConfig {
+                interval_ms: 1000_u64,
+                gauge_mode,
+                ..Default::default()
+            })
+            .unwrap();
+            agg.record(gauge(1.0));
+            agg.record(gauge(6.0));
+            agg.record(gauge(2.0));
+            let mut out = vec![];
+            agg.flush_into(&mut out);
+            assert_eq!(vec![gauge(expected)], out, "{:?}", gauge_mode);
+
+            // The mean starts over after a flush.
+            agg.record(gauge(4.0));
+            agg.record(gauge(4.0));
+            let mut out = vec![];
+            agg.flush_into(&mut out);
+            assert_eq!(vec![gauge(4.0)], out, "{:?}", gauge_mode);
+        }
+    }
+
+    #[test]
+    fn gauge_mean_starts_over_when_replaced() {
+        let gauge = |value: f64| {
+            make_metric(
+                "gauge_a",
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value },
+            )
+        };
+
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            gauge_mode: GaugeMode::Mean,
+            ..Default::default()
+        })
+        .unwrap();
+        agg.record(gauge(1.0));
+        agg.record(gauge(3.0));
+        agg.record(make_metric(
+            "gauge_a",
+            metric::MetricKind::Absolute,
+            metric::MetricValue::Counter { value: 10.0 },
+        ));
+        agg.record(gauge(6.0));
+        agg.record(gauge(8.0));
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(vec![gauge(7.0)], out);
+    }
+
+    #[test]
+    fn summarizes_distributions() {
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            quantiles: Some(vec![0.5, 0.99]),
+            ..Default::default()
+        })
+        .unwrap();
+
+        for value in 1..=100 {
+            agg.record(make_metric(
+                "distribution_a",
+                metric::MetricKind::Incremental,
+                metric::MetricValue::Distribution {
+                    samples: vector_core::samples![value as f64 => 1],
+                    statistic: metric::StatisticKind::Histogram,
+                },
+            ));
+        }
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(1, out.len());
+
+        match out[0].as_metric().value() {
+            metric::MetricValue::AggregatedSummary {
+                quantiles,
+                count,
+                sum,
+            } => {
+                assert_eq!(*count, 100);
+                assert_eq!(*sum, 5050.0);
+                assert_eq!(quantiles.len(), 2);
+                // The quantiles are estimated by the sketch, within its relative error.
+                assert_eq!(quantiles[0].quantile, 0.5);
+                assert!((quantiles[0].value - 50.0).abs() <= 2.0);
+                assert_eq!(quantiles[1].quantile, 0.99);
+                assert!((quantiles[1].value - 99.0).abs() <= 2.0);
+            }
+            value => panic!("Unexpected metric value {:?}", value),
+        }
+
+        // Distributions without samples aren't summarized.
+        agg.record(make_metric(
+            "distribution_a",
+            metric::MetricKind::Incremental,
+            metric::MetricValue::Distribution {
+                samples: vec![],
+                statistic: metric::StatisticKind::Histogram,
+            },
+        ));
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn validates_config() {
+        assert!(Aggregate::new(&AggregateConfig {
+            keep_tags: Some(vec!["host".into()]),
+            drop_tags: vec!["pod_name".into()],
+            ..Default::default()
+        })
+        .is_err());
+        assert!(Aggregate::new(&AggregateConfig {
+            quantiles: Some(vec![]),
+            ..Default::default()
+        })
+        .is_err());
+        assert!(Aggregate::new(&AggregateConfig {
+            quantiles: Some(vec![0.5, 1.5]),
+            ..Default::default()
+        })
+        .is_err());
+    }
+
     #[tokio::test]
     async fn transform_interval() {
         let transform_config = toml::from_str::<AggregateConfig>("").unwrap();
@@ -0,0 +1,112 @@
+use vector_config::configurable_component;
+
+use crate::{
+    config::{DataType, Input, Output, TransformConfig, TransformContext},
+    event::{Event, LogEvent},
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// The field of the event metadata that holds the side of the join an event is on.
+const SIDE_KEY: &str = "join_side";
+
+/// A side of the join.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinSide {
+    /// The left side of the join.
+    #[derivative(Default)]
+    Left,
+
+    /// The right side of the join.
+    Right,
+}
+
+impl JoinSide {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+
+    /// Marks the event as being on this side of the join.
+    fn mark(self, log: &mut LogEvent) {
+        if let Some(metadata) = log.metadata_mut().value_mut().as_object_mut() {
+            metadata.insert(SIDE_KEY.into(), self.as_str().into());
+        }
+    }
+
+    /// Removes the mark of the side of the join from the event, returning the side it was on.
+    pub fn take(log: &mut LogEvent) -> Option<Self> {
+        let side = log
+            .metadata_mut()
+            .value_mut()
+            .as_object_mut()?
+            .remove(SIDE_KEY)?;
+        match side.as_bytes()?.as_ref() {
+            b"left" => Some(Self::Left),
+            b"right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration for the `join_input` transform.
+#[configurable_component(transform("join_input"))]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct JoinInputConfig {
+    #[configurable(derived)]
+    side: JoinSide,
+}
+
+impl JoinInputConfig {
+    pub const fn new(side: JoinSide) -> Self {
+        Self { side }
+    }
+}
+
+impl_generate_config_from_default!(JoinInputConfig);
+
+#[async_trait::async_trait]
+impl TransformConfig for JoinInputConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        // The `join` transform names the transforms marking its sides after them.
+        let suffix = format!(".{}", self.side.as_str());
+        if !context
+            .key
+            .as_ref()
+            .map_or(false, |key| key.id().ends_with(&suffix))
+        {
+            return Err("the join_input transform can only be used by the join transform".into());
+        }
+        Ok(Transform::function(JoinInput { side: self.side }))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(&self, merged_definition: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::Log).with_schema_definition(merged_definition.clone())]
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone)]
+struct JoinInput {
+    side: JoinSide,
+}
+
+impl FunctionTransform for JoinInput {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        self.side.mark(event.as_mut_log());
+        output.push(event);
+    }
+}
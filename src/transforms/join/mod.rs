@@ -0,0 +1,531 @@
+use std::time::Duration;
+
+use indexmap::IndexMap;
+use serde_with::serde_as;
+use vector_config::configurable_component;
+
+use crate::{
+    config::{
+        ComponentKey, DataType, GenerateConfig, InnerTopology, InnerTopologyTransform, Input,
+        Inputs, Output, TransformConfig, TransformContext,
+    },
+    schema,
+    template::Template,
+    transforms::{Transform, Transforms},
+};
+
+mod input;
+mod transform;
+
+pub use self::input::JoinInputConfig;
+use self::transform::Join;
+
+/// Configuration for the `join` transform.
+#[serde_as]
+#[configurable_component(transform("join"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JoinConfig {
+    #[configurable(derived)]
+    pub left: JoinSideConfig,
+
+    #[configurable(derived)]
+    pub right: JoinSideConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub how: JoinType,
+
+    /// The period of time that events wait for a matching event from the other side, in seconds.
+    ///
+    /// The window starts when the first event with a given key is received on a side, and a right
+    /// event can be matched by left events until its window ends.
+    #[serde(default = "default_window_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    pub window_secs: Duration,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub merge: JoinMerge,
+
+    /// The maximum number of keys that events are buffered for, on each side.
+    ///
+    /// Once reached, events with a new key are handled as if no matching event was received.
+    #[serde(default = "default_max_keys")]
+    pub max_keys: usize,
+
+    /// The maximum number of left events that are buffered for a single key.
+    ///
+    /// Once reached, further left events with that key are handled as if no matching event was
+    /// received, so that at most `max_keys` times this many left events are buffered.
+    #[serde(default = "default_max_events_per_key")]
+    pub max_events_per_key: usize,
+}
+
+const fn default_window_secs() -> Duration {
+    Duration::from_secs(30)
+}
+
+const fn default_max_keys() -> usize {
+    10000
+}
+
+const fn default_max_events_per_key() -> usize {
+    1000
+}
+
+/// Configuration of a side of the join.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JoinSideConfig {
+    /// The inputs whose events are on this side of the join.
+    ///
+    /// Each input of the transform must be on exactly one side.
+    #[configurable(metadata(docs::examples = "requests"))]
+    pub inputs: Vec<String>,
+
+    /// The key that events are matched on.
+    ///
+    /// Events from both sides are matched when their keys render to the same value.
+    #[configurable(metadata(docs::examples = "{{ request_id }}"))]
+    pub key: Template,
+}
+
+/// How events that are not matched are handled.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinType {
+    /// Only matched events are forwarded.
+    #[derivative(Default)]
+    Inner,
+
+    /// Left events are forwarded without being merged if no right event matched them within the
+    /// window.
+    Left,
+}
+
+/// How matched events are merged into a single event.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JoinMerge {
+    /// The fields of the right event are added to the left event, keeping the values of the left
+    /// event for fields found in both.
+    #[derivative(Default)]
+    PreferLeft,
+
+    /// The fields of the right event are added to the left event, replacing the values of the
+    /// left event for fields found in both.
+    PreferRight,
+
+    /// The left and right events are nested under the `left` and `right` fields.
+    Nested,
+}
+
+impl GenerateConfig for JoinConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"
+            left.inputs = ["requests"]
+            left.key = "{{ request_id }}"
+            right.inputs = ["responses"]
+            right.key = "{{ request_id }}"
+            "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransformConfig for JoinConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::event_task(Join::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(&self, merged_definition: &schema::Definition) -> Vec<Output> {
+        let definition = match self.merge {
+            JoinMerge::PreferLeft | JoinMerge::PreferRight => merged_definition.clone(),
+            JoinMerge::Nested => schema::Definition::any(),
+        };
+        vec![Output::default(DataType::Log).with_schema_definition(definition)]
+    }
+
+    fn nestable(&self, parents: &std::collections::HashSet<&'static str>) -> bool {
+        // The inputs of the transform are only known at the top level of the topology.
+        parents.is_empty()
+    }
+
+    fn expand(
+        &mut self,
+        name: &ComponentKey,
+        inputs: &[String],
+    ) -> crate::Result<Option<InnerTopology>> {
+        for input in inputs {
+            let sides = [&self.left, &self.right]
+                .iter()
+                .filter(|side| side.inputs.contains(input))
+                .count();
+            if sides != 1 {
+                return Err(
+                    format!("input {:?} must be on exactly one side of the join", input).into(),
+                );
+            }
+        }
+        for input in self.left.inputs.iter().chain(&self.right.inputs) {
+            if !inputs.contains(input) {
+                return Err(format!("{:?} is not an input of the transform", input).into());
+            }
+        }
+
+        // Events don't carry the component they come from, so the events of each side go through
+        // a transform marking their side before being joined.
+        let mut inner = IndexMap::new();
+        let mut side_names = Vec::new();
+        for (side, config) in [
+            (input::JoinSide::Left, &self.left),
+            (input::JoinSide::Right, &self.right),
+        ] {
+            let side_name = name.join(side.as_str());
+            side_names.push(side_name.to_string());
+            inner.insert(
+                side_name,
+                InnerTopologyTransform {
+                    inputs: Inputs::from(config.inputs.clone()),
+                    inner: Transforms::JoinInput(JoinInputConfig::new(side)),
+                },
+            );
+        }
+
+        let join_name = name.join("join");
+        inner.insert(
+            join_name.clone(),
+            InnerTopologyTransform {
+                inputs: Inputs::from(side_names),
+                inner: self.clone().into(),
+            },
+        );
+
+        Ok(Some(InnerTopology {
+            inner,
+            outputs: vec![(join_name, vec![Output::default(DataType::Log)])],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::SinkExt;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use tokio_util::sync::PollSender;
+
+    use super::*;
+    use crate::{
+        config::{
+            unit_test::{UnitTestStreamSinkConfig, UnitTestStreamSourceConfig},
+            ConfigBuilder,
+        },
+        event::{Event, LogEvent},
+        test_util::{components::assert_transform_compliance, start_topology},
+        topology::RunningTopology,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<JoinConfig>();
+    }
+
+    fn make_config(extra: &str) -> JoinConfig {
+        toml::from_str(&format!(
+            r#"
+            left.inputs = ["requests"]
+            left.key = "{{{{ request_id }}}}"
+            right.inputs = ["responses"]
+            right.key = "{{{{ id }}}}"
+            {}
+            "#,
+            extra
+        ))
+        .unwrap()
+    }
+
+    fn expand(config: &mut JoinConfig, inputs: &[&str]) -> crate::Result<Option<InnerTopology>> {
+        let inputs = inputs
+            .iter()
+            .map(|input| input.to_string())
+            .collect::<Vec<_>>();
+        config.expand(&ComponentKey::from("join"), &inputs)
+    }
+
+    #[test]
+    fn expands_into_a_transform_per_side() {
+        let topology = expand(&mut make_config(""), &["requests", "responses"])
+            .unwrap()
+            .unwrap();
+
+        let names = topology
+            .inner
+            .keys()
+            .map(ComponentKey::id)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["join.left", "join.right", "join.join"]);
+        assert_eq!(
+            topology.inner[&ComponentKey::from("join.join")].inputs,
+            vec!["join.left", "join.right"]
+        );
+        assert_eq!(topology.outputs(), ["join.join"]);
+    }
+
+    #[tokio::test]
+    async fn join_input_requires_join_expansion() {
+        let config = JoinInputConfig::new(input::JoinSide::Right);
+        assert!(config.build(&TransformContext::default()).await.is_err());
+
+        let context = TransformContext {
+            key: Some(ComponentKey::from("marker")),
+            ..Default::default()
+        };
+        assert!(config.build(&context).await.is_err());
+
+        let context = TransformContext {
+            key: Some(ComponentKey::from("join").join("right")),
+            ..Default::default()
+        };
+        assert!(config.build(&context).await.is_ok());
+    }
+
+    #[test]
+    fn requires_inputs_on_exactly_one_side() {
+        let error = expand(&mut make_config(""), &["requests", "responses", "other"])
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            r#"input "other" must be on exactly one side of the join"#
+        );
+
+        let mut config = make_config("");
+        config.right.inputs.push("requests".into());
+        let error = expand(&mut config, &["requests", "responses"])
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            r#"input "requests" must be on exactly one side of the join"#
+        );
+
+        let error = expand(&mut make_config(""), &["requests"])
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, r#""responses" is not an input of the transform"#);
+    }
+
+    async fn create_topology(
+        config: JoinConfig,
+    ) -> (
+        RunningTopology,
+        mpsc::Sender<Event>,
+        mpsc::Sender<Event>,
+        mpsc::Receiver<Event>,
+    ) {
+        let mut builder = ConfigBuilder::default();
+
+        let (requests_tx, requests_rx) = mpsc::channel(1);
+        let (responses_tx, responses_rx) = mpsc::channel(1);
+        let (tx, rx) = mpsc::channel(1);
+
+        builder.add_source(
+            "requests",
+            UnitTestStreamSourceConfig::new(ReceiverStream::new(requests_rx)),
+        );
+        builder.add_source(
+            "responses",
+            UnitTestStreamSourceConfig::new(ReceiverStream::new(responses_rx)),
+        );
+        builder.add_transform("join", &["requests", "responses"], config);
+        builder.add_sink(
+            "out",
+            &["join"],
+            UnitTestStreamSinkConfig::new(
+                PollSender::new(tx).sink_map_err(|error| panic!("{}", error)),
+            ),
+        );
+
+        let config = builder.build().expect("building config should not fail");
+        let (topology, _) = start_topology(config, false).await;
+
+        (topology, requests_tx, responses_tx, rx)
+    }
+
+    fn request(id: &str, path: &str) -> Event {
+        let mut log = LogEvent::default();
+        log.insert("request_id", id);
+        log.insert("message", path);
+        Event::from(log)
+    }
+
+    fn response(id: &str, status: i64) -> Event {
+        let mut log = LogEvent::default();
+        log.insert("id", id);
+        log.insert("message", "response");
+        log.insert("status", status);
+        Event::from(log)
+    }
+
+    #[tokio::test]
+    async fn inner_join_merges_matched_events() {
+        assert_transform_compliance(async {
+            let (topology, requests, responses, mut out) = create_topology(make_config("")).await;
+
+            requests.send(request("1", "/a")).await.unwrap();
+            requests.send(request("2", "/b")).await.unwrap();
+            responses.send(response("1", 200)).await.unwrap();
+
+            let event = out.recv().await.unwrap();
+            let log = event.as_log();
+            assert_eq!(log["request_id"], "1".into());
+            assert_eq!(log["id"], "1".into());
+            assert_eq!(log["message"], "/a".into());
+            assert_eq!(log["status"], 200.into());
+
+            // A right event matches the left events received after it within the window.
+            requests.send(request("1", "/c")).await.unwrap();
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log()["message"], "/c".into());
+            assert_eq!(event.as_log()["status"], 200.into());
+
+            // The unmatched request is dropped once the inputs end.
+            drop(requests);
+            drop(responses);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn left_join_forwards_unmatched_events() {
+        assert_transform_compliance(async {
+            let config = make_config(
+                r#"
+                how = "left"
+                merge = "prefer_right"
+                "#,
+            );
+            let (topology, requests, responses, mut out) = create_topology(config).await;
+
+            requests.send(request("1", "/a")).await.unwrap();
+            requests.send(request("2", "/b")).await.unwrap();
+            responses.send(response("1", 200)).await.unwrap();
+
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log()["message"], "response".into());
+            assert_eq!(event.as_log()["status"], 200.into());
+
+            drop(requests);
+            drop(responses);
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log()["request_id"], "2".into());
+            assert_eq!(event.as_log()["message"], "/b".into());
+            assert!(event.as_log().get("status").is_none());
+
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn left_join_forwards_unmatched_events_after_the_window() {
+        let config = make_config(
+            r#"
+            how = "left"
+            window_secs = 1
+            "#,
+        );
+        let (topology, requests, responses, mut out) = create_topology(config).await;
+
+        requests.send(request("1", "/a")).await.unwrap();
+        let event = out.recv().await.unwrap();
+        assert_eq!(event.as_log()["message"], "/a".into());
+
+        // The response arrives too late to be matched.
+        responses.send(response("1", 200)).await.unwrap();
+        requests.send(request("2", "/b")).await.unwrap();
+        responses.send(response("2", 404)).await.unwrap();
+        let event = out.recv().await.unwrap();
+        assert_eq!(event.as_log()["message"], "/b".into());
+        assert_eq!(event.as_log()["status"], 404.into());
+
+        drop(requests);
+        drop(responses);
+        topology.stop().await;
+        assert_eq!(out.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn left_join_forwards_events_over_the_limits() {
+        let config = make_config(
+            r#"
+            how = "left"
+            max_keys = 1
+            max_events_per_key = 2
+            "#,
+        );
+        let (topology, requests, responses, mut out) = create_topology(config).await;
+
+        requests.send(request("1", "/a")).await.unwrap();
+        requests.send(request("1", "/b")).await.unwrap();
+        requests.send(request("1", "/c")).await.unwrap();
+        let event = out.recv().await.unwrap();
+        assert_eq!(event.as_log()["message"], "/c".into());
+        assert!(event.as_log().get("status").is_none());
+
+        requests.send(request("2", "/d")).await.unwrap();
+        let event = out.recv().await.unwrap();
+        assert_eq!(event.as_log()["message"], "/d".into());
+        assert!(event.as_log().get("status").is_none());
+
+        // The events buffered within the limits are still matched.
+        responses.send(response("1", 200)).await.unwrap();
+        for message in ["/a", "/b"] {
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log()["message"], message.into());
+            assert_eq!(event.as_log()["status"], 200.into());
+        }
+
+        drop(requests);
+        drop(responses);
+        topology.stop().await;
+        assert_eq!(out.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn nested_merge() {
+        let (topology, requests, responses, mut out) =
+            create_topology(make_config(r#"merge = "nested""#)).await;
+
+        responses.send(response("1", 200)).await.unwrap();
+        requests.send(request("1", "/a")).await.unwrap();
+
+        let event = out.recv().await.unwrap();
+        let log = event.as_log();
+        assert_eq!(log["left.message"], "/a".into());
+        assert_eq!(log["right.message"], "response".into());
+        assert_eq!(log["right.status"], 200.into());
+        assert!(log.get("message").is_none());
+
+        drop(requests);
+        drop(responses);
+        topology.stop().await;
+        assert_eq!(out.recv().await, None);
+    }
+}
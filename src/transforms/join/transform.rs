@@ -0,0 +1,252 @@
+use std::{collections::btree_map::Entry, pin::Pin, time::Duration};
+
+use async_stream::stream;
+use futures::{stream, Stream, StreamExt};
+
+use super::{input::JoinSide, JoinConfig, JoinMerge, JoinType};
+use crate::{
+    event::{Event, LogEvent, Value},
+    expiring_hash_map::ExpiringHashMap,
+    internal_events::{JoinEventsUnmatched, TemplateRenderingError},
+    template::Template,
+    transforms::TaskTransform,
+};
+
+/// The latest right event received for a key.
+struct RightEvent {
+    log: LogEvent,
+    matched: bool,
+}
+
+pub struct Join {
+    left_key: Template,
+    right_key: Template,
+    how: JoinType,
+    merge: JoinMerge,
+    window: Duration,
+    max_keys: usize,
+    max_events_per_key: usize,
+    // The left events are buffered until a right event with the same key is received, or until the
+    // window of their key ends.
+    left: ExpiringHashMap<String, Vec<LogEvent>>,
+    right: ExpiringHashMap<String, RightEvent>,
+}
+
+impl Join {
+    pub fn new(config: &JoinConfig) -> Self {
+        Self {
+            left_key: config.left.key.clone(),
+            right_key: config.right.key.clone(),
+            how: config.how,
+            merge: config.merge,
+            window: config.window_secs,
+            max_keys: config.max_keys,
+            max_events_per_key: config.max_events_per_key,
+            left: ExpiringHashMap::default(),
+            right: ExpiringHashMap::default(),
+        }
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event) {
+        let mut log = event.into_log();
+        match JoinSide::take(&mut log) {
+            Some(JoinSide::Left) => self.push_left(output, log),
+            Some(JoinSide::Right) => self.push_right(output, log),
+            // Only events from the inputs of the join are marked, which is not expected to happen.
+            None => output.push(Event::from(log)),
+        }
+    }
+
+    fn push_left(&mut self, output: &mut Vec<Event>, log: LogEvent) {
+        let drop_event = self.how == JoinType::Inner;
+        let key = match render_key(&self.left_key, &log, "left.key", drop_event) {
+            Some(key) => key,
+            None => {
+                // Events without a key can't be matched, but are still forwarded by a left join.
+                if !drop_event {
+                    output.push(Event::from(log));
+                }
+                return;
+            }
+        };
+
+        if let Some(right) = self.right.get_mut(&key) {
+            right.matched = true;
+            let right = right.log.clone();
+            output.push(Event::from(merge(self.merge, log, right)));
+        } else if let Some(logs) = self.left.get_mut(&key) {
+            if logs.len() < self.max_events_per_key {
+                logs.push(log);
+            } else {
+                self.unmatched_left(output, vec![log]);
+            }
+        } else if self.left.len() < self.max_keys {
+            self.left.insert(key, vec![log], self.window);
+        } else {
+            self.unmatched_left(output, vec![log]);
+        }
+    }
+
+    fn push_right(&mut self, output: &mut Vec<Event>, log: LogEvent) {
+        let key = match render_key(&self.right_key, &log, "right.key", true) {
+            Some(key) => key,
+            None => return,
+        };
+
+        let mut matched = false;
+        if let Some((lefts, _)) = self.left.remove(&key) {
+            matched = true;
+            output.extend(
+                lefts
+                    .into_iter()
+                    .map(|left| Event::from(merge(self.merge, left, log.clone()))),
+            );
+        }
+
+        // The right event replaces the previous one received for its key, which also starts a new
+        // window for it.
+        if let Some((previous, _)) = self.right.remove(&key) {
+            if !previous.matched {
+                emit!(JoinEventsUnmatched { count: 1 });
+            }
+        }
+        if self.right.len() < self.max_keys {
+            self.right
+                .insert(key, RightEvent { log, matched }, self.window);
+        } else if !matched {
+            emit!(JoinEventsUnmatched { count: 1 });
+        }
+    }
+
+    fn unmatched_left(&self, output: &mut Vec<Event>, logs: Vec<LogEvent>) {
+        match self.how {
+            JoinType::Inner => emit!(JoinEventsUnmatched { count: logs.len() }),
+            JoinType::Left => output.extend(logs.into_iter().map(Event::from)),
+        }
+    }
+
+    fn expire_right(right: RightEvent) {
+        if !right.matched {
+            emit!(JoinEventsUnmatched { count: 1 });
+        }
+    }
+
+    fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        let keys = self
+            .left
+            .iter_mut()
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        for key in keys {
+            if let Some((logs, _)) = self.left.remove(&key) {
+                self.unmatched_left(output, logs);
+            }
+        }
+
+        let keys = self
+            .right
+            .iter_mut()
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        for key in keys {
+            if let Some((right, _)) = self.right.remove(&key) {
+                Self::expire_right(right);
+            }
+        }
+    }
+}
+
+fn render_key(key: &Template, log: &LogEvent, field: &str, drop_event: bool) -> Option<String> {
+    key.render_string(log)
+        .map_err(|error| {
+            emit!(TemplateRenderingError {
+                error,
+                field: Some(field),
+                drop_event,
+            })
+        })
+        .ok()
+}
+
+/// Merges a left event with the right event that matched it.
+fn merge(strategy: JoinMerge, mut left: LogEvent, right: LogEvent) -> LogEvent {
+    let (right_value, right_metadata) = right.into_parts();
+    match strategy {
+        JoinMerge::PreferLeft | JoinMerge::PreferRight => {
+            if let (Some(fields), Value::Object(right_fields)) = (left.as_map_mut(), right_value) {
+                for (name, value) in right_fields {
+                    match fields.entry(name) {
+                        Entry::Vacant(entry) => {
+                            entry.insert(value);
+                        }
+                        Entry::Occupied(mut entry) => {
+                            if strategy == JoinMerge::PreferRight {
+                                entry.insert(value);
+                            }
+                        }
+                    }
+                }
+            }
+            left.metadata_mut().merge(right_metadata);
+            left
+        }
+        JoinMerge::Nested => {
+            let (left_value, mut metadata) = left.into_parts();
+            metadata.merge(right_metadata);
+            let fields = [
+                ("left".to_owned(), left_value),
+                ("right".to_owned(), right_value),
+            ];
+            LogEvent::from_map(fields.into_iter().collect(), metadata)
+        }
+    }
+}
+
+impl TaskTransform<Event> for Join {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        Box::pin(
+            stream! {
+                loop {
+                    let mut output = Vec::new();
+                    let done = tokio::select! {
+                        expired = me.left.next_expired(), if !me.left.is_empty() => {
+                            if let Some((logs, _)) = expired {
+                                me.unmatched_left(&mut output, logs);
+                            }
+                            false
+                        }
+                        expired = me.right.next_expired(), if !me.right.is_empty() => {
+                            if let Some((right, _)) = expired {
+                                Join::expire_right(right);
+                            }
+                            false
+                        }
+                        maybe_event = input_rx.next() => {
+                            match maybe_event {
+                                None => {
+                                    me.flush_all_into(&mut output);
+                                    true
+                                }
+                                Some(event) => {
+                                    me.transform_one(&mut output, event);
+                                    false
+                                }
+                            }
+                        }
+                    };
+                    yield stream::iter(output.into_iter());
+                    if done { break }
+                }
+            }
+            .flatten(),
+        )
+    }
+}
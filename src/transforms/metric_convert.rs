@@ -0,0 +1,472 @@
+use std::{
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use futures::{stream, Stream, StreamExt};
+use serde_with::serde_as;
+use vector_config::configurable_component;
+
+use crate::{
+    config::{DataType, GenerateConfig, Input, Output, TransformConfig, TransformContext},
+    event::{
+        metric::{MetricKind, MetricSeries, MetricValue},
+        Event, Metric,
+    },
+    expiring_hash_map::ExpiringHashMap,
+    internal_events::MetricConvertMetricDropped,
+    schema,
+    transforms::{TaskTransform, Transform},
+};
+
+/// Configuration for the `metric_convert` transform.
+#[serde_as]
+#[configurable_component(transform("metric_convert"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct MetricConvertConfig {
+    #[configurable(derived)]
+    pub mode: ConvertMode,
+
+    /// The period of time after which a series that has not been received is forgotten, in seconds.
+    ///
+    /// The next metric of a forgotten series is handled as if it was the first one of the series.
+    #[serde(default = "default_expire_after_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    pub expire_after_secs: Duration,
+}
+
+const fn default_expire_after_secs() -> Duration {
+    Duration::from_secs(300)
+}
+
+/// How counters are converted.
+///
+/// Metrics other than counters are forwarded as they are.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConvertMode {
+    /// Absolute counters are turned into incremental counters, holding the increase since the
+    /// previous metric of their series.
+    ///
+    /// The first metric of a series is only used as a reference. A counter lower than the previous
+    /// one is considered to have been reset, and its value is used as the increase.
+    Incremental,
+
+    /// Incremental counters are turned into absolute counters, holding the sum of the increments
+    /// received for their series.
+    Absolute,
+
+    /// Counters are turned into absolute gauges, holding their rate of increase per second since
+    /// the previous metric of their series.
+    ///
+    /// The first metric of a series is only used as a reference. The time of a metric is its
+    /// timestamp, or the time it was received at if it has none.
+    Rate,
+}
+
+impl GenerateConfig for MetricConvertConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(r#"mode = "incremental""#).unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransformConfig for MetricConvertConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::event_task(MetricConvert::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::metric()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::Metric)]
+    }
+}
+
+/// The state kept for a counter series.
+#[derive(Debug)]
+struct SeriesState {
+    /// The latest value of an absolute counter, or the sum of the increments of an incremental one.
+    value: f64,
+    time: DateTime<Utc>,
+}
+
+pub struct MetricConvert {
+    mode: ConvertMode,
+    expire_after: Duration,
+    // The state of absolute and incremental counters of the same series is kept apart.
+    series: ExpiringHashMap<(MetricKind, MetricSeries), SeriesState>,
+}
+
+impl MetricConvert {
+    pub fn new(config: &MetricConvertConfig) -> Self {
+        Self {
+            mode: config.mode,
+            expire_after: config.expire_after_secs,
+            series: ExpiringHashMap::default(),
+        }
+    }
+
+    fn convert(&mut self, metric: Metric) -> Option<Metric> {
+        let value = match metric.value() {
+            MetricValue::Counter { value } => *value,
+            _ => return Some(metric),
+        };
+        let time = metric.timestamp().unwrap_or_else(Utc::now);
+
+        match (self.mode, metric.kind()) {
+            (ConvertMode::Incremental, MetricKind::Absolute) => {
+                let previous = self.reference(&metric, value, time)?;
+                let increase = increase(previous.value, value);
+                previous.value = value;
+                previous.time = time;
+                Some(
+                    metric
+                        .with_value(MetricValue::Counter { value: increase })
+                        .into_incremental(),
+                )
+            }
+            (ConvertMode::Absolute, MetricKind::Incremental) => {
+                let total = match self.update(&metric, value, time) {
+                    Some(previous) => {
+                        previous.value += value;
+                        previous.time = time;
+                        previous.value
+                    }
+                    None => value,
+                };
+                Some(
+                    metric
+                        .with_value(MetricValue::Counter { value: total })
+                        .into_absolute(),
+                )
+            }
+            (ConvertMode::Rate, kind) => {
+                // Increments of the first metric of a series can't be turned into a rate.
+                let initial = match kind {
+                    MetricKind::Absolute => value,
+                    MetricKind::Incremental => 0.0,
+                };
+                let previous = self.reference(&metric, initial, time)?;
+                let increase = match kind {
+                    MetricKind::Absolute => increase(previous.value, value),
+                    MetricKind::Incremental => previous.value + value,
+                };
+                let elapsed = (time - previous.time).num_milliseconds();
+                if elapsed <= 0 {
+                    // The increments are kept until time has passed to derive a rate from them.
+                    if kind == MetricKind::Incremental {
+                        previous.value = increase;
+                    }
+                    emit!(MetricConvertMetricDropped {
+                        reason: "No time elapsed since the previous metric of the series to derive a rate.",
+                    });
+                    return None;
+                }
+
+                previous.value = match kind {
+                    MetricKind::Absolute => value,
+                    MetricKind::Incremental => 0.0,
+                };
+                previous.time = time;
+                let rate = increase * 1000.0 / elapsed as f64;
+                Some(
+                    metric
+                        .with_value(MetricValue::Gauge { value: rate })
+                        .into_absolute(),
+                )
+            }
+            _ => Some(metric),
+        }
+    }
+
+    /// Returns the state of the series of the metric like `update`, but drops the metric if it is
+    /// only used as the reference of a new series.
+    fn reference(
+        &mut self,
+        metric: &Metric,
+        value: f64,
+        time: DateTime<Utc>,
+    ) -> Option<&mut SeriesState> {
+        let previous = self.update(metric, value, time);
+        if previous.is_none() {
+            emit!(MetricConvertMetricDropped {
+                reason: "The first metric of a series is only used as a reference.",
+            });
+        }
+        previous
+    }
+
+    /// Returns the state of the series of the metric, postponing its expiration.
+    ///
+    /// If the series isn't known yet, its state is initialized with the given value instead.
+    fn update(
+        &mut self,
+        metric: &Metric,
+        value: f64,
+        time: DateTime<Utc>,
+    ) -> Option<&mut SeriesState> {
+        let deadline = Instant::now() + self.expire_after;
+        let key = (metric.kind(), metric.series().clone());
+        if self.series.get_mut(&key).is_none() {
+            self.series
+                .insert_at(key, SeriesState { value, time }, deadline);
+            return None;
+        }
+        self.series.reset_at(&key, deadline)
+    }
+}
+
+/// Returns the increase of a counter from its previous value.
+///
+/// A counter that went backwards was reset, and has increased by its whole value since.
+fn increase(previous: f64, value: f64) -> f64 {
+    if value >= previous {
+        value - previous
+    } else {
+        value
+    }
+}
+
+impl TaskTransform<Event> for MetricConvert {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        Box::pin(
+            stream! {
+                loop {
+                    let mut output = Vec::new();
+                    let done = tokio::select! {
+                        _ = me.series.next_expired(), if !me.series.is_empty() => false,
+                        maybe_event = input_rx.next() => {
+                            match maybe_event {
+                                None => true,
+                                Some(event) => {
+                                    if let Some(metric) = me.convert(event.into_metric()) {
+                                        output.push(Event::Metric(metric));
+                                    }
+                                    false
+                                }
+                            }
+                        }
+                    };
+                    yield stream::iter(output.into_iter());
+                    if done { break }
+                }
+            }
+            .flatten(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::{
+        test_util::components::assert_transform_compliance, transforms::test::create_topology,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<MetricConvertConfig>();
+    }
+
+    fn make_convert(mode: ConvertMode) -> MetricConvert {
+        MetricConvert::new(&MetricConvertConfig {
+            mode,
+            expire_after_secs: default_expire_after_secs(),
+        })
+    }
+
+    fn counter(kind: MetricKind, value: f64, secs: i64) -> Metric {
+        Metric::new("requests", kind, MetricValue::Counter { value })
+            .with_timestamp(Some(Utc.timestamp(secs, 0)))
+    }
+
+    fn converted_values(convert: &mut MetricConvert, metrics: Vec<Metric>) -> Vec<Option<f64>> {
+        metrics
+            .into_iter()
+            .map(|metric| {
+                convert.convert(metric).map(|metric| match metric.value() {
+                    MetricValue::Counter { value } | MetricValue::Gauge { value } => *value,
+                    value => panic!("Unexpected metric value {:?}", value),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn converts_absolute_counters_to_incremental() {
+        let mut convert = make_convert(ConvertMode::Incremental);
+
+        let values = converted_values(
+            &mut convert,
+            vec![
+                counter(MetricKind::Absolute, 10.0, 0),
+                counter(MetricKind::Absolute, 15.0, 10),
+                counter(MetricKind::Absolute, 15.0, 20),
+                // The counter was reset.
+                counter(MetricKind::Absolute, 4.0, 30),
+                counter(MetricKind::Absolute, 6.0, 40),
+            ],
+        );
+        assert_eq!(values, [None, Some(5.0), Some(0.0), Some(4.0), Some(2.0)]);
+
+        let metric = convert
+            .convert(counter(MetricKind::Absolute, 7.0, 50))
+            .unwrap();
+        assert_eq!(metric.kind(), MetricKind::Incremental);
+
+        // Incremental counters are forwarded as they are.
+        let incremental = counter(MetricKind::Incremental, 3.0, 60);
+        assert_eq!(convert.convert(incremental.clone()), Some(incremental));
+    }
+
+    #[test]
+    fn converts_incremental_counters_to_absolute() {
+        let mut convert = make_convert(ConvertMode::Absolute);
+
+        let values = converted_values(
+            &mut convert,
+            vec![
+                counter(MetricKind::Incremental, 1.0, 0),
+                counter(MetricKind::Incremental, 2.0, 10),
+                counter(MetricKind::Incremental, 3.0, 20),
+            ],
+        );
+        assert_eq!(values, [Some(1.0), Some(3.0), Some(6.0)]);
+
+        let metric = convert
+            .convert(counter(MetricKind::Incremental, 1.0, 30))
+            .unwrap();
+        assert_eq!(metric.kind(), MetricKind::Absolute);
+        assert_eq!(metric.value(), &MetricValue::Counter { value: 7.0 });
+    }
+
+    #[test]
+    fn derives_rates_from_counters() {
+        let mut convert = make_convert(ConvertMode::Rate);
+
+        let values = converted_values(
+            &mut convert,
+            vec![
+                counter(MetricKind::Absolute, 100.0, 0),
+                counter(MetricKind::Absolute, 150.0, 10),
+                // The counter was reset.
+                counter(MetricKind::Absolute, 20.0, 20),
+            ],
+        );
+        assert_eq!(values, [None, Some(5.0), Some(2.0)]);
+
+        let mut convert = make_convert(ConvertMode::Rate);
+        let values = converted_values(
+            &mut convert,
+            vec![
+                counter(MetricKind::Incremental, 100.0, 0),
+                counter(MetricKind::Incremental, 10.0, 10),
+                // Increments received at the same time are summed into the next rate.
+                counter(MetricKind::Incremental, 10.0, 10),
+                counter(MetricKind::Incremental, 30.0, 20),
+            ],
+        );
+        assert_eq!(values, [None, Some(1.0), None, Some(4.0)]);
+
+        let metric = convert
+            .convert(counter(MetricKind::Incremental, 1.0, 30))
+            .unwrap();
+        assert_eq!(metric.kind(), MetricKind::Absolute);
+        assert!(matches!(metric.value(), MetricValue::Gauge { .. }));
+    }
+
+    #[test]
+    fn keeps_rates_of_absolute_and_incremental_counters_apart() {
+        let mut convert = make_convert(ConvertMode::Rate);
+
+        let values = converted_values(
+            &mut convert,
+            vec![
+                counter(MetricKind::Absolute, 100.0, 0),
+                counter(MetricKind::Incremental, 5.0, 0),
+                counter(MetricKind::Absolute, 150.0, 10),
+                counter(MetricKind::Incremental, 20.0, 10),
+            ],
+        );
+        assert_eq!(values, [None, None, Some(5.0), Some(2.0)]);
+    }
+
+    #[test]
+    fn forwards_other_metrics() {
+        let mut convert = make_convert(ConvertMode::Rate);
+
+        let gauge = Metric::new(
+            "temperature",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value: 20.0 },
+        );
+        assert_eq!(convert.convert(gauge.clone()), Some(gauge));
+    }
+
+    #[tokio::test]
+    async fn expires_stale_series() {
+        let mut convert = MetricConvert::new(&MetricConvertConfig {
+            mode: ConvertMode::Incremental,
+            expire_after_secs: Duration::from_secs(1),
+        });
+
+        assert_eq!(
+            convert.convert(counter(MetricKind::Absolute, 10.0, 0)),
+            None
+        );
+        assert!(convert.series.next_expired().await.is_some());
+        assert!(convert.series.is_empty());
+
+        // The series is handled as a new one.
+        assert_eq!(
+            convert.convert(counter(MetricKind::Absolute, 15.0, 10)),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn metric_convert_topology() {
+        assert_transform_compliance(async {
+            let config = toml::from_str::<MetricConvertConfig>(r#"mode = "incremental""#).unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(counter(MetricKind::Absolute, 10.0, 0).into())
+                .await
+                .unwrap();
+            tx.send(counter(MetricKind::Absolute, 12.0, 10).into())
+                .await
+                .unwrap();
+
+            let event = out.recv().await.unwrap();
+            assert_eq!(
+                event.as_metric(),
+                &counter(MetricKind::Incremental, 2.0, 10)
+            );
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+}
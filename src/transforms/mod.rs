@@ -12,9 +12,13 @@ pub mod aws_ec2_metadata;
 pub mod dedupe;
 #[cfg(feature = "transforms-filter")]
 pub mod filter;
+#[cfg(feature = "transforms-join")]
+pub mod join;
 pub mod log_to_metric;
 #[cfg(feature = "transforms-lua")]
 pub mod lua;
+#[cfg(feature = "transforms-metric_convert")]
+pub mod metric_convert;
 #[cfg(feature = "transforms-metric_to_log")]
 pub mod metric_to_log;
 #[cfg(feature = "transforms-pipelines")]
@@ -31,6 +35,8 @@ pub mod sample;
 pub mod tag_cardinality_limit;
 #[cfg(feature = "transforms-throttle")]
 pub mod throttle;
+#[cfg(feature = "transforms-trace_sampler")]
+pub mod trace_sampler;
 
 use vector_common::config::ComponentKey;
 use vector_config::{configurable_component, NamedComponent};
@@ -76,6 +82,15 @@ pub enum Transforms {
     #[cfg(feature = "transforms-filter")]
     Filter(#[configurable(derived)] filter::FilterConfig),
 
+    /// Join.
+    #[cfg(feature = "transforms-join")]
+    Join(#[configurable(derived)] join::JoinConfig),
+
+    /// Join input, marking the side of a `join` transform its events are on.
+    #[cfg(feature = "transforms-join")]
+    #[configurable(metadata(skip_docs))]
+    JoinInput(#[configurable(derived)] join::JoinInputConfig),
+
     /// Log to metric.
     LogToMetric(#[configurable(derived)] log_to_metric::LogToMetricConfig),
 
@@ -83,6 +98,10 @@ pub enum Transforms {
     #[cfg(feature = "transforms-lua")]
     Lua(#[configurable(derived)] lua::LuaConfig),
 
+    /// Metric convert.
+    #[cfg(feature = "transforms-metric_convert")]
+    MetricConvert(#[configurable(derived)] metric_convert::MetricConvertConfig),
+
     /// Metric to log.
     #[cfg(feature = "transforms-metric_to_log")]
     MetricToLog(#[configurable(derived)] metric_to_log::MetricToLogConfig),
@@ -127,6 +146,10 @@ pub enum Transforms {
     /// Throttle.
     #[cfg(feature = "transforms-throttle")]
     Throttle(#[configurable(derived)] throttle::ThrottleConfig),
+
+    /// Trace sampler.
+    #[cfg(feature = "transforms-trace_sampler")]
+    TraceSampler(#[configurable(derived)] trace_sampler::TraceSamplerConfig),
 }
 
 // We can't use `enum_dispatch` here because it doesn't support associated constants.
@@ -143,9 +166,15 @@ impl NamedComponent for Transforms {
             Transforms::Dedupe(config) => config.get_component_name(),
             #[cfg(feature = "transforms-filter")]
             Transforms::Filter(config) => config.get_component_name(),
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(config) => config.get_component_name(),
+            #[cfg(feature = "transforms-join")]
+            Transforms::JoinInput(config) => config.get_component_name(),
             Transforms::LogToMetric(config) => config.get_component_name(),
             #[cfg(feature = "transforms-lua")]
             Transforms::Lua(config) => config.get_component_name(),
+            #[cfg(feature = "transforms-metric_convert")]
+            Transforms::MetricConvert(config) => config.get_component_name(),
             #[cfg(feature = "transforms-metric_to_log")]
             Transforms::MetricToLog(config) => config.get_component_name(),
             #[cfg(feature = "transforms-pipelines")]
@@ -168,6 +197,8 @@ impl NamedComponent for Transforms {
             Transforms::TestNoop(config) => config.get_component_name(),
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(config) => config.get_component_name(),
+            #[cfg(feature = "transforms-trace_sampler")]
+            Transforms::TraceSampler(config) => config.get_component_name(),
             #[allow(unreachable_patterns)]
             _ => unimplemented!(),
         }
@@ -1,3 +1,9 @@
+use std::{
+    collections::HashMap,
+    iter,
+    time::{Duration, Instant},
+};
+
 use vector_config::configurable_component;
 
 use crate::{
@@ -9,6 +15,9 @@ use crate::{
     transforms::{FunctionTransform, OutputBuffer, Transform},
 };
 
+/// How often the adaptive rates are updated from the volume of each key.
+const ADAPTIVE_RATE_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Configuration for the `sample` transform.
 #[configurable_component(transform("sample"))]
 #[derive(Clone, Debug)]
@@ -18,7 +27,9 @@ pub struct SampleConfig {
     ///
     /// For example, `rate = 10` means 1 out of every 10 events will be forwarded and the rest will
     /// be dropped.
-    pub rate: u64,
+    ///
+    /// Exactly one of `rate` or `adaptive` must be set.
+    pub rate: Option<u64>,
 
     /// The name of the log field whose value will be hashed to determine if the event should be
     /// passed.
@@ -26,19 +37,59 @@ pub struct SampleConfig {
     /// Consistently samples the same events. Actual rate of sampling may differ from the configured
     /// one if values in the field are not uniformly distributed. If left unspecified, or if the
     /// event doesn’t have `key_field`, events will be count rated.
+    ///
+    /// With `adaptive`, the rate is instead adapted to the volume of each value of the field.
     #[configurable(metadata(docs::examples = "message",))]
     pub key_field: Option<String>,
 
     /// A logical condition used to exclude events from sampling.
     pub exclude: Option<AnyCondition>,
+
+    #[configurable(derived)]
+    pub adaptive: Option<AdaptiveSampleConfig>,
+}
+
+/// Configuration for adapting the sampling rate to the volume of events.
+///
+/// Events are counted by their value of `key_field` over a sliding window, and each value is
+/// sampled at a rate such that the events forwarded stay within a target throughput. Values seen
+/// rarely are forwarded in full, while the most frequent values are sampled the hardest.
+///
+/// Exactly one of `rate` or `adaptive` must be set.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AdaptiveSampleConfig {
+    /// The number of events to forward per second, across all values of `key_field`.
+    pub target_events_per_second: f64,
+
+    /// The length of the sliding window over which events are counted, in seconds.
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+
+    /// The maximum number of values of `key_field` that are tracked.
+    ///
+    /// Once reached, events with a value that isn't tracked are sampled together, as if they all
+    /// had the same value.
+    #[serde(default = "default_max_keys")]
+    pub max_keys: usize,
+}
+
+const fn default_window_secs() -> u64 {
+    30
+}
+
+const fn default_max_keys() -> usize {
+    10000
 }
 
 impl GenerateConfig for SampleConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
-            rate: 10,
+            rate: Some(10),
             key_field: None,
             exclude: None::<AnyCondition>,
+            adaptive: None,
         })
         .unwrap()
     }
@@ -47,14 +98,26 @@ impl GenerateConfig for SampleConfig {
 #[async_trait::async_trait]
 impl TransformConfig for SampleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::function(Sample::new(
-            self.rate,
-            self.key_field.clone(),
-            self.exclude
-                .as_ref()
-                .map(|condition| condition.build(&context.enrichment_tables))
-                .transpose()?,
-        )))
+        let exclude = self
+            .exclude
+            .as_ref()
+            .map(|condition| condition.build(&context.enrichment_tables))
+            .transpose()?;
+
+        let sample = match (self.rate, &self.adaptive) {
+            (Some(rate), None) => Sample::new(rate, self.key_field.clone(), exclude),
+            (None, Some(adaptive)) => {
+                if adaptive.target_events_per_second <= 0.0 || adaptive.window_secs == 0 {
+                    return Err(
+                        "`target_events_per_second` and `window_secs` must be positive.".into(),
+                    );
+                }
+                Sample::new_adaptive(adaptive, self.key_field.clone(), exclude)
+            }
+            _ => return Err("Exactly one of `rate` or `adaptive` must be set.".into()),
+        };
+
+        Ok(Transform::function(sample))
     }
 
     fn input(&self) -> Input {
@@ -67,21 +130,37 @@ impl TransformConfig for SampleConfig {
     }
 }
 
+#[derive(Clone)]
+enum SampleMode {
+    Fixed { rate: u64, count: u64 },
+    Adaptive(AdaptiveSampler),
+}
+
 #[derive(Clone)]
 pub struct Sample {
-    rate: u64,
+    mode: SampleMode,
     key_field: Option<String>,
     exclude: Option<Condition>,
-    count: u64,
 }
 
 impl Sample {
     pub const fn new(rate: u64, key_field: Option<String>, exclude: Option<Condition>) -> Self {
         Self {
-            rate,
+            mode: SampleMode::Fixed { rate, count: 0 },
+            key_field,
+            exclude,
+        }
+    }
+
+    pub fn new_adaptive(
+        config: &AdaptiveSampleConfig,
+        key_field: Option<String>,
+        exclude: Option<Condition>,
+    ) -> Self {
+        Self {
+            mode: SampleMode::Adaptive(AdaptiveSampler::new(config, Instant::now())),
             key_field,
             exclude,
-            count: 0,
         }
     }
 }
@@ -112,18 +191,27 @@ impl FunctionTransform for Sample {
             })
             .map(|v| v.to_string_lossy());
 
-        let num = if let Some(value) = value {
-            seahash::hash(value.as_bytes())
-        } else {
-            self.count
-        };
+        let rate = match &mut self.mode {
+            SampleMode::Fixed { rate, count } => {
+                let num = if let Some(value) = value {
+                    seahash::hash(value.as_bytes())
+                } else {
+                    *count
+                };
+
+                *count = (*count + 1) % *rate;
 
-        self.count = (self.count + 1) % self.rate;
+                (num % *rate == 0).then_some(*rate)
+            }
+            SampleMode::Adaptive(sampler) => {
+                sampler.sample(value.as_deref().unwrap_or_default(), Instant::now())
+            }
+        };
 
-        if num % self.rate == 0 {
+        if let Some(rate) = rate {
             match event {
-                Event::Log(ref mut event) => event.insert("sample_rate", self.rate.to_string()),
-                Event::Trace(ref mut event) => event.insert("sample_rate", self.rate.to_string()),
+                Event::Log(ref mut event) => event.insert("sample_rate", rate.to_string()),
+                Event::Trace(ref mut event) => event.insert("sample_rate", rate.to_string()),
                 Event::Metric(_) => panic!("component can never receive metric events"),
             };
             output.push(event);
@@ -133,6 +221,159 @@ impl FunctionTransform for Sample {
     }
 }
 
+#[derive(Clone)]
+struct KeyCounts {
+    // The number of events in the current and previous windows.
+    current: u64,
+    previous: u64,
+    // The number of events sampled since the key was first rated, to keep one of every `rate`.
+    sampled: u64,
+    // The rate the key is sampled at, which is only set once the rates are updated after the key
+    // is first seen.
+    rate: Option<u64>,
+}
+
+impl KeyCounts {
+    const fn new(rate: Option<u64>) -> Self {
+        Self {
+            current: 0,
+            previous: 0,
+            sampled: 0,
+            rate,
+        }
+    }
+
+    fn sample(&mut self, rate: u64) -> Option<u64> {
+        self.current += 1;
+        let kept = self.sampled % rate == 0;
+        self.sampled = self.sampled.wrapping_add(1);
+        kept.then_some(rate)
+    }
+
+    fn rotate(&mut self, skipped: bool) {
+        self.previous = if skipped { 0 } else { self.current };
+        self.current = 0;
+    }
+}
+
+/// Samples each key at a rate adapted to its volume over a sliding window.
+///
+/// The volume over the sliding window is estimated from the counts of the current and previous
+/// windows, weighting the previous count by the share of the previous window that is still within
+/// the sliding window.
+///
+/// Keys that aren't rated yet, either because they were first seen since the rates were last
+/// updated or because `max_keys` keys are already tracked, are sampled together as the untracked
+/// keys. Events of new keys are counted both for their key and for the untracked keys, so that the
+/// volume of the untracked keys also estimates the volume of the keys yet to be seen.
+#[derive(Clone)]
+struct AdaptiveSampler {
+    // The number of events to forward per window.
+    budget: f64,
+    window: Duration,
+    window_start: Instant,
+    rates_updated: Instant,
+    max_keys: usize,
+    keys: HashMap<String, KeyCounts>,
+    untracked: KeyCounts,
+}
+
+impl AdaptiveSampler {
+    fn new(config: &AdaptiveSampleConfig, now: Instant) -> Self {
+        Self {
+            budget: config.target_events_per_second * config.window_secs as f64,
+            window: Duration::from_secs(config.window_secs),
+            window_start: now,
+            rates_updated: now,
+            max_keys: config.max_keys,
+            keys: HashMap::new(),
+            // Until the rates are first updated, every key is kept in full.
+            untracked: KeyCounts::new(Some(1)),
+        }
+    }
+
+    /// Counts an event of the key, returning the rate it was sampled at if it is kept.
+    fn sample(&mut self, key: &str, now: Instant) -> Option<u64> {
+        self.rotate_window(now);
+        if now.duration_since(self.rates_updated) >= ADAPTIVE_RATE_UPDATE_INTERVAL {
+            self.update_rates(now);
+        }
+
+        if !self.keys.contains_key(key) && self.keys.len() < self.max_keys {
+            self.keys.insert(key.to_owned(), KeyCounts::new(None));
+        }
+
+        let untracked_rate = self.untracked.rate.unwrap_or(1);
+        match self.keys.get_mut(key) {
+            Some(counts) => match counts.rate {
+                Some(rate) => counts.sample(rate),
+                None => {
+                    counts.current += 1;
+                    self.untracked.sample(untracked_rate)
+                }
+            },
+            None => self.untracked.sample(untracked_rate),
+        }
+    }
+
+    fn rotate_window(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.window_start);
+        if elapsed < self.window {
+            return;
+        }
+
+        // Nothing was counted in the previous window if more than a window has passed since the
+        // current one ended.
+        let skipped = elapsed >= self.window * 2;
+        self.keys.retain(|_, counts| {
+            counts.rotate(skipped);
+            counts.previous > 0
+        });
+        self.untracked.rotate(skipped);
+        self.window_start = if skipped {
+            now
+        } else {
+            self.window_start + self.window
+        };
+    }
+
+    /// Shares the budget between the keys, starting from the least frequent ones.
+    ///
+    /// Keys whose volume fits in their share of the remaining budget are kept in full, leaving the
+    /// rest of their share to the more frequent keys, which are sampled down to their share.
+    fn update_rates(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.window_start).as_secs_f64();
+        let previous_weight = 1.0 - elapsed / self.window.as_secs_f64();
+
+        let mut volumes = self
+            .keys
+            .values_mut()
+            .chain(iter::once(&mut self.untracked))
+            .map(|counts| {
+                let volume = counts.current as f64 + counts.previous as f64 * previous_weight;
+                (volume, counts)
+            })
+            .collect::<Vec<_>>();
+        volumes.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let mut budget = self.budget;
+        let mut remaining = volumes.len();
+        for (volume, counts) in volumes {
+            let share = budget / remaining as f64;
+            let rate = if volume <= share {
+                1
+            } else {
+                ((volume / share).ceil() as u64).max(1)
+            };
+            counts.rate = Some(rate);
+            budget = (budget - volume / rate as f64).max(0.0);
+            remaining -= 1;
+        }
+
+        self.rates_updated = now;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -338,13 +579,120 @@ mod tests {
         assert_eq!(total_passed, 1);
     }
 
+    #[test]
+    fn adapts_rates_to_the_volume_of_each_key() {
+        let start = Instant::now();
+        let mut sampler = AdaptiveSampler::new(
+            &AdaptiveSampleConfig {
+                target_events_per_second: 10.0,
+                window_secs: 10,
+                max_keys: 100,
+            },
+            start,
+        );
+
+        // Until the rates are first updated, every key is kept in full.
+        for _ in 0..1000 {
+            assert_eq!(sampler.sample("noisy", start), Some(1));
+        }
+        for _ in 0..10 {
+            assert_eq!(sampler.sample("rare", start), Some(1));
+        }
+
+        // The rare key fits in its share of the 100 events of the window and is still kept in
+        // full, leaving the rest of its share to the noisy key and to the keys yet to be seen.
+        let now = start + Duration::from_secs(1);
+        let rare = (0..10)
+            .filter_map(|_| sampler.sample("rare", now))
+            .collect::<Vec<_>>();
+        assert_eq!(rare, vec![1; 10]);
+        let noisy = (0..120)
+            .filter_map(|_| sampler.sample("noisy", now))
+            .collect::<Vec<_>>();
+        assert_eq!(noisy, vec![23; 6]);
+
+        // New keys are sampled against the remaining budget until their rate is updated.
+        let new = (0..44)
+            .filter_map(|_| sampler.sample("new", now))
+            .collect::<Vec<_>>();
+        assert_eq!(new, vec![22; 2]);
+
+        // The counts of the previous window are still weighted in after it ends.
+        sampler.sample("noisy", start + Duration::from_secs(11));
+        assert!(sampler.keys["noisy"].rate > Some(1));
+
+        // Keys are forgotten once they haven't been seen for a whole window.
+        assert_eq!(
+            sampler.sample("noisy", start + Duration::from_secs(35)),
+            Some(1)
+        );
+        assert!(!sampler.keys.contains_key("rare"));
+    }
+
+    #[test]
+    fn samples_untracked_keys_together() {
+        let start = Instant::now();
+        let mut sampler = AdaptiveSampler::new(
+            &AdaptiveSampleConfig {
+                target_events_per_second: 10.0,
+                window_secs: 10,
+                max_keys: 2,
+            },
+            start,
+        );
+
+        for key in ["a", "b", "c"] {
+            assert_eq!(sampler.sample(key, start), Some(1));
+        }
+        assert_eq!(sampler.keys.len(), 2);
+        assert!(!sampler.keys.contains_key("c"));
+
+        for i in 0..1000 {
+            sampler.sample(&i.to_string(), start + Duration::from_secs(1));
+        }
+
+        // The keys over the limit share the budget left by the tracked keys.
+        let now = start + Duration::from_secs(2);
+        let untracked = (1000..2100)
+            .filter_map(|i| sampler.sample(&i.to_string(), now))
+            .collect::<Vec<_>>();
+        assert_eq!(untracked, vec![11; 100]);
+        assert_eq!(sampler.keys.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn requires_either_rate_or_adaptive() {
+        for config in [
+            "",
+            r#"
+            rate = 10
+            adaptive.target_events_per_second = 100
+            "#,
+            "adaptive.target_events_per_second = 0",
+        ] {
+            let config = toml::from_str::<SampleConfig>(config).unwrap();
+            assert!(config.build(&TransformContext::default()).await.is_err());
+        }
+
+        let config = toml::from_str::<SampleConfig>(
+            r#"
+            key_field = "service"
+            adaptive.target_events_per_second = 100
+            adaptive.window_secs = 60
+            "#,
+        )
+        .unwrap();
+        assert!(config.build(&TransformContext::default()).await.is_ok());
+    }
+
     #[tokio::test]
     async fn emits_internal_events() {
         assert_transform_compliance(async move {
             let config = SampleConfig {
-                rate: 1,
+                rate: Some(1),
                 key_field: None,
                 exclude: None,
+                adaptive: None,
             };
             let (tx, rx) = mpsc::channel(1);
             let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
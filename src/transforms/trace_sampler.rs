@@ -0,0 +1,637 @@
+use std::{
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use futures::{stream, Stream, StreamExt};
+use indexmap::IndexMap;
+use serde_with::serde_as;
+use vector_config::configurable_component;
+
+use crate::{
+    conditions::{AnyCondition, Condition},
+    config::{DataType, GenerateConfig, Input, Output, TransformConfig, TransformContext},
+    event::{Event, TraceEvent},
+    internal_events::TraceSamplerTraceDiscarded,
+    schema,
+    transforms::{TaskTransform, Transform},
+};
+
+// The layout of spans decoded by the `opentelemetry` source.
+const PARENT_SPAN_ID_KEY: &str = "parent_span_id";
+const START_TIME_KEY: &str = "start_time";
+const END_TIME_KEY: &str = "end_time";
+const STATUS_CODE_KEY: &str = "status.code";
+
+/// The status code of spans that ended with an error.
+const STATUS_CODE_ERROR: i64 = 2;
+
+/// Configuration for the `trace_sampler` transform.
+#[serde_as]
+#[configurable_component(transform("trace_sampler"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TraceSamplerConfig {
+    /// The policies deciding whether a trace is kept.
+    ///
+    /// A trace is kept if any of the policies keeps it, and dropped otherwise. All the spans of a
+    /// trace are kept or dropped together.
+    pub policies: Vec<SamplingPolicy>,
+
+    /// The name of the field containing the ID of the trace that a span belongs to.
+    ///
+    /// Spans without this field are forwarded without being sampled.
+    #[serde(default = "default_trace_id_field")]
+    #[configurable(metadata(docs::examples = "trace_id"))]
+    pub trace_id_field: String,
+
+    /// The period of time to wait after the last span of a trace is received, in milliseconds,
+    /// before the trace is considered complete and sampled.
+    ///
+    /// Spans received after their trace has been sampled are buffered as a new trace.
+    #[serde(default = "default_completion_timeout_ms")]
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    pub completion_timeout_ms: Duration,
+
+    /// The interval to check for and sample any completed traces, in milliseconds.
+    #[serde(default = "default_flush_period_ms")]
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    pub flush_period_ms: Duration,
+
+    /// The maximum number of traces buffered at once.
+    ///
+    /// Once reached, the trace whose first span was received the earliest is sampled before a new
+    /// trace is buffered.
+    #[serde(default = "default_max_traces")]
+    pub max_traces: usize,
+}
+
+fn default_trace_id_field() -> String {
+    "trace_id".to_string()
+}
+
+const fn default_completion_timeout_ms() -> Duration {
+    Duration::from_millis(30000)
+}
+
+const fn default_flush_period_ms() -> Duration {
+    Duration::from_millis(1000)
+}
+
+const fn default_max_traces() -> usize {
+    50000
+}
+
+/// A policy deciding whether a trace is kept.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SamplingPolicy {
+    /// Keeps traces in which any span ended with an error.
+    ///
+    /// A span ended with an error if its `status.code` is `2`.
+    Error,
+
+    /// Keeps traces that lasted longer than a threshold.
+    Latency(#[configurable(derived)] LatencyPolicy),
+
+    /// Keeps a percentage of traces.
+    Probabilistic(#[configurable(derived)] ProbabilisticPolicy),
+
+    /// Keeps traces in which any span matches a condition.
+    Condition(#[configurable(derived)] ConditionPolicy),
+}
+
+/// Configuration of the `latency` sampling policy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct LatencyPolicy {
+    /// The duration above which traces are kept, in milliseconds.
+    ///
+    /// The duration of a trace is the one of its root span, which is the span without a
+    /// `parent_span_id`. If the root span was not received, it is the time between the earliest
+    /// `start_time` and the latest `end_time` of the spans.
+    pub threshold_ms: u64,
+}
+
+/// Configuration of the `probabilistic` sampling policy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ProbabilisticPolicy {
+    /// The percentage of traces that are kept, between `0` and `100`.
+    ///
+    /// Traces are picked by hashing their ID, so the same traces are kept by every instance of
+    /// Vector sampling with the same percentage.
+    pub percentage: f64,
+}
+
+/// Configuration of the `condition` sampling policy.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ConditionPolicy {
+    /// The condition that a span must match for its trace to be kept.
+    pub condition: AnyCondition,
+}
+
+impl GenerateConfig for TraceSamplerConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"
+            policies = [
+                { type = "error" },
+                { type = "latency", threshold_ms = 5000 },
+                { type = "probabilistic", percentage = 10.0 },
+            ]
+            "#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransformConfig for TraceSamplerConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        TraceSampler::new(self, &context.enrichment_tables).map(Transform::event_task)
+    }
+
+    fn input(&self) -> Input {
+        Input::trace()
+    }
+
+    fn outputs(&self, merged_definition: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::Trace).with_schema_definition(merged_definition.clone())]
+    }
+}
+
+enum Policy {
+    Error,
+    Latency(chrono::Duration),
+    // The number of traces kept out of every 10000.
+    Probabilistic(u64),
+    Condition(Condition),
+}
+
+impl Policy {
+    fn keeps(&self, trace_id: &str, spans: &mut [TraceEvent]) -> bool {
+        match self {
+            Self::Error => spans.iter().any(|span| {
+                span.get(STATUS_CODE_KEY).and_then(|code| code.as_integer())
+                    == Some(STATUS_CODE_ERROR)
+            }),
+            Self::Latency(threshold) => {
+                trace_duration(spans).map_or(false, |duration| duration > *threshold)
+            }
+            Self::Probabilistic(kept) => seahash::hash(trace_id.as_bytes()) % 10000 < *kept,
+            Self::Condition(condition) => spans.iter_mut().any(|span| {
+                let (matched, event) = condition.check(Event::Trace(std::mem::take(span)));
+                *span = event.into_trace();
+                matched
+            }),
+        }
+    }
+}
+
+fn span_times(span: &TraceEvent) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let start = span.get(START_TIME_KEY)?.as_timestamp()?;
+    let end = span.get(END_TIME_KEY)?.as_timestamp()?;
+    Some((*start, *end))
+}
+
+fn trace_duration(spans: &[TraceEvent]) -> Option<chrono::Duration> {
+    if let Some((start, end)) = spans
+        .iter()
+        .find(|span| !span.contains(PARENT_SPAN_ID_KEY))
+        .and_then(span_times)
+    {
+        return Some(end - start);
+    }
+
+    let start = spans
+        .iter()
+        .filter_map(span_times)
+        .map(|(start, _)| start)
+        .min()?;
+    let end = spans
+        .iter()
+        .filter_map(span_times)
+        .map(|(_, end)| end)
+        .max()?;
+    Some(end - start)
+}
+
+struct BufferedTrace {
+    spans: Vec<TraceEvent>,
+    last_received: Instant,
+}
+
+pub struct TraceSampler {
+    policies: Vec<Policy>,
+    trace_id_field: String,
+    completion_timeout: Duration,
+    flush_period: Duration,
+    max_traces: usize,
+    // Ordered by the time the first span of each trace was received.
+    traces: IndexMap<String, BufferedTrace>,
+}
+
+impl TraceSampler {
+    pub fn new(
+        config: &TraceSamplerConfig,
+        enrichment_tables: &enrichment::TableRegistry,
+    ) -> crate::Result<Self> {
+        if config.policies.is_empty() {
+            return Err("At least one sampling policy must be configured.".into());
+        }
+
+        let policies = config
+            .policies
+            .iter()
+            .map(|policy| match policy {
+                SamplingPolicy::Error => Ok(Policy::Error),
+                SamplingPolicy::Latency(latency) => Ok(Policy::Latency(
+                    chrono::Duration::milliseconds(latency.threshold_ms as i64),
+                )),
+                SamplingPolicy::Probabilistic(probabilistic) => {
+                    if !(0.0..=100.0).contains(&probabilistic.percentage) {
+                        return Err(format!(
+                            "Sampling percentage must be between 0 and 100, got {}.",
+                            probabilistic.percentage
+                        )
+                        .into());
+                    }
+                    Ok(Policy::Probabilistic(
+                        (probabilistic.percentage * 100.0).round() as u64,
+                    ))
+                }
+                SamplingPolicy::Condition(condition) => condition
+                    .condition
+                    .build(enrichment_tables)
+                    .map(Policy::Condition),
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self {
+            policies,
+            trace_id_field: config.trace_id_field.clone(),
+            completion_timeout: config.completion_timeout_ms,
+            flush_period: config.flush_period_ms,
+            max_traces: config.max_traces,
+            traces: IndexMap::new(),
+        })
+    }
+
+    fn sample_into(&self, output: &mut Vec<Event>, trace_id: &str, mut spans: Vec<TraceEvent>) {
+        if self
+            .policies
+            .iter()
+            .any(|policy| policy.keeps(trace_id, &mut spans))
+        {
+            output.extend(spans.into_iter().map(Event::Trace));
+        } else {
+            emit!(TraceSamplerTraceDiscarded { count: spans.len() });
+        }
+    }
+
+    fn flush_into(&mut self, output: &mut Vec<Event>) {
+        let completion_timeout = self.completion_timeout;
+        let (completed, pending): (IndexMap<_, _>, _) = std::mem::take(&mut self.traces)
+            .into_iter()
+            .partition(|(_, trace)| trace.last_received.elapsed() >= completion_timeout);
+        self.traces = pending;
+
+        for (trace_id, trace) in completed {
+            self.sample_into(output, &trace_id, trace.spans);
+        }
+    }
+
+    fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        for (trace_id, trace) in std::mem::take(&mut self.traces) {
+            self.sample_into(output, &trace_id, trace.spans);
+        }
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event) {
+        let span = event.into_trace();
+        let trace_id = match span.get(self.trace_id_field.as_str()) {
+            Some(trace_id) => trace_id.to_string_lossy().into_owned(),
+            None => {
+                output.push(Event::Trace(span));
+                return;
+            }
+        };
+
+        if !self.traces.contains_key(&trace_id) && self.traces.len() >= self.max_traces {
+            if let Some((oldest_id, oldest)) = self.traces.shift_remove_index(0) {
+                self.sample_into(output, &oldest_id, oldest.spans);
+            }
+        }
+
+        let now = Instant::now();
+        let trace = self
+            .traces
+            .entry(trace_id)
+            .or_insert_with(|| BufferedTrace {
+                spans: Vec::new(),
+                last_received: now,
+            });
+        trace.spans.push(span);
+        trace.last_received = now;
+    }
+}
+
+impl TaskTransform<Event> for TraceSampler {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        let mut flush_stream = tokio::time::interval(me.flush_period);
+
+        Box::pin(
+            stream! {
+                loop {
+                    let mut output = Vec::new();
+                    let done = tokio::select! {
+                        _ = flush_stream.tick() => {
+                            me.flush_into(&mut output);
+                            false
+                        }
+                        maybe_event = input_rx.next() => {
+                            match maybe_event {
+                                None => {
+                                    me.flush_all_into(&mut output);
+                                    true
+                                }
+                                Some(event) => {
+                                    me.transform_one(&mut output, event);
+                                    false
+                                }
+                            }
+                        }
+                    };
+                    yield stream::iter(output.into_iter());
+                    if done { break }
+                }
+            }
+            .flatten(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::{
+        event::Value, test_util::components::assert_transform_compliance,
+        transforms::test::create_topology,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<TraceSamplerConfig>();
+    }
+
+    fn make_sampler(config: &str) -> TraceSampler {
+        let config = toml::from_str::<TraceSamplerConfig>(config).unwrap();
+        TraceSampler::new(&config, &Default::default()).unwrap()
+    }
+
+    fn make_span(trace_id: &str, span_id: &str, parent_span_id: Option<&str>) -> Event {
+        let mut span = TraceEvent::default();
+        span.insert("trace_id", trace_id);
+        span.insert("span_id", span_id);
+        if let Some(parent_span_id) = parent_span_id {
+            span.insert(PARENT_SPAN_ID_KEY, parent_span_id);
+        }
+        span.insert(START_TIME_KEY, Utc.timestamp(1_600_000_000, 0));
+        span.insert(END_TIME_KEY, Utc.timestamp_millis(1_600_000_000_100));
+        Event::Trace(span)
+    }
+
+    fn sample(sampler: &mut TraceSampler, spans: Vec<Event>) -> Vec<Event> {
+        let mut output = Vec::new();
+        for span in spans {
+            sampler.transform_one(&mut output, span);
+        }
+        sampler.flush_all_into(&mut output);
+        output
+    }
+
+    fn span_ids(events: &[Event]) -> Vec<Value> {
+        events
+            .iter()
+            .map(|event| event.as_trace().get("span_id").unwrap().clone())
+            .collect()
+    }
+
+    #[test]
+    fn keeps_traces_with_errors() {
+        let mut sampler = make_sampler(r#"policies = [{ type = "error" }]"#);
+
+        let mut error = make_span("1", "b", Some("a"));
+        error
+            .as_mut_trace()
+            .insert(STATUS_CODE_KEY, STATUS_CODE_ERROR);
+        let output = sample(
+            &mut sampler,
+            vec![
+                make_span("1", "a", None),
+                make_span("2", "c", None),
+                error,
+                make_span("2", "d", Some("c")),
+            ],
+        );
+
+        assert_eq!(span_ids(&output), vec![Value::from("a"), Value::from("b")]);
+    }
+
+    #[test]
+    fn keeps_slow_traces() {
+        let mut sampler = make_sampler(r#"policies = [{ type = "latency", threshold_ms = 500 }]"#);
+
+        let mut slow = make_span("1", "a", None);
+        slow.as_mut_trace()
+            .insert(END_TIME_KEY, Utc.timestamp(1_600_000_001, 0));
+        // Without its root span, the duration of a trace spans all of its spans.
+        let mut slow_child = make_span("3", "f", Some("e"));
+        slow_child
+            .as_mut_trace()
+            .insert(END_TIME_KEY, Utc.timestamp(1_600_000_001, 0));
+        let output = sample(
+            &mut sampler,
+            vec![
+                slow,
+                make_span("1", "b", Some("a")),
+                make_span("2", "c", None),
+                make_span("2", "d", Some("c")),
+                make_span("3", "e", Some("root")),
+                slow_child,
+            ],
+        );
+
+        assert_eq!(
+            span_ids(&output),
+            vec![
+                Value::from("a"),
+                Value::from("b"),
+                Value::from("e"),
+                Value::from("f")
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_percentage_of_traces() {
+        let spans = (0..1000)
+            .flat_map(|i| {
+                let trace_id = i.to_string();
+                vec![
+                    make_span(&trace_id, "a", None),
+                    make_span(&trace_id, "b", Some("a")),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let mut sampler =
+            make_sampler(r#"policies = [{ type = "probabilistic", percentage = 25.0 }]"#);
+        let output = sample(&mut sampler, spans.clone());
+        // Spans of a trace are kept together.
+        assert_eq!(output.len() % 2, 0);
+        assert!((400..600).contains(&output.len()));
+
+        // The same traces are kept every time.
+        let mut sampler =
+            make_sampler(r#"policies = [{ type = "probabilistic", percentage = 25.0 }]"#);
+        assert_eq!(sample(&mut sampler, spans.clone()), output);
+
+        let mut sampler =
+            make_sampler(r#"policies = [{ type = "probabilistic", percentage = 0.0 }]"#);
+        assert!(sample(&mut sampler, spans.clone()).is_empty());
+
+        let mut sampler =
+            make_sampler(r#"policies = [{ type = "probabilistic", percentage = 100.0 }]"#);
+        assert_eq!(sample(&mut sampler, spans).len(), 2000);
+    }
+
+    #[test]
+    fn keeps_traces_matching_condition() {
+        let mut sampler = make_sampler(
+            r#"
+            [[policies]]
+            type = "condition"
+            condition = '.name == "checkout"'
+            "#,
+        );
+
+        let mut checkout = make_span("2", "d", Some("c"));
+        checkout.as_mut_trace().insert("name", "checkout");
+        let output = sample(
+            &mut sampler,
+            vec![
+                make_span("1", "a", None),
+                make_span("2", "c", None),
+                checkout,
+            ],
+        );
+
+        assert_eq!(span_ids(&output), vec![Value::from("c"), Value::from("d")]);
+    }
+
+    #[test]
+    fn forwards_spans_without_trace_id() {
+        let mut sampler = make_sampler(r#"policies = [{ type = "error" }]"#);
+
+        let mut span = make_span("1", "a", None);
+        span.as_mut_trace().as_mut().remove("trace_id");
+        let output = sample(&mut sampler, vec![span, make_span("2", "b", None)]);
+
+        assert_eq!(span_ids(&output), vec![Value::from("a")]);
+    }
+
+    #[test]
+    fn samples_oldest_trace_when_full() {
+        let mut sampler = make_sampler(
+            r#"
+            policies = [{ type = "probabilistic", percentage = 100.0 }]
+            max_traces = 2
+            "#,
+        );
+
+        let mut output = Vec::new();
+        for span in [
+            make_span("1", "a", None),
+            make_span("2", "b", None),
+            make_span("1", "c", Some("a")),
+        ] {
+            sampler.transform_one(&mut output, span);
+        }
+        assert!(output.is_empty());
+
+        sampler.transform_one(&mut output, make_span("3", "d", None));
+        assert_eq!(span_ids(&output), vec![Value::from("a"), Value::from("c")]);
+        assert_eq!(sampler.traces.len(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_policies() {
+        let config = toml::from_str::<TraceSamplerConfig>("policies = []").unwrap();
+        assert!(TraceSampler::new(&config, &Default::default()).is_err());
+
+        let config = toml::from_str::<TraceSamplerConfig>(
+            r#"policies = [{ type = "probabilistic", percentage = 150.0 }]"#,
+        )
+        .unwrap();
+        assert!(TraceSampler::new(&config, &Default::default()).is_err());
+    }
+
+    #[tokio::test]
+    async fn samples_completed_traces() {
+        let config = toml::from_str::<TraceSamplerConfig>(
+            r#"
+            policies = [{ type = "error" }]
+            completion_timeout_ms = 100
+            flush_period_ms = 10
+            "#,
+        )
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            let mut error = make_span("1", "b", Some("a"));
+            error
+                .as_mut_trace()
+                .insert(STATUS_CODE_KEY, STATUS_CODE_ERROR);
+            for span in [make_span("1", "a", None), error, make_span("2", "c", None)] {
+                tx.send(span).await.unwrap();
+            }
+
+            // The trace is sampled once no span has been received for the completion timeout,
+            // while the transform is still running.
+            assert_eq!(
+                out.recv().await.unwrap().as_trace().get("span_id"),
+                Some(&Value::from("a"))
+            );
+            assert_eq!(
+                out.recv().await.unwrap().as_trace().get("span_id"),
+                Some(&Value::from("b"))
+            );
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
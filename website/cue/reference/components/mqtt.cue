@@ -0,0 +1,133 @@
+package metadata
+
+components: _mqtt: {
+	features: {
+		collect: from: {
+			service: services.mqtt
+			interface: {
+				socket: {
+					api: {
+						title: "MQTT protocol"
+						url:   urls.mqtt
+					}
+					direction: "outgoing"
+					port:      1883
+					protocols: ["tcp"]
+					ssl: "optional"
+				}
+			}
+		}
+
+		send: to: {
+			service: services.mqtt
+			interface: {
+				socket: {
+					api: {
+						title: "MQTT protocol"
+						url:   urls.mqtt
+					}
+					direction: "outgoing"
+					port:      1883
+					protocols: ["tcp"]
+					ssl: "optional"
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		notices: []
+		warnings: []
+	}
+
+	configuration: {
+		host: {
+			description: "The domain name or IP address of the MQTT broker."
+			required:    true
+			type: string: {
+				examples: ["mosquitto.local", "127.0.0.1"]
+			}
+		}
+		port: {
+			common:      false
+			description: "The TCP port of the MQTT broker."
+			required:    false
+			type: uint: {
+				default: 1883
+				unit:    null
+			}
+		}
+		client_id: {
+			common:      false
+			description: "The client ID used to identify this connection to the broker. Client IDs must be unique per broker. If not set, a random client ID is generated each time the component connects."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["vector"]
+			}
+		}
+		keep_alive_secs: {
+			common:      false
+			description: "The interval at which keep-alive pings are sent to the broker."
+			required:    false
+			type: uint: {
+				default: 60
+				unit:    "seconds"
+			}
+		}
+		user: {
+			common:      false
+			description: "The username to authenticate with. Requires `password` to be set."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["vector"]
+			}
+		}
+		password: {
+			common:      false
+			description: "The password to authenticate with. Requires `user` to be set."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["${MQTT_PASSWORD}"]
+			}
+		}
+		qos: {
+			common:      false
+			description: "The quality of service level used to deliver messages."
+			required:    false
+			type: string: {
+				default: "at_least_once"
+				enum: {
+					at_most_once:  "Messages are delivered at most once, and may be lost."
+					at_least_once: "Messages are delivered at least once, and may be duplicated."
+					exactly_once:  "Messages are delivered exactly once."
+				}
+			}
+		}
+	}
+
+	how_it_works: {
+		rumqttc: {
+			title: "rumqttc"
+			body:  """
+				The `mqtt` source/sink uses [`rumqttc`](\(urls.rumqttc)) under the hood, and supports
+				MQTT 3.1.1.
+				"""
+		}
+		tls: {
+			title: "TLS"
+			body:  """
+				When TLS is enabled, the broker is verified against the certificate authority set with
+				`tls.ca_file`, or against the certificates trusted by the system if it isn't set. A client
+				certificate can be provided with `tls.crt_file` and `tls.key_file`, along with
+				`tls.key_pass` if the key is encrypted.
+
+				The certificate and hostname of the broker are always verified, so setting
+				`tls.verify_certificate` or `tls.verify_hostname` to `false` is rejected.
+				"""
+		}
+	}
+}
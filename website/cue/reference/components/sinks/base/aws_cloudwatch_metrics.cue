@@ -125,11 +125,11 @@ base: components: sinks: aws_cloudwatch_metrics: configuration: {
 					required:    false
 					type: {
 						string: enum: ["none", "fast", "best", "default"]
-						uint: enum: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+						uint: enum: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21]
 					}
 				}
 			}
-			string: enum: ["none", "gzip", "zlib"]
+			string: enum: ["none", "gzip", "zlib", "zstd", "snappy"]
 		}
 	}
 	default_namespace: {
@@ -116,11 +116,11 @@ base: components: sinks: aws_kinesis_streams: configuration: {
 					required:    false
 					type: {
 						string: enum: ["none", "fast", "best", "default"]
-						uint: enum: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+						uint: enum: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21]
 					}
 				}
 			}
-			string: enum: ["none", "gzip", "zlib"]
+			string: enum: ["none", "gzip", "zlib", "zstd", "snappy"]
 		}
 	}
 	encoding: {
@@ -137,15 +137,108 @@ base: components: sinks: aws_kinesis_streams: configuration: {
 					type: string: syntax: "literal"
 				}
 			}
+			cef: {
+				description:   "CEF serializer options."
+				relevant_when: "codec = \"cef\""
+				required:      true
+				type: object: options: {
+					device_product: {
+						description: "The product that sends the events, written as the `Device Product` header."
+						required:    true
+						type: string: syntax: "literal"
+					}
+					device_vendor: {
+						description: "The vendor of the device that sends the events, written as the `Device Vendor` header."
+						required:    true
+						type: string: syntax: "literal"
+					}
+					device_version: {
+						description: "The version of the product that sends the events, written as the `Device Version` header."
+						required:    true
+						type: string: syntax: "literal"
+					}
+					extensions: {
+						description: """
+							The extensions to encode, as a map of extension keys to the fields holding their values.
+
+							Keys must be alphanumeric, such as `src` or `cs1Label`. Fields that are missing or `null`
+							in an event are left out, timestamps are encoded as milliseconds since the Unix epoch, and
+							objects and arrays are encoded as JSON.
+							"""
+						required: false
+						type: object: options: "*": {
+							description: """
+								The extensions to encode, as a map of extension keys to the fields holding their values.
+
+								Keys must be alphanumeric, such as `src` or `cs1Label`. Fields that are missing or `null`
+								in an event are left out, timestamps are encoded as milliseconds since the Unix epoch, and
+								objects and arrays are encoded as JSON.
+								"""
+							required: true
+							type: string: syntax: "literal"
+						}
+					}
+					name_field: {
+						description: "The field holding the `Name` header, a human-readable description of the event."
+						required:    false
+						type: string: {
+							default: "name"
+							syntax:  "literal"
+						}
+					}
+					severity_field: {
+						description: """
+							The field holding the `Severity` header.
+
+							Integer severities must be between 0 and 10. Strings, such as `High`, are written as is.
+							"""
+						required: false
+						type: string: {
+							default: "severity"
+							syntax:  "literal"
+						}
+					}
+					signature_id_field: {
+						description: "The field holding the `Signature ID` header, which identifies the type of the event."
+						required:    false
+						type: string: {
+							default: "signature_id"
+							syntax:  "literal"
+						}
+					}
+				}
+			}
 			codec: {
 				required: true
 				type: string: enum: {
-					avro:        "Apache Avro serialization."
-					gelf:        "GELF serialization."
-					json:        "JSON serialization."
+					avro: "Apache Avro serialization."
+					cef: """
+						[Common Event Format][cef] (CEF) serialization.
+
+						Each event is encoded as a single CEF message, with the configured fields as its header and
+						extensions.
+
+						[cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors/pdfdoc/common-event-format-v25/common-event-format-v25.pdf
+						"""
+					csv: """
+						CSV serialization.
+
+						Each event is encoded as a single row containing the configured fields.
+						"""
+					gelf: "GELF serialization."
+					json: "JSON serialization."
+					leef: """
+						[Log Event Extended Format][leef] (LEEF) serialization.
+
+						Each event is encoded as a single LEEF message, with the configured fields as its header and
+						attributes.
+
+						[leef]: https://www.ibm.com/docs/en/dsm?topic=leef-overview
+						"""
 					logfmt:      "Logfmt serialization."
 					native:      "Native Vector serialization based on Protocol Buffers."
 					native_json: "Native Vector serialization based on JSON."
+					protobuf:    "Protocol Buffers serialization, using a user-supplied message type."
 					raw_message: """
 						No serialization.
 
@@ -154,6 +247,12 @@ base: components: sinks: aws_kinesis_streams: configuration: {
 						while doing additional parsing on it, as this could lead to the encoding emitting empty strings for the given
 						event.
 						"""
+					syslog: """
+						Syslog serialization.
+
+						Each event is encoded as a single syslog message, following either RFC 5424 or RFC 3164.
+						Multi-line messages can be sent over TCP with the `octet_counting` framing.
+						"""
 					text: """
 						Plaintext serialization.
 
@@ -164,16 +263,259 @@ base: components: sinks: aws_kinesis_streams: configuration: {
 						"""
 				}
 			}
+			csv: {
+				description:   "CSV serializer options."
+				relevant_when: "codec = \"csv\""
+				required:      true
+				type: object: options: {
+					delimiter: {
+						description: "The ASCII (7-bit) character that separates the columns of a row."
+						required:    false
+						type: uint: default: 44
+					}
+					fields: {
+						description: """
+							The fields to encode, in column order.
+
+							Fields that are missing or `null` in an event are encoded as empty columns. Objects and
+							arrays are encoded as JSON.
+							"""
+						required: true
+						type: array: items: type: string: syntax: "literal"
+					}
+					include_header: {
+						description: """
+							Whether to write a header row with the field names.
+
+							The header is written at the start of each batch of events, which for object store sinks
+							means once per object, and by the `file` sink whenever it starts writing to an empty file.
+							"""
+						required: false
+						type: bool: default: false
+					}
+					quote_style: {
+						description: "The quoting style to use when writing columns."
+						required:    false
+						type: string: {
+							default: "necessary"
+							enum: {
+								always: "Always quote columns."
+								necessary: """
+									Only quote columns when necessary, such as when they contain the delimiter, a quote or a
+									line break.
+									"""
+								never:       "Never quote columns, even if that produces invalid CSV."
+								non_numeric: "Quote every column that isn't numeric."
+							}
+						}
+					}
+				}
+			}
 			except_fields: {
 				description: "List of fields that will be excluded from the encoded event."
 				required:    false
 				type: array: items: type: string: syntax: "literal"
 			}
+			leef: {
+				description:   "LEEF serializer options."
+				relevant_when: "codec = \"leef\""
+				required:      true
+				type: object: options: {
+					attributes: {
+						description: """
+							The attributes to encode, as a map of attribute keys to the fields holding their values.
+
+							Fields that are missing or `null` in an event are left out, timestamps are encoded as
+							milliseconds since the Unix epoch, and objects and arrays are encoded as JSON.
+							"""
+						required: false
+						type: object: options: "*": {
+							description: """
+								The attributes to encode, as a map of attribute keys to the fields holding their values.
+
+								Fields that are missing or `null` in an event are left out, timestamps are encoded as
+								milliseconds since the Unix epoch, and objects and arrays are encoded as JSON.
+								"""
+							required: true
+							type: string: syntax: "literal"
+						}
+					}
+					delimiter: {
+						description: """
+							The ASCII (7-bit) character that separates the attributes.
+
+							LEEF 1.0 only supports tab, while LEEF 2.0 writes the delimiter to its header.
+							"""
+						required: false
+						type: uint: default: 9
+					}
+					event_id_field: {
+						description: "The field holding the `EventID` header, which identifies the type of the event."
+						required:    false
+						type: string: {
+							default: "event_id"
+							syntax:  "literal"
+						}
+					}
+					product_name: {
+						description: "The product that sends the events, written as the `Product Name` header."
+						required:    true
+						type: string: syntax: "literal"
+					}
+					product_version: {
+						description: "The version of the product that sends the events, written as the `Product Version` header."
+						required:    true
+						type: string: syntax: "literal"
+					}
+					vendor: {
+						description: "The vendor of the device that sends the events, written as the `Vendor` header."
+						required:    true
+						type: string: syntax: "literal"
+					}
+					version: {
+						description: "The version of the LEEF format."
+						required:    false
+						type: string: {
+							default: "2.0"
+							enum: {
+								"1.0": "LEEF 1.0, with tab-delimited attributes."
+								"2.0": "LEEF 2.0, with a configurable attribute delimiter."
+							}
+						}
+					}
+				}
+			}
 			only_fields: {
 				description: "List of fields that will be included in the encoded event."
 				required:    false
 				type: array: items: type: string: syntax: "literal"
 			}
+			protobuf: {
+				description:   "Protobuf serializer options."
+				relevant_when: "codec = \"protobuf\""
+				required:      true
+				type: object: options: {
+					desc_file: {
+						description: """
+							Path to a compiled `FileDescriptorSet` containing the message type.
+
+							The file can be generated with `protoc --include_imports --descriptor_set_out=<file>`.
+							"""
+						required: true
+						type: string: syntax: "literal"
+					}
+					message_type: {
+						description: """
+							The fully qualified name of the message type to encode events as, e.g. `package.Message`.
+
+							Top-level event fields are mapped onto message fields of the same name. Event fields
+							without a matching message field are ignored.
+							"""
+						required: true
+						type: string: syntax: "literal"
+					}
+				}
+			}
+			syslog: {
+				description: """
+					Syslog serializer options.
+
+					The message and timestamp are taken from the fields with the `message` and `timestamp`
+					meanings, and the other fields default to the ones produced by the `syslog` decoder.
+					"""
+				relevant_when: "codec = \"syslog\""
+				required:      false
+				type: object: options: {
+					appname_field: {
+						description: "The field holding the application name, which is used as the tag for RFC 3164."
+						required:    false
+						type: string: {
+							default: "appname"
+							syntax:  "literal"
+						}
+					}
+					facility_field: {
+						description: """
+							The field holding the facility, either as a code or as a name such as `local0`.
+
+							Events without a valid facility are sent with the `user` facility.
+							"""
+						required: false
+						type: string: {
+							default: "facility"
+							syntax:  "literal"
+						}
+					}
+					hostname_field: {
+						description: "The field holding the hostname."
+						required:    false
+						type: string: {
+							default: "hostname"
+							syntax:  "literal"
+						}
+					}
+					msgid_field: {
+						description: "The field holding the message ID, which is only encoded for RFC 5424."
+						required:    false
+						type: string: {
+							default: "msgid"
+							syntax:  "literal"
+						}
+					}
+					procid_field: {
+						description: "The field holding the process ID."
+						required:    false
+						type: string: {
+							default: "procid"
+							syntax:  "literal"
+						}
+					}
+					rfc: {
+						description: "The syslog protocol to encode messages with."
+						required:    false
+						type: string: {
+							default: "rfc5424"
+							enum: {
+								rfc3164: """
+									The BSD syslog protocol, as described in [RFC 3164][rfc3164].
+
+									[rfc3164]: https://datatracker.ietf.org/doc/html/rfc3164
+									"""
+								rfc5424: """
+									The syslog protocol, as described in [RFC 5424][rfc5424].
+
+									[rfc5424]: https://datatracker.ietf.org/doc/html/rfc5424
+									"""
+							}
+						}
+					}
+					severity_field: {
+						description: """
+							The field holding the severity, either as a code or as a name such as `warning`.
+
+							Events without a valid severity are sent with the `info` severity.
+							"""
+						required: false
+						type: string: {
+							default: "severity"
+							syntax:  "literal"
+						}
+					}
+					structured_data_field: {
+						description: """
+							The field holding the structured data, which is only encoded for RFC 5424.
+
+							The field must be an object mapping each SD-ID, such as `origin` or `exampleSDID@32473`, to
+							an object of its parameters.
+							"""
+						required: false
+						type: string: {
+							default: "structured_data"
+							syntax:  "literal"
+						}
+					}
+				}
+			}
 			timestamp_format: {
 				description: "Format used for timestamp fields."
 				required:    false
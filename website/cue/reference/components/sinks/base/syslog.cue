@@ -0,0 +1,334 @@
+package metadata
+
+base: components: sinks: syslog: configuration: {
+	acknowledgements: {
+		description: """
+			Controls how acknowledgements are handled for this sink.
+
+			See [End-to-end Acknowledgements][e2e_acks] for more information on how Vector handles event acknowledgement.
+
+			[e2e_acks]: https://vector.dev/docs/about/under-the-hood/architecture/end-to-end-acknowledgements/
+			"""
+		required: false
+		type: object: options: enabled: {
+			description: """
+				Whether or not end-to-end acknowledgements are enabled.
+
+				When enabled for a sink, any source connected to that sink, where the source supports
+				end-to-end acknowledgements as well, will wait for events to be acknowledged by the sink
+				before acknowledging them at the source.
+
+				Enabling or disabling acknowledgements at the sink level takes precedence over any global
+				[`acknowledgements`][global_acks] configuration.
+
+				[global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
+				"""
+			required: false
+			type: bool: {}
+		}
+	}
+	address: {
+		description: """
+			The address to connect to.
+
+			The address _must_ include a port.
+			"""
+		relevant_when: "mode = \"tcp\" or mode = \"udp\""
+		required:      true
+		type: string: syntax: "literal"
+	}
+	app_name: {
+		description: """
+			The application name to send events with.
+
+			This can be a template, and takes precedence over the field set by `appname_field`.
+			"""
+		required: false
+		type: string: syntax: "template"
+	}
+	appname_field: {
+		description: "The field holding the application name, which is used as the tag for RFC 3164."
+		required:    false
+		type: string: {
+			default: "appname"
+			syntax:  "literal"
+		}
+	}
+	encoding: {
+		description: "Transformations to prepare an event for serialization."
+		required:    false
+		type: object: options: {
+			except_fields: {
+				description: "List of fields that will be excluded from the encoded event."
+				required:    false
+				type: array: items: type: string: syntax: "literal"
+			}
+			only_fields: {
+				description: "List of fields that will be included in the encoded event."
+				required:    false
+				type: array: items: type: string: syntax: "literal"
+			}
+			timestamp_format: {
+				description: "Format used for timestamp fields."
+				required:    false
+				type: string: enum: {
+					rfc3339: "Represent the timestamp as a RFC 3339 timestamp."
+					unix:    "Represent the timestamp as a Unix timestamp."
+				}
+			}
+		}
+	}
+	facility: {
+		description: """
+			The facility to send events with, either as a code or as a name such as `local0`.
+
+			This can be a template, and takes precedence over the field set by `facility_field`.
+			"""
+		required: false
+		type: string: syntax: "template"
+	}
+	facility_field: {
+		description: """
+			The field holding the facility, either as a code or as a name such as `local0`.
+
+			Events without a valid facility are sent with the `user` facility.
+			"""
+		required: false
+		type: string: {
+			default: "facility"
+			syntax:  "literal"
+		}
+	}
+	framing: {
+		description: """
+			The framing used to delimit messages sent over a stream.
+
+			Messages sent over UDP are never framed, as each datagram holds a single message.
+			"""
+		relevant_when: "mode = \"tcp\" or mode = \"unix\""
+		required:      false
+		type: string: {
+			default: "octet_counting"
+			enum: {
+				newline_delimited: "Each message is followed by a newline."
+				octet_counting: """
+					Each message is prefixed with its length, as described in [RFC 6587][rfc6587].
+
+					[rfc6587]: https://datatracker.ietf.org/doc/html/rfc6587#section-3.4.1
+					"""
+			}
+		}
+	}
+	hostname_field: {
+		description: "The field holding the hostname."
+		required:    false
+		type: string: {
+			default: "hostname"
+			syntax:  "literal"
+		}
+	}
+	keepalive: {
+		description:   "TCP keepalive settings for socket-based components."
+		relevant_when: "mode = \"tcp\""
+		required:      false
+		type: object: options: time_secs: {
+			description: "The time to wait, in seconds, before starting to send TCP keepalive probes on an idle connection."
+			required:    false
+			type: uint: {}
+		}
+	}
+	mode: {
+		description: "Connection mode for the `syslog` sink."
+		required:    true
+		type: string: enum: {
+			tcp:  "Send over TCP."
+			udp:  "Send over UDP."
+			unix: "Send over UDS. (Unix domain socket)"
+		}
+	}
+	msgid_field: {
+		description: "The field holding the message ID, which is only encoded for RFC 5424."
+		required:    false
+		type: string: {
+			default: "msgid"
+			syntax:  "literal"
+		}
+	}
+	path: {
+		description: """
+			The Unix socket path.
+
+			This should be an absolute path.
+			"""
+		relevant_when: "mode = \"unix\""
+		required:      true
+		type: string: syntax: "literal"
+	}
+	procid_field: {
+		description: "The field holding the process ID."
+		required:    false
+		type: string: {
+			default: "procid"
+			syntax:  "literal"
+		}
+	}
+	rfc: {
+		description: "The syslog protocol to encode messages with."
+		required:    false
+		type: string: {
+			default: "rfc5424"
+			enum: {
+				rfc3164: """
+					The BSD syslog protocol, as described in [RFC 3164][rfc3164].
+
+					[rfc3164]: https://datatracker.ietf.org/doc/html/rfc3164
+					"""
+				rfc5424: """
+					The syslog protocol, as described in [RFC 5424][rfc5424].
+
+					[rfc5424]: https://datatracker.ietf.org/doc/html/rfc5424
+					"""
+			}
+		}
+	}
+	send_buffer_bytes: {
+		description: """
+			The size, in bytes, of the socket's send buffer.
+
+			If set, the value of the setting is passed via the `SO_SNDBUF` option.
+			"""
+		relevant_when: "mode = \"tcp\" or mode = \"udp\""
+		required:      false
+		type: uint: {}
+	}
+	severity: {
+		description: """
+			The severity to send events with, either as a code or as a name such as `warning`.
+
+			This can be a template, and takes precedence over the field set by `severity_field`.
+			"""
+		required: false
+		type: string: syntax: "template"
+	}
+	severity_field: {
+		description: """
+			The field holding the severity, either as a code or as a name such as `warning`.
+
+			Events without a valid severity are sent with the `info` severity.
+			"""
+		required: false
+		type: string: {
+			default: "severity"
+			syntax:  "literal"
+		}
+	}
+	structured_data_field: {
+		description: """
+			The field holding the structured data, which is only encoded for RFC 5424.
+
+			The field must be an object mapping each SD-ID, such as `origin` or `exampleSDID@32473`, to
+			an object of its parameters.
+			"""
+		required: false
+		type: string: {
+			default: "structured_data"
+			syntax:  "literal"
+		}
+	}
+	tls: {
+		description:   "Configures the TLS options for incoming/outgoing connections."
+		relevant_when: "mode = \"tcp\""
+		required:      false
+		type: object: options: {
+			alpn_protocols: {
+				description: """
+					Sets the list of supported ALPN protocols.
+
+					Declare the supported ALPN protocols, which are used during negotiation with peer. Prioritized in the order
+					they are defined.
+					"""
+				required: false
+				type: array: items: type: string: syntax: "literal"
+			}
+			ca_file: {
+				description: """
+					Absolute path to an additional CA certificate file.
+
+					The certficate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+			crt_file: {
+				description: """
+					Absolute path to a certificate file used to identify this server.
+
+					The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+					an inline string in PEM format.
+
+					If this is set, and is not a PKCS#12 archive, `key_file` must also be set.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+			enabled: {
+				description: """
+					Whether or not to require TLS for incoming/outgoing connections.
+
+					When enabled and used for incoming connections, an identity certificate is also required. See `tls.crt_file` for
+					more information.
+					"""
+				required: false
+				type: bool: {}
+			}
+			key_file: {
+				description: """
+					Absolute path to a private key file used to identify this server.
+
+					The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+			key_pass: {
+				description: """
+					Passphrase used to unlock the encrypted key file.
+
+					This has no effect unless `key_file` is set.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+			verify_certificate: {
+				description: """
+					Enables certificate verification.
+
+					If enabled, certificates must be valid in terms of not being expired, as well as being issued by a trusted
+					issuer. This verification operates in a hierarchical manner, checking that not only the leaf certificate (the
+					certificate presented by the client/server) is valid, but also that the issuer of that certificate is valid, and
+					so on until reaching a root certificate.
+
+					Relevant for both incoming and outgoing connections.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+					"""
+				required: false
+				type: bool: {}
+			}
+			verify_hostname: {
+				description: """
+					Enables hostname verification.
+
+					If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+					the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+					Only relevant for outgoing connections.
+
+					Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+					"""
+				required: false
+				type: bool: {}
+			}
+		}
+	}
+}
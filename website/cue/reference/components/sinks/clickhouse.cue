@@ -25,7 +25,7 @@ components: sinks: clickhouse: {
 			compression: {
 				enabled: true
 				default: "gzip"
-				algorithms: ["none", "gzip"]
+				algorithms: ["none", "gzip", "zstd", "snappy"]
 				levels: ["none", "fast", "default", "best", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
 			}
 			encoding: {
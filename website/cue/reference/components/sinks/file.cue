@@ -20,7 +20,7 @@ components: sinks: file: {
 			compression: {
 				enabled: true
 				default: "none"
-				algorithms: ["none", "gzip", "zstd"]
+				algorithms: ["none", "gzip", "snappy", "zstd"]
 				levels: ["none", "fast", "default", "best", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
 			}
 			encoding: {
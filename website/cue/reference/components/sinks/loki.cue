@@ -26,7 +26,7 @@ components: sinks: loki: {
 			compression: {
 				enabled: true
 				default: "snappy"
-				algorithms: ["none", "gzip", "snappy"]
+				algorithms: ["none", "gzip", "snappy", "zstd"]
 				levels: ["none", "fast", "default", "best", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
 			}
 			encoding: {
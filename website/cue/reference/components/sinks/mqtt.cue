@@ -0,0 +1,86 @@
+package metadata
+
+components: sinks: mqtt: {
+	title: "MQTT"
+
+	classes: {
+		commonly_used: false
+		delivery:      "best_effort"
+		development:   "beta"
+		egress_method: "stream"
+		service_providers: []
+		stateful: false
+	}
+
+	features: {
+		acknowledgements: true
+		healthcheck: enabled: false
+		send: {
+			compression: enabled: false
+			encoding: {
+				enabled: true
+				codec: {
+					enabled: true
+					enum: ["json", "text"]
+				}
+			}
+			request: enabled: false
+			tls: {
+				enabled:                true
+				can_verify_certificate: false
+				can_verify_hostname:    false
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+			to: components._mqtt.features.send.to
+		}
+	}
+
+	support: components._mqtt.support
+
+	configuration: components._mqtt.configuration & {
+		topic: {
+			description: "The topic to publish messages to."
+			required:    true
+			type: string: {
+				examples: ["vector", "logs/{{ host }}"]
+				syntax: "template"
+			}
+		}
+		retain: {
+			common:      false
+			description: "Whether the broker should retain the last message published to each topic, and deliver it to new subscribers."
+			required:    false
+			type: bool: default: false
+		}
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: components._mqtt.how_it_works & {
+		acknowledgements: {
+			title: "Acknowledgements"
+			body:  """
+				Events are acknowledged once the broker acknowledges the message they were published
+				in, which is a `PUBACK` for `at_least_once` and a `PUBCOMP` for `exactly_once`. The
+				broker doesn't acknowledge messages published with `at_most_once`, so their events are
+				acknowledged as soon as the message is written to the connection.
+
+				When its input ends, the sink waits up to 30 seconds for the broker to acknowledge the
+				pending messages. The events of messages that still aren't acknowledged then, or once
+				the connection is closed, are marked as errored.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		component_errors_total:           components.sources.internal_metrics.output.metrics.component_errors_total
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+	}
+}
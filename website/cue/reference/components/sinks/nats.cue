@@ -52,7 +52,17 @@ components: sinks: nats: {
 		notices: []
 	}
 
-	configuration: components._nats.configuration & {}
+	configuration: components._nats.configuration & {
+		jetstream: {
+			common:      false
+			description: """
+				Publish messages to JetStream, waiting for the server to acknowledge that each message was
+				stored by the stream bound to the subject.
+				"""
+			required: false
+			type: bool: default: false
+		}
+	}
 
 	input: {
 		logs:    true
@@ -0,0 +1,139 @@
+package metadata
+
+components: sinks: opentelemetry: {
+	_grpc_port: 4317
+	_http_port: 4318
+
+	title: "OpenTelemetry"
+
+	description: """
+		Sends logs, metrics and traces to an OpenTelemetry collector, or any other service that
+		accepts the OpenTelemetry Protocol (OTLP), over gRPC or HTTP.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: []
+		stateful: false
+	}
+	features: {
+		acknowledgements: true
+		healthcheck: enabled: false
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    10_000_000
+				timeout_secs: 1.0
+			}
+			compression: enabled: false
+			encoding: enabled:    false
+			request: {
+				enabled: true
+				headers: false
+			}
+
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+			to: {
+				service: services.opentelemetry
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			summary:      true
+			set:          true
+		}
+		traces: true
+	}
+
+	configuration: {
+		endpoint: {
+			description: """
+				The endpoint to send OTLP requests to.
+
+				With the `grpc` protocol, this is the address of the gRPC server. With the `http`
+				protocol, this is the base URL to which `/v1/logs`, `/v1/metrics` and `/v1/traces`
+				are appended.
+				"""
+			required: true
+			type: string: {
+				examples: ["http://127.0.0.1:\(_grpc_port)", "https://otel-collector.example.com:\(_http_port)"]
+			}
+		}
+		protocol: {
+			description: "The protocol used to send OTLP requests."
+			common:      true
+			required:    false
+			type: string: {
+				default: "grpc"
+				enum: {
+					grpc: "OTLP over gRPC."
+					http: "OTLP over HTTP, with Protobuf-encoded request bodies."
+				}
+			}
+		}
+		compression: {
+			description: "Compress requests with gzip."
+			common:      true
+			required:    false
+			type: bool: default: false
+		}
+	}
+
+	how_it_works: {
+		event_mapping: {
+			title: "Event mapping"
+			body: """
+				Events are mapped back into OTLP the same way the `opentelemetry` source maps OTLP
+				into events, so events received by that source are sent on unchanged.
+
+				Logs and traces are grouped by their `resources` and `scope` fields, which become the
+				resource and instrumentation scope of the exported records. For logs, the `message`
+				field becomes the body, and any fields not part of the OTLP log data model are added
+				to the record's attributes.
+
+				Metric tags prefixed with `resource.` become resource attributes, and all other tags
+				become data point attributes. Counters are sent as monotonic sums, gauges as gauges,
+				sets as a gauge of the number of values, aggregated histograms as histograms and
+				aggregated summaries as summaries. Distributions and sketches are sent as histograms
+				with a bucket for each bin of their sketch, distributions being sketched first.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+	}
+}
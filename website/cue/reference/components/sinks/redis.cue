@@ -73,13 +73,14 @@ components: sinks: redis: {
 		}
 		data_type: {
 			common:      false
-			description: "The Redis data type (`list` or `channel`) to use."
+			description: "The Redis data type (`list`, `channel`, or `stream`) to use."
 			required:    false
 			type: string: {
 				default: "list"
 				enum: {
 					list:    "Use the Redis `list` data type."
 					channel: "Use the Redis `channel` data type."
+					stream:  "Use the Redis `stream` data type."
 				}
 			}
 		}
@@ -105,6 +106,38 @@ components: sinks: redis: {
 				}
 			}
 		}
+		stream: {
+			common:      false
+			description: "Options for the Redis `stream` data type."
+			required:    false
+			type: object: {
+				examples: []
+				options: {
+					field: {
+						common:      false
+						description: "The field of stream entries to store messages in."
+						required:    false
+						type: string: default: "message"
+					}
+					max_len: {
+						common:      false
+						description: "The maximum length to trim the stream to when adding entries, with `XADD`'s `MAXLEN` option. By default, the stream is not trimmed."
+						required:    false
+						type: uint: {
+							default: null
+							examples: [10000]
+							unit: "events"
+						}
+					}
+					approximate: {
+						common:      false
+						description: "Whether the stream can be trimmed to about `max_len` entries, rather than exactly, which is much more efficient."
+						required:    false
+						type: bool: default: true
+					}
+				}
+			}
+		}
 	}
 
 	input: {
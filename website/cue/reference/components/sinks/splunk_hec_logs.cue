@@ -26,7 +26,7 @@ components: sinks: splunk_hec_logs: {
 			compression: {
 				enabled: true
 				default: "none"
-				algorithms: ["gzip"]
+				algorithms: ["gzip", "zstd", "snappy"]
 				levels: ["none", "fast", "default", "best", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
 			}
 			encoding: {
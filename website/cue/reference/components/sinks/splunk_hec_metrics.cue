@@ -25,7 +25,7 @@ components: sinks: splunk_hec_metrics: {
 			compression: {
 				enabled: true
 				default: "none"
-				algorithms: ["gzip"]
+				algorithms: ["gzip", "zstd", "snappy"]
 				levels: ["none", "fast", "default", "best", 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
 			}
 			encoding: enabled: false
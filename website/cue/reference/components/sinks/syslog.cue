@@ -0,0 +1,179 @@
+package metadata
+
+components: sinks: syslog: {
+	title: "Syslog"
+
+	classes: {
+		commonly_used: false
+		delivery:      "best_effort"
+		development:   "beta"
+		egress_method: "stream"
+		service_providers: []
+		stateful: false
+	}
+
+	features: {
+		acknowledgements: true
+		healthcheck: enabled: true
+		send: {
+			compression: enabled: false
+			encoding: enabled:    false
+			send_buffer_bytes: {
+				enabled:       true
+				relevant_when: "mode = `tcp` or mode = `udp`"
+			}
+			keepalive: enabled: true
+			request: enabled:   false
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+				enabled_by_scheme:      false
+			}
+			to: {
+				service: services.syslog
+
+				interface: {
+					socket: {
+						api: {
+							title: "Syslog"
+							url:   urls.syslog
+						}
+						direction: "outgoing"
+						protocols: ["tcp", "udp", "unix"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: {
+		address: {
+			description:   "The address to connect to. The address _must_ include a port."
+			relevant_when: "mode = `tcp` or `udp`"
+			required:      true
+			type: string: {
+				examples: ["92.12.333.224:514"]
+			}
+		}
+		app_name: {
+			description: "The application name to send events with. Takes precedence over the field set by `appname_field`."
+			common:      false
+			required:    false
+			type: string: {
+				default: null
+				examples: ["vector", "{{ service }}"]
+				syntax: "template"
+			}
+		}
+		facility: {
+			description: "The facility to send events with, either as a code or as a name such as `local0`. Takes precedence over the field set by `facility_field`."
+			common:      true
+			required:    false
+			type: string: {
+				default: null
+				examples: ["local0", "{{ facility }}"]
+				syntax: "template"
+			}
+		}
+		framing: {
+			description:   "The framing used to delimit messages sent over a stream."
+			relevant_when: "mode = `tcp` or `unix`"
+			common:        true
+			required:      false
+			type: string: {
+				default: "octet_counting"
+				enum: {
+					newline_delimited: "Each message is followed by a newline."
+					octet_counting:    "Each message is prefixed with its length, as described in [RFC 6587](\(urls.syslog_6587))."
+				}
+			}
+		}
+		mode: {
+			description: "The type of socket to use."
+			required:    true
+			type: string: {
+				enum: {
+					tcp:  "TCP socket"
+					udp:  "UDP socket"
+					unix: "Unix domain socket"
+				}
+			}
+		}
+		path: {
+			description:   "The unix socket path. This should be the absolute path."
+			relevant_when: "mode = `unix`"
+			required:      true
+			type: string: {
+				examples: ["/path/to/socket"]
+			}
+		}
+		rfc: {
+			description: "The syslog protocol to encode messages with."
+			common:      true
+			required:    false
+			type: string: {
+				default: "rfc5424"
+				enum: {
+					rfc3164: "The BSD syslog protocol, as described in [RFC 3164](\(urls.syslog_3164))."
+					rfc5424: "The syslog protocol, as described in [RFC 5424](\(urls.syslog_5424))."
+				}
+			}
+		}
+		severity: {
+			description: "The severity to send events with, either as a code or as a name such as `warning`. Takes precedence over the field set by `severity_field`."
+			common:      true
+			required:    false
+			type: string: {
+				default: null
+				examples: ["warning", "{{ level }}"]
+				syntax: "template"
+			}
+		}
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		header: {
+			title: "Message header"
+			body: """
+				The syslog header is built from the event, using the same fields that the `syslog`
+				source produces, so events received by that source are sent on with their original
+				header. The `*_field` options change which fields are used, and the `facility`,
+				`severity` and `app_name` templates take precedence over the fields when set.
+
+				Events without a valid facility or severity are sent with the `user` facility and the
+				`info` severity. The timestamp and message are taken from the fields with the
+				`timestamp` and `message` meanings.
+				"""
+		}
+		framing: {
+			title: "Framing"
+			body: """
+				Over TCP and Unix domain sockets, messages are framed using octet counting as
+				described in [RFC 6587](\(urls.syslog_6587)) by default, which allows messages to contain
+				newlines. Set `framing` to `newline_delimited` for receivers that expect one message
+				per line. Messages sent over UDP are never framed.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		connection_errors_total: components.sources.internal_metrics.output.metrics.connection_errors_total
+		processed_bytes_total:   components.sources.internal_metrics.output.metrics.processed_bytes_total
+		processed_events_total:  components.sources.internal_metrics.output.metrics.processed_events_total
+	}
+}
@@ -130,17 +130,91 @@ base: components: sources: amqp: configuration: {
 	decoding: {
 		description: "Configuration for building a `Deserializer`."
 		required:    false
-		type: object: options: codec: {
-			required: false
-			type: string: {
-				default: "bytes"
-				enum: {
-					bytes:       "Configures the `BytesDeserializer`."
-					gelf:        "Configures the `GelfDeserializer`."
-					json:        "Configures the `JsonDeserializer`."
-					native:      "Configures the `NativeDeserializer`."
-					native_json: "Configures the `NativeJsonDeserializer`."
-					syslog:      "Configures the `SyslogDeserializer`."
+		type: object: options: {
+			avro: {
+				description:   "Apache Avro deserializer options."
+				relevant_when: "codec = \"avro\""
+				required:      true
+				type: object: options: {
+					schema: {
+						description: """
+							The Avro schema, as a JSON string.
+
+							Exactly one of `schema` or `schema_file` must be set.
+							"""
+						required: false
+						type: string: syntax: "literal"
+					}
+					schema_file: {
+						description: """
+							Path to a file containing the Avro schema, as JSON.
+
+							Exactly one of `schema` or `schema_file` must be set.
+							"""
+						required: false
+						type: string: syntax: "literal"
+					}
+					single_object_encoding: {
+						description: """
+							Whether each message uses the Avro single-object encoding.
+
+							When enabled, the leading `C3 01` marker and 8-byte schema fingerprint are validated and
+							stripped before the remaining bytes are decoded with the configured schema. The fingerprint
+							must be the CRC-64-AVRO fingerprint of the configured schema.
+
+							Only one of `strip_schema_id_prefix` or `single_object_encoding` can be set.
+							"""
+						required: false
+						type: bool: default: false
+					}
+					strip_schema_id_prefix: {
+						description: """
+							Whether each message is prefixed with the Confluent Schema Registry wire format header.
+
+							When enabled, the leading magic byte and 4-byte schema ID are validated and stripped
+							before the remaining bytes are decoded with the configured schema. The schema ID itself
+							isn't used to look up a schema.
+							"""
+						required: false
+						type: bool: default: false
+					}
+				}
+			}
+			codec: {
+				required: false
+				type: string: {
+					default: "bytes"
+					enum: {
+						avro:        "Configures the `AvroDeserializer`."
+						bytes:       "Configures the `BytesDeserializer`."
+						gelf:        "Configures the `GelfDeserializer`."
+						json:        "Configures the `JsonDeserializer`."
+						native:      "Configures the `NativeDeserializer`."
+						native_json: "Configures the `NativeJsonDeserializer`."
+						protobuf:    "Configures the `ProtobufDeserializer`."
+						syslog:      "Configures the `SyslogDeserializer`."
+					}
+				}
+			}
+			protobuf: {
+				description:   "Protobuf-specific decoding options."
+				relevant_when: "codec = \"protobuf\""
+				required:      true
+				type: object: options: {
+					desc_file: {
+						description: """
+							Path to a compiled `FileDescriptorSet` containing the message type.
+
+							The file can be generated with `protoc --include_imports --descriptor_set_out=<file>`.
+							"""
+						required: true
+						type: string: syntax: "literal"
+					}
+					message_type: {
+						description: "The fully qualified name of the message type to decode each frame as, e.g. `package.Message`."
+						required:    true
+						type: string: syntax: "literal"
+					}
 				}
 			}
 		}
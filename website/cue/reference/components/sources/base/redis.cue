@@ -1,8 +1,26 @@
 package metadata
 
 base: components: sources: redis: configuration: {
+	acknowledgements: {
+		description: """
+			Controls how acknowledgements are handled by this source.
+
+			This setting is **deprecated** in favor of enabling `acknowledgements` at the [global][global_acks] or sink level. Enabling or disabling acknowledgements at the source level has **no effect** on acknowledgement behavior.
+
+			See [End-to-end Acknowledgements][e2e_acks] for more information on how Vector handles event acknowledgement.
+
+			[global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
+			[e2e_acks]: https://vector.dev/docs/about/under-the-hood/architecture/end-to-end-acknowledgements/
+			"""
+		required: false
+		type: object: options: enabled: {
+			description: "Whether or not end-to-end acknowledgements are enabled for this source."
+			required:    false
+			type: bool: {}
+		}
+	}
 	data_type: {
-		description: "The Redis data type (`list` or `channel`) to use."
+		description: "The Redis data type (`list`, `channel`, or `stream`) to use."
 		required:    false
 		type: string: {
 			default: "list"
@@ -13,23 +31,102 @@ base: components: sources: redis: configuration: {
 					This is based on Redis' Pub/Sub capabilities.
 					"""
 				list: "The `list` data type."
+				stream: """
+					The `stream` data type.
+
+					Entries are read as a member of a consumer group, and acknowledged once processed.
+					"""
 			}
 		}
 	}
 	decoding: {
 		description: "Configuration for building a `Deserializer`."
 		required:    false
-		type: object: options: codec: {
-			required: false
-			type: string: {
-				default: "bytes"
-				enum: {
-					bytes:       "Configures the `BytesDeserializer`."
-					gelf:        "Configures the `GelfDeserializer`."
-					json:        "Configures the `JsonDeserializer`."
-					native:      "Configures the `NativeDeserializer`."
-					native_json: "Configures the `NativeJsonDeserializer`."
-					syslog:      "Configures the `SyslogDeserializer`."
+		type: object: options: {
+			avro: {
+				description:   "Apache Avro deserializer options."
+				relevant_when: "codec = \"avro\""
+				required:      true
+				type: object: options: {
+					schema: {
+						description: """
+							The Avro schema, as a JSON string.
+
+							Exactly one of `schema` or `schema_file` must be set.
+							"""
+						required: false
+						type: string: syntax: "literal"
+					}
+					schema_file: {
+						description: """
+							Path to a file containing the Avro schema, as JSON.
+
+							Exactly one of `schema` or `schema_file` must be set.
+							"""
+						required: false
+						type: string: syntax: "literal"
+					}
+					single_object_encoding: {
+						description: """
+							Whether each message uses the Avro single-object encoding.
+
+							When enabled, the leading `C3 01` marker and 8-byte schema fingerprint are validated and
+							stripped before the remaining bytes are decoded with the configured schema. The fingerprint
+							must be the CRC-64-AVRO fingerprint of the configured schema.
+
+							Only one of `strip_schema_id_prefix` or `single_object_encoding` can be set.
+							"""
+						required: false
+						type: bool: default: false
+					}
+					strip_schema_id_prefix: {
+						description: """
+							Whether each message is prefixed with the Confluent Schema Registry wire format header.
+
+							When enabled, the leading magic byte and 4-byte schema ID are validated and stripped
+							before the remaining bytes are decoded with the configured schema. The schema ID itself
+							isn't used to look up a schema.
+							"""
+						required: false
+						type: bool: default: false
+					}
+				}
+			}
+			codec: {
+				required: false
+				type: string: {
+					default: "bytes"
+					enum: {
+						avro:        "Configures the `AvroDeserializer`."
+						bytes:       "Configures the `BytesDeserializer`."
+						gelf:        "Configures the `GelfDeserializer`."
+						json:        "Configures the `JsonDeserializer`."
+						native:      "Configures the `NativeDeserializer`."
+						native_json: "Configures the `NativeJsonDeserializer`."
+						protobuf:    "Configures the `ProtobufDeserializer`."
+						syslog:      "Configures the `SyslogDeserializer`."
+					}
+				}
+			}
+			protobuf: {
+				description:   "Protobuf-specific decoding options."
+				relevant_when: "codec = \"protobuf\""
+				required:      true
+				type: object: options: {
+					desc_file: {
+						description: """
+							Path to a compiled `FileDescriptorSet` containing the message type.
+
+							The file can be generated with `protoc --include_imports --descriptor_set_out=<file>`.
+							"""
+						required: true
+						type: string: syntax: "literal"
+					}
+					message_type: {
+						description: "The fully qualified name of the message type to decode each frame as, e.g. `package.Message`."
+						required:    true
+						type: string: syntax: "literal"
+					}
 				}
 			}
 		}
@@ -126,6 +223,64 @@ base: components: sources: redis: configuration: {
 		required: false
 		type: string: syntax: "literal"
 	}
+	stream: {
+		description: "Options for the Redis `stream` data type."
+		required:    false
+		type: object: options: {
+			batch_size: {
+				description: "The maximum number of entries to read from the stream at once."
+				required:    false
+				type: uint: default: 100
+			}
+			claim_idle_secs: {
+				description: """
+					The minimum time, in seconds, that entries delivered to any consumer of the group must remain
+					unacknowledged before they are claimed and read again.
+
+					This recovers the entries left pending by consumers that stopped, or failed to process them. Pending
+					entries are checked for at the same interval.
+					"""
+				required: false
+				type: uint: default: 60
+			}
+			consumer: {
+				description: """
+					The name of the consumer to read the stream as, within the consumer group.
+
+					Each instance of Vector reading the stream with the same group must use a different consumer name.
+
+					By default, the hostname is used.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+			field: {
+				description: """
+					The field of stream entries holding the message to decode.
+
+					Entries without this field are acknowledged and dropped.
+					"""
+				required: false
+				type: string: {
+					default: "message"
+					syntax:  "literal"
+				}
+			}
+			group: {
+				description: """
+					The consumer group to read the stream as.
+
+					The group is created if it doesn't exist, starting from the entries added after its creation. The
+					stream is created as well if it doesn't exist.
+					"""
+				required: false
+				type: string: {
+					default: "vector"
+					syntax:  "literal"
+				}
+			}
+		}
+	}
 	url: {
 		description: """
 			The Redis URL to connect to.
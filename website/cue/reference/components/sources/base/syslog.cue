@@ -1,9 +1,28 @@
 package metadata
 
 base: components: sources: syslog: configuration: {
+	acknowledgements: {
+		description: """
+			Controls how acknowledgements are handled by this source.
+
+			This setting is **deprecated** in favor of enabling `acknowledgements` at the [global][global_acks] or sink level. Enabling or disabling acknowledgements at the source level has **no effect** on acknowledgement behavior.
+
+			See [End-to-end Acknowledgements][e2e_acks] for more information on how Vector handles event acknowledgement.
+
+			[global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
+			[e2e_acks]: https://vector.dev/docs/about/under-the-hood/architecture/end-to-end-acknowledgements/
+			"""
+		relevant_when: "mode = \"relp\""
+		required:      false
+		type: object: options: enabled: {
+			description: "Whether or not end-to-end acknowledgements are enabled for this source."
+			required:    false
+			type: bool: {}
+		}
+	}
 	address: {
 		description:   "The address to listen for connections on."
-		relevant_when: "mode = \"tcp\" or mode = \"udp\""
+		relevant_when: "mode = \"tcp\" or mode = \"relp\" or mode = \"udp\""
 		required:      true
 		type: {
 			number: {}
@@ -12,7 +31,7 @@ base: components: sources: syslog: configuration: {
 	}
 	connection_limit: {
 		description:   "The maximum number of TCP connections that will be allowed at any given time."
-		relevant_when: "mode = \"tcp\""
+		relevant_when: "mode = \"tcp\" or mode = \"relp\""
 		required:      false
 		type: uint: {}
 	}
@@ -32,7 +51,7 @@ base: components: sources: syslog: configuration: {
 	}
 	keepalive: {
 		description:   "TCP keepalive settings for socket-based components."
-		relevant_when: "mode = \"tcp\""
+		relevant_when: "mode = \"tcp\" or mode = \"relp\""
 		required:      false
 		type: object: options: time_secs: {
 			description: "The time to wait, in seconds, before starting to send TCP keepalive probes on an idle connection."
@@ -52,6 +71,12 @@ base: components: sources: syslog: configuration: {
 	mode: {
 		required: true
 		type: string: enum: {
+			relp: """
+				Listen on TCP for RELP (Reliable Event Logging Protocol) sessions.
+
+				Each message is acknowledged to the client once it has been accepted, which means once it
+				has been delivered to all connected sinks when acknowledgements are enabled.
+				"""
 			tcp:  "Listen on TCP."
 			udp:  "Listen on UDP."
 			unix: "Listen on UDS. (Unix domain socket)"
@@ -73,7 +98,7 @@ base: components: sources: syslog: configuration: {
 
 			This should not typically needed to be changed.
 			"""
-		relevant_when: "mode = \"tcp\" or mode = \"udp\""
+		relevant_when: "mode = \"tcp\" or mode = \"relp\" or mode = \"udp\""
 		required:      false
 		type: uint: {}
 	}
@@ -90,7 +115,7 @@ base: components: sources: syslog: configuration: {
 	}
 	tls: {
 		description:   "TlsEnableableConfig for `sources`, adding metadata from the client certificate"
-		relevant_when: "mode = \"tcp\""
+		relevant_when: "mode = \"tcp\" or mode = \"relp\""
 		required:      false
 		type: object: options: {
 			alpn_protocols: {
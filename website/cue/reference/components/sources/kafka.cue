@@ -181,6 +181,16 @@ components: sources: kafka: {
 			}
 		}
 		socket_timeout_ms: components._kafka.configuration.socket_timeout_ms
+		sync_commit_on_rebalance: {
+			common:      false
+			description: """
+				Whether to synchronously commit the offsets of the partitions revoked by a consumer group
+				rebalance, before they are assigned to another consumer. Otherwise, the consumer that is
+				assigned a revoked partition reads again the messages processed since the last commit.
+				"""
+			required: false
+			type: bool: default: false
+		}
 		topics: {
 			description: "The Kafka topics names to read events from. Regex is supported if the topic begins with `^`."
 			required:    true
@@ -258,5 +268,26 @@ components: sources: kafka: {
 		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
 	}
 
-	how_it_works: components._kafka.how_it_works
+	how_it_works: components._kafka.how_it_works & {
+		backpressure: {
+			title: "Backpressure"
+			body: """
+				When the events of a message can't be sent downstream within 100 milliseconds, the
+				source pauses fetching from its assigned partitions. They stay paused until at least half
+				of the source's output buffer has been drained downstream, and only the partitions still
+				assigned to the consumer are then resumed. Each partition is resumed from the last message
+				read from it, so no messages are skipped or read twice.
+				"""
+		}
+		rebalancing: {
+			title: "Rebalancing"
+			body: """
+				Offsets of acknowledged messages are committed every `commit_interval_ms`. When a consumer
+				group rebalance revokes partitions from this consumer, the consumer that they are assigned
+				to next resumes from the last committed offsets, and so reads again any messages
+				acknowledged since then. Set `sync_commit_on_rebalance` to `true` to commit the offsets
+				before the partitions are revoked.
+				"""
+		}
+	}
 }
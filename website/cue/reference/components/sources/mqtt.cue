@@ -0,0 +1,94 @@
+package metadata
+
+components: sources: mqtt: {
+	title: "MQTT"
+
+	features: {
+		acknowledgements: false
+		collect: {
+			checkpoint: enabled: false
+			from: components._mqtt.features.collect.from
+		}
+		multiline: enabled: false
+		codecs: {
+			enabled:         true
+			default_framing: "bytes"
+		}
+	}
+
+	classes: {
+		commonly_used: false
+		deployment_roles: ["aggregator"]
+		delivery:      "best_effort"
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	support: components._mqtt.support
+
+	installation: {
+		platform_name: null
+	}
+
+	configuration: components._mqtt.configuration & {
+		topics: {
+			description: "The topics to subscribe to. Topic filters can contain the single-level wildcard `+` and the multi-level wildcard `#`."
+			required:    true
+			type: array: items: type: string: {
+				examples: ["sensors/+/temperature", "logs/#"]
+			}
+		}
+		clean_session: {
+			common:      false
+			description: "Whether to start a clean session when connecting to the broker. When disabled, the broker keeps the subscriptions, and queues messages at a QoS above `at_most_once`, while Vector is disconnected. This requires `client_id` to be set."
+			required:    false
+			type: bool: default: true
+		}
+		topic_key: {
+			common:      false
+			description: "The log field name to use for the topic a message was published to."
+			required:    false
+			type: string: {
+				default: "topic"
+			}
+		}
+	}
+
+	output: logs: record: {
+		description: "An individual MQTT message."
+		fields: {
+			message: {
+				description: "The raw line from the MQTT message."
+				required:    true
+				type: string: {
+					examples: ["53.126.150.246 - - [01/Oct/2020:11:25:58 -0400] \"GET /disintermediate HTTP/2.0\" 401 20308"]
+				}
+			}
+			source_type: {
+				description: "The name of the source type."
+				required:    true
+				type: string: {
+					examples: ["mqtt"]
+				}
+			}
+			timestamp: fields._current_timestamp
+			topic: {
+				description: "The topic the message was published to."
+				required:    true
+				type: string: {
+					examples: ["sensors/kitchen/temperature"]
+				}
+			}
+		}
+	}
+
+	telemetry: metrics: {
+		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
+		component_received_bytes_total:       components.sources.internal_metrics.output.metrics.component_received_bytes_total
+		component_received_events_total:      components.sources.internal_metrics.output.metrics.component_received_events_total
+		component_received_event_bytes_total: components.sources.internal_metrics.output.metrics.component_received_event_bytes_total
+	}
+
+	how_it_works: components._mqtt.how_it_works
+}
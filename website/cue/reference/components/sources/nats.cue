@@ -4,7 +4,7 @@ components: sources: nats: {
 	title: "NATS"
 
 	features: {
-		acknowledgements: false
+		acknowledgements: true
 		collect: {
 			checkpoint: enabled: false
 			from: components._nats.features.collect.from
@@ -41,6 +41,52 @@ components: sources: nats: {
 				examples: ["foo", "API Name Option Example"]
 			}
 		}
+		jetstream: {
+			common:      false
+			description: """
+				Consume messages from a JetStream stream through a durable consumer, instead of
+				subscribing to `subject` with core NATS. Messages are acknowledged once processed, so
+				messages in flight are redelivered if Vector stops before then.
+				"""
+			required: false
+			type: object: options: {
+				batch_size: {
+					common:      false
+					description: "The maximum number of messages fetched by each request of a `pull` consumer."
+					required:    false
+					type: uint: {
+						default: 100
+						unit:    "events"
+					}
+				}
+				consumer: {
+					common:      false
+					description: "How messages are delivered by the JetStream consumer."
+					required:    false
+					type: string: {
+						default: "push"
+						enum: {
+							pull: "Vector fetches messages from the server in batches."
+							push: "The server pushes messages to Vector as they become available."
+						}
+					}
+				}
+				durable_name: {
+					description: "The name of the durable consumer, which is created if it doesn't exist."
+					required:    true
+					type: string: examples: ["vector"]
+				}
+				stream: {
+					common:      false
+					description: "The name of the stream to consume from. If not set, the stream is looked up from `subject`."
+					required:    false
+					type: string: {
+						default: null
+						examples: ["EVENTS"]
+					}
+				}
+			}
+		}
 	}
 
 	output: logs: record: {
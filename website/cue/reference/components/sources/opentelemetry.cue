@@ -38,11 +38,7 @@ components: sources: opentelemetry: {
 
 	support: {
 		requirements: []
-		warnings: [
-			"""
-				The `opentelemetry` source only supports log events at this time.
-				""",
-		]
+		warnings: []
 		notices: []
 	}
 
@@ -131,6 +127,18 @@ components: sources: opentelemetry: {
 				Received log events will go to this output stream. Use `<component_id>.logs` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "metrics"
+			description: """
+				Received metric events will go to this output stream. Use `<component_id>.metrics` as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "traces"
+			description: """
+				Received trace events will go to this output stream. Use `<component_id>.traces` as an input to downstream transforms and sinks.
+				"""
+		},
 	]
 
 	output: {
@@ -177,6 +185,19 @@ components: sources: opentelemetry: {
 						]
 					}
 				}
+				scope: {
+					description: "The instrumentation scope that emitted the log record, with its `name`, `version` and `attributes`. Only set when the scope is known."
+					required:    false
+					common:      false
+					type: object: {
+						examples: [
+							{
+								"name":    "io.opentelemetry.contrib.mongodb"
+								"version": "1.0.0"
+							},
+						]
+					}
+				}
 				message: {
 					description: "Contains the body of the log record."
 					required:    false
@@ -263,6 +284,158 @@ components: sources: opentelemetry: {
 				}
 			}
 		}
+		metrics: {
+			counter:   output._passthrough_counter
+			gauge:     output._passthrough_gauge
+			histogram: output._passthrough_histogram
+			summary:   output._passthrough_summary
+		}
+		traces: {
+			description: "An individual span from a batch of spans received through an OTLP request."
+			fields: {
+				trace_id: {
+					description: "The trace id, as defined in W3C Trace Context, hex-encoded."
+					required:    true
+					type: string: {
+						examples: ["66346462623365646437363566363230"]
+					}
+				}
+				span_id: {
+					description: "The span id, hex-encoded."
+					required:    true
+					type: string: {
+						examples: ["43222c2d51a7abe3"]
+					}
+				}
+				parent_span_id: {
+					description: "The span id of the parent span, hex-encoded. Unset for root spans."
+					required:    false
+					common:      true
+					type: string: {
+						default: null
+						examples: ["8b9e4bda2a55530e"]
+					}
+				}
+				trace_state: {
+					description: "The trace state, as defined in W3C Trace Context."
+					required:    false
+					common:      false
+					type: string: {
+						default: null
+						examples: ["rojo=00f067aa0ba902b7"]
+					}
+				}
+				name: {
+					description: "The name of the operation the span describes."
+					required:    true
+					type: string: {
+						examples: ["GET /order"]
+					}
+				}
+				kind: {
+					description: "The kind of span, such as `2` for a server span or `3` for a client span."
+					required:    true
+					type: uint: {
+						unit: null
+						examples: [1, 2, 3]
+					}
+				}
+				start_time: {
+					description: "The UTC Datetime when the span started."
+					required:    true
+					type: timestamp: {}
+				}
+				end_time: {
+					description: "The UTC Datetime when the span ended."
+					required:    true
+					type: timestamp: {}
+				}
+				attributes: {
+					description: "Attributes that describe the span."
+					required:    false
+					common:      true
+					type: object: {
+						examples: [
+							{
+								"http.method":      "post"
+								"http.status_code": 500
+							},
+						]
+					}
+				}
+				resources: {
+					description: "Set of attributes that describe the resource."
+					required:    false
+					common:      true
+					type: object: {
+						examples: [
+							{
+								"service.name": "donut_shop"
+							},
+						]
+					}
+				}
+				scope: {
+					description: "The instrumentation scope that emitted the span, with its `name`, `version` and `attributes`. Only set when the scope is known."
+					required:    false
+					common:      false
+					type: object: {
+						examples: [
+							{
+								"name":    "io.opentelemetry.contrib.mongodb"
+								"version": "1.0.0"
+							},
+						]
+					}
+				}
+				events: {
+					description: "The time-stamped annotations of the span, with their `name`, `timestamp`, `attributes` and `dropped_attributes_count`."
+					required:    false
+					common:      false
+					type: array: items: type: object: options: {}
+				}
+				links: {
+					description: "The links to other spans, with their `trace_id`, `span_id`, `trace_state`, `attributes` and `dropped_attributes_count`."
+					required:    false
+					common:      false
+					type: array: items: type: object: options: {}
+				}
+				status: {
+					description: "The status of the span, with its `code` and `message`."
+					required:    false
+					common:      true
+					type: object: options: {}
+				}
+				dropped_attributes_count: {
+					description: "Counts for attributes dropped due to collection limits."
+					required:    true
+					type: uint: {
+						unit: null
+					}
+				}
+				dropped_events_count: {
+					description: "Counts for events dropped due to collection limits."
+					required:    true
+					type: uint: {
+						unit: null
+					}
+				}
+				dropped_links_count: {
+					description: "Counts for links dropped due to collection limits."
+					required:    true
+					type: uint: {
+						unit: null
+					}
+				}
+				source_type: {
+					description: "The name of the source type."
+					required:    true
+					type: string: {
+						examples: ["opentelemetry"]
+					}
+				}
+			}
+		}
 	}
 
 	telemetry: metrics: {
@@ -275,6 +448,22 @@ components: sources: opentelemetry: {
 	}
 
 	how_it_works: {
+		metrics: {
+			title: "Metrics"
+			body: """
+				OTLP metrics are converted to Vector metrics as follows:
+
+				- Monotonic sums are counters, and non-monotonic sums are gauges. Sums with a delta
+				  aggregation temporality are incremental, while cumulative sums are absolute.
+				- Gauges are absolute gauges.
+				- Histograms and exponential histograms are aggregated histograms, with the same
+				  temporality mapping as sums. The bucket above the last bound is implied by the count.
+				- Summaries are absolute aggregated summaries.
+
+				The attributes of each data point become the tags of the metric, along with the
+				attributes of the resource, which are prefixed with `resource.`.
+				"""
+		}
 		tls: {
 			title: "Transport Layer Security (TLS)"
 			body:  """
@@ -4,7 +4,7 @@ components: sources: redis: {
 	title: "Redis"
 
 	features: {
-		acknowledgements: false
+		acknowledgements: true
 		collect: {
 			checkpoint: enabled: false
 			tls: enabled:        false
@@ -79,13 +79,14 @@ components: sources: redis: {
 		}
 		data_type: {
 			common:      false
-			description: "The Redis data type (`list` or `channel`) to use."
+			description: "The Redis data type (`list`, `channel`, or `stream`) to use."
 			required:    false
 			type: string: {
 				default: "list"
 				enum: {
 					list:    "Use the Redis `list` data type."
 					channel: "Use the Redis `channel` data type."
+					stream:  "Use the Redis `stream` data type, reading it as a member of a consumer group."
 				}
 				syntax: "literal"
 			}
@@ -114,6 +115,63 @@ components: sources: redis: {
 				}
 			}
 		}
+		stream: {
+			common:      false
+			description: "Options for the Redis `stream` data type."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: []
+				options: {
+					group: {
+						common:      false
+						description: "The consumer group to read the stream as. The group is created if it doesn't exist, starting from the entries added after its creation."
+						required:    false
+						type: string: {
+							default: "vector"
+							syntax:  "literal"
+						}
+					}
+					consumer: {
+						common:      false
+						description: "The name of the consumer to read the stream as, which must be unique within the consumer group. By default, the hostname is used."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["vector-0"]
+							syntax: "literal"
+						}
+					}
+					field: {
+						common:      false
+						description: "The field of stream entries holding the message to decode. Entries without this field are acknowledged and dropped."
+						required:    false
+						type: string: {
+							default: "message"
+							syntax:  "literal"
+						}
+					}
+					batch_size: {
+						common:      false
+						description: "The maximum number of entries to read from the stream at once."
+						required:    false
+						type: uint: {
+							default: 100
+							unit:    "events"
+						}
+					}
+					claim_idle_secs: {
+						common:      false
+						description: "The minimum time that entries must remain pending before they are claimed from other consumers of the group and read again."
+						required:    false
+						type: uint: {
+							default: 60
+							unit:    "seconds"
+						}
+					}
+				}
+			}
+		}
 		redis_key: {
 			common:      false
 			description: "The log field name to use for the redis key. If set to an empty string or null, the key is not added to the log event."
@@ -153,6 +211,18 @@ components: sources: redis: {
 	}
 
 	how_it_works: {
+		consumer_groups: {
+			title: "Consumer groups"
+			body:  """
+				With the `stream` data type, entries are read with `XREADGROUP` as a member of a consumer group, so
+				that several instances of Vector can share the entries of a stream. Entries are acknowledged with
+				`XACK` once they have been processed, or once they have been delivered by the sinks when
+				end-to-end acknowledgements are enabled.
+
+				Entries left pending for `claim_idle_secs`, whether because a consumer stopped or because their
+				delivery failed, are claimed with `XAUTOCLAIM` and read again. This requires Redis 6.2 or later.
+				"""
+		}
 		redis_rs: {
 			title: "redis-rs"
 			body:  """
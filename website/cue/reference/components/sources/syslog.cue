@@ -8,7 +8,7 @@ components: sources: syslog: {
 	classes: sources.socket.classes
 
 	features: {
-		acknowledgements: sources.socket.features.acknowledgements
+		acknowledgements: true
 
 		multiline: sources.socket.features.multiline
 
@@ -29,7 +29,7 @@ components: sources: syslog: {
 			}
 			receive_buffer_bytes: {
 				enabled:       true
-				relevant_when: "mode = `tcp` or mode = `udp`"
+				relevant_when: "mode = `tcp`, `relp` or `udp`"
 			}
 			keepalive: enabled: true
 			tls: sources.socket.features.receive.tls
@@ -49,9 +49,10 @@ components: sources: syslog: {
 	}
 
 	configuration: {
+		acknowledgements: configuration._source_acknowledgements
 		address: {
 			description:   "The address to listen for connections on, or `systemd#N` to use the Nth socket passed by systemd socket activation. If an address is used it _must_ include a port."
-			relevant_when: "mode = `tcp` or `udp`"
+			relevant_when: "mode = `tcp`, `relp` or `udp`"
 			required:      true
 			type: string: {
 				examples: ["0.0.0.0:\(_port)", "systemd", "systemd#3"]
@@ -83,6 +84,7 @@ components: sources: syslog: {
 			required:    true
 			type: string: {
 				enum: {
+					relp: "TCP socket speaking RELP (Reliable Event Logging Protocol)."
 					tcp:  "TCP socket."
 					udp:  "UDP socket."
 					unix: "Unix domain stream socket."
@@ -101,7 +103,7 @@ components: sources: syslog: {
 		connection_limit: {
 			common:        false
 			description:   "The max number of TCP connections that will be processed."
-			relevant_when: "mode = `tcp`"
+			relevant_when: "mode = `tcp` or `relp`"
 			required:      false
 			type: uint: {
 				default: null
@@ -246,6 +248,21 @@ components: sources: syslog: {
 				"""
 		}
 
+		relp: {
+			title: "RELP"
+			body:  """
+				In the `relp` mode, Vector accepts messages sent with the [Reliable Event Logging
+				Protocol](\(urls.relp)), as spoken by `rsyslog`'s `omrelp` module and many appliances.
+				Each message is answered with a RELP response once it has been accepted. When
+				acknowledgements are enabled, the response is only sent once the event has been
+				delivered to all connected sinks, and a failed delivery is answered with an error
+				response so that the client sends the message again.
+
+				TLS, as described in [RFC 5425](\(urls.syslog_5425)), is supported in both the `tcp`
+				and `relp` modes through the `tls` options.
+				"""
+		}
+
 		parsing: {
 			title: "Parsing"
 			body:  """
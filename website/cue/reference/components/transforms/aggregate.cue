@@ -173,6 +173,33 @@ components: transforms: aggregate: {
 				"""
 		}
 
+		tags: {
+			title: "Tags"
+			body: """
+				Tags can be dropped before metrics are aggregated, with either `keep_tags` or
+				`drop_tags`. Metrics whose series only differ by the dropped tags are then aggregated
+				together, for example to combine the metrics of all the pods of a service.
+				"""
+		}
+
+		gauges: {
+			title: "Gauges"
+			body: """
+				By default, the latest value of an `absolute` `gauge` is kept. With `gauge_mode`, the
+				lowest, highest, or mean value received during the interval can be kept instead.
+				"""
+		}
+
+		summaries: {
+			title: "Summaries"
+			body: """
+				When `quantiles` is set, `incremental` `distribution` metrics are turned into
+				`summary` metrics when flushed. The quantiles are estimated with a
+				[DDSketch](\(urls.ddsketch)), while the count and sum of the samples are exact.
+				Distributions without any samples have nothing to summarize, and aren't flushed.
+				"""
+		}
+
 		advantages: {
 			title: "Advantages of Use"
 			body: """
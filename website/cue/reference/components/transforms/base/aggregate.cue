@@ -1,11 +1,65 @@
 package metadata
 
-base: components: transforms: aggregate: configuration: interval_ms: {
-	description: """
-		The interval between flushes, in milliseconds.
-
-		Over this period metrics with the same series data (name, namespace, tags, …) will be aggregated.
-		"""
-	required: false
-	type: uint: default: 10000
+base: components: transforms: aggregate: configuration: {
+	drop_tags: {
+		description: """
+			The tags to drop from metrics before they are aggregated.
+
+			Metrics only differing by these tags are aggregated together. Can't be used along with
+			`keep_tags`.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: {
+				examples: ["pod_name"]
+				syntax: "literal"
+			}
+		}
+	}
+	gauge_mode: {
+		description: "How absolute gauges with the same series are aggregated."
+		required:    false
+		type: string: {
+			default: "latest"
+			enum: {
+				latest: "The latest value is kept."
+				max:    "The highest value is kept."
+				mean:   "The mean of the values is kept."
+				min:    "The lowest value is kept."
+			}
+		}
+	}
+	interval_ms: {
+		description: """
+			The interval between flushes, in milliseconds.
+
+			Over this period metrics with the same series data (name, namespace, tags, …) will be aggregated.
+			"""
+		required: false
+		type: uint: default: 10000
+	}
+	keep_tags: {
+		description: """
+			The tags to keep on metrics before they are aggregated.
+
+			All other tags are dropped, so that metrics only differing by them are aggregated together.
+			Can't be used along with `drop_tags`.
+			"""
+		required: false
+		type: array: items: type: string: {
+			examples: ["host"]
+			syntax: "literal"
+		}
+	}
+	quantiles: {
+		description: """
+			The quantiles to turn incremental distributions into summaries with.
+
+			When set, the samples of incremental distributions are summarized into these quantiles when
+			flushed, along with their count and sum. Each quantile must be between 0.0 and 1.0.
+			"""
+		required: false
+		type: array: items: type: float: {}
+	}
 }
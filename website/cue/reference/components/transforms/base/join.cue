@@ -0,0 +1,128 @@
+package metadata
+
+base: components: transforms: join: configuration: {
+	how: {
+		description: "How events that are not matched are handled."
+		required:    false
+		type: string: {
+			default: "inner"
+			enum: {
+				inner: "Only matched events are forwarded."
+				left: """
+					Left events are forwarded without being merged if no right event matched them within the
+					window.
+					"""
+			}
+		}
+	}
+	left: {
+		description: "Configuration of a side of the join."
+		required:    true
+		type: object: options: {
+			inputs: {
+				description: """
+					The inputs whose events are on this side of the join.
+
+					Each input of the transform must be on exactly one side.
+					"""
+				required: true
+				type: array: items: type: string: {
+					examples: ["requests"]
+					syntax: "literal"
+				}
+			}
+			key: {
+				description: """
+					The key that events are matched on.
+
+					Events from both sides are matched when their keys render to the same value.
+					"""
+				required: true
+				type: string: {
+					examples: ["{{ request_id }}"]
+					syntax: "template"
+				}
+			}
+		}
+	}
+	max_events_per_key: {
+		description: """
+			The maximum number of left events that are buffered for a single key.
+
+			Once reached, further left events with that key are handled as if no matching event was
+			received, so that at most `max_keys` times this many left events are buffered.
+			"""
+		required: false
+		type: uint: default: 1000
+	}
+	max_keys: {
+		description: """
+			The maximum number of keys that events are buffered for, on each side.
+
+			Once reached, events with a new key are handled as if no matching event was received.
+			"""
+		required: false
+		type: uint: default: 10000
+	}
+	merge: {
+		description: "How matched events are merged into a single event."
+		required:    false
+		type: string: {
+			default: "prefer_left"
+			enum: {
+				nested: "The left and right events are nested under the `left` and `right` fields."
+				prefer_left: """
+					The fields of the right event are added to the left event, keeping the values of the left
+					event for fields found in both.
+					"""
+				prefer_right: """
+					The fields of the right event are added to the left event, replacing the values of the
+					left event for fields found in both.
+					"""
+			}
+		}
+	}
+	right: {
+		description: "Configuration of a side of the join."
+		required:    true
+		type: object: options: {
+			inputs: {
+				description: """
+					The inputs whose events are on this side of the join.
+
+					Each input of the transform must be on exactly one side.
+					"""
+				required: true
+				type: array: items: type: string: {
+					examples: ["requests"]
+					syntax: "literal"
+				}
+			}
+			key: {
+				description: """
+					The key that events are matched on.
+
+					Events from both sides are matched when their keys render to the same value.
+					"""
+				required: true
+				type: string: {
+					examples: ["{{ request_id }}"]
+					syntax: "template"
+				}
+			}
+		}
+	}
+	window_secs: {
+		description: """
+			The period of time that events wait for a matching event from the other side, in seconds.
+
+			The window starts when the first event with a given key is received on a side, and a right
+			event can be matched by left events until its window ends.
+			"""
+		required: false
+		type: uint: {
+			default: 30
+			unit:    "seconds"
+		}
+	}
+}
@@ -0,0 +1,44 @@
+package metadata
+
+base: components: transforms: metric_convert: configuration: {
+	expire_after_secs: {
+		description: """
+			The period of time after which a series that has not been received is forgotten, in seconds.
+
+			The next metric of a forgotten series is handled as if it was the first one of the series.
+			"""
+		required: false
+		type: uint: {
+			default: 300
+			unit:    "seconds"
+		}
+	}
+	mode: {
+		description: """
+			How counters are converted.
+
+			Metrics other than counters are forwarded as they are.
+			"""
+		required: true
+		type: string: enum: {
+			absolute: """
+				Incremental counters are turned into absolute counters, holding the sum of the increments
+				received for their series.
+				"""
+			incremental: """
+				Absolute counters are turned into incremental counters, holding the increase since the
+				previous metric of their series.
+
+				The first metric of a series is only used as a reference. A counter lower than the previous
+				one is considered to have been reset, and its value is used as the increase.
+				"""
+			rate: """
+				Counters are turned into absolute gauges, holding their rate of increase per second since
+				the previous metric of their series.
+
+				The first metric of a series is only used as a reference. The time of a metric is its
+				timestamp, or the time it was received at if it has none.
+				"""
+		}
+	}
+}
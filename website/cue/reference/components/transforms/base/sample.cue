@@ -1,6 +1,40 @@
 package metadata
 
 base: components: transforms: sample: configuration: {
+	adaptive: {
+		description: """
+			Configuration for adapting the sampling rate to the volume of events.
+
+			Events are counted by their value of `key_field` over a sliding window, and each value is
+			sampled at a rate such that the events forwarded stay within a target throughput. Values seen
+			rarely are forwarded in full, while the most frequent values are sampled the hardest.
+
+			Exactly one of `rate` or `adaptive` must be set.
+			"""
+		required: false
+		type: object: options: {
+			max_keys: {
+				description: """
+					The maximum number of values of `key_field` that are tracked.
+
+					Once reached, events with a value that isn't tracked are sampled together, as if they all
+					had the same value.
+					"""
+				required: false
+				type: uint: default: 10000
+			}
+			target_events_per_second: {
+				description: "The number of events to forward per second, across all values of `key_field`."
+				required:    true
+				type: float: {}
+			}
+			window_secs: {
+				description: "The length of the sliding window over which events are counted, in seconds."
+				required:    false
+				type: uint: default: 30
+			}
+		}
+	}
 	exclude: {
 		description: "A logical condition used to exclude events from sampling."
 		required:    false
@@ -14,6 +48,8 @@ base: components: transforms: sample: configuration: {
 			Consistently samples the same events. Actual rate of sampling may differ from the configured
 			one if values in the field are not uniformly distributed. If left unspecified, or if the
 			event doesn’t have `key_field`, events will be count rated.
+
+			With `adaptive`, the rate is instead adapted to the volume of each value of the field.
 			"""
 		required: false
 		type: string: {
@@ -27,8 +63,10 @@ base: components: transforms: sample: configuration: {
 
 			For example, `rate = 10` means 1 out of every 10 events will be forwarded and the rest will
 			be dropped.
+
+			Exactly one of `rate` or `adaptive` must be set.
 			"""
-		required: true
+		required: false
 		type: uint: {}
 	}
 }
@@ -0,0 +1,101 @@
+package metadata
+
+base: components: transforms: trace_sampler: configuration: {
+	completion_timeout_ms: {
+		description: """
+			The period of time to wait after the last span of a trace is received, in milliseconds,
+			before the trace is considered complete and sampled.
+
+			Spans received after their trace has been sampled are buffered as a new trace.
+			"""
+		required: false
+		type: uint: {
+			default: 30000
+			unit:    "milliseconds"
+		}
+	}
+	flush_period_ms: {
+		description: "The interval to check for and sample any completed traces, in milliseconds."
+		required:    false
+		type: uint: {
+			default: 1000
+			unit:    "milliseconds"
+		}
+	}
+	max_traces: {
+		description: """
+			The maximum number of traces buffered at once.
+
+			Once reached, the trace whose first span was received the earliest is sampled before a new
+			trace is buffered.
+			"""
+		required: false
+		type: uint: default: 50000
+	}
+	policies: {
+		description: """
+			The policies deciding whether a trace is kept.
+
+			A trace is kept if any of the policies keeps it, and dropped otherwise. All the spans of a
+			trace are kept or dropped together.
+			"""
+		required: true
+		type: array: items: type: object: options: {
+			condition: {
+				description:   "The condition that a span must match for its trace to be kept."
+				relevant_when: "type = \"condition\""
+				required:      true
+				type: condition: {}
+			}
+			percentage: {
+				description: """
+					The percentage of traces that are kept, between `0` and `100`.
+
+					Traces are picked by hashing their ID, so the same traces are kept by every instance of
+					Vector sampling with the same percentage.
+					"""
+				relevant_when: "type = \"probabilistic\""
+				required:      true
+				type: float: {}
+			}
+			threshold_ms: {
+				description: """
+					The duration above which traces are kept, in milliseconds.
+
+					The duration of a trace is the one of its root span, which is the span without a
+					`parent_span_id`. If the root span was not received, it is the time between the earliest
+					`start_time` and the latest `end_time` of the spans.
+					"""
+				relevant_when: "type = \"latency\""
+				required:      true
+				type: uint: {}
+			}
+			type: {
+				required: true
+				type: string: enum: {
+					condition: "Keeps traces in which any span matches a condition."
+					error: """
+						Keeps traces in which any span ended with an error.
+
+						A span ended with an error if its `status.code` is `2`.
+						"""
+					latency:       "Keeps traces that lasted longer than a threshold."
+					probabilistic: "Keeps a percentage of traces."
+				}
+			}
+		}
+	}
+	trace_id_field: {
+		description: """
+			The name of the field containing the ID of the trace that a span belongs to.
+
+			Spans without this field are forwarded without being sampled.
+			"""
+		required: false
+		type: string: {
+			default: "trace_id"
+			examples: ["trace_id"]
+			syntax: "literal"
+		}
+	}
+}
@@ -0,0 +1,71 @@
+package metadata
+
+components: transforms: join: {
+	title: "Join"
+
+	description: """
+		Joins log events from two sets of inputs, merging each event of the left inputs with the event
+		of the right inputs that has the same key.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		reduce: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.join.configuration
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+	}
+
+	how_it_works: {
+		sides: {
+			title: "Sides"
+			body: """
+				Each input of the transform is on either the `left` or the `right` side of the join, and
+				must be listed in the `inputs` of its side. The inputs are matched by their exact name,
+				so wildcards can't be used.
+				"""
+		}
+		windows: {
+			title: "Windows"
+			body: """
+				Left events are buffered by key until a right event with the same key is received, or
+				until `window_secs` has passed since the first of them was received. The latest right
+				event received for each key is also kept for `window_secs`, so that it is merged with
+				the left events received after it. Unmatched left events are forwarded as they are with
+				the `left` join type, and dropped otherwise. Unmatched right events are always dropped.
+
+				At most `max_keys` keys are buffered for each side, and at most `max_events_per_key` left
+				events for each key. Events received once these limits are reached are handled as
+				unmatched.
+				"""
+		}
+		merging: {
+			title: "Merging"
+			body: """
+				A left event and the right event matching it are merged into a single event according to
+				`merge`. A right event can be merged with several left events.
+				"""
+		}
+	}
+}
@@ -0,0 +1,65 @@
+package metadata
+
+components: transforms: metric_convert: {
+	title: "Metric Convert"
+
+	description: """
+		Converts counters between incremental and absolute kinds, or into rates per second,
+		so that downstream components receive consistently shaped metrics.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		convert: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.metric_convert.configuration
+
+	input: {
+		logs: false
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: false
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+	}
+
+	how_it_works: {
+		state: {
+			title: "State"
+			body: """
+				The latest value and time of each counter series is kept to convert the next metric of
+				the series. Series that haven't been received for `expire_after_secs` are forgotten, so
+				that the state only holds the series that are still active.
+				"""
+		}
+		counter_resets: {
+			title: "Counter resets"
+			body: """
+				An absolute counter whose value is lower than the previous one is considered to have
+				been reset, for example by a restart of the process reporting it. Its whole value is
+				then used as its increase since the previous metric.
+				"""
+		}
+	}
+}
@@ -35,4 +35,24 @@ components: transforms: sample: {
 	telemetry: metrics: {
 		events_discarded_total: components.sources.internal_metrics.output.metrics.events_discarded_total
 	}
+
+	how_it_works: {
+		adaptive_sampling: {
+			title: "Adaptive sampling"
+			body: """
+				With `adaptive`, the number of events of each value of `key_field` is tracked over a
+				sliding window of `window_secs`. Every second, the rates are updated to share
+				`target_events_per_second` between the values, starting from the least frequent ones:
+				values that fit in their share are forwarded in full, and leave the rest of their share
+				to the more frequent values, which are sampled down to their share.
+
+				Values seen since the rates were last updated, and values beyond the `max_keys` tracked
+				ones, are sampled together as a single value against the rest of the budget, so that a
+				`key_field` with many distinct values doesn't exceed `target_events_per_second`.
+
+				Each forwarded event has its effective rate written to the `sample_rate` field, so that
+				downstream counts can be re-weighted by multiplying them by it.
+				"""
+		}
+	}
 }
@@ -0,0 +1,61 @@
+package metadata
+
+components: transforms: trace_sampler: {
+	title: "Trace Sampler"
+
+	description: """
+		Samples whole traces, keeping or dropping all of their spans together based on
+		sampling policies.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		filter: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.trace_sampler.configuration
+
+	input: {
+		logs:    false
+		metrics: null
+		traces:  true
+	}
+
+	telemetry: metrics: {
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+	}
+
+	how_it_works: {
+		buffering: {
+			title: "Buffering"
+			body: """
+				Spans are buffered by the ID of their trace, until no span of the trace has been
+				received for `completion_timeout_ms`. The policies are then applied to all of the spans
+				of the trace at once, so that they are forwarded or dropped together. Buffering delays
+				spans by at least `completion_timeout_ms`, and holds up to `max_traces` traces in
+				memory.
+				"""
+		}
+		span_layout: {
+			title: "Span layout"
+			body: """
+				Each span is expected to be a separate trace event, as decoded by the
+				[`opentelemetry` source](\(urls.vector_opentelemetry_source)). The `error` and
+				`latency` policies read the `status.code`, `parent_span_id`, `start_time`, and
+				`end_time` fields of the spans.
+				"""
+		}
+	}
+}
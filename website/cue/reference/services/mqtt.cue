@@ -0,0 +1,10 @@
+package metadata
+
+services: mqtt: {
+	name:     "MQTT"
+	thing:    "an \(name) broker"
+	url:      urls.mqtt
+	versions: null
+
+	description: "[MQTT](\(urls.mqtt)) is a lightweight publish/subscribe messaging protocol, commonly used to connect IoT devices. Messages are published to topics on a broker, which delivers them to the clients subscribed to those topics."
+}
@@ -137,6 +137,7 @@ urls: {
 	datadog_search_syntax:                      "\(datadog_docs)/logs/explorer/search_syntax/"
 	datadog_traces:                             "\(datadog_docs)/tracing/"
 	date:                                       "https://man7.org/linux/man-pages/man1/date.1.html"
+	ddsketch:                                   "https://www.vldb.org/pvldb/vol12/p2195-masson.pdf"
 	debian:                                     "https://www.debian.org/"
 	debian_system_groups:                       "https://wiki.debian.org/SystemGroups"
 	default_configuration:                      "\(vector_repo)/blob/master/config/vector.toml"
@@ -349,6 +350,8 @@ urls: {
 	mongodb:                                    "https://www.mongodb.com"
 	mongodb_command_server_status:              "https://docs.mongodb.com/manual/reference/command/serverStatus/"
 	mongodb_connection_string_uri_format:       "https://docs.mongodb.com/manual/reference/connection-string/"
+	mqtt:                                       "https://mqtt.org/"
+	mysql:                                      "https://www.mysql.com/"
 	musl_builder_docker_image:                  "\(vector_repo)/blob/master/scripts/ci-docker-images/builder-x86_64-unknown-linux-musl/Dockerfile"
 	native_proto_schema:                        "\(vector_repo)/blob/master/lib/vector-core/proto/event.proto"
 	native_json_schema:                         "\(vector_repo)/blob/master/lib/codecs/tests/data/native_encoding/schema.cue"
@@ -389,6 +392,7 @@ urls: {
 	percent_encoding_www_form_urlencoded:       "https://url.spec.whatwg.org/#application-x-www-form-urlencoded-percent-encode-set"
 	posix_acls:                                 "https://www.usenix.org/legacy/publications/library/proceedings/usenix03/tech/freenix03/full_papers/gruenbacher/gruenbacher_html/main.html"
 	postgresql:                                 "https://www.postgresql.org/"
+	postgresql_connection_uri:                  "https://www.postgresql.org/docs/current/libpq-connect.html#id-1.7.3.8.3.6"
 	postgresql_csvlog:                          "https://www.postgresql.org/docs/current/runtime-config-logging.html#RUNTIME-CONFIG-LOGGING-CSVLOG"
 	postgresql_matching:                        "https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP"
 	procfs:                                     "https://en.wikipedia.org/wiki/Procfs#:~:text=The%20proc%20filesystem%20(procfs)%20is,in%20the%20kernel%20than%20traditional"
@@ -414,6 +418,7 @@ urls: {
 	regex:                                      "\(wikipedia)/wiki/Regular_expression"
 	regex_grouping_and_flags:                   "https://docs.rs/regex/latest/regex/#grouping-and-flags"
 	regex_tester:                               "https://rustexp.lpil.uk/"
+	relp:                                       "https://www.rsyslog.com/doc/master/configuration/modules/omrelp.html"
 	rfc_768:                                    "https://tools.ietf.org/html/rfc768"
 	rfc_791:                                    "https://tools.ietf.org/html/rfc791"
 	rfc_793:                                    "https://tools.ietf.org/html/rfc793"
@@ -429,6 +434,7 @@ urls: {
 	rfc_6891:                                   "https://tools.ietf.org/html/rfc6891"
 	rhel:                                       "https://www.redhat.com/en/technologies/linux-platforms/enterprise-linux"
 	rpm:                                        "https://rpm.org/"
+	rumqttc:                                    "\(github)/bytebeamio/rumqtt"
 	rust:                                       "https://www.rust-lang.org/"
 	rust_date_time:                             "https://docs.rs/chrono/latest/chrono/struct.DateTime.html"
 	rust_grok_library:                          "\(github)/daschl/grok"
@@ -483,6 +489,7 @@ urls: {
 	syslog:                                     "\(wikipedia)/wiki/Syslog"
 	syslog_3164:                                "https://tools.ietf.org/html/rfc3164"
 	syslog_5424:                                "https://tools.ietf.org/html/rfc5424"
+	syslog_5425:                                "https://tools.ietf.org/html/rfc5425"
 	syslog_6587:                                "https://tools.ietf.org/html/rfc6587"
 	syslog_facility:                            "\(wikipedia)/wiki/Syslog#Facility"
 	syslog_levels:                              "\(wikipedia)/wiki/Syslog#Severity_level"
@@ -565,6 +572,7 @@ urls: {
 	vector_new_relic_sink:                      "/docs/reference/configuration/sinks/new_relic/"
 	vector_nightly_builds:                      "https://packages.timber.io/vector/nightly/latest/"
 	vector_nix_package:                         "\(github)/NixOS/nixpkgs/blob/master/pkgs/tools/misc/vector/default.nix"
+	vector_opentelemetry_source:                "/docs/reference/configuration/sources/opentelemetry/"
 	vector_packages_root:                       "https://packages.timber.io"
 	vector_parsing_transforms:                  "/components/?functions%5B%5D=parse"
 	vector_performance:                         "\(vector_repo)/#performance"